@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+use case::CaseExt;
+
+use tl_parser::types::{TLTokenGroup, TLTokenGroupType};
+
+use crate::tokenwrap::TokenWrap;
+
+/// The functions whose description returns another type than the declaration, `--check-blood`.
+///
+/// The return type of a function is read from its declaration, `getChat chat_id:int53 = Chat;`, never from the
+/// description; the description is only checked against it: the sentence after `Returns` names no word of the
+/// declared type and names another type of the schema, e.g. `Returns the chats` of a function returning `Users`.
+pub fn cross_check(tknwrap: &TokenWrap) -> Vec<String> {
+  let names: HashSet<String> = tknwrap.tokens().iter()
+    .flat_map(|token| vec![Some(token.name()), token.blood()])
+    .flatten()
+    .map(|name| name.to_lowercase())
+    .collect();
+  tknwrap.tokens().iter()
+    .filter(|token| token.type_() == TLTokenGroupType::Function)
+    .filter_map(|token| self::mismatch(token, &names))
+    .collect()
+}
+
+/// `function -> Declared, documented "phrase"` if the description returns another type
+fn mismatch(token: &TLTokenGroup, names: &HashSet<String>) -> Option<String> {
+  let declared = token.blood()?;
+  let returns = self::returns(&token.description()?)?;
+  let words: Vec<String> = returns.split(|c: char| !c.is_alphanumeric())
+    .filter(|word| !word.is_empty())
+    .map(|word| word.to_lowercase())
+    .collect();
+
+  let declared_words: Vec<String> = declared.to_snake().split('_').map(|word| word.to_string()).collect();
+  if words.iter().any(|word| declared_words.iter().any(|declared| self::same_word(word, declared))) {
+    return None;
+  }
+  let declared = declared.to_lowercase();
+  let other = words.iter().any(|word| {
+    let singular = word.strip_suffix('s').unwrap_or(word);
+    [&word[..], singular].iter().any(|name| *name != declared && names.contains(*name))
+  });
+  if !other { return None; }
+  Some(format!("{} -> {}, documented \"{}\"", token.name(), token.blood()?, returns))
+}
+
+/// the sentence after `Returns` of a description
+fn returns(description: &str) -> Option<String> {
+  let index = description.find("Returns ").or_else(|| description.find("returns "))?;
+  let sentence = &description[index + "Returns ".len()..];
+  let end = sentence.find(['.', ';']).unwrap_or(sentence.len());
+  Some(sentence[..end].trim().to_string())
+}
+
+/// a word of a description and a word of a type, singular or plural
+fn same_word(word: &str, type_word: &str) -> bool {
+  let singular = |word: &str| -> String {
+    if let Some(stem) = word.strip_suffix("ies") { return format!("{}y", stem); }
+    if let Some(stem) = word.strip_suffix("es") { if stem.ends_with('s') || stem.ends_with('x') { return stem.to_string(); } }
+    word.strip_suffix('s').unwrap_or(word).to_string()
+  };
+  word == type_word || singular(word) == singular(type_word)
+}
@@ -0,0 +1,113 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use colored::Colorize;
+
+use crate::cycle::Config;
+use crate::golden;
+
+/// lines of a hunk shown
+const HUNK_LINES: usize = 12;
+
+/// Regenerate the crates in a temporary directory and report the drift of an existing crate, e.g. a published
+/// rtdlib, per file with its diff; an error if any file differs.
+///
+/// The generated crate is chosen by the package name of the existing crate, or by `crate_name`; a fork is
+/// checked with its `extra_templates`.
+pub fn check<P: AsRef<Path>>(against: P, crate_name: Option<&str>, extra_templates: Option<PathBuf>) -> Result<(), failure::Error> {
+  let against = against.as_ref();
+  let crate_name = match crate_name {
+    Some(name) => name.to_string(),
+    None => self::package_name(against)?,
+  };
+  let path_out = std::env::temp_dir().join(format!("fantasy-check-{}", std::process::id()));
+  if path_out.exists() {
+    std::fs::remove_dir_all(&path_out)?;
+  }
+  let file_tl = Path::new("./").join("schema/master/td_api.tl");
+  let config: Config = Config::builder()
+    .path_rtd(path_out.join("rtdlib"))
+    .path_rtd_types(Some(path_out.join("rtdlib-types")))
+    .path_telegram_client(path_out.join("telegram-client"))
+    .path_telegram_grpc(Some(path_out.join("telegram-grpc")))
+    .path_template(Path::new("./").join("template"))
+    .path_extra_templates(extra_templates)
+    .tdlib_version(crate::schema_version(&file_tl, None))
+    .schema_commit(crate::schema_commit(&file_tl))
+    .schema_source(crate::schema_source(&file_tl))
+    .file_tl(file_tl)
+    .build();
+  let cycle = crate::cycle(config)?;
+  crate::generate(&cycle)?;
+
+  let result = match cycle.layout().crate_layout(&crate_name) {
+    Ok(layout) => self::compare(&path_out.join(&crate_name), against, layout.clear()),
+    Err(_) => Err(format_err!("fantasy doesn't generate the crate {}, set it by --crate: rtdlib, rtdlib-types, telegram-client or telegram-grpc", crate_name)),
+  };
+  std::fs::remove_dir_all(&path_out)?;
+  result
+}
+
+/// the package name in the Cargo.toml of the crate
+fn package_name(path: &Path) -> Result<String, failure::Error> {
+  let manifest: toml::Value = toml::from_str(&std::fs::read_to_string(path.join("Cargo.toml"))?)?;
+  match manifest.get("package").and_then(|package| package.get("name")).and_then(|name| name.as_str()) {
+    Some(name) => Ok(name.to_string()),
+    None => bail!("No package name in {:?}, set it by --crate", path.join("Cargo.toml")),
+  }
+}
+
+/// the files in the dirs the generator clears, a file of the crate in these is expected to be generated
+fn compare(generated_dir: &Path, against: &Path, dirs: &[String]) -> Result<(), failure::Error> {
+  let mut generated = BTreeSet::new();
+  golden::files(generated_dir, Path::new(""), &mut generated)?;
+  let mut existing = BTreeSet::new();
+  for dir in dirs {
+    golden::files(against, Path::new(dir), &mut existing)?;
+  }
+
+  let mut drifts = 0;
+  for file in generated.union(&existing) {
+    let path: &PathBuf = file;
+    let drift = match (against.join(path).is_file(), generated.contains(path)) {
+      (false, _) => Some(format!("{} {}", "A".green(), "generated, not in the crate")),
+      (true, false) => Some(format!("{} {}", "D".red(), "in the crate, not generated")),
+      (true, true) => {
+        let existing = std::fs::read_to_string(against.join(path))?;
+        let generated = std::fs::read_to_string(generated_dir.join(path))?;
+        self::hunk(&existing, &generated).map(|hunk| format!("{}\n{}", "M".yellow(), hunk))
+      }
+    };
+    if let Some(drift) = drift {
+      drifts += 1;
+      println!("{} {}", path.to_string_lossy().bold(), drift);
+    }
+  }
+  if drifts > 0 {
+    bail!("{} files of {} drifted from the templates and the schema, regenerate it", drifts, against.to_string_lossy());
+  }
+  info!("{} is up to date with the templates and the schema", against.to_string_lossy());
+  Ok(())
+}
+
+/// the lines changed between the common start and the common end, `None` if same
+fn hunk(existing: &str, generated: &str) -> Option<String> {
+  if existing == generated { return None; }
+  let existing: Vec<&str> = existing.lines().collect();
+  let generated: Vec<&str> = generated.lines().collect();
+  let start = existing.iter().zip(generated.iter()).take_while(|(a, b)| a == b).count();
+  let end = existing[start..].iter().rev().zip(generated[start..].iter().rev()).take_while(|(a, b)| a == b).count();
+  let removed = &existing[start..existing.len() - end];
+  let added = &generated[start..generated.len() - end];
+  let mut hunk = vec![format!("@@ -{},{} +{},{} @@", start + 1, removed.len(), start + 1, added.len())];
+  for (sign, lines) in &[("-", removed), ("+", added)] {
+    for line in lines.iter().take(HUNK_LINES) {
+      let line = format!("{}{}", sign, line);
+      hunk.push(if *sign == "-" { line.red().to_string() } else { line.green().to_string() });
+    }
+    if lines.len() > HUNK_LINES {
+      hunk.push(format!("{} ... {} more lines", sign, lines.len() - HUNK_LINES));
+    }
+  }
+  Some(hunk.join("\n"))
+}
@@ -1,10 +1,15 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use colored::Colorize;
+use rayon::prelude::*;
 use tera::{Context, Tera};
 
-use tl_parser::types::TLTokenGroup;
+use tl_parser::types::{TLTokenGroup, TLUnknown};
 
+use crate::layout::Layout;
+use crate::output::Output;
 use crate::TokenWrap;
 
 /// fantasy config
@@ -12,19 +17,73 @@ use crate::TokenWrap;
 pub struct Config {
   /// rtdlib project root path
   path_rtd: PathBuf,
+  /// types only crate root path, not generated if none
+  #[builder(default)]
+  path_rtd_types: Option<PathBuf>,
   /// telegram client project root path
   path_telegram_client: PathBuf,
-  /// tl schema file path
+  /// gRPC gateway crate root path, not generated if none
+  #[builder(default)]
+  path_telegram_grpc: Option<PathBuf>,
+  /// tl schema file path, or the json ir of a schema
   file_tl: PathBuf,
   /// template projct path
   path_template: PathBuf,
+  /// user templates overriding the templates of the same path or adding new ones, none if not set
+  #[builder(default)]
+  path_extra_templates: Option<PathBuf>,
+  /// tdlib version of the tl schema
+  tdlib_version: String,
+  /// last commit of the tl schema file
+  schema_commit: String,
+  /// url a fetched tl schema is downloaded from, empty if not fetched
+  #[builder(default)]
+  schema_source: String,
+  /// format the rust files rendered by rustfmt
+  #[builder(default)]
+  rustfmt: bool,
+  /// write all files, not only the ones changed since the last generation
+  #[builder(default)]
+  touch_all: bool,
+  /// fail on the schema constructs the parser doesn't understand, instead of listing them in the report
+  #[builder(default)]
+  strict: bool,
+  /// cross-check the return type of the functions against their description
+  #[builder(default)]
+  check_blood: bool,
+  /// functions and types kept besides the `keep` of `[select]`
+  #[builder(default)]
+  keep: Vec<String>,
+  /// functions and types skipped besides the `skip` of `[select]`
+  #[builder(default)]
+  skip: Vec<String>,
 }
 
 impl Config {
+  /// the template dirs, a file of the extra templates overrides the same file of the templates
+  pub fn template_dirs(&self) -> Vec<&PathBuf> {
+    let mut dirs = vec![&self.path_template];
+    dirs.extend(self.path_extra_templates.as_ref());
+    dirs
+  }
+
+
   pub fn path_rtd             (&self) -> &PathBuf { &self.path_rtd             }
+  pub fn path_rtd_types       (&self) -> Option<&PathBuf> { self.path_rtd_types.as_ref() }
   pub fn path_telegram_client (&self) -> &PathBuf { &self.path_telegram_client }
+  pub fn path_telegram_grpc   (&self) -> Option<&PathBuf> { self.path_telegram_grpc.as_ref() }
   pub fn file_tl              (&self) -> &PathBuf { &self.file_tl              }
   pub fn path_template        (&self) -> &PathBuf { &self.path_template        }
+  pub fn path_extra_templates (&self) -> Option<&PathBuf> { self.path_extra_templates.as_ref() }
+  pub fn tdlib_version        (&self) -> &String  { &self.tdlib_version        }
+  pub fn schema_commit        (&self) -> &String  { &self.schema_commit        }
+  pub fn schema_source        (&self) -> &String  { &self.schema_source        }
+  pub fn rustfmt              (&self) -> bool     { self.rustfmt               }
+  pub fn touch_all            (&self) -> bool     { self.touch_all             }
+  pub fn strict               (&self) -> bool     { self.strict                }
+  pub fn check_blood          (&self) -> bool     { self.check_blood           }
+  pub fn keep                 (&self) -> &Vec<String> { &self.keep         }
+  pub fn skip                 (&self) -> &Vec<String> { &self.skip         }
 }
 
 /// cycle
@@ -35,46 +94,117 @@ pub struct Cycle {
   renderer: Renderer,
   /// token wrap
   tknwrap: TokenWrap,
+  /// layout of the generated crates
+  layout: Layout,
+  /// the tokens of the schema removed by `[select]`
+  #[builder(default)]
+  unselected: Vec<String>,
+  /// the schema constructs the parser drops or misreads, none for an ir
+  #[builder(default)]
+  unknowns: Vec<TLUnknown>,
+  /// the functions whose description returns another type than the declaration, by `--check-blood`
+  #[builder(default)]
+  blood_mismatches: Vec<String>,
 }
 
 impl Cycle {
   pub fn config  (&self)      -> &Config   { &self.config   }
   pub fn tknwrap (&self)      -> &TokenWrap{ &self.tknwrap  }
   pub fn renderer(&self)      -> &Renderer { &self.renderer }
+  pub fn layout  (&self)      -> &Layout   { &self.layout   }
+  pub fn unselected(&self)    -> &Vec<String> { &self.unselected }
+  pub fn unknowns(&self)      -> &Vec<TLUnknown> { &self.unknowns }
+  pub fn blood_mismatches(&self) -> &Vec<String> { &self.blood_mismatches }
 }
 
 
 #[derive(Debug, TypedBuilder)]
 pub struct Renderer {
-  tera: Tera
+  tera: Tera,
+  /// files written by the templates
+  #[builder(default)]
+  rendered: Mutex<BTreeSet<PathBuf>>,
+  /// the templates appended to each file
+  #[builder(default)]
+  sources: Mutex<BTreeMap<PathBuf, Vec<Source>>>,
+  /// the files of the crates, written by `flush`
+  #[builder(default)]
+  output: Output,
+}
+
+/// a template appended to a file, the line of the file its body starts at and its lines
+#[derive(Debug)]
+struct Source {
+  template: String,
+  start: usize,
+  lines: usize,
 }
 
 impl Renderer {
+  /// the files written by the templates, not the files copied
+  pub fn rendered(&self) -> Vec<PathBuf> {
+    self.rendered.lock().unwrap().iter().cloned().collect()
+  }
+
+  pub fn output(&self) -> &Output { &self.output }
+
+  /// the template rendered to this line of a file and the line in its body
+  pub fn source<P: AsRef<Path>>(&self, file: P, line: usize) -> Option<(String, usize)> {
+    let sources = self.sources.lock().unwrap();
+    sources.get(file.as_ref())?
+      .iter()
+      .rev()
+      .find(|source| source.start <= line)
+      .map(|source| (source.template.clone(), line - source.start + 1))
+  }
+
   pub fn render<S: AsRef<str>, P: AsRef<Path>>(&self, tpl_file: S, write_to: P, context: &mut Context) -> Result<(), failure::Error> {
-    let write_to = write_to.as_ref();
-    let tpl_file = tpl_file.as_ref();
-
-    let mut first_write = false;
-    if !write_to.exists() {
-      let write_dir = write_to.parent();
-      if write_dir.is_none() { return bail!("Cant not get write dir"); }
-      let write_dir = write_dir.unwrap();
-      if !write_dir.exists() {
-        std::fs::create_dir_all(write_dir)?;
-      }
-      first_write = true;
-    }
+    let first_write = !self.output.contains(write_to.as_ref());
     context.insert("first_write", &first_write);
+    let body = self.body(tpl_file.as_ref(), context)?;
+    self.append(tpl_file.as_ref(), write_to.as_ref(), body);
+    Ok(())
+  }
 
-    match self.tera.render(tpl_file.as_ref(), context) {
-      Ok(body) => {
-        debug!("USE TEMPLATE [{}] WRITE TO [{}]", tpl_file.blue(), write_to.to_str().map_or("", |v| v).blue());
-        toolkit::fs::append(write_to, body)?;
-        Ok(())
-      },
-      Err(e) => bail!("Tera template fail: {:?}", e)
+  /// render the jobs, a template, the file it's appended to and its context, on the rayon pool; the bodies are
+  /// appended in the order of the jobs, so the files are the same as rendered one by one
+  pub fn render_all(&self, jobs: Vec<(String, PathBuf, Context)>) -> Result<(), failure::Error> {
+    let mut files = BTreeSet::new();
+    let jobs: Vec<(String, PathBuf, Context)> = jobs.into_iter()
+      .map(|(tpl_file, write_to, mut context)| {
+        let first_write = !self.output.contains(&write_to) && files.insert(write_to.clone());
+        context.insert("first_write", &first_write);
+        (tpl_file, write_to, context)
+      })
+      .collect();
+    let bodies: Vec<Result<String, failure::Error>> = jobs.par_iter()
+      .map(|(tpl_file, _, context)| self.body(tpl_file, context))
+      .collect();
+    for ((tpl_file, write_to, _), body) in jobs.iter().zip(bodies) {
+      self.append(tpl_file, write_to, body?);
     }
+    Ok(())
   }
-}
 
+  fn body(&self, tpl_file: &str, context: &Context) -> Result<String, failure::Error> {
+    match self.tera.render(tpl_file, context) {
+      Ok(body) => Ok(body),
+      Err(e) => bail!("Tera template fail: {:?}", e)
+    }
+  }
 
+  fn append(&self, tpl_file: &str, write_to: &Path, body: String) {
+    debug!("USE TEMPLATE [{}] WRITE TO [{}]", tpl_file.blue(), write_to.to_str().map_or("", |v| v).blue());
+    {
+      let mut sources = self.sources.lock().unwrap();
+      let chunks = sources.entry(write_to.to_path_buf()).or_default();
+      let start = match chunks.last() {
+        Some(source) => source.start + source.lines,
+        None => self.output.read(write_to).map_or(0, |content| content.matches('\n').count()) + 1,
+      };
+      chunks.push(Source { template: tpl_file.to_string(), start, lines: body.matches('\n').count() + 1 });
+    }
+    self.output.append(write_to, body);
+    self.rendered.lock().unwrap().insert(write_to.to_path_buf());
+  }
+}
@@ -0,0 +1,81 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use colored::Colorize;
+
+/// the tdlib repository the schemas are fetched from
+const TDLIB_REPO: &str = "https://github.com/tdlib/td";
+/// the schema in the tdlib repository, with its docs
+const TDLIB_SCHEMA: &str = "td/generate/scheme/td_api.tl";
+/// where a fetched schema came from, next to the schema
+pub const SOURCE_FILE: &str = "source.toml";
+
+/// where a fetched schema came from, `source.toml`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaSource {
+  /// tdlib tag, e.g. `v1.8.29`
+  tag: String,
+  /// tdlib commit of the tag
+  commit: String,
+  /// url the schema is downloaded from
+  url: String,
+}
+
+impl SchemaSource {
+  /// the source of a schema file, none if it isn't fetched
+  pub fn of<P: AsRef<Path>>(file_tl: P) -> Option<Self> {
+    let file = file_tl.as_ref().parent()?.join(SOURCE_FILE);
+    toml::from_str(&std::fs::read_to_string(file).ok()?).ok()
+  }
+
+  pub fn url(&self) -> &String { &self.url }
+}
+
+/// Download the schema of a tdlib tag to `schema/v<version>/td_api.tl`, a schema already downloaded is used
+/// unless `force`; `source.toml` next to it records the tag, its commit and the url.
+pub fn fetch(tag: &str, force: bool) -> Result<PathBuf, failure::Error> {
+  let tag = if tag.starts_with('v') { tag.to_string() } else { format!("v{}", tag) };
+  let dir = Path::new("./").join("schema").join(&tag);
+  let file_tl = dir.join("td_api.tl");
+  if file_tl.is_file() && !force {
+    info!("{} is cached, fetch it again by --force", file_tl.to_string_lossy());
+    return Ok(file_tl);
+  }
+
+  let commit = self::tag_commit(&tag)?;
+  let url = format!("https://raw.githubusercontent.com/tdlib/td/{}/{}", commit, TDLIB_SCHEMA);
+  std::fs::create_dir_all(&dir)?;
+  // downloaded aside, a failed download doesn't leave a broken schema in the cache
+  let part = dir.join("td_api.tl.part");
+  let status = Command::new("curl").args(["-fsSL", "-o"]).arg(&part).arg(&url).status()?;
+  if !status.success() {
+    let _ = std::fs::remove_file(&part);
+    bail!("Can not download {}: curl {}", url, status);
+  }
+  std::fs::rename(&part, &file_tl)?;
+
+  let source = SchemaSource { tag, commit, url };
+  std::fs::write(dir.join(SOURCE_FILE), toml::to_string(&source)?)?;
+  info!("{} fetched from {}", file_tl.to_string_lossy().green(), source.url);
+  Ok(file_tl)
+}
+
+/// the commit of a tdlib tag, the commit of an annotated tag is its peeled line
+fn tag_commit(tag: &str) -> Result<String, failure::Error> {
+  let output = Command::new("git")
+    .args(["ls-remote", TDLIB_REPO, &format!("refs/tags/{}", tag), &format!("refs/tags/{}^{{}}", tag)])
+    .output()?;
+  if !output.status.success() {
+    bail!("Can not list the tags of {}: {}", TDLIB_REPO, String::from_utf8_lossy(&output.stderr).trim());
+  }
+  let refs = String::from_utf8(output.stdout)?;
+  let mut lines: Vec<(&str, &str)> = refs.lines().filter_map(|line| {
+    let mut parts = line.split_whitespace();
+    Some((parts.next()?, parts.next()?))
+  }).collect();
+  lines.sort_by_key(|(_, name)| !name.ends_with("^{}"));
+  match lines.first() {
+    Some((commit, _)) => Ok(commit.to_string()),
+    None => bail!("No tag {} in {}", tag, TDLIB_REPO),
+  }
+}
@@ -0,0 +1,114 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use colored::Colorize;
+
+use crate::cycle::Config;
+
+/// frozen schema the golden files are rendered against
+const GOLDEN_TL: &str = "schema/golden/td_api.tl";
+/// the checked in files rendered from the templates
+const GOLDEN_EXPECTED: &str = "schema/golden/expected";
+
+/// Render all templates against the frozen schema and compare the files with the golden files, `bless`
+/// writes the rendered files as the golden files instead, after an intended change of the templates.
+pub fn check(bless: bool) -> Result<(), failure::Error> {
+  let path_out = std::env::temp_dir().join(format!("fantasy-golden-{}", std::process::id()));
+  if path_out.exists() {
+    std::fs::remove_dir_all(&path_out)?;
+  }
+  let config: Config = Config::builder()
+    .path_rtd(path_out.join("rtdlib"))
+    .path_rtd_types(Some(path_out.join("rtdlib-types")))
+    .path_telegram_client(path_out.join("telegram-client"))
+    .path_telegram_grpc(Some(path_out.join("telegram-grpc")))
+    .path_template(Path::new("./").join("template"))
+    // fixed, the golden files don't change with the master schema
+    .tdlib_version("0.0.0".to_string())
+    .schema_commit("".to_string())
+    .file_tl(Path::new("./").join(GOLDEN_TL))
+    .build();
+  let cycle = crate::cycle(config)?;
+  crate::generate(&cycle)?;
+
+  let rendered: BTreeSet<PathBuf> = cycle.renderer().rendered().iter()
+    .filter_map(|path| path.strip_prefix(&path_out).ok().map(|path| path.to_path_buf()))
+    .collect();
+  let result = if bless {
+    self::bless(&path_out, &rendered)
+  } else {
+    self::compare(&path_out, &rendered)
+  };
+  std::fs::remove_dir_all(&path_out)?;
+  result
+}
+
+fn bless(path_out: &Path, rendered: &BTreeSet<PathBuf>) -> Result<(), failure::Error> {
+  let expected = Path::new(GOLDEN_EXPECTED);
+  if expected.exists() {
+    std::fs::remove_dir_all(expected)?;
+  }
+  for file in rendered {
+    let to = expected.join(file);
+    if let Some(dir) = to.parent() {
+      std::fs::create_dir_all(dir)?;
+    }
+    std::fs::copy(path_out.join(file), to)?;
+  }
+  info!("{} golden files written to {}", rendered.len(), GOLDEN_EXPECTED.green());
+  Ok(())
+}
+
+fn compare(path_out: &Path, rendered: &BTreeSet<PathBuf>) -> Result<(), failure::Error> {
+  let expected_dir = Path::new(GOLDEN_EXPECTED);
+  let mut expected = BTreeSet::new();
+  self::files(expected_dir, Path::new(""), &mut expected)?;
+
+  let mut diffs = 0;
+  for file in rendered.union(&expected) {
+    let diff = match (rendered.contains(file), expected.contains(file)) {
+      (true, false) => Some("new file, not in the golden files".to_string()),
+      (false, true) => Some("golden file not rendered anymore".to_string()),
+      _ => self::diff(&std::fs::read_to_string(expected_dir.join(file))?, &std::fs::read_to_string(path_out.join(file))?),
+    };
+    if let Some(diff) = diff {
+      diffs += 1;
+      error!("{} {}", file.to_string_lossy().red(), diff);
+    }
+  }
+  if diffs > 0 {
+    bail!("{} golden files differ, run `cargo run -- golden --bless` if the change is intended", diffs);
+  }
+  info!("{} golden files are the same", rendered.len());
+  Ok(())
+}
+
+/// the first line differing, `None` if same
+fn diff(expected: &str, rendered: &str) -> Option<String> {
+  if expected == rendered { return None; }
+  let (mut expected_lines, mut rendered_lines) = (expected.lines(), rendered.lines());
+  let mut number = 0;
+  loop {
+    number += 1;
+    match (expected_lines.next(), rendered_lines.next()) {
+      (Some(a), Some(b)) if a == b => continue,
+      (a, b) => return Some(format!("line {}\n  - {}\n  + {}", number, a.unwrap_or("<end>"), b.unwrap_or("<end>"))),
+    }
+  }
+}
+
+/// all files in this dir, relative to the root dir
+pub(crate) fn files(root: &Path, dir: &Path, files: &mut BTreeSet<PathBuf>) -> Result<(), failure::Error> {
+  let path = root.join(dir);
+  if !path.is_dir() { return Ok(()); }
+  for entry in path.read_dir()? {
+    let entry = entry?;
+    let relative = dir.join(entry.file_name());
+    if entry.path().is_dir() {
+      self::files(root, &relative, files)?;
+    } else {
+      files.insert(relative);
+    }
+  }
+  Ok(())
+}
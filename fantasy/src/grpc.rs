@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use tera::Context;
+
+use tl_parser::types::{TLTokenArgType, TLTokenComponentType, TLTokenGroup};
+
+use crate::Cycle;
+use crate::layout;
+
+/// gRPC gateway, a `.proto` of tdlib functions and types and a tonic server wrapping telegram-client
+pub struct Grpc<'a> {
+  cycle: &'a Cycle,
+  path_out: PathBuf,
+}
+
+/// message of a vector of a vector, proto has no repeated of repeated
+#[derive(Debug, Clone, Serialize)]
+struct ProtoVector {
+  name: String,
+  item: String,
+}
+
+impl<'a> Grpc<'a> {
+  pub fn new<P: AsRef<Path>>(cycle: &'a Cycle, path_out: P) -> Self {
+    Self { cycle, path_out: path_out.as_ref().to_path_buf() }
+  }
+
+  pub fn generate(&self) -> Result<(), failure::Error> {
+    let path_template = self.cycle.config().path_template().join("telegram-grpc");
+    if !path_template.is_dir() {
+      bail!("gRPC template path is not dir -> {:?}", path_template);
+    }
+
+    let layout = self.cycle.layout().crate_layout("telegram-grpc")?;
+    let renderer = self.cycle.renderer();
+    layout.copy_files(&self.cycle.config().template_dirs(), &self.path_out, renderer.output())?;
+
+    let tknwrap = self.cycle.tknwrap();
+    let tokens: Vec<&TLTokenGroup> = tknwrap.tokens().iter()
+      .filter(|token| !tknwrap.is_skip_type(token.name()))
+      .collect();
+
+    let mut context = Context::new();
+    context.insert("tokens", &tokens);
+    context.insert("vectors", &self.vectors(&tokens));
+    context.insert("tdlib_version", self.cycle.config().tdlib_version());
+
+    for template in layout.render() {
+      renderer.render(template, self.path_out.join(layout::out_path(template)), &mut context)?;
+    }
+    renderer.output().flush(&self.path_out, layout, self.cycle.config().touch_all())
+  }
+
+  /// the messages of the vectors of vectors used by the arguments
+  fn vectors(&self, tokens: &[&TLTokenGroup]) -> Vec<ProtoVector> {
+    let mut vectors = BTreeMap::new();
+    for token in tokens {
+      for arg in token.arguments() {
+        if arg.sign_type() != "vector" { continue }
+        self::vector_messages(&arg.components(), &mut vectors);
+      }
+    }
+    vectors.into_iter().map(|(name, item)| ProtoVector { name, item }).collect()
+  }
+}
+
+/// the messages of the vectors in the items of a vector, of any depth
+fn vector_messages(items: &[TLTokenComponentType], vectors: &mut BTreeMap<String, String>) {
+  for item in items.iter().filter(|item| item.sign_type() == "vector") {
+    let inner = item.components();
+    vectors.insert(proto_item(item), inner.first().map_or("bytes".to_string(), proto_item));
+    self::vector_messages(&inner, vectors);
+  }
+}
+
+
+/// words of a td name split like prost splits them, e.g. `deviceTokenApplePushVoIP` -> device, Token, Apple, Push, Vo, IP
+fn proto_words(name: &str) -> Vec<String> {
+  let chars: Vec<char> = name.chars().collect();
+  let mut words: Vec<String> = vec![];
+  let mut word = String::new();
+  for (i, &c) in chars.iter().enumerate() {
+    let prev = if i > 0 { Some(chars[i - 1]) } else { None };
+    let next = chars.get(i + 1);
+    let boundary = match prev {
+      Some(prev) if c.is_uppercase() => {
+        prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next.is_some_and(|n| n.is_lowercase()))
+      }
+      _ => c == '_',
+    };
+    if boundary && !word.is_empty() {
+      words.push(word);
+      word = String::new();
+    }
+    if c != '_' { word.push(c); }
+  }
+  if !word.is_empty() { words.push(word); }
+  words
+}
+
+/// proto message name, the same as the rust name generated by prost, e.g. `textParseModeHTML` -> `TextParseModeHtml`
+pub fn proto_camel(name: &str) -> String {
+  proto_words(name).iter().map(|word| {
+    let mut chars = word.chars();
+    match chars.next() {
+      Some(first) => first.to_uppercase().chain(chars.flat_map(|c| c.to_lowercase())).collect(),
+      None => String::new(),
+    }
+  }).collect()
+}
+
+/// snake name generated by prost for a proto name, e.g. the module of the oneof of a message
+pub fn proto_snake(name: &str) -> String {
+  proto_words(name).iter().map(|word| word.to_lowercase()).collect::<Vec<String>>().join("_")
+}
+
+/// proto type of a td type which isn't a vector
+fn proto_type_name(sign_type: &str) -> String {
+  match sign_type {
+    "double" => "double".to_string(),
+    "string" => "string".to_string(),
+    "int32" => "int32".to_string(),
+    "int53" | "int64" => "int64".to_string(),
+    "bytes" => "bytes".to_string(),
+    "Bool" => "bool".to_string(),
+    other => proto_camel(other),
+  }
+}
+
+fn vector_name(item: &str) -> String {
+  format!("{}Vector", proto_camel(item))
+}
+
+/// proto type of an item of a vector, a vector is a message of its items, e.g. `vector<keyboardButton>` ->
+/// `KeyboardButtonVector`
+fn proto_item(item: &TLTokenComponentType) -> String {
+  if item.sign_type() != "vector" {
+    return proto_type_name(&item.sign_type());
+  }
+  let inner = item.components().first().map_or("bytes".to_string(), proto_item);
+  vector_name(&inner)
+}
+
+/// proto type of an argument, e.g. `vector<vector<keyboardButton>>` -> `repeated KeyboardButtonVector`
+pub fn proto_type(arg: &TLTokenArgType) -> String {
+  let sign_type = arg.sign_type();
+  if sign_type != "vector" {
+    return proto_type_name(&sign_type);
+  }
+  format!("repeated {}", arg.components().first().map_or("bytes".to_string(), proto_item))
+}
+
+/// rust field name generated by prost, keywords are raw identifiers
+pub fn proto_field(name: &str) -> String {
+  match name {
+    "type" | "async" | "static" | "final" | "override" | "move" | "ref" | "match" | "loop" | "use" | "mod" | "in" | "as" => format!("r#{}", name),
+    _ => name.to_string(),
+  }
+}
@@ -0,0 +1,59 @@
+use std::path::Path;
+
+use tl_parser::parser::parser::TLParser;
+use tl_parser::types::TLTokenGroup;
+
+/// format of the ir, changed when a field of the tokens is changed
+const IR_FORMAT: u32 = 1;
+
+/// The parsed schema, all tokens with their fields, descriptions and blood, written by `fantasy parse --emit-ir`;
+/// the generation reads it by `--from-ir` instead of parsing the schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaIr {
+  format: u32,
+  /// tdlib version of the schema
+  tdlib_version: String,
+  /// last commit of the schema file
+  schema_commit: String,
+  /// url the schema is fetched from, empty if not fetched
+  #[serde(default)]
+  schema_source: String,
+  tokens: Vec<TLTokenGroup>,
+}
+
+impl SchemaIr {
+  /// parse a tl schema of this tdlib version
+  pub fn parse<P: AsRef<Path>>(file_tl: P, tdlib_version: String) -> Result<Self, failure::Error> {
+    let file_tl = file_tl.as_ref();
+    Ok(Self {
+      format: IR_FORMAT,
+      tdlib_version,
+      schema_commit: crate::schema_commit(file_tl),
+      schema_source: crate::schema_source(file_tl),
+      tokens: TLParser::new(file_tl).parse()?,
+    })
+  }
+
+  pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, failure::Error> {
+    let ir: SchemaIr = serde_json::from_str(&std::fs::read_to_string(path.as_ref())?)?;
+    if ir.format != IR_FORMAT {
+      bail!("The ir {:?} is format {}, fantasy reads format {}, emit it again", path.as_ref(), ir.format, IR_FORMAT);
+    }
+    Ok(ir)
+  }
+
+  pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), failure::Error> {
+    std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+    Ok(())
+  }
+
+  pub fn tdlib_version(&self) -> &String { &self.tdlib_version }
+  pub fn schema_commit(&self) -> &String { &self.schema_commit }
+  pub fn schema_source(&self) -> &String { &self.schema_source }
+  pub fn into_tokens(self) -> Vec<TLTokenGroup> { self.tokens }
+}
+
+/// is the schema file an ir, not a tl schema
+pub fn is_ir<P: AsRef<Path>>(file: P) -> bool {
+  file.as_ref().extension().is_some_and(|extension| extension == "json")
+}
@@ -0,0 +1,176 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use colored::Colorize;
+
+use crate::output::Output;
+use crate::plugin::CommandPlugin;
+
+/// layout of the generated crates, `fantasy.toml`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Layout {
+  /// the command plugins of the templates
+  #[serde(default)]
+  plugin: Vec<CommandPlugin>,
+  #[serde(flatten)]
+  crates: HashMap<String, CrateLayout>,
+}
+
+/// the files of a generated crate, the paths are relative to the template dir
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CrateLayout {
+  /// dirs of the crate recreated before the generation
+  #[serde(default)]
+  clear: Vec<String>,
+  /// static files copied in order, a dir copies its files
+  #[serde(default)]
+  copy: Vec<String>,
+  /// templates rendered once
+  #[serde(default)]
+  render: Vec<String>,
+  /// templates rendered for each td type
+  #[serde(default)]
+  render_type: Vec<String>,
+}
+
+impl Layout {
+  pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, failure::Error> {
+    let path = path.as_ref();
+    if !path.is_file() { bail!("layout file not found -> {:?}", path); }
+    match toml::from_str(&std::fs::read_to_string(path)?) {
+      Ok(layout) => Ok(layout),
+      Err(e) => bail!("Can not convert layout file -> {:?}", e)
+    }
+  }
+
+  /// add the files of another layout, e.g. of the extra templates
+  pub fn extend(&mut self, other: Layout) {
+    self.plugin.extend(other.plugin);
+    for (name, other) in other.crates {
+      let crate_layout = self.crates.entry(name).or_default();
+      for (files, others) in [
+        (&mut crate_layout.clear, other.clear),
+        (&mut crate_layout.copy, other.copy),
+        (&mut crate_layout.render, other.render),
+        (&mut crate_layout.render_type, other.render_type),
+      ] {
+        for file in others {
+          if !files.contains(&file) { files.push(file); }
+        }
+      }
+    }
+  }
+
+  pub fn plugins(&self) -> &Vec<CommandPlugin> { &self.plugin }
+
+  pub fn crate_layout<S: AsRef<str>>(&self, name: S) -> Result<&CrateLayout, failure::Error> {
+    match self.crates.get(name.as_ref()) {
+      Some(crate_layout) => Ok(crate_layout),
+      None => bail!("No [{}] in the layout file", name.as_ref())
+    }
+  }
+}
+
+impl CrateLayout {
+  pub fn clear(&self) -> &Vec<String> { &self.clear }
+  pub fn render(&self) -> &Vec<String> { &self.render }
+  pub fn render_type(&self) -> &Vec<String> { &self.render_type }
+
+  /// remove the files of the dirs to clear which aren't generated, the count of the files removed
+  pub fn clearance<P: AsRef<Path>>(&self, path_out: P, generated: &BTreeSet<&PathBuf>) -> Result<usize, failure::Error> {
+    let mut removed = 0;
+    for dir in &self.clear {
+      let path = path_out.as_ref().join(dir);
+      if path.is_dir() {
+        removed += self::remove_stale(&path, generated)?;
+      }
+      std::fs::create_dir_all(&path)?;
+    }
+    Ok(removed)
+  }
+
+  /// the sources of the files copied and the templates rendered once, template path -> source; the file of the last
+  /// dir having it wins
+  pub fn sources(&self, path_templates: &[&PathBuf]) -> Result<BTreeMap<String, String>, failure::Error> {
+    let mut sources = BTreeMap::new();
+    for file in self.copy.iter().chain(&self.render) {
+      for path_template in path_templates {
+        let from = path_template.join(file);
+        if from.is_dir() {
+          for entry in from.read_dir()? {
+            let entry_path = entry?.path();
+            if let (true, Some(name)) = (entry_path.is_file(), entry_path.file_name()) {
+              sources.insert(format!("{}/{}", file, name.to_string_lossy()), String::from_utf8_lossy(&std::fs::read(&entry_path)?).to_string());
+            }
+          }
+        } else if from.is_file() {
+          sources.insert(file.clone(), String::from_utf8_lossy(&std::fs::read(&from)?).to_string());
+        }
+      }
+    }
+    Ok(sources)
+  }
+
+  /// copy the static files from the template dirs to the crate, the file of the last dir having it wins
+  pub fn copy_files<P: AsRef<Path>>(&self, path_templates: &[&PathBuf], path_out: P, output: &Output) -> Result<(), failure::Error> {
+    for file in &self.copy {
+      let to = path_out.as_ref().join(self::out_path(file));
+      let mut copied = false;
+      for path_template in path_templates {
+        let from = path_template.join(file);
+        if from.is_dir() {
+          self::copy_dir(&from, &to, output)?;
+        } else if from.is_file() {
+          self::copy_file(&from, &to, output)?;
+        } else {
+          continue;
+        }
+        copied = true;
+      }
+      if !copied { bail!("Can not find {} in the template dirs", file); }
+    }
+    Ok(())
+  }
+}
+
+/// path of a template in the crate, without the first dir
+pub fn out_path<S: AsRef<str>>(template: S) -> PathBuf {
+  Path::new(template.as_ref()).components().skip(1).collect()
+}
+
+fn copy_dir(from_dir: &Path, to_dir: &Path, output: &Output) -> Result<(), failure::Error> {
+  for entry in from_dir.read_dir()? {
+    let entry_path = entry?.path();
+    if !entry_path.is_file() { continue; }
+    match entry_path.file_name() {
+      Some(name) => self::copy_file(&entry_path, &to_dir.join(name), output)?,
+      None => bail!("Can not read file name")
+    }
+  }
+  Ok(())
+}
+
+fn copy_file(from: &Path, to: &Path, output: &Output) -> Result<(), failure::Error> {
+  debug!("COPY {} -> {}", from.to_str().map_or("", |v| v).blue(), to.to_str().map_or("", |v| v).blue());
+  output.write(to, std::fs::read(from)?);
+  Ok(())
+}
+
+/// remove the files of a dir not generated, and the dirs left empty
+fn remove_stale(dir: &Path, generated: &BTreeSet<&PathBuf>) -> Result<usize, failure::Error> {
+  let mut removed = 0;
+  for entry in dir.read_dir()? {
+    let entry_path = entry?.path();
+    if entry_path.is_dir() {
+      removed += self::remove_stale(&entry_path, generated)?;
+      if entry_path.read_dir()?.next().is_none() {
+        std::fs::remove_dir(&entry_path)?;
+      }
+    } else if !generated.contains(&entry_path) {
+      debug!("REMOVE {}", entry_path.to_string_lossy().red());
+      std::fs::remove_file(&entry_path)?;
+      removed += 1;
+    }
+  }
+  Ok(removed)
+}
@@ -9,56 +9,309 @@ extern crate serde_derive;
 #[macro_use]
 extern crate typed_builder;
 
+use std::collections::{BTreeSet, HashSet};
 use std::path::{Path, PathBuf};
+use std::process::Command;
 
 use tera::Tera;
 
 use cycle::*;
+use grpc::Grpc;
 use rtd::RTD;
 use tgclient::TGClient;
 use tl_parser::parser::parser::TLParser;
 use tokenwrap::TokenWrap;
 
+mod blood;
+mod check;
 mod cycle;
+mod fetch;
+mod golden;
+mod grpc;
+mod ir;
+mod layout;
+mod naming;
+mod output;
+mod plugin;
+mod report;
 mod rtd;
+mod select;
 mod tgclient;
 mod tokenwrap;
 mod terafill;
 mod types;
 mod tdfill;
+mod validate;
+
+/// tdlib version of schema/master/td_api.tl
+const MASTER_TDLIB_VERSION: &str = "1.6.9";
 
 fn main() {
   simple_logger::init().unwrap();
+
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  let option = |name: &str| args.iter().position(|arg| arg == name).and_then(|index| args.get(index + 1));
+  let extra_templates = option("--extra-templates").map(PathBuf::from);
+  // the version of a schema not in `schema/v<version>`, empty skips the version check of telegram-client
+  let tdlib_version = option("--tdlib-version");
+  if args.first().is_some_and(|command| command == "golden") {
+    log::set_max_level(log::LevelFilter::Info);
+    if let Err(e) = golden::check(args.iter().any(|arg| arg == "--bless")) {
+      error!("{}", e);
+      std::process::exit(1);
+    }
+    return;
+  }
+  if args.first().is_some_and(|command| command == "check") {
+    log::set_max_level(log::LevelFilter::Info);
+    let against = match option("--against") {
+      Some(against) => against,
+      None => {
+        error!("Usage: fantasy check --against <crate path> [--crate <rtdlib|rtdlib-types|telegram-client|telegram-grpc>] [--extra-templates <dir>]");
+        std::process::exit(2);
+      }
+    };
+    if let Err(e) = check::check(against, option("--crate").map(|name| &name[..]), extra_templates) {
+      error!("{}", e);
+      std::process::exit(1);
+    }
+    return;
+  }
+  if args.first().is_some_and(|command| command == "parse") {
+    log::set_max_level(log::LevelFilter::Info);
+    let file_ir = match option("--emit-ir") {
+      Some(file_ir) => file_ir,
+      None => {
+        error!("Usage: fantasy parse --emit-ir <out.json> [--schema <td_api.tl>] [--tdlib-version <1.8.29>]");
+        std::process::exit(2);
+      }
+    };
+    let file_tl = option("--schema").map_or(Path::new("./").join("schema/master/td_api.tl"), PathBuf::from);
+    if let Err(e) = ir::SchemaIr::parse(&file_tl, schema_version(&file_tl, tdlib_version)).and_then(|ir| ir.write(file_ir)) {
+      error!("{}", e);
+      std::process::exit(1);
+    }
+    info!("{} parsed to {}", file_tl.to_string_lossy(), file_ir);
+    return;
+  }
+  if args.first().is_some_and(|command| command == "fetch") {
+    log::set_max_level(log::LevelFilter::Info);
+    let tag = match option("--tdlib") {
+      Some(tag) => tag,
+      None => {
+        error!("Usage: fantasy fetch --tdlib <v1.8.29> [--force]");
+        std::process::exit(2);
+      }
+    };
+    if let Err(e) = fetch::fetch(tag, args.iter().any(|arg| arg == "--force")) {
+      error!("{}", e);
+      std::process::exit(1);
+    }
+    return;
+  }
   log::set_max_level(log::LevelFilter::Debug);
 
+  // the threads rendering the types, `--jobs 1` renders them one by one
+  if let Some(jobs) = option("--jobs").and_then(|jobs| jobs.parse().ok()) {
+    rayon::ThreadPoolBuilder::new().num_threads(jobs).build_global().unwrap();
+  }
 
   let project_path = Path::new("./");
 
-  let tdtypefill = tdfill::TDTypeFill::new(project_path.join("schema/td_type_fill.toml")).unwrap();
+  // the ir of `fantasy parse --emit-ir` instead of the schema
+  let (file_tl, tdlib_version, schema_commit, schema_source) = match option("--from-ir") {
+    Some(file_ir) => match ir::SchemaIr::read(file_ir) {
+      Ok(ir) => (PathBuf::from(file_ir), ir.tdlib_version().clone(), ir.schema_commit().clone(), ir.schema_source().clone()),
+      Err(e) => {
+        error!("{}", e);
+        std::process::exit(1);
+      }
+    },
+    None => {
+      // the schema of a tdlib tag is fetched if not cached
+      let file_tl = match (option("--tdlib"), option("--schema")) {
+        (Some(tag), _) => fetch::fetch(tag, false).unwrap_or_else(|e| {
+          error!("{}", e);
+          std::process::exit(1);
+        }),
+        (None, Some(file_tl)) => PathBuf::from(file_tl),
+        (None, None) => project_path.join("schema/master/td_api.tl"),
+      };
+      (file_tl.clone(), schema_version(&file_tl, tdlib_version), schema_commit(&file_tl), schema_source(&file_tl))
+    }
+  };
+
+  // the dir of the generated crates, `--out`
+  let path_out = option("--out").map_or(project_path.join(".."), PathBuf::from);
+  // a comma separated list, e.g. `--keep sendMessage,getChat*`
+  let names = |name: &str| option(name).map_or(vec![], |names| names.split(',').map(|name| name.trim().to_string()).filter(|name| !name.is_empty()).collect());
 
   let config: Config = Config::builder()
-    .path_rtd(project_path.join("../rtdlib"))
-    .path_telegram_client(project_path.join("../telegram-client"))
+    .path_rtd(path_out.join("rtdlib"))
+    .path_rtd_types(Some(path_out.join("rtdlib-types")))
+    .path_telegram_client(path_out.join("telegram-client"))
+    .path_telegram_grpc(Some(path_out.join("telegram-grpc")))
     .path_template(project_path.join("template"))
-    .file_tl(project_path.join("schema/master/td_api.tl"))
+    .path_extra_templates(extra_templates)
+    .tdlib_version(tdlib_version)
+    .schema_commit(schema_commit)
+    .schema_source(schema_source)
+    .file_tl(file_tl)
+    .rustfmt(args.iter().any(|arg| arg == "--rustfmt"))
+    .touch_all(args.iter().any(|arg| arg == "--touch-all"))
+    .strict(args.iter().any(|arg| arg == "--strict"))
+    .check_blood(args.iter().any(|arg| arg == "--check-blood"))
+    .keep(names("--keep"))
+    .skip(names("--skip"))
     .build();
 
+  let result = cycle(config).and_then(|cycle| {
+    generate(&cycle)?;
+    let report = report::Report::new(&cycle);
+    report.log();
+    match option("--report") {
+      Some(file_report) => report.write(file_report),
+      None => Ok(()),
+    }
+  });
+  if let Err(e) = result {
+    error!("{}", e);
+    std::process::exit(1);
+  }
+}
+
+/// parse the schema of the config and load the templates
+fn cycle(config: Config) -> Result<Cycle, failure::Error> {
+  let mut tdtypefill = tdfill::TDTypeFill::new(Path::new("./").join("schema/td_type_fill.toml"))?;
+  tdtypefill.extend_select(config.keep(), config.skip());
+  let mut layout = layout::Layout::new(Path::new("./").join("fantasy.toml"))?;
+
   let mut tera = Tera::new("template/**/*").expect("Can not create Tera template engine.");
+  if let Some(path_extra_templates) = config.path_extra_templates() {
+    self::extra_templates(&mut tera, &mut layout, path_extra_templates)?;
+  }
+
+  let (tokens, unknowns) = if ir::is_ir(config.file_tl()) {
+    (ir::SchemaIr::read(config.file_tl())?.into_tokens(), vec![])
+  } else {
+    let parser = TLParser::new(config.file_tl());
+    (parser.parse()?, parser.unknowns()?)
+  };
+  if config.strict() && !unknowns.is_empty() {
+    let lines: Vec<String> = unknowns.iter()
+      .map(|unknown| format!("  line {}: {}\n    {}", unknown.line(), unknown.reason(), unknown.text()))
+      .collect();
+    bail!("{} schema constructs fantasy doesn't understand in {}:\n{}", unknowns.len(), config.file_tl().to_string_lossy(), lines.join("\n"));
+  }
+  let all_tokens = tokens;
+  // the crates generated use the tokens their templates name, a select keeps them
+  let mut crates = vec!["rtdlib", "telegram-client"];
+  if config.path_rtd_types().is_some() { crates.push("rtdlib-types"); }
+  if config.path_telegram_grpc().is_some() { crates.push("telegram-grpc"); }
+  let mut templates = vec![];
+  for name in crates {
+    templates.extend(layout.crate_layout(name)?.sources(&config.template_dirs())?);
+  }
+  let template_uses = select::template_uses(&all_tokens, &templates);
+  let tokens = select::select(&all_tokens, tdtypefill.select(), &template_uses)?;
+  let selected: HashSet<String> = tokens.iter().map(|token| token.name()).collect();
+  let unselected: Vec<String> = all_tokens.iter().map(|token| token.name()).filter(|name| !selected.contains(name)).collect();
+  let removed_items = select::removed_items(&all_tokens, &tokens);
+  let tknwrap = TokenWrap::new(tokens, tdtypefill, removed_items);
 
-  let tokens = TLParser::new(config.file_tl()).parse().unwrap();
-  let tknwrap = TokenWrap::new(tokens, tdtypefill.clone());
+  let blood_mismatches = if config.check_blood() { blood::cross_check(&tknwrap) } else { vec![] };
+  if config.strict() && !blood_mismatches.is_empty() {
+    bail!("{} functions whose description returns another type than the declaration:\n  {}", blood_mismatches.len(), blood_mismatches.join("\n  "));
+  }
 
   terafill::fill(&mut tera, tknwrap.clone());
+  naming::check(&tknwrap)?;
+  plugin::register(&mut tera, layout.plugins())?;
 
   let renderer = Renderer::builder().tera(tera).build();
 
-
-  let cycle: Cycle = Cycle::builder()
+  Ok(Cycle::builder()
     .config(config)
     .tknwrap(tknwrap)
     .renderer(renderer)
-    .build();
+    .layout(layout)
+    .unselected(unselected)
+    .unknowns(unknowns)
+    .blood_mismatches(blood_mismatches)
+    .build())
+}
+
+/// load the extra templates, a template overrides the template of the same path, a `fantasy.toml` in the dir adds
+/// its files to the layout
+fn extra_templates(tera: &mut Tera, layout: &mut layout::Layout, path_extra_templates: &Path) -> Result<(), failure::Error> {
+  if !path_extra_templates.is_dir() {
+    bail!("extra templates path is not dir -> {:?}", path_extra_templates);
+  }
+  let mut files = BTreeSet::new();
+  golden::files(path_extra_templates, Path::new(""), &mut files)?;
+  let file_layout = Path::new("fantasy.toml");
+  if files.remove(file_layout) {
+    layout.extend(layout::Layout::new(path_extra_templates.join(file_layout))?);
+  }
+  let names: Vec<String> = files.iter()
+    .map(|file| file.components().map(|component| component.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/"))
+    .collect();
+  let templates = files.iter().map(|file| path_extra_templates.join(file)).zip(names.iter().map(|name| Some(&name[..]))).collect();
+  if let Err(e) = tera.add_template_files(templates) {
+    bail!("Can not load the extra templates -> {:?}", e);
+  }
+  info!("{} extra templates loaded from {}", files.len(), path_extra_templates.to_string_lossy());
+  Ok(())
+}
+
+/// generate all crates of the config
+fn generate(cycle: &Cycle) -> Result<(), failure::Error> {
+  RTD::new(cycle).generate()?;
+  if let Some(path_rtd_types) = cycle.config().path_rtd_types() {
+    RTD::types_only(cycle, path_rtd_types).generate()?;
+  }
+  TGClient::new(cycle).generate()?;
+  if let Some(path_telegram_grpc) = cycle.config().path_telegram_grpc() {
+    Grpc::new(cycle, path_telegram_grpc).generate()?;
+  }
+  validate::validate(cycle)
+}
+
+/// tdlib version of the schema: `--tdlib-version`, else `schema/v1.6.0/td_api.tl` is 1.6.0 and master is the version
+/// it's synced to; empty if not known, a schema elsewhere has no version in its path
+fn schema_version(file_tl: &Path, tdlib_version: Option<&String>) -> String {
+  if let Some(tdlib_version) = tdlib_version {
+    return tdlib_version.clone();
+  }
+  let dir = file_tl.parent()
+    .and_then(|dir| dir.file_name())
+    .and_then(|name| name.to_str());
+  if dir == Some("master") {
+    return MASTER_TDLIB_VERSION.to_string();
+  }
+  match dir.and_then(|name| name.strip_prefix('v')).filter(|version| version.starts_with(|c: char| c.is_ascii_digit())) {
+    Some(version) => version.to_string(),
+    None => {
+      warn!("The tdlib version of {} is not known, set it by --tdlib-version; the generated crate won't check it", file_tl.to_string_lossy());
+      String::new()
+    }
+  }
+}
+
+/// url a fetched schema is downloaded from, empty if not fetched
+fn schema_source(file_tl: &Path) -> String {
+  fetch::SchemaSource::of(file_tl).map(|source| source.url().clone()).unwrap_or_default()
+}
 
-  RTD::new(&cycle).generate().unwrap();
-  TGClient::new(&cycle).generate().unwrap();
+/// last git commit of the schema file, empty if not in a git repository
+fn schema_commit(file_tl: &Path) -> String {
+  Command::new("git")
+    .args(["log", "-1", "--format=%H", "--"])
+    .arg(file_tl)
+    .output()
+    .ok()
+    .filter(|output| output.status.success())
+    .and_then(|output| String::from_utf8(output.stdout).ok())
+    .map(|commit| commit.trim().to_string())
+    .unwrap_or_default()
 }
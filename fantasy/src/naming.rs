@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use case::CaseExt;
+
+use tl_parser::types::{TLTokenGroup, TLTokenGroupType};
+
+use crate::tdfill::TDRename;
+use crate::tokenwrap::TokenWrap;
+
+/// rust keywords, strict and reserved
+const KEYWORDS: &[&str] = &[
+  "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for", "if", "impl", "in",
+  "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait",
+  "true", "type", "unsafe", "use", "where", "while", "async", "await", "dyn", "abstract", "become", "box", "do",
+  "final", "macro", "override", "priv", "typeof", "unsized", "virtual", "yield", "try",
+];
+
+/// the types of rtdlib in `types`
+const CRATE_TYPES: &[&str] = &["RObject", "RFunction", "TdBytes"];
+
+lazy_static! {
+  /// the rename table of the type fill, the filters of tera are functions without state
+  static ref RENAME: RwLock<TDRename> = RwLock::new(TDRename::default());
+}
+
+/// use the rename table of this type fill in `to_snake` and `to_camel`
+pub fn set_rename(rename: &TDRename) {
+  *RENAME.write().unwrap() = rename.clone();
+}
+
+/// snake name of a schema name, the rename table first, e.g. the method of a function
+pub fn snake<S: AsRef<str>>(name: S) -> String {
+  let name = name.as_ref();
+  RENAME.read().unwrap().snake(name).cloned().unwrap_or_else(|| name.to_snake())
+}
+
+/// camel name of a schema name, the rename table first, e.g. the type of a td type
+pub fn camel<S: AsRef<str>>(name: S) -> String {
+  let name = name.as_ref();
+  RENAME.read().unwrap().camel(name).cloned().unwrap_or_else(|| name.to_camel())
+}
+
+/// the name of an item of a trait without the name of the trait, e.g. `textEntityTypeBold` -> `Bold`
+pub fn remove_prefix(text: &str, prefix: &str) -> String {
+  let plura_prefix = format!("{}s", prefix);
+  if plura_prefix.to_lowercase() == text.to_lowercase() {
+    return text.to_string();
+  }
+  if !text.to_lowercase().starts_with(&prefix.to_lowercase()[..]) {
+    return text.to_string();
+  }
+  text.chars().skip(prefix.chars().count()).collect()
+}
+
+/// field name of a schema field, the keywords used by the schema end with `_`
+pub fn safe_field(name: &str) -> String {
+  match name {
+    "type" => "type_".to_string(),
+    "async" => "async_".to_string(),
+    "static" => "static_".to_string(),
+    _ => name.to_snake(),
+  }
+}
+
+/// The schema names which can't be rust names, an error with a report of all of them: the names colliding
+/// after renamed and converted, the types with the builders, parts and traits generated for them and the
+/// types of rtdlib, the methods of the functions, the items of a trait and the fields of a type; the
+/// fields and the methods which are rust keywords and the modules of the domains.
+pub fn check(tknwrap: &TokenWrap) -> Result<(), failure::Error> {
+  let tokens: Vec<&TLTokenGroup> = tknwrap.tokens().iter()
+    .filter(|token| !tknwrap.is_skip_type(token.name()))
+    .collect();
+  let mut problems: Vec<(&str, String)> = vec![];
+
+  let mut types: Vec<(String, String)> = CRATE_TYPES.iter().map(|name| ("rtdlib".to_string(), name.to_string())).collect();
+  for token in &tokens {
+    let (name, camel) = (token.name(), self::camel(token.name()));
+    match token.type_() {
+      TLTokenGroupType::Trait => types.push((format!("{} (trait)", name), format!("TD{}", camel))),
+      _ => types.push((format!("{} (builder)", name), format!("RTD{}Builder", camel))),
+    }
+    if tknwrap.has_parts(&name) {
+      types.push((format!("{} (parts)", name), format!("{}Parts", camel)));
+    }
+    types.push((name, camel));
+  }
+  self::collide("type", types, &mut problems);
+
+  let functions: Vec<(String, String)> = tokens.iter()
+    .filter(|token| token.type_() == TLTokenGroupType::Function)
+    .map(|token| (token.name(), self::snake(token.name())))
+    .collect();
+  for (name, method) in &functions {
+    if KEYWORDS.contains(&&method[..]) { problems.push(("keyword", format!("method {} of {}", method, name))); }
+  }
+  self::collide("method of Api", functions, &mut problems);
+
+  for token in tokens.iter().filter(|token| token.type_() == TLTokenGroupType::Trait) {
+    let trait_name = self::camel(token.name());
+    let items: Vec<String> = tokens.iter()
+      .filter(|item| item.blood().is_some_and(|blood| blood.to_lowercase() == token.name().to_lowercase()))
+      .filter(|item| item.name().to_lowercase() != token.name().to_lowercase())
+      .map(|item| self::remove_prefix(&item.name(), &trait_name))
+      .collect();
+    self::collide(&format!("item of {}", trait_name),
+                  items.iter().map(|item| (item.clone(), self::camel(item))).collect(), &mut problems);
+    self::collide(&format!("method of {}", trait_name),
+                  items.iter().map(|item| (item.clone(), self::snake(item))).collect(), &mut problems);
+  }
+
+  for token in tokens.iter().filter(|token| token.type_() != TLTokenGroupType::Trait) {
+    let fields: Vec<(String, String)> = token.arguments().iter()
+      .map(|arg| (arg.sign_name(), self::safe_field(&arg.sign_name())))
+      .collect();
+    for (name, field) in &fields {
+      if KEYWORDS.contains(&&field[..]) { problems.push(("keyword", format!("field {} of {}", name, token.name()))); }
+    }
+    self::collide(&format!("field of {}", token.name()), fields, &mut problems);
+  }
+
+  for domain in tknwrap.tdtypefill().domains() {
+    let name = domain.name();
+    if KEYWORDS.contains(&&name[..]) || name == "prelude" {
+      problems.push(("reserved", format!("module {} of the domain {}", name, name)));
+    }
+  }
+
+  if !problems.is_empty() {
+    let report: Vec<String> = problems.iter().map(|(kind, problem)| format!("  {:<10} {}", kind, problem)).collect();
+    bail!("{} schema names can't be rust names, rename them by [rename] of the type fill, a field by `naming::safe_field`:\n{}",
+          problems.len(), report.join("\n"));
+  }
+  Ok(())
+}
+
+/// the names converted to the same rust name, a pair is the name and its rust name
+fn collide(kind: &str, names: Vec<(String, String)>, problems: &mut Vec<(&str, String)>) {
+  let mut seen: HashMap<String, String> = HashMap::new();
+  for (name, rust) in names {
+    match seen.get(&rust) {
+      Some(other) if *other != name => problems.push(("collision", format!("{} {} of {} and {}", kind, rust, other, name))),
+      Some(_) => {}
+      None => { seen.insert(rust, name); }
+    }
+  }
+}
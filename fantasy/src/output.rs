@@ -0,0 +1,93 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use colored::Colorize;
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::layout::CrateLayout;
+
+/// file in the crate root with the hash of each file generated
+const FILE_HASH: &str = ".fantasy-hash";
+
+/// The files of a generation, kept until the crate is written; a file is only rewritten if its content
+/// differs from the file on disk, so the incremental build of the crate isn't invalidated.
+#[derive(Debug, Default)]
+pub struct Output {
+  files: Mutex<BTreeMap<PathBuf, Vec<u8>>>,
+  /// the files written to the crates, not the unchanged ones
+  written: Mutex<BTreeSet<PathBuf>>,
+}
+
+impl Output {
+  pub fn contains<P: AsRef<Path>>(&self, file: P) -> bool {
+    self.files.lock().unwrap().contains_key(file.as_ref())
+  }
+
+  /// content of a file of this generation
+  pub fn read<P: AsRef<Path>>(&self, file: P) -> Option<String> {
+    self.files.lock().unwrap().get(file.as_ref()).map(|content| String::from_utf8_lossy(content).to_string())
+  }
+
+  pub fn write<P: AsRef<Path>>(&self, file: P, content: Vec<u8>) {
+    self.files.lock().unwrap().insert(file.as_ref().to_path_buf(), content);
+  }
+
+  /// append a body and a newline
+  pub fn append<P: AsRef<Path>, S: AsRef<str>>(&self, file: P, body: S) {
+    let mut files = self.files.lock().unwrap();
+    let content = files.entry(file.as_ref().to_path_buf()).or_default();
+    content.extend_from_slice(body.as_ref().as_bytes());
+    content.push(b'\n');
+  }
+
+  /// the files written to the crates by `flush`
+  pub fn written(&self) -> BTreeSet<PathBuf> {
+    self.written.lock().unwrap().clone()
+  }
+
+  /// write the files of a crate whose content differs from the file on disk, or all of them if `touch_all`,
+  /// and remove the files of the dirs to clear which aren't generated anymore
+  pub fn flush<P: AsRef<Path>>(&self, path_out: P, layout: &CrateLayout, touch_all: bool) -> Result<(), failure::Error> {
+    let path_out = path_out.as_ref();
+    let file_hash = path_out.join(FILE_HASH);
+
+    let files = self.files.lock().unwrap();
+    let generated: Vec<(&PathBuf, &Vec<u8>)> = files.iter().filter(|(file, _)| file.starts_with(path_out)).collect();
+    // the files are hashed and written on the rayon pool
+    let hashed: Vec<Result<(String, String, bool), failure::Error>> = generated.par_iter()
+      .map(|(file, content)| {
+        let name = file.strip_prefix(path_out)?.to_string_lossy().to_string();
+        let hash = format!("{:x}", Sha256::digest(content));
+        // compare with the file on disk, not the last generation, a file edited by hand is rewritten
+        let changed = touch_all || std::fs::read(file).ok().as_ref() != Some(*content);
+        if changed {
+          if let Some(dir) = file.parent() {
+            std::fs::create_dir_all(dir)?;
+          }
+          std::fs::write(file, content)?;
+        }
+        Ok((name, hash, changed))
+      })
+      .collect();
+    let mut hashes = BTreeMap::new();
+    let mut written = 0;
+    for ((file, _), hashed) in generated.iter().zip(hashed) {
+      let (name, hash, changed) = hashed?;
+      if changed {
+        self.written.lock().unwrap().insert(file.to_path_buf());
+        written += 1;
+      }
+      hashes.insert(name, hash);
+    }
+    let generated: BTreeSet<&PathBuf> = generated.into_iter().map(|(file, _)| file).collect();
+    let removed = layout.clearance(path_out, &generated)?;
+    std::fs::create_dir_all(path_out)?;
+    std::fs::write(&file_hash, serde_json::to_string_pretty(&hashes)?)?;
+
+    info!("{} {} files written, {} unchanged, {} removed", path_out.to_string_lossy().blue(),
+          written, hashes.len() - written, removed);
+    Ok(())
+  }
+}
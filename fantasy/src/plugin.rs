@@ -0,0 +1,94 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+use tera::Tera;
+
+/// Adds filters and functions to the templates, after the built-in ones of `terafill`.
+///
+/// A plugin compiled in the generator is added to `compiled`, e.g. a filter for the names of an internal
+/// variant; a `[[plugin]]` of `fantasy.toml` is a function running a command, without building the generator.
+pub trait TeraPlugin {
+  fn name(&self) -> &str;
+
+  fn register(&self, tera: &mut Tera) -> Result<(), failure::Error>;
+}
+
+/// the plugins compiled in the generator
+fn compiled() -> Vec<Box<dyn TeraPlugin>> {
+  vec![]
+}
+
+/// register the compiled plugins and the command plugins, a plugin can replace a built-in filter or function
+pub fn register(tera: &mut Tera, commands: &[CommandPlugin]) -> Result<(), failure::Error> {
+  let mut plugins = self::compiled();
+  plugins.extend(commands.iter().map(|command| Box::new(command.clone()) as Box<dyn TeraPlugin>));
+  for plugin in plugins {
+    plugin.register(tera)?;
+    debug!("PLUGIN {}", plugin.name());
+  }
+  Ok(())
+}
+
+/// A function of the templates running a command, `{{ name(value=token.name) }}` writes the value to its stdin
+/// and is its stdout without the last newline; the other arguments are the env `FANTASY_ARG_<NAME>`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandPlugin {
+  name: String,
+  /// the program and its arguments, e.g. `["sh", "-c", "tr _ -"]`
+  command: Vec<String>,
+}
+
+impl CommandPlugin {
+  fn run(&self, value: &str, args: &HashMap<String, Value>) -> Result<String, String> {
+    let (program, program_args) = match self.command.split_first() {
+      Some(command) => command,
+      None => return Err(format!("plugin {} has no command", self.name)),
+    };
+    let mut command = Command::new(program);
+    command.args(program_args).stdin(Stdio::piped()).stdout(Stdio::piped());
+    for (name, arg) in args {
+      let arg = arg.as_str().map_or(arg.to_string(), |arg| arg.to_string());
+      command.env(format!("FANTASY_ARG_{}", name.to_uppercase()), arg);
+    }
+    let mut child = command.spawn().map_err(|e| format!("plugin {} can not run {}: {}", self.name, program, e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+      stdin.write_all(value.as_bytes()).map_err(|e| e.to_string())?;
+    }
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    if !output.status.success() {
+      return Err(format!("plugin {} fail: {}", self.name, output.status));
+    }
+    let stdout = String::from_utf8(output.stdout).map_err(|e| e.to_string())?;
+    Ok(stdout.strip_suffix('\n').unwrap_or(&stdout).to_string())
+  }
+}
+
+impl TeraPlugin for CommandPlugin {
+  fn name(&self) -> &str { &self.name }
+
+  fn register(&self, tera: &mut Tera) -> Result<(), failure::Error> {
+    if self.command.is_empty() { bail!("plugin {} has no command", self.name); }
+    let plugin = self.clone();
+    // the same value is rendered for many types, the command runs once for it
+    let outputs: Arc<Mutex<HashMap<String, String>>> = Arc::new(Mutex::new(HashMap::new()));
+    tera.register_function(&self.name, Box::new(move |mut argument: HashMap<String, Value>| -> tera::Result<Value> {
+      let value = match argument.remove("value") {
+        Some(Value::String(value)) => value,
+        Some(value) => value.to_string(),
+        None => return Err(format!("plugin {} can't found value", plugin.name).into())
+      };
+      let args: BTreeMap<&String, &Value> = argument.iter().collect();
+      let key = format!("{}\n{}", value, serde_json::to_string(&args).unwrap_or_default());
+      if let Some(output) = outputs.lock().unwrap().get(&key) {
+        return Ok(Value::String(output.clone()));
+      }
+      let output = plugin.run(&value, &argument)?;
+      outputs.lock().unwrap().insert(key, output.clone());
+      Ok(Value::String(output))
+    }));
+    Ok(())
+  }
+}
@@ -0,0 +1,122 @@
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+
+use colored::Colorize;
+
+use tl_parser::types::TLTokenGroupType;
+
+use crate::cycle::Cycle;
+use crate::select;
+
+/// items of a list logged, the json report has all of them
+const LOG_ITEMS: usize = 10;
+
+/// Statistics of a generation, logged after it and written as json by `--report <file>` for the assertions
+/// of a ci, e.g. no unknown type after a schema update.
+#[derive(Debug, Serialize)]
+pub struct Report {
+  tdlib_version: String,
+  functions: usize,
+  types: usize,
+  traits: usize,
+  /// the builtin types of the schema and the tokens removed by `[select]`
+  skipped: Vec<String>,
+  /// the types used by a field or a function and not in the schema
+  unknown: Vec<String>,
+  /// the schema constructs the parser drops or misreads, `line N: reason`
+  unknown_constructs: Vec<String>,
+  /// the functions whose return type, and the types whose trait, isn't in the schema
+  unresolved_blood: Vec<String>,
+  /// the functions whose description returns another type than the declaration, by `--check-blood`
+  blood_mismatches: Vec<String>,
+  /// the types, functions and fields without description, a field is `type.field`
+  undocumented: Vec<String>,
+  /// files rendered by the templates
+  rendered: usize,
+  /// files written to the crates, the others are unchanged
+  written: usize,
+}
+
+impl Report {
+  pub fn new(cycle: &Cycle) -> Self {
+    let tknwrap = cycle.tknwrap();
+    let tokens: Vec<_> = tknwrap.tokens().iter()
+      .filter(|token| !tknwrap.is_skip_type(token.name()))
+      .collect();
+    // the builtin types are known, with their type, e.g. `Bool` of `boolTrue`
+    let mut names: HashSet<String> = tknwrap.tokens().iter().map(|token| token.name().to_lowercase()).collect();
+    names.extend(tknwrap.tokens().iter()
+      .filter(|token| tknwrap.is_skip_type(token.name()))
+      .filter_map(|token| token.blood())
+      .map(|blood| blood.to_lowercase()));
+    let count = |type_: TLTokenGroupType| tokens.iter().filter(|token| token.type_() == type_).count();
+
+    let mut skipped: Vec<String> = tknwrap.tokens().iter()
+      .filter(|token| tknwrap.is_skip_type(token.name()))
+      .map(|token| token.name())
+      .collect();
+    skipped.extend(cycle.unselected().iter().cloned());
+
+    let mut unknown = BTreeSet::new();
+    let mut unresolved_blood = vec![];
+    let mut undocumented = vec![];
+    for token in &tokens {
+      for arg in token.arguments() {
+        let mut used = vec![arg.sign_type()];
+        select::component_types(arg.components(), &mut used);
+        unknown.extend(used.into_iter().filter(|used| !names.contains(&used.to_lowercase())));
+        if arg.description().is_none_or(|description| description.trim().is_empty()) {
+          undocumented.push(format!("{}.{}", token.name(), arg.sign_name()));
+        }
+      }
+      match token.blood() {
+        Some(blood) if names.contains(&blood.to_lowercase()) => {}
+        Some(blood) => unresolved_blood.push(format!("{} -> {}", token.name(), blood)),
+        None if token.type_() == TLTokenGroupType::Function => unresolved_blood.push(token.name()),
+        None => {}
+      }
+      if token.description().is_none_or(|description| description.trim().is_empty()) {
+        undocumented.push(token.name());
+      }
+    }
+
+    Self {
+      tdlib_version: cycle.config().tdlib_version().clone(),
+      functions: count(TLTokenGroupType::Function),
+      types: count(TLTokenGroupType::Struct),
+      traits: count(TLTokenGroupType::Trait),
+      skipped,
+      unknown: unknown.into_iter().collect(),
+      unknown_constructs: cycle.unknowns().iter()
+        .map(|unknown| format!("line {}: {}", unknown.line(), unknown.reason()))
+        .collect(),
+      unresolved_blood,
+      blood_mismatches: cycle.blood_mismatches().clone(),
+      undocumented,
+      rendered: cycle.renderer().rendered().len(),
+      written: cycle.renderer().output().written().len(),
+    }
+  }
+
+
+  /// log the counts, a warning with the first names of a list which should be empty
+  pub fn log(&self) {
+    info!("tdlib {}: {} functions, {} types, {} traits, {} skipped, {} undocumented",
+          self.tdlib_version.bold(), self.functions, self.types, self.traits, self.skipped.len(), self.undocumented.len());
+    info!("{} files rendered, {} written", self.rendered, self.written);
+    for (name, items) in &[("unknown types", &self.unknown), ("unknown schema constructs", &self.unknown_constructs),
+                           ("unresolved return types", &self.unresolved_blood),
+                           ("return types documented otherwise", &self.blood_mismatches)] {
+      if items.is_empty() { continue }
+      let mut shown: Vec<&str> = items.iter().take(LOG_ITEMS).map(|item| &item[..]).collect();
+      if items.len() > LOG_ITEMS { shown.push("..."); }
+      warn!("{} {}: {}", items.len(), name.yellow(), shown.join(", "));
+    }
+  }
+
+  pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), failure::Error> {
+    std::fs::write(path.as_ref(), serde_json::to_string_pretty(self)?)?;
+    info!("report written to {}", path.as_ref().to_string_lossy());
+    Ok(())
+  }
+}
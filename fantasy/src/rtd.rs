@@ -1,23 +1,38 @@
-use std::fs::DirEntry;
 use std::path::{Path, PathBuf};
 
 use case::CaseExt;
-use colored::Colorize;
 use tera::Context;
 
 use tl_parser::types::TLTokenGroup;
 
 use crate::Cycle;
+use crate::layout::{self, CrateLayout};
 use crate::tokenwrap::TokenWrap;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+/// the types of `types::prelude`, the ones generated
+const PRELUDE: &[&str] = &[
+  "Update", "AuthorizationState", "Message", "MessageContent", "InputMessageContent", "InputMessageText",
+  "FormattedText", "TextEntity", "Chat", "ChatType", "User", "File", "Error", "Ok", "SendMessage", "GetChat",
+  "GetChatHistory", "GetMe", "GetUser",
+];
 
 pub struct RTD<'a> {
   cycle: &'a Cycle,
+  /// generated crate root path
+  path_out: PathBuf,
+  /// name of the crate in the layout
+  name: &'static str,
 }
 
 impl<'a> RTD<'a> {
   pub fn new(cycle: &'a Cycle) -> Self {
-    Self { cycle }
+    Self { cycle, path_out: cycle.config().path_rtd().clone(), name: "rtdlib" }
+  }
+
+  /// only the types, as a standalone crate without tdjson
+  pub fn types_only<P: AsRef<Path>>(cycle: &'a Cycle, path_out: P) -> Self {
+    Self { cycle, path_out: path_out.as_ref().to_path_buf(), name: "rtdlib-types" }
   }
 
 
@@ -29,63 +44,19 @@ impl<'a> RTD<'a> {
       return bail!("RTD template path is not dir -> {:?}", path_template);
     }
 
-    self.clearance();
-
-    // move root path file
-    self.copy_file_to(&path_template, config.path_rtd())?;
-    // generate src file
-    self.gensrc(&path_template)?;
-    Ok(())
-  }
-
-  fn clearance(&self) -> Result<(), failure::Error> {
-    let base_dir = self.cycle.config().path_rtd();
-    std::fs::remove_dir_all(base_dir.join("src"))?;
-    Ok(())
-  }
-
-  /// copy template file to target path
-  fn copy_file_to<P: AsRef<Path>>(&self, from_dir: P, to_dir: P) -> Result<(), failure::Error> {
-    let from_dir = from_dir.as_ref();
-    let to_dir = to_dir.as_ref();
-    if !to_dir.exists() {
-      std::fs::create_dir_all(to_dir)?;
-    }
-    let rtd_read_dir: Vec<Result<DirEntry, std::io::Error>> = from_dir.read_dir()?.collect();
-    for entry in rtd_read_dir {
-      let entry_path = entry?.path();
-      if !entry_path.is_file() { continue; }
-      let file_name = match entry_path.file_name() {
-        Some(name) => name.to_string_lossy().into_owned(),
-        None => return bail!("Can not read file name")
-      };
-      let copy_to = to_dir.join(file_name);
-      debug!("COPY {} -> {}", entry_path.to_str().map_or("", |v| v).blue(), copy_to.to_str().map_or("", |v| v).blue());
-      std::fs::copy(entry_path, copy_to)?;
-    }
-    Ok(())
-  }
-
-  /// generate src rs file.
-  fn gensrc<P: AsRef<Path>>(&self, path_template: P) -> Result<(), failure::Error> {
-    let config = self.cycle.config();
-    let path_template = path_template.as_ref();
-
-    let templatesrc: PathBuf = path_template.join("src");
-    let rtdsrc: PathBuf = config.path_rtd().join("src");
-    // copy src path rs file to target dir
-    self.copy_file_to(&templatesrc, &rtdsrc)?;
+    let layout = self.cycle.layout().crate_layout(self.name)?;
+    // copy the static files, the rtdlib-types files override the rtdlib files
+    layout.copy_files(&config.template_dirs(), &self.path_out, self.cycle.renderer().output())?;
 
     // generate common rs
-    self.gen_common()?;
+    self.gen_common(layout)?;
 
-    self.gen_types()?;
-
-    Ok(())
+    self.gen_types(layout)?;
+    self.cycle.renderer().output().flush(&self.path_out, layout, config.touch_all())
   }
 
   /// generate common rs file
-  fn gen_common(&self) -> Result<(), failure::Error> {
+  fn gen_common(&self, layout: &CrateLayout) -> Result<(), failure::Error> {
     let config = self.cycle.config();
     let tknwrap = self.cycle.tknwrap();
 
@@ -101,35 +72,49 @@ impl<'a> RTD<'a> {
       file_obj_map.insert(file_name, vec_of_file_obj);
     }
     context.insert("file_obj_map", &file_obj_map);
-    context.insert("tokens", tokens);
-
 
+    let mut domain_map: BTreeMap<String, Vec<TLTokenGroup>> = BTreeMap::new();
+    for token in tokens {
+      if tknwrap.is_skip_type(token.name()) { continue }
+      domain_map.entry(tknwrap.domain(token.name())).or_default().push(token.clone());
+    }
+    context.insert("domain_map", &domain_map);
+    let prelude: Vec<&str> = PRELUDE.iter()
+      .filter(|name| tokens.iter().any(|token| token.name().to_lowercase() == name.to_lowercase()))
+      .cloned()
+      .collect();
+    context.insert("prelude", &prelude);
+    context.insert("tokens", tokens);
+    context.insert("tdlib_version", config.tdlib_version());
+    context.insert("schema_commit", config.schema_commit());
+    context.insert("schema_source", config.schema_source());
 
-    self.cycle.renderer().render("rtdlib/src/types/_common.rs",
-                                 config.path_rtd().join("src/types/_common.rs"),
-                                 &mut context)?;
-    self.cycle.renderer().render("rtdlib/src/types/mod.rs",
-                                 config.path_rtd().join("src/types/mod.rs"),
-                                 &mut context)?;
+    for template in layout.render() {
+      self.cycle.renderer().render(template,
+                                   self.path_out.join(layout::out_path(template)),
+                                   &mut context)?;
+    }
     Ok(())
   }
 
   /// generate types
-  fn gen_types(&self) -> Result<(), failure::Error> {
-    let config = self.cycle.config();
+  fn gen_types(&self, layout: &CrateLayout) -> Result<(), failure::Error> {
     let tknwrap = self.cycle.tknwrap();
 
     let mut context = Context::new();
+    context.insert("non_exhaustive", &tknwrap.tdtypefill().non_exhaustive());
     let tokens = tknwrap.tokens();
+    let mut jobs = vec![];
     for token in tokens {
       if tknwrap.is_skip_type(token.name()) { continue }
       let file_name = tknwrap.which_file(token.name());
       context.insert("token", token);
-      self.cycle.renderer().render("rtdlib/src/types/td_type.rs",
-                                   config.path_rtd().join(&format!("src/types/{}.rs", file_name)[..]),
-                                   &mut context)?;
+      for template in layout.render_type() {
+        let dir = layout::out_path(template).parent().map_or(PathBuf::new(), |dir| dir.to_path_buf());
+        jobs.push((template.clone(), self.path_out.join(dir).join(format!("{}.rs", file_name)), context.clone()));
+      }
     }
-    Ok(())
+    self.cycle.renderer().render_all(jobs)
   }
 }
 
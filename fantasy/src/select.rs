@@ -0,0 +1,152 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use tl_parser::types::{TLTokenComponentType, TLTokenGroup, TLTokenGroupType};
+
+use crate::tdfill::TDSelect;
+use crate::tokenwrap::SKIP_TYPES;
+
+/// the types rtdlib uses, always kept
+const ALWAYS_KEEP: &[&str] = &["error", "ok"];
+
+/// The tokens of a minimal crate: the kept functions and types, all if none is kept, and the ones the templates
+/// use (`template_uses`), with all they use, the types of their fields, the return types, the trait of a type and
+/// the types of a trait used by a field or returned; then without the skipped ones. A trait only reached as the
+/// trait of a kept type keeps none of its other types, e.g. `messageText` doesn't keep `messageCall`. A skipped
+/// type still used by a kept one or by a template is an error, e.g. `message` used by `sendMessage`, a skipped
+/// type of a trait is only removed from it.
+pub fn select(tokens: &[TLTokenGroup], select: &TDSelect, template_uses: &HashMap<String, String>) -> Result<Vec<TLTokenGroup>, failure::Error> {
+  if select.keep().is_empty() && select.skip().is_empty() {
+    return Ok(tokens.to_vec());
+  }
+  let by_name: HashMap<String, &TLTokenGroup> = tokens.iter()
+    .map(|token| (token.name().to_lowercase(), token))
+    .collect();
+  // the items of each trait
+  let mut items: HashMap<String, Vec<String>> = HashMap::new();
+  for token in tokens {
+    if token.type_() != TLTokenGroupType::Struct { continue }
+    if let Some(blood) = token.blood() {
+      items.entry(blood.to_lowercase()).or_default().push(token.name().to_lowercase());
+    }
+  }
+  let skipped = |name: &str| by_name.get(name).is_some_and(|token| select.is_skip(token.name()));
+  // a trait is deserialized to any of its types
+  let trait_items = |name: &str| -> Vec<String> {
+    items.get(name).into_iter().flatten().filter(|item| !skipped(item)).cloned().collect()
+  };
+
+  let mut kept: BTreeSet<String> = BTreeSet::new();
+  let keep_all = select.keep().is_empty();
+  let mut wait: Vec<String> = tokens.iter()
+    .filter(|token| keep_all || select.is_keep(token.name()) || SKIP_TYPES.contains(&&token.name()[..])
+      || ALWAYS_KEEP.contains(&&token.name()[..]))
+    .map(|token| token.name().to_lowercase())
+    .collect();
+  let kept_items: Vec<String> = wait.iter().flat_map(|name| trait_items(name)).collect();
+  wait.extend(kept_items);
+  // a trait used by a template keeps only the items it names, e.g. `Update::NewMessage`
+  if !keep_all {
+    wait.extend(template_uses.keys().cloned());
+  }
+  let mut used_by: HashMap<String, String> = template_uses.clone();
+  while let Some(name) = wait.pop() {
+    if !kept.insert(name.clone()) || skipped(&name) { continue }
+    let token = match by_name.get(&name) {
+      Some(token) => token,
+      None => continue,
+    };
+    let mut used: Vec<String> = vec![];
+    for arg in token.arguments() {
+      used.push(arg.sign_type());
+      self::component_types(arg.components(), &mut used);
+    }
+    if token.type_() == TLTokenGroupType::Function {
+      used.extend(token.blood());
+    }
+    let used_items: Vec<String> = used.iter().flat_map(|used| trait_items(&used.to_lowercase())).collect();
+    used.extend(used_items);
+    // the trait of a type, without its other types
+    if token.type_() != TLTokenGroupType::Function {
+      used.extend(token.blood());
+    }
+    for used in used {
+      let used = used.to_lowercase();
+      if !by_name.contains_key(&used) { continue }
+      used_by.entry(used.clone()).or_insert_with(|| token.name());
+      if !kept.contains(&used) { wait.push(used); }
+    }
+  }
+
+  let mut errors = vec![];
+  for name in &kept {
+    if let (true, Some(user)) = (skipped(name), used_by.get(name)) {
+      errors.push(format!("{} is skipped but used by {}", by_name[name].name(), user));
+    }
+  }
+  if !errors.is_empty() {
+    bail!("The select of the type fill is wrong:\n  {}", errors.join("\n  "));
+  }
+  let selected: Vec<TLTokenGroup> = tokens.iter()
+    .filter(|token| {
+      let name = token.name().to_lowercase();
+      kept.contains(&name) && !skipped(&name)
+    })
+    .cloned()
+    .collect();
+  info!("{} of {} tokens selected", selected.len(), tokens.len());
+  Ok(selected)
+}
+
+/// The types of each selected trait not selected, skipped or not reached, lowercase trait name -> td names;
+/// they're deserialized to the default item of the trait like the types gated by a feature.
+pub fn removed_items(tokens: &[TLTokenGroup], selected: &[TLTokenGroup]) -> HashMap<String, Vec<String>> {
+  let selected: HashSet<String> = selected.iter().map(|token| token.name().to_lowercase()).collect();
+  let mut removed: HashMap<String, Vec<String>> = HashMap::new();
+  for token in tokens {
+    if token.type_() != TLTokenGroupType::Struct || selected.contains(&token.name().to_lowercase()) { continue }
+    if let Some(blood) = token.blood() {
+      let blood = blood.to_lowercase();
+      if selected.contains(&blood) {
+        removed.entry(blood).or_default().push(token.name());
+      }
+    }
+  }
+  removed
+}
+
+/// The tokens the templates use by name, lowercase td name -> the template using it first: a type, e.g.
+/// `AuthorizationState`, an item of a trait, e.g. `Update::NewMessage` or `InputFile::local`, a function called
+/// by its api method, e.g. `get_me`, or a name given to `find_token`. Any word of a template may be a name, so
+/// more may be kept than used, but the crates always compile.
+pub fn template_uses(tokens: &[TLTokenGroup], templates: &[(String, String)]) -> HashMap<String, String> {
+  let names: HashSet<String> = tokens.iter().map(|token| token.name().to_lowercase()).collect();
+  let mut uses: HashMap<String, String> = HashMap::new();
+  for (template, source) in templates {
+    // the word before `::`, the trait of an item
+    let mut path: Option<String> = None;
+    let mut rest = &source[..];
+    while let Some(start) = rest.find(|c: char| c.is_ascii_alphanumeric() || c == '_') {
+      rest = &rest[start..];
+      let end = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(rest.len());
+      let word = rest[..end].replace('_', "").to_lowercase();
+      rest = &rest[end..];
+      let candidates = path.take().map(|path| format!("{}{}", path, word)).into_iter().chain(Some(word.clone()));
+      for candidate in candidates {
+        if names.contains(&candidate) {
+          uses.entry(candidate).or_insert_with(|| template.clone());
+        }
+      }
+      if rest.starts_with("::") {
+        path = Some(word);
+      }
+    }
+  }
+  uses
+}
+
+pub(crate) fn component_types(components: Vec<TLTokenComponentType>, used: &mut Vec<String>) {
+  for component in components {
+    used.push(component.sign_type());
+    self::component_types(component.components(), used);
+  }
+}
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use case::CaseExt;
+
 /// td type fill
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TDTypeFill {
@@ -10,6 +12,27 @@ pub struct TDTypeFill {
   filter: HashMap<String, HashMap<String, TDTypeFilter>>,
   /// addition listener
   listener: HashMap<String, String>,
+  /// cargo features gating the api groups
+  #[serde(default)]
+  feature: Vec<TDWordGroup>,
+  /// modules of the types by domain
+  #[serde(default)]
+  domain: Vec<TDWordGroup>,
+  /// derives of the types
+  #[serde(default)]
+  derive: TDDerive,
+  /// object fields tdlib accepts as null though the schema doesn't say it, `field` or `type.field`
+  #[serde(default)]
+  nullable: Vec<String>,
+  /// mark the enums and the public field structs `#[non_exhaustive]`
+  #[serde(default)]
+  non_exhaustive: bool,
+  /// the functions and types of a minimal crate
+  #[serde(default)]
+  select: TDSelect,
+  /// rust names of the schema names
+  #[serde(default)]
+  rename: TDRename,
 }
 
 impl TDTypeFill {
@@ -80,6 +103,44 @@ impl TDTypeFill {
   pub fn listener(&self) -> &HashMap<String, String> {
     &self.listener
   }
+
+  pub fn features(&self) -> &Vec<TDWordGroup> {
+    &self.feature
+  }
+
+  pub fn domains(&self) -> &Vec<TDWordGroup> {
+    &self.domain
+  }
+
+  pub fn derive(&self) -> &TDDerive {
+    &self.derive
+  }
+
+  pub fn non_exhaustive(&self) -> bool {
+    self.non_exhaustive
+  }
+
+  pub fn select(&self) -> &TDSelect {
+    &self.select
+  }
+
+  /// keep and skip these too, e.g. by `--keep` and `--skip`
+  pub fn extend_select(&mut self, keep: &[String], skip: &[String]) {
+    self.select.keep.extend(keep.iter().cloned());
+    self.select.skip.extend(skip.iter().cloned());
+  }
+
+  pub fn rename(&self) -> &TDRename {
+    &self.rename
+  }
+
+  pub fn is_nullable<S: AsRef<str>>(&self, type_name: S, field: S) -> bool {
+    let (type_name, field) = (type_name.as_ref().to_lowercase(), field.as_ref());
+    self.nullable.iter().any(|item| match item.find('.') {
+      Some(ix) => item[..ix].to_lowercase() == type_name && &item[ix + 1..] == field,
+      None => item == field,
+    })
+  }
 }
 
 
@@ -99,6 +160,98 @@ impl TDTypeFilter {
   pub fn macros(&self) -> Option<Vec<String>> { self.macros.clone() }
 }
 
+/// td types grouped by the words of their names, a cargo feature or a domain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TDWordGroup {
+  name: String,
+  /// a td type belongs to the group if one word of its name is in this list, e.g. `call` for `discardCall`
+  words: Vec<String>,
+  /// td types never in the group, e.g. `authenticationCodeTypeCall`
+  #[serde(default)]
+  except: Vec<String>,
+}
+
+impl TDWordGroup {
+  /// does the td type belong to the group
+  pub fn contains<S: AsRef<str>>(&self, type_name: S) -> bool {
+    let type_name = type_name.as_ref();
+    if self.except.iter().any(|name| name == type_name) { return false; }
+    let snake = type_name.to_snake();
+    snake.split('_').any(|word| self.words.iter().any(|w| w == word))
+  }
+
+  pub fn name(&self) -> &String { &self.name }
+}
+
 
+/// functions and types kept in a minimal crate or skipped, a name or a prefix ending with `*`, e.g. `getChat*`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TDSelect {
+  /// kept with all they use, all are kept if empty
+  #[serde(default)]
+  keep: Vec<String>,
+  /// never generated
+  #[serde(default)]
+  skip: Vec<String>,
+}
+
+impl TDSelect {
+  pub fn keep(&self) -> &Vec<String> { &self.keep }
+  pub fn skip(&self) -> &Vec<String> { &self.skip }
 
+  pub fn is_keep<S: AsRef<str>>(&self, name: S) -> bool { self::matches(&self.keep, name.as_ref()) }
+  pub fn is_skip<S: AsRef<str>>(&self, name: S) -> bool { self::matches(&self.skip, name.as_ref()) }
+}
+
+fn matches(patterns: &[String], name: &str) -> bool {
+  patterns.iter().any(|pattern| match pattern.strip_suffix('*') {
+    Some(prefix) => name.starts_with(prefix),
+    None => pattern == name,
+  })
+}
+
+
+/// rust names of schema names, used by the `to_snake` and `to_camel` filters instead of converting the name,
+/// e.g. `deviceTokenApplePushVoIP` -> `DeviceTokenApplePushVoip`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TDRename {
+  #[serde(default)]
+  snake: HashMap<String, String>,
+  #[serde(default)]
+  camel: HashMap<String, String>,
+}
 
+impl TDRename {
+  pub fn snake<S: AsRef<str>>(&self, name: S) -> Option<&String> { self.snake.get(name.as_ref()) }
+  pub fn camel<S: AsRef<str>>(&self, name: S) -> Option<&String> { self.camel.get(name.as_ref()) }
+}
+
+
+/// derives of the generated types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TDDerive {
+  /// derives of the structs
+  #[serde(rename = "struct")]
+  struct_: Vec<String>,
+  /// derives of the trait enums, `Deserialize` is implemented by rtdlib
+  #[serde(rename = "trait")]
+  trait_: Vec<String>,
+  /// derives of the types without float field, e.g. `Eq`, `Hash`
+  no_float: Vec<String>,
+}
+
+impl Default for TDDerive {
+  fn default() -> Self {
+    Self {
+      struct_: vec!["Debug", "Clone", "Default", "Serialize", "Deserialize"].into_iter().map(|v| v.to_string()).collect(),
+      trait_: vec!["Debug", "Clone", "Serialize"].into_iter().map(|v| v.to_string()).collect(),
+      no_float: vec![],
+    }
+  }
+}
+
+impl TDDerive {
+  pub fn struct_(&self) -> &Vec<String> { &self.struct_ }
+  pub fn trait_(&self) -> &Vec<String> { &self.trait_ }
+  pub fn no_float(&self) -> &Vec<String> { &self.no_float }
+}
@@ -8,10 +8,13 @@ use tera::Tera;
 
 use tl_parser::types::{TLTokenArgType, TLTokenComponentType, TLTokenGroup};
 
+use crate::grpc;
+use crate::naming;
 use crate::tdfill::TDTypeFill;
 use crate::tokenwrap::TokenWrap;
 
 pub fn fill(tera: &mut Tera, tknwrap: TokenWrap) -> Result<(), failure::Error> {
+  naming::set_rename(tknwrap.tdtypefill().rename());
   self::add_filter_case(tera)?;
   self::add_filter_td(tera)?;
   self::add_td_fnc(tera, tknwrap)?;
@@ -22,13 +25,13 @@ fn add_filter_case(tera: &mut Tera) -> Result<(), failure::Error> {
   // snake
   fn case_to_snake_filter(value: Value, arg: HashMap<String, Value>) -> tera::Result<Value> {
     match value.as_str() {
-      Some(text) => Ok(serde_json::value::to_value(text.to_snake()).unwrap()),
+      Some(text) => Ok(serde_json::value::to_value(naming::snake(text)).unwrap()),
       None => Err(format!("Error value {:?}", value).into())
     }
   }
   fn case_to_camel(value: Value, arg: HashMap<String, Value>) -> tera::Result<Value> {
     match value.as_str() {
-      Some(text) => Ok(serde_json::value::to_value(text.to_camel()).unwrap()),
+      Some(text) => Ok(serde_json::value::to_value(naming::camel(text)).unwrap()),
       None => Err(format!("Error value {:?}", value).into())
     }
   }
@@ -62,12 +65,7 @@ fn add_filter_case(tera: &mut Tera) -> Result<(), failure::Error> {
 fn add_filter_td(tera: &mut Tera) -> Result<(), failure::Error> {
   fn td_safe_field(value: Value, arg: HashMap<String, Value>) -> tera::Result<Value> {
     match value.as_str() {
-      Some(text) => match text {
-        "type" => Ok(serde_json::value::to_value("type_".to_string()).unwrap()),
-        "async" => Ok(serde_json::value::to_value("async_".to_string()).unwrap()),
-        "static" => Ok(serde_json::value::to_value("static_".to_string()).unwrap()),
-        _ => Ok(serde_json::value::to_value(text.to_snake()).unwrap())
-      },
+      Some(text) => Ok(serde_json::value::to_value(naming::safe_field(text)).unwrap()),
       None => Err(format!("Error value {:?}", value).into())
     }
   }
@@ -81,24 +79,52 @@ fn add_filter_td(tera: &mut Tera) -> Result<(), failure::Error> {
       Some(text) => {
         if prefix.is_none() { return Ok(serde_json::value::to_value(text.to_string()).unwrap()) }
         let prefix = prefix.unwrap();
-        let plura_prefix = format!("{}s", prefix);
-        if plura_prefix.to_lowercase() == text.to_lowercase() {
-          return Ok(serde_json::value::to_value(text).unwrap());
-        }
-        if !text.to_lowercase().starts_with(&prefix.to_lowercase()[..]) {
-          return Ok(serde_json::value::to_value(text).unwrap());
-        }
-
-        let prefix_chars = prefix.chars().collect::<Vec<char>>();
-        let text_chars = text.chars().collect::<Vec<char>>();
-        let ret = text.chars().skip(prefix_chars.len()).take(text_chars.len()).collect::<String>();
-        Ok(serde_json::value::to_value(ret).unwrap())
+        Ok(serde_json::value::to_value(naming::remove_prefix(text, prefix)).unwrap())
       }
       None => Err(format!("Error value {:?}", value).into())
     }
   }
 
+  // tdlib reference page of the td type, named by doxygen, e.g. `sendMessage` -> `classtd_1_1td__api_1_1send_message.html`
+  fn td_doc_url(value: Value, _: HashMap<String, Value>) -> tera::Result<Value> {
+    match value.as_str() {
+      Some(text) => {
+        let page = text.chars().map(|c| match c {
+          '_' => "__".to_string(),
+          c if c.is_uppercase() => format!("_{}", c.to_lowercase()),
+          c => c.to_string(),
+        }).collect::<String>();
+        Ok(serde_json::value::to_value(format!("https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1{}.html", page)).unwrap())
+      }
+      None => Err(format!("Error value {:?}", value).into())
+    }
+  }
+
+  // names of the gRPC target, the same as the names generated by prost from the proto
+  fn to_proto_camel(value: Value, _: HashMap<String, Value>) -> tera::Result<Value> {
+    match value.as_str() {
+      Some(text) => Ok(serde_json::value::to_value(grpc::proto_camel(text)).unwrap()),
+      None => Err(format!("Error value {:?}", value).into())
+    }
+  }
+  fn to_proto_snake(value: Value, _: HashMap<String, Value>) -> tera::Result<Value> {
+    match value.as_str() {
+      Some(text) => Ok(serde_json::value::to_value(grpc::proto_snake(text)).unwrap()),
+      None => Err(format!("Error value {:?}", value).into())
+    }
+  }
+  fn td_proto_field(value: Value, _: HashMap<String, Value>) -> tera::Result<Value> {
+    match value.as_str() {
+      Some(text) => Ok(serde_json::value::to_value(grpc::proto_field(text)).unwrap()),
+      None => Err(format!("Error value {:?}", value).into())
+    }
+  }
+
   tera.register_filter("td_safe_field", td_safe_field);
+  tera.register_filter("td_doc_url", td_doc_url);
+  tera.register_filter("to_proto_camel", to_proto_camel);
+  tera.register_filter("to_proto_snake", to_proto_snake);
+  tera.register_filter("td_proto_field", td_proto_field);
   tera.register_filter("td_remove_prefix", td_remove_prefix);
   Ok(())
 }
@@ -108,6 +134,14 @@ fn add_td_fnc(tera: &mut Tera, tknwrap: TokenWrap) -> Result<(), failure::Error>
   let tknwrap1 = tknwrap.clone();
   let tknwrap2 = tknwrap.clone();
   let tknwrap3 = tknwrap.clone();
+  let tknwrap4 = tknwrap.clone();
+  let tknwrap5 = tknwrap.clone();
+  let tknwrap6 = tknwrap.clone();
+  let tknwrap7 = tknwrap.clone();
+  let tknwrap8 = tknwrap.clone();
+  let tknwrap9 = tknwrap.clone();
+  let tknwrap10 = tknwrap.clone();
+  let tknwrap11 = tknwrap.clone();
 
   // argument serde_aux field_attributes
   let td_macros = Box::new(move |argument: HashMap<String, Value>| -> tera::Result<Value> {
@@ -128,7 +162,16 @@ fn add_td_fnc(tera: &mut Tera, tknwrap: TokenWrap) -> Result<(), failure::Error>
       None => return Err("Can't found arg".into())
     };
 
-    let aux = tdtypefill.td_filter_macros(token.name(), arg.sign_name());
+    let mut aux = tdtypefill.td_filter_macros(token.name(), arg.sign_name());
+    // int64 is a json string or number
+    if tdtypefill.td_filter(token.name(), arg.sign_name()).and_then(|v| v.sign_type()).is_none() {
+      let components = arg.components();
+      if arg.sign_type() == "int64" {
+        aux.push(r#"#[serde(with = "crate::types::_common::int64")]"#.to_string());
+      } else if self::is_vec_int64(&components) {
+        aux.push(r#"#[serde(with = "crate::types::_common::vec_int64")]"#.to_string());
+      }
+    }
     Ok(serde_json::value::to_value(aux).unwrap())
   });
 
@@ -208,6 +251,31 @@ fn add_td_fnc(tera: &mut Tera, tknwrap: TokenWrap) -> Result<(), failure::Error>
     Ok(serde_json::value::to_value(token).unwrap())
   });
 
+  // the schema has the token, e.g. `tdlibParameters` removed by tdlib 1.8.6, with the `field` if set
+  let has_token = Box::new(move |argument: HashMap<String, Value>| -> tera::Result<Value> {
+    let token_name = match argument.get("token_name").and_then(|t| t.as_str()) {
+      Some(n) => n.to_lowercase(),
+      None => return Err("Lose token name".into())
+    };
+    let field = argument.get("field").and_then(|f| f.as_str());
+    let has = tknwrap10.tokens().iter()
+      .filter(|token| token.name().to_lowercase() == token_name)
+      .any(|token| match field {
+        Some(field) => token.arguments().iter().any(|arg| arg.sign_name() == field),
+        None => true,
+      });
+    Ok(Value::Bool(has))
+  });
+
+  // td names of the items of the trait removed by `[select]`, deserialized to the default item
+  let removed_items = Box::new(move |argument: HashMap<String, Value>| -> tera::Result<Value> {
+    let name = match argument.get("name").and_then(|v| v.as_str()) {
+      Some(name) => name,
+      None => return Err("Can't found name".into())
+    };
+    Ok(serde_json::value::to_value(tknwrap11.removed_items(name)).unwrap())
+  });
+
   // type is primitive
   let is_primitive = Box::new(|argument: HashMap<String, Value>| -> tera::Result<Value> {
     let type_ = match argument.get("type_") {
@@ -259,10 +327,129 @@ fn add_td_fnc(tera: &mut Tera, tknwrap: TokenWrap) -> Result<(), failure::Error>
     Ok(serde_json::value::to_value(is).unwrap())
   });
 
+  // deprecated attribute with the deprecation `note` parsed from the schema, empty if none
+  let td_deprecated = Box::new(move |argument: HashMap<String, Value>| -> tera::Result<Value> {
+    let attr = argument.get("note")
+      .and_then(|v| v.as_str())
+      .map_or("".to_string(), |note| format!("#[deprecated(note = {:?})]", note));
+    Ok(serde_json::value::to_value(attr).unwrap())
+  });
+
+  // cfg attribute of the cargo features gating these td types (`name`, `also`) or this file (`file`), empty if not gated
+  let td_cfg = Box::new(move |argument: HashMap<String, Value>| -> tera::Result<Value> {
+    let mut features: Vec<&String> = vec![];
+    for key in &["name", "also"] {
+      if let Some(name) = argument.get(*key).and_then(|v| v.as_str()) {
+        if let Some(feature) = tknwrap4.feature(name) { features.push(feature); }
+      }
+    }
+    if let Some(file) = argument.get("file").and_then(|v| v.as_str()) {
+      if let Some(feature) = tknwrap4.file_feature(file) { features.push(feature); }
+    }
+    features.sort();
+    features.dedup();
+    let predicate = match features.len() {
+      0 => return Ok(serde_json::value::to_value("").unwrap()),
+      1 => format!("feature = \"{}\"", features[0]),
+      _ => format!("all({})", features.iter().map(|f| format!("feature = \"{}\"", f)).collect::<Vec<String>>().join(", ")),
+    };
+    // `not` is the cfg of the code used when the types are gated out
+    let not = argument.get("not").and_then(|v| v.as_bool()).unwrap_or(false);
+    let cfg = if not { format!("#[cfg(not({}))]", predicate) } else { format!("#[cfg({})]", predicate) };
+    Ok(serde_json::value::to_value(cfg).unwrap())
+  });
+
+  // integer argument documented as 0 if absent, the getter returns `None` for 0
+  let is_zero_absent = Box::new(move |argument: HashMap<String, Value>| -> tera::Result<Value> {
+    let token: TLTokenGroup = match argument.get("token") {
+      Some(t) => match serde_json::from_value(t.clone()) {
+        Ok(a) => a,
+        Err(_) => return Err("Can't covert token to TLTokenGroup".into())
+      },
+      None => return Err("Can't found token".into())
+    };
+    let arg: TLTokenArgType = match argument.get("arg") {
+      Some(t) => match serde_json::from_value(t.clone()) {
+        Ok(a) => a,
+        Err(_) => return Err("Can't covert arg to TLTokenArgType".into())
+      },
+      None => return Err("Can't found arg".into())
+    };
+    Ok(serde_json::value::to_value(tknwrap5.is_zero_absent_arg(&token, &arg)).unwrap())
+  });
+
+  // non-nullable td object argument, checked by `missing_fields`
+  let is_required = Box::new(move |argument: HashMap<String, Value>| -> tera::Result<Value> {
+    let token: TLTokenGroup = match argument.get("token") {
+      Some(t) => match serde_json::from_value(t.clone()) {
+        Ok(a) => a,
+        Err(_) => return Err("Can't covert token to TLTokenGroup".into())
+      },
+      None => return Err("Can't found token".into())
+    };
+    let arg: TLTokenArgType = match argument.get("arg") {
+      Some(t) => match serde_json::from_value(t.clone()) {
+        Ok(a) => a,
+        Err(_) => return Err("Can't covert arg to TLTokenArgType".into())
+      },
+      None => return Err("Can't found arg".into())
+    };
+    Ok(serde_json::value::to_value(tknwrap8.is_required_arg(&token, &arg)).unwrap())
+  });
+
+  // proto type of the argument
+  let td_proto_type = Box::new(move |argument: HashMap<String, Value>| -> tera::Result<Value> {
+    let arg: TLTokenArgType = match argument.get("arg") {
+      Some(t) => match serde_json::from_value(t.clone()) {
+        Ok(a) => a,
+        Err(_) => return Err("Can't covert arg to TLTokenArgType".into())
+      },
+      None => return Err("Can't found arg".into())
+    };
+    Ok(serde_json::value::to_value(grpc::proto_type(&arg)).unwrap())
+  });
+
+  // the leaf item of the trait, empty if none
+  let td_leaf_variant = Box::new(move |argument: HashMap<String, Value>| -> tera::Result<Value> {
+    let name = match argument.get("name").and_then(|v| v.as_str()) {
+      Some(name) => name,
+      None => return Err("Can't found name".into())
+    };
+    Ok(serde_json::value::to_value(tknwrap9.leaf_variant(name).map_or("".to_string(), |token| token.name())).unwrap())
+  });
+
+  // derives of the td type
+  let td_derive = Box::new(move |argument: HashMap<String, Value>| -> tera::Result<Value> {
+    let name = match argument.get("name").and_then(|v| v.as_str()) {
+      Some(name) => name,
+      None => return Err("Can't found name".into())
+    };
+    Ok(serde_json::value::to_value(tknwrap6.derives(name).join(", ")).unwrap())
+  });
+
+  // the td type has `into_parts`
+  let has_parts = Box::new(move |argument: HashMap<String, Value>| -> tera::Result<Value> {
+    let name = match argument.get("name").and_then(|v| v.as_str()) {
+      Some(name) => name,
+      None => return Err("Can't found name".into())
+    };
+    Ok(serde_json::value::to_value(tknwrap7.has_parts(name)).unwrap())
+  });
+
   tera.register_function("td_arg", td_arg);
+  tera.register_function("has_parts", has_parts);
+  tera.register_function("td_leaf_variant", td_leaf_variant);
+  tera.register_function("td_derive", td_derive);
+  tera.register_function("is_zero_absent", is_zero_absent);
+  tera.register_function("is_required", is_required);
+  tera.register_function("td_cfg", td_cfg);
+  tera.register_function("td_deprecated", td_deprecated);
+  tera.register_function("td_proto_type", td_proto_type);
   tera.register_function("td_macros", td_macros);
   tera.register_function("sub_tokens", sub_tokens);
   tera.register_function("find_token", find_token);
+  tera.register_function("has_token", has_token);
+  tera.register_function("removed_items", removed_items);
   tera.register_function("is_primitive", is_primitive);
   tera.register_function("is_optional", is_optional);
   tera.register_function("is_builder_ref", is_builder_ref);
@@ -270,6 +457,7 @@ fn add_td_fnc(tera: &mut Tera, tknwrap: TokenWrap) -> Result<(), failure::Error>
 }
 
 
+/// the rust parameters of a generic type of any depth, e.g. `<Vec<i64>>` of `vector<vector<int64>>`
 fn fill_type_components(components: Vec<TLTokenComponentType>, tdtypefill: &TDTypeFill) -> String {
   let mut rets = vec![];
   for component in components {
@@ -281,8 +469,17 @@ fn fill_type_components(components: Vec<TLTokenComponentType>, tdtypefill: &TDTy
       let component_type = fill_type_components(sub_components, tdtypefill);
       sign_type = format!("{}{}", sign_type, component_type);
     }
-    rets.push(format!("<{}>", sign_type));
+    rets.push(sign_type);
+  }
+  format!("<{}>", rets.join(", "))
+}
+
+/// the components of a vector of int64 of any depth, e.g. `vector<vector<int64>>`
+fn is_vec_int64(components: &[TLTokenComponentType]) -> bool {
+  match components {
+    [item] if item.sign_type() == "int64" => item.components().is_empty(),
+    [item] if item.sign_type() == "vector" => self::is_vec_int64(&item.components()),
+    _ => false,
   }
-  rets.join(", ")
 }
 
@@ -1,14 +1,13 @@
 use std::collections::HashMap;
-use std::fs::DirEntry;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 
 use case::CaseExt;
-use colored::Colorize;
 use tera::Context;
 
 use tl_parser::types::TLTokenGroup;
 
 use crate::Cycle;
+use crate::layout::{self, CrateLayout};
 use crate::tokenwrap::TokenWrap;
 
 pub struct TGClient<'a> {
@@ -30,63 +29,18 @@ impl<'a> TGClient<'a> {
       return bail!("RTD template path is not dir -> {:?}", path_template);
     }
 
-    self.clearance();
-
-    // move root path file
-    self.copy_file_to(&path_template, config.path_telegram_client())?;
+    let layout = self.cycle.layout().crate_layout("telegram-client")?;
+    layout.copy_files(&config.template_dirs(), config.path_telegram_client(), self.cycle.renderer().output())?;
 
     // generate src file
-    self.gensrc(&path_template)?;
-
-    Ok(())
-  }
-
-  fn clearance(&self) -> Result<(), failure::Error> {
-    let base_dir = self.cycle.config().path_telegram_client();
-    let path_src = base_dir.join("src");
-    if path_src.exists() {
-      std::fs::remove_dir_all(&path_src)?;
-    }
-    std::fs::create_dir_all(&path_src)?;
-    Ok(())
-  }
-
+    self.gensrc(layout)?;
 
-  /// copy template file to target path
-  fn copy_file_to<P: AsRef<Path>>(&self, from_dir: P, to_dir: P) -> Result<(), failure::Error> {
-    let from_dir = from_dir.as_ref();
-    let to_dir = to_dir.as_ref();
-    if !to_dir.exists() {
-      std::fs::create_dir_all(to_dir)?;
-    }
-    let rtd_read_dir: Vec<Result<DirEntry, std::io::Error>> = from_dir.read_dir()?.collect();
-    for entry in rtd_read_dir {
-      let entry_path = entry?.path();
-      if !entry_path.is_file() { continue; }
-      let file_name = match entry_path.file_name() {
-        Some(name) => name.to_string_lossy().into_owned(),
-        None => return bail!("Can not read file name")
-      };
-      let copy_to = to_dir.join(file_name);
-      debug!("COPY {} -> {}", entry_path.to_str().map_or("", |v| v).blue(), copy_to.to_str().map_or("", |v| v).blue());
-      std::fs::copy(entry_path, copy_to)?;
-    }
-    Ok(())
+    self.cycle.renderer().output().flush(config.path_telegram_client(), layout, config.touch_all())
   }
 
 
   /// generate src rs file.
-  fn gensrc<P: AsRef<Path>>(&self, path_template: P) -> Result<(), failure::Error> {
-    self.copy_rs(path_template)?;
-    self.gen_api()?;
-    self.gen_listener()?;
-    self.gen_handler()?;
-
-    Ok(())
-  }
-
-
-  fn gen_listener(&self) -> Result<(), failure::Error> {
+  fn gensrc(&self, layout: &CrateLayout) -> Result<(), failure::Error> {
     let config = self.cycle.config();
     let tknwrap = self.cycle.tknwrap();
 
@@ -106,71 +60,11 @@ impl<'a> TGClient<'a> {
 
     context.insert("listener", &listener);
 
-    self.cycle.renderer().render("telegram-client/src/listener.rs",
-                                 config.path_telegram_client().join("src/listener.rs"),
-                                 &mut context)?;
-    Ok(())
-  }
-
-
-  fn gen_handler(&self) -> Result<(), failure::Error> {
-    let config = self.cycle.config();
-    let tknwrap = self.cycle.tknwrap();
-
-    let mut context = Context::new();
-    let tokens = tknwrap.tokens();
-
-    context.insert("tokens", tokens);
-    let listener: HashMap<&String, &String> = tknwrap.tdtypefill().listener()
-      .iter()
-      .filter(|(key, value)| {
-        tknwrap.tokens().iter()
-          .filter(|&token| token.blood() == Some("Update".to_string()))
-          .find(|&token| token.name().to_lowercase() == value.to_lowercase())
-          .is_none()
-      })
-      .collect();
-
-    context.insert("listener", &listener);
-
-    self.cycle.renderer().render("telegram-client/src/handler.rs",
-                                 config.path_telegram_client().join("src/handler.rs"),
-                                 &mut context)?;
-    Ok(())
-  }
-
-  fn gen_api(&self) -> Result<(), failure::Error> {
-    let config = self.cycle.config();
-    let tknwrap = self.cycle.tknwrap();
-
-    let mut context = Context::new();
-    let tokens = tknwrap.tokens();
-    context.insert("tokens", tokens);
-
-    self.cycle.renderer().render("telegram-client/src/api.rs",
-                                 config.path_telegram_client().join("src/api.rs"),
-                                 &mut context)?;
-    Ok(())
-  }
-
-
-  fn copy_rs<P: AsRef<Path>>(&self, path_template: P) -> Result<(), failure::Error> {
-    let path_template = path_template.as_ref();
-    let base_dir = self.cycle.config().path_telegram_client();
-    let wait_copies: Vec<(PathBuf, PathBuf)> = vec![
-      (path_template.join("src/lib.rs"), base_dir.join("src/lib.rs")),
-      (path_template.join("src/client.rs"), base_dir.join("src/client.rs")),
-      (path_template.join("src/rtd.rs"), base_dir.join("src/rtd.rs")),
-      (path_template.join("src/rtd.rs"), base_dir.join("src/rtd.rs")),
-      (path_template.join("src/tip.rs"), base_dir.join("src/tip.rs")),
-      (path_template.join("src/errors.rs"), base_dir.join("src/errors.rs")),
-    ];
-
-    for (from, to) in wait_copies {
-      debug!("COPY {} -> {}", from.to_str().map_or("", |v| v).blue(), to.to_str().map_or("", |v| v).blue());
-      std::fs::copy(from, to)?;
+    for template in layout.render() {
+      self.cycle.renderer().render(template,
+                                   config.path_telegram_client().join(layout::out_path(template)),
+                                   &mut context)?;
     }
-
     Ok(())
   }
 }
@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use case::CaseExt;
 
 use tl_parser::types::*;
@@ -5,7 +7,7 @@ use tl_parser::types::*;
 use crate::tdfill::TDTypeFill;
 
 lazy_static! {
-  static ref SKIP_TYPES: Vec<&'static str> = {
+  pub(crate) static ref SKIP_TYPES: Vec<&'static str> = {
     vec![
       "double",
       "string",
@@ -24,11 +26,23 @@ lazy_static! {
 pub struct TokenWrap {
   tokens: Vec<TLTokenGroup>,
   tdtypefill: TDTypeFill,
+  /// lowercase td type name -> cargo feature gating it
+  features: HashMap<String, String>,
+  /// file name -> cargo feature gating all types of the file
+  file_features: HashMap<String, String>,
+  /// lowercase names of the td types with a float field, also in the fields types
+  floats: HashSet<String>,
+  /// lowercase trait name -> td names of its types removed by `[select]`
+  removed_items: HashMap<String, Vec<String>>,
 }
 
 impl TokenWrap {
-  pub fn new(tokens: Vec<TLTokenGroup>, tdtypefill: TDTypeFill) -> Self {
-    Self { tokens, tdtypefill }
+  pub fn new(tokens: Vec<TLTokenGroup>, tdtypefill: TDTypeFill, removed_items: HashMap<String, Vec<String>>) -> Self {
+    let features = self::gate_features(&tokens, &tdtypefill);
+    let floats = self::float_types(&tokens);
+    let mut tknwrap = Self { tokens, tdtypefill, features, file_features: HashMap::new(), floats, removed_items };
+    tknwrap.file_features = tknwrap.gate_files();
+    tknwrap
   }
 
   pub fn tokens(&self) -> &Vec<TLTokenGroup> {
@@ -44,6 +58,66 @@ impl TokenWrap {
     SKIP_TYPES.contains(&&type_name[..])
   }
 
+  /// The cargo feature gating this td type, `None` if it's always generated
+  pub fn feature<S: AsRef<str>>(&self, type_name: S) -> Option<&String> {
+    self.features.get(&type_name.as_ref().to_lowercase())
+  }
+
+  /// The td names of the types of this trait removed by `[select]`, see `select::removed_items`
+  pub fn removed_items<S: AsRef<str>>(&self, trait_name: S) -> Vec<String> {
+    self.removed_items.get(&trait_name.as_ref().to_lowercase()).cloned().unwrap_or_default()
+  }
+
+  /// The derives of this td type
+  pub fn derives<S: AsRef<str>>(&self, type_name: S) -> Vec<String> {
+    let type_name = type_name.as_ref().to_lowercase();
+    let derive = self.tdtypefill.derive();
+    let is_trait = self.tokens.iter()
+      .any(|token| token.name().to_lowercase() == type_name && token.type_() == TLTokenGroupType::Trait);
+    let mut derives = if is_trait { derive.trait_().clone() } else { derive.struct_().clone() };
+    if !self.floats.contains(&type_name) {
+      derives.extend(derive.no_float().iter().cloned());
+    }
+    derives
+  }
+
+  /// Does this td type have `into_parts`, a struct with more than 2 fields that aren't numbers or bool
+  pub fn has_parts<S: AsRef<str>>(&self, type_name: S) -> bool {
+    let type_name = type_name.as_ref().to_lowercase();
+    self.tokens.iter()
+      .find(|token| token.name().to_lowercase() == type_name)
+      .filter(|token| token.type_() == TLTokenGroupType::Struct)
+      .is_some_and(|token| token.arguments().iter()
+        .filter(|arg| !["int32", "int53", "int64", "double", "Bool"].contains(&&arg.sign_type()[..]))
+        .count() > 2)
+  }
+
+  /// The module of this td type by domain, `misc` if it's out of all domains
+  pub fn domain<S: AsRef<str>>(&self, type_name: S) -> String {
+    self.tdtypefill.domains().iter()
+      .find(|&domain| domain.contains(type_name.as_ref()))
+      .map_or("misc".to_string(), |domain| domain.name().clone())
+  }
+
+  /// The cargo feature gating all td types of this file
+  pub fn file_feature<S: AsRef<str>>(&self, file_name: S) -> Option<&String> {
+    self.file_features.get(file_name.as_ref())
+  }
+
+  fn gate_files(&self) -> HashMap<String, String> {
+    let mut files: HashMap<String, Option<String>> = HashMap::new();
+    for token in &self.tokens {
+      if self.is_skip_type(token.name()) { continue }
+      let feature = self.feature(token.name()).cloned();
+      files.entry(self.which_file(token.name()))
+        .and_modify(|file_feature| if *file_feature != feature { *file_feature = None })
+        .or_insert(feature);
+    }
+    files.into_iter()
+      .filter_map(|(file, feature)| feature.map(|feature| (file, feature)))
+      .collect()
+  }
+
   /// The file where td type is located
   pub fn which_file(&self, type_name: String) -> String {
     let token = self.tokens.iter()
@@ -75,4 +149,142 @@ impl TokenWrap {
         false
       }), |v| v.optional())
   }
+
+  /// non-nullable field of a td object, tdlib rejects the request if it's left as default
+  pub fn is_required_arg(&self, token: &TLTokenGroup, arg: &TLTokenArgType) -> bool {
+    if self.is_optional_arg(token, arg) { return false }
+    if self.tdtypefill.is_nullable(token.name(), arg.sign_name()) { return false }
+    if arg.description().is_some_and(|v| v.contains("null") || v.contains("for bots only")) { return false }
+    let sign_type = arg.sign_type();
+    if self.is_skip_type(sign_type.clone()) || sign_type == "Bool" { return false }
+    self.tokens.iter().any(|item| item.name().to_lowercase() == sign_type.to_lowercase())
+  }
+
+  /// The item of this trait with the fewest td object fields and not gated by a feature, the end of a
+  /// recursive arbitrary value, e.g. `richTextPlain` of `RichText`
+  pub fn leaf_variant<S: AsRef<str>>(&self, trait_name: S) -> Option<&TLTokenGroup> {
+    let trait_name = trait_name.as_ref().to_lowercase();
+    self.tokens.iter()
+      .filter(|token| token.blood().is_some_and(|blood| blood.to_lowercase() == trait_name))
+      .filter(|token| token.name().to_lowercase() != trait_name && self.feature(token.name()).is_none())
+      .min_by_key(|token| token.arguments().iter()
+        .filter(|arg| arg.sign_type() != "vector" && !self.is_skip_type(arg.sign_type()) && arg.sign_type() != "Bool")
+        .count())
+  }
+
+  /// integer field documented as 0 if absent, e.g. `reply_to_message_id ...; 0 if none`
+  pub fn is_zero_absent_arg(&self, token: &TLTokenGroup, arg: &TLTokenArgType) -> bool {
+    if token.type_() == TLTokenGroupType::Function { return false }
+    if self.tdtypefill.td_filter(token.name(), arg.sign_name()).is_some() { return false }
+    match &arg.sign_type()[..] {
+      "int32" | "int53" | "int64" => {}
+      _ => return false
+    }
+    arg.description().is_some_and(|v| {
+      // whole words, "10 if" is not "0 if"
+      let v = v.to_lowercase();
+      let words: Vec<&str> = v.split(|c: char| !c.is_alphanumeric()).filter(|word| !word.is_empty()).collect();
+      words.windows(2).any(|pair| pair == ["0", "if"]) || words.windows(3).any(|triple| triple == ["may", "be", "0"])
+    })
+  }
+}
+
+/// Assign the td types to the cargo features by the words of their names, then remove the types used
+/// by a type out of their feature, until no type is removed. A trait enum only gates its items, so
+/// `messageCall` is gated but `MessageContent` is not.
+fn gate_features(tokens: &[TLTokenGroup], tdtypefill: &TDTypeFill) -> HashMap<String, String> {
+  let mut features: HashMap<String, String> = HashMap::new();
+  for token in tokens {
+    let name = token.name();
+    if SKIP_TYPES.contains(&&name[..]) { continue; }
+    let feature = tdtypefill.features().iter().find(|&feature| feature.contains(&name));
+    if let Some(feature) = feature {
+      features.insert(name.to_lowercase(), feature.name().clone());
+    }
+  }
+
+  loop {
+    let mut ungated = vec![];
+    for token in tokens {
+      let name = token.name().to_lowercase();
+      let feature = features.get(&name);
+      let mut used: Vec<String> = vec![];
+      for arg in token.arguments() {
+        used.push(arg.sign_type());
+        self::component_types(arg.components(), &mut used);
+      }
+      for used_type in used {
+        let used_type = used_type.to_lowercase();
+        match features.get(&used_type) {
+          Some(used_feature) if Some(used_feature) != feature => ungated.push(used_type),
+          _ => {}
+        }
+      }
+      // the item of a gated trait must be in the same feature
+      if let Some(blood) = token.blood() {
+        let blood = blood.to_lowercase();
+        if blood != name && token.type_() != TLTokenGroupType::Function {
+          if let (Some(feature), Some(blood_feature)) = (feature, features.get(&blood)) {
+            if feature != blood_feature { ungated.push(name.clone()); }
+          }
+        }
+      }
+    }
+    if ungated.is_empty() { break; }
+    for name in ungated {
+      let feature = match features.remove(&name) {
+        Some(feature) => feature,
+        None => continue,
+      };
+      // the items of an ungated trait in the same feature are ungated with the trait
+      for token in tokens {
+        let item = token.name().to_lowercase();
+        if token.blood().is_some_and(|blood| blood.to_lowercase() == name) && item != name
+          && features.get(&item) == Some(&feature) {
+          features.remove(&item);
+        }
+      }
+    }
+  }
+  features
+}
+
+fn component_types(components: Vec<TLTokenComponentType>, used: &mut Vec<String>) {
+  for component in components {
+    used.push(component.sign_type());
+    self::component_types(component.components(), used);
+  }
+}
+
+/// The td types with a float field, also in the fields types or the items of a trait
+fn float_types(tokens: &[TLTokenGroup]) -> HashSet<String> {
+  let mut floats: HashSet<String> = HashSet::new();
+  floats.insert("double".to_string());
+  loop {
+    let mut found = vec![];
+    for token in tokens {
+      let name = token.name().to_lowercase();
+      if floats.contains(&name) {
+        // the trait enum of a float item
+        if let Some(blood) = token.blood() {
+          let blood = blood.to_lowercase();
+          if token.type_() != TLTokenGroupType::Function && !floats.contains(&blood) {
+            found.push(blood);
+          }
+        }
+        continue;
+      }
+      let mut used: Vec<String> = vec![];
+      for arg in token.arguments() {
+        used.push(arg.sign_type());
+        self::component_types(arg.components(), &mut used);
+      }
+      if used.iter().any(|used_type| floats.contains(&used_type.to_lowercase())) {
+        found.push(name);
+      }
+    }
+    if found.is_empty() { break; }
+    floats.extend(found);
+  }
+  floats
 }
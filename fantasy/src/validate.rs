@@ -0,0 +1,57 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use colored::Colorize;
+
+use crate::cycle::Cycle;
+
+/// Parse every rust file rendered by the templates, an error with the template and the line of its body
+/// for each file that isn't rust; format the files written by rustfmt if the config says so.
+pub fn validate(cycle: &Cycle) -> Result<(), failure::Error> {
+  let renderer = cycle.renderer();
+  let files: Vec<PathBuf> = renderer.rendered().into_iter()
+    .filter(|file| file.extension().is_some_and(|extension| extension == "rs"))
+    .collect();
+
+  let mut errors = 0;
+  for file in &files {
+    let code = std::fs::read_to_string(file)?;
+    if let Err(e) = syn::parse_file(&code) {
+      errors += 1;
+      let line = e.span().start().line;
+      let source = renderer.source(file, line)
+        .map(|(template, line)| format!("template {} line {}", template.red(), line))
+        .unwrap_or_else(|| "unknown template".to_string());
+      error!("{} line {}: {}, {}", file.to_string_lossy(), line, e, source);
+    }
+  }
+  if errors > 0 {
+    bail!("{} rendered files aren't rust, fix the templates", errors);
+  }
+  debug!("{} rendered rust files parsed", files.len());
+
+  // the unchanged files are formatted by the last generation
+  if cycle.config().rustfmt() {
+    let written = renderer.output().written();
+    let files: Vec<PathBuf> = files.into_iter().filter(|file| written.contains(file)).collect();
+    self::rustfmt(&files)?;
+  }
+  Ok(())
+}
+
+/// format the files by rustfmt, a warning if it isn't installed
+fn rustfmt(files: &[PathBuf]) -> Result<(), failure::Error> {
+  for chunk in files.chunks(256) {
+    let status = match Command::new("rustfmt").args(["--edition", "2018"]).args(chunk.iter().map(PathBuf::as_path).map(Path::as_os_str)).status() {
+      Ok(status) => status,
+      Err(e) => {
+        warn!("Can not run rustfmt, the rendered files aren't formatted: {}", e);
+        return Ok(());
+      }
+    };
+    if !status.success() {
+      bail!("rustfmt fail: {}", status);
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,36 @@
+use std::path::Path;
+use std::process::Command;
+
+/// the templates rendered against `schema/golden/td_api.tl` are the same as `schema/golden/expected`
+#[test]
+fn test_golden() {
+  let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+  let output = Command::new(env!("CARGO_BIN_EXE_fantasy"))
+    .arg("golden")
+    .current_dir(root)
+    .output()
+    .expect("Can not run fantasy");
+  assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
+
+/// the cargo feature of the golden types by the words of their names, `None` if not gated
+#[test]
+fn test_gate_features() {
+  let mod_rs = std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("../schema/golden/expected/rtdlib/src/types/mod.rs")).unwrap();
+  // the re-export of a type in its domain module, e.g. `#[cfg(feature = "payments")] pub use super::{MessageInvoice, ...}`
+  let feature = |type_name: &str| {
+    let export = format!("pub use super::{{{}, RTD{}Builder}};", type_name, type_name);
+    let line = mod_rs.lines().find(|line| line.ends_with(&export)).unwrap_or_else(|| panic!("{} is not exported", type_name));
+    line.trim().strip_prefix("#[cfg(feature = \"").map(|rest| rest[..rest.find('"').unwrap()].to_string())
+  };
+  assert_eq!(feature("MessageInvoice").as_deref(), Some("payments"));
+  assert_eq!(feature("MessageSticker").as_deref(), Some("stickers"));
+  assert_eq!(feature("Sticker").as_deref(), Some("stickers"));
+  assert_eq!(feature("MessageCall").as_deref(), Some("calls"));
+  assert_eq!(feature("CallProtocol").as_deref(), Some("calls"));
+  assert_eq!(feature("MessageText"), None);
+  // `except` of the payments feature
+  assert_eq!(feature("TextEntityTypeBankCardNumber"), None);
+  // a trait only gates its items
+  assert!(mod_rs.lines().any(|line| line.trim() == "pub use super::{MessageContent, TDMessageContent};"));
+}
@@ -0,0 +1,24 @@
+use std::path::Path;
+use std::process::Command;
+
+/// the ir of `schema/golden/td_api.tl` has the tokens with their blood
+#[test]
+fn test_emit_ir() {
+  let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+  let file_ir = std::env::temp_dir().join(format!("fantasy-ir-{}.json", std::process::id()));
+  let output = Command::new(env!("CARGO_BIN_EXE_fantasy"))
+    .args(["parse", "--schema", "schema/golden/td_api.tl", "--emit-ir"])
+    .arg(&file_ir)
+    .current_dir(root)
+    .output()
+    .expect("Can not run fantasy");
+  assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+
+  let ir: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&file_ir).unwrap()).unwrap();
+  std::fs::remove_file(&file_ir).unwrap();
+  assert_eq!(ir["format"], 1);
+  let tokens = ir["tokens"].as_array().unwrap();
+  let get_option = tokens.iter().find(|token| token["name"] == "getOption").unwrap();
+  assert_eq!(get_option["type_"], "Function");
+  assert_eq!(get_option["blood"], "OptionValue");
+}
@@ -14,6 +14,7 @@ pub struct UpdateAuthorizationState {
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum AuthorizationState {
+  _Default(()),
   Closed             (AuthorizationStateClosed               ),
   Closing            (AuthorizationStateClosing              ),
   LoggingOut         (AuthorizationStateLoggingOut           ),
@@ -157,6 +158,7 @@ macro_rules! rtd_enum_deserialize {
     // {"@type":"authorizationStateWaitEncryptionKey","is_encrypted":false}
     |deserializer: D| -> Result<$type_name, D::Error> {
       let rtd_trait_value: serde_json::Value = Deserialize::deserialize(deserializer)?;
+      if rtd_trait_value.is_null() { return Ok($type_name::_Default(())); }
       // the `rtd_trait_value` variable type is &serde_json::Value, tdlib trait will return a object, convert this type to object `&Map<String, Value>`
       let rtd_trait_map = match rtd_trait_value.as_object() {
         Some(map) => map,
@@ -244,3 +246,12 @@ fn test_authorization_state() {
   assert!(aut.is_ok(), true);
   assert_eq!(aut.unwrap(), json);
 }
+
+#[test]
+fn test_default_round_trip() {
+  let update = UpdateAuthorizationState { td_name: "updateAuthorizationState".to_string(), authorization_state: AuthorizationState::_Default(()) };
+  let json = serde_json::to_string(&update).unwrap();
+  assert_eq!(json, r#"{"@type":"updateAuthorizationState","authorization_state":null}"#);
+  let update: UpdateAuthorizationState = serde_json::from_str(&json).unwrap();
+  assert_eq!(serde_json::to_string(&update).unwrap(), json);
+}
@@ -0,0 +1,29 @@
+use std::path::Path;
+use std::process::Command;
+
+/// generate all crates from `schema/golden/td_api.tl` to `path_out`
+fn generate(path_out: &Path) {
+  let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+  let output = Command::new(env!("CARGO_BIN_EXE_fantasy"))
+    .args(["--schema", "schema/golden/td_api.tl", "--tdlib-version", "0.0.0", "--keep", "getTextEntities", "--out"])
+    .arg(path_out)
+    .current_dir(root)
+    .output()
+    .expect("Can not run fantasy");
+  assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+}
+
+/// a generated file edited by hand is rewritten although the generation didn't change it
+#[test]
+fn test_output_rewrites_edited_file() {
+  let path_out = std::env::temp_dir().join(format!("fantasy-output-{}", std::process::id()));
+  generate(&path_out);
+  let api_rs = path_out.join("telegram-client/src/api.rs");
+  let generated = std::fs::read_to_string(&api_rs).unwrap();
+  std::fs::write(&api_rs, "// edited by hand\n").unwrap();
+
+  generate(&path_out);
+  let regenerated = std::fs::read_to_string(&api_rs).unwrap();
+  std::fs::remove_dir_all(&path_out).unwrap();
+  assert_eq!(regenerated, generated);
+}
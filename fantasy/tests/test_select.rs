@@ -0,0 +1,47 @@
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// generate all crates from `schema/golden/td_api.tl` by a select to a temp dir
+fn generate(name: &str, select: &[&str]) -> (Output, std::path::PathBuf) {
+  let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+  let path_out = std::env::temp_dir().join(format!("fantasy-select-{}-{}", name, std::process::id()));
+  let output = Command::new(env!("CARGO_BIN_EXE_fantasy"))
+    .args(["--schema", "schema/golden/td_api.tl", "--tdlib-version", "0.0.0", "--out"])
+    .arg(&path_out)
+    .args(select)
+    .current_dir(root)
+    .output()
+    .expect("Can not run fantasy");
+  (output, path_out)
+}
+
+/// a keep list generates all crates, with the kept functions and the types the templates use
+#[test]
+fn test_select_keep() {
+  let (output, path_out) = generate("keep", &["--keep", "getTextEntities"]);
+  assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+
+  let mod_rs = std::fs::read_to_string(path_out.join("rtdlib/src/types/mod.rs")).unwrap();
+  std::fs::remove_dir_all(&path_out).unwrap();
+  let exported = |type_name: &str| mod_rs.contains(&format!("pub use super::{{{}, RTD{}Builder}};", type_name, type_name))
+    || mod_rs.contains(&format!("pub use super::{{{}, TD{}}};", type_name, type_name));
+  assert!(exported("GetTextEntities"));
+  assert!(exported("TextEntities"));
+  // used by the templates of telegram-client
+  assert!(exported("AuthorizationState"));
+  assert!(exported("UpdateAuthorizationState"));
+  assert!(exported("AuthorizationStateReady"));
+  // used by none
+  assert!(!exported("MessageCall"));
+  assert!(!exported("DiscardCall"));
+}
+
+/// a skipped type used by a template fails the generation with the template
+#[test]
+fn test_select_skip_used_by_template() {
+  let (output, path_out) = generate("skip", &["--keep", "getTextEntities", "--skip", "authorizationStateReady"]);
+  let _ = std::fs::remove_dir_all(&path_out);
+  assert!(!output.status.success());
+  let stdout = String::from_utf8_lossy(&output.stdout);
+  assert!(stdout.contains("authorizationStateReady is skipped but used by telegram-client/src/"), "{}", stdout);
+}
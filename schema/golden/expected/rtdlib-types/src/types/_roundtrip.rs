@@ -0,0 +1,189 @@
+//! json round trip of arbitrary values of every td type, `cargo test --features test-gen`
+
+use crate::arbitrary::check_round_trip;
+use crate::types::*;
+
+/// cases of every td type
+const CASES: u32 = 32;
+
+
+#[test]
+fn authorization_state() { check_round_trip::<AuthorizationState>(CASES); }
+
+#[cfg(feature = "calls")]
+#[test]
+fn call_discard_reason() { check_round_trip::<CallDiscardReason>(CASES); }
+
+
+#[test]
+fn keyboard_button_type() { check_round_trip::<KeyboardButtonType>(CASES); }
+
+
+#[test]
+fn message_content() { check_round_trip::<MessageContent>(CASES); }
+
+
+#[test]
+fn option_value() { check_round_trip::<OptionValue>(CASES); }
+
+
+#[test]
+fn reply_markup() { check_round_trip::<ReplyMarkup>(CASES); }
+
+
+#[test]
+fn rich_text() { check_round_trip::<RichText>(CASES); }
+
+
+#[test]
+fn text_entity_type() { check_round_trip::<TextEntityType>(CASES); }
+
+
+#[test]
+fn update() { check_round_trip::<Update>(CASES); }
+
+
+#[test]
+fn authorization_state_closed() { check_round_trip::<AuthorizationStateClosed>(CASES); }
+
+
+#[test]
+fn authorization_state_ready() { check_round_trip::<AuthorizationStateReady>(CASES); }
+
+
+#[test]
+fn authorization_state_wait_tdlib_parameters() { check_round_trip::<AuthorizationStateWaitTdlibParameters>(CASES); }
+
+#[cfg(feature = "calls")]
+#[test]
+fn call_discard_reason_empty() { check_round_trip::<CallDiscardReasonEmpty>(CASES); }
+
+#[cfg(feature = "calls")]
+#[test]
+fn call_discard_reason_hung_up() { check_round_trip::<CallDiscardReasonHungUp>(CASES); }
+
+#[cfg(feature = "calls")]
+#[test]
+fn call_protocol() { check_round_trip::<CallProtocol>(CASES); }
+
+#[cfg(feature = "calls")]
+#[test]
+fn discard_call() { check_round_trip::<DiscardCall>(CASES); }
+
+
+#[test]
+fn error() { check_round_trip::<Error>(CASES); }
+
+
+#[test]
+fn formatted_text() { check_round_trip::<FormattedText>(CASES); }
+
+
+#[test]
+fn get_authorization_state() { check_round_trip::<GetAuthorizationState>(CASES); }
+
+
+#[test]
+fn get_option() { check_round_trip::<GetOption>(CASES); }
+
+
+#[test]
+fn get_text_entities() { check_round_trip::<GetTextEntities>(CASES); }
+
+
+#[test]
+fn keyboard_button() { check_round_trip::<KeyboardButton>(CASES); }
+
+
+#[test]
+fn keyboard_button_type_request_location() { check_round_trip::<KeyboardButtonTypeRequestLocation>(CASES); }
+
+
+#[test]
+fn keyboard_button_type_text() { check_round_trip::<KeyboardButtonTypeText>(CASES); }
+
+#[cfg(feature = "calls")]
+#[test]
+fn message_call() { check_round_trip::<MessageCall>(CASES); }
+
+#[cfg(feature = "payments")]
+#[test]
+fn message_invoice() { check_round_trip::<MessageInvoice>(CASES); }
+
+#[cfg(feature = "stickers")]
+#[test]
+fn message_sticker() { check_round_trip::<MessageSticker>(CASES); }
+
+
+#[test]
+fn message_text() { check_round_trip::<MessageText>(CASES); }
+
+
+#[test]
+fn ok() { check_round_trip::<Ok>(CASES); }
+
+
+#[test]
+fn option_value_boolean() { check_round_trip::<OptionValueBoolean>(CASES); }
+
+
+#[test]
+fn option_value_empty() { check_round_trip::<OptionValueEmpty>(CASES); }
+
+
+#[test]
+fn reply_markup_remove_keyboard() { check_round_trip::<ReplyMarkupRemoveKeyboard>(CASES); }
+
+
+#[test]
+fn reply_markup_show_keyboard() { check_round_trip::<ReplyMarkupShowKeyboard>(CASES); }
+
+
+#[test]
+fn rich_text_bold() { check_round_trip::<RichTextBold>(CASES); }
+
+
+#[test]
+fn rich_text_plain() { check_round_trip::<RichTextPlain>(CASES); }
+
+
+#[test]
+fn set_tdlib_parameters() { check_round_trip::<SetTdlibParameters>(CASES); }
+
+#[cfg(feature = "stickers")]
+#[test]
+fn sticker() { check_round_trip::<Sticker>(CASES); }
+
+
+#[test]
+fn tdlib_parameters() { check_round_trip::<TdlibParameters>(CASES); }
+
+
+#[test]
+fn text_entities() { check_round_trip::<TextEntities>(CASES); }
+
+
+#[test]
+fn text_entity() { check_round_trip::<TextEntity>(CASES); }
+
+
+#[test]
+fn text_entity_type_bank_card_number() { check_round_trip::<TextEntityTypeBankCardNumber>(CASES); }
+
+
+#[test]
+fn text_entity_type_bold() { check_round_trip::<TextEntityTypeBold>(CASES); }
+
+
+#[test]
+fn text_entity_type_url() { check_round_trip::<TextEntityTypeUrl>(CASES); }
+
+
+#[test]
+fn update_authorization_state() { check_round_trip::<UpdateAuthorizationState>(CASES); }
+
+
+#[test]
+fn update_option() { check_round_trip::<UpdateOption>(CASES); }
+
+
@@ -0,0 +1,475 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+use std::fmt::Debug;
+use serde::de::{Deserialize, Deserializer};
+
+
+
+
+/// TRAIT | Represents the current authorization state of the TDLib client
+pub trait TDAuthorizationState: Debug + RObject {}
+
+ 
+/// Represents the current authorization state of the TDLib client
+///
+/// [AuthorizationState](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1_authorization_state.html) of the tdlib reference
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum AuthorizationState {
+  #[doc(hidden)] _Default(()),
+   
+  /// TDLib client is in its final state. All databases are closed and all resources are released. No other updates will be received after this. All queries will be responded to with error code 500. To continue working, one should create a new instance of the TDLib client
+  Closed(AuthorizationStateClosed),
+   
+  /// The user has been successfully authorized. TDLib is now ready to answer queries
+  Ready(AuthorizationStateReady),
+   
+  /// TDLib needs TdlibParameters for initialization
+  WaitTdlibParameters(AuthorizationStateWaitTdlibParameters),
+   
+  /// Returns the current authorization state; this is an offline request. For informational purposes only. Use updateAuthorizationState instead to maintain the current authorization state. Can be called before initialization
+  GetAuthorizationState(GetAuthorizationState),
+
+}
+
+
+impl Default for AuthorizationState {
+  fn default() -> Self { AuthorizationState::_Default(()) }
+}
+
+
+impl<'de> Deserialize<'de> for AuthorizationState {
+  fn deserialize<D>(deserializer: D) -> Result<AuthorizationState, D::Error> where D: Deserializer<'de> {
+    use serde::de::Error;
+    rtd_enum_deserialize!(
+      AuthorizationState,
+       (authorizationStateClosed, Closed);
+       (authorizationStateReady, Ready);
+       (authorizationStateWaitTdlibParameters, WaitTdlibParameters);
+       (getAuthorizationState, GetAuthorizationState);
+
+    )(deserializer)
+  }
+}
+
+
+impl RObject for AuthorizationState {
+  #[doc(hidden)] fn td_name(&self) -> &'static str {
+    match self {
+       AuthorizationState::Closed(t) => t.td_name(),
+       AuthorizationState::Ready(t) => t.td_name(),
+       AuthorizationState::WaitTdlibParameters(t) => t.td_name(),
+       AuthorizationState::GetAuthorizationState(t) => t.td_name(),
+
+      _ => "-1",
+    }
+  }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+
+impl AuthorizationState {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let AuthorizationState::_Default(_) = self { true } else { false } }
+
+   pub fn is_closed(&self) -> bool { if let AuthorizationState::Closed(_) = self { true } else { false } }
+   pub fn is_ready(&self) -> bool { if let AuthorizationState::Ready(_) = self { true } else { false } }
+   pub fn is_wait_tdlib_parameters(&self) -> bool { if let AuthorizationState::WaitTdlibParameters(_) = self { true } else { false } }
+   pub fn is_get_authorization_state(&self) -> bool { if let AuthorizationState::GetAuthorizationState(_) = self { true } else { false } }
+
+   pub fn on_closed<F: FnOnce(&AuthorizationStateClosed)>(&self, fnc: F) -> &Self { if let AuthorizationState::Closed(t) = self { fnc(t) }; self }
+   pub fn on_ready<F: FnOnce(&AuthorizationStateReady)>(&self, fnc: F) -> &Self { if let AuthorizationState::Ready(t) = self { fnc(t) }; self }
+   pub fn on_wait_tdlib_parameters<F: FnOnce(&AuthorizationStateWaitTdlibParameters)>(&self, fnc: F) -> &Self { if let AuthorizationState::WaitTdlibParameters(t) = self { fnc(t) }; self }
+   pub fn on_get_authorization_state<F: FnOnce(&GetAuthorizationState)>(&self, fnc: F) -> &Self { if let AuthorizationState::GetAuthorizationState(t) = self { fnc(t) }; self }
+
+   pub fn as_closed(&self) -> Option<&AuthorizationStateClosed> { if let AuthorizationState::Closed(t) = self { return Some(t) } None }
+   pub fn as_ready(&self) -> Option<&AuthorizationStateReady> { if let AuthorizationState::Ready(t) = self { return Some(t) } None }
+   pub fn as_wait_tdlib_parameters(&self) -> Option<&AuthorizationStateWaitTdlibParameters> { if let AuthorizationState::WaitTdlibParameters(t) = self { return Some(t) } None }
+   pub fn as_get_authorization_state(&self) -> Option<&GetAuthorizationState> { if let AuthorizationState::GetAuthorizationState(t) = self { return Some(t) } None }
+
+
+
+   pub fn closed<T: AsRef<AuthorizationStateClosed>>(t: T) -> Self { AuthorizationState::Closed(t.as_ref().clone()) }
+
+   pub fn ready<T: AsRef<AuthorizationStateReady>>(t: T) -> Self { AuthorizationState::Ready(t.as_ref().clone()) }
+
+   pub fn wait_tdlib_parameters<T: AsRef<AuthorizationStateWaitTdlibParameters>>(t: T) -> Self { AuthorizationState::WaitTdlibParameters(t.as_ref().clone()) }
+
+   pub fn get_authorization_state<T: AsRef<GetAuthorizationState>>(t: T) -> Self { AuthorizationState::GetAuthorizationState(t.as_ref().clone()) }
+
+}
+
+
+impl AsRef<AuthorizationState> for AuthorizationState {
+  fn as_ref(&self) -> &AuthorizationState { self }
+}
+
+
+
+impl From<AuthorizationStateClosed> for AuthorizationState {
+  fn from(t: AuthorizationStateClosed) -> Self { AuthorizationState::Closed(t) }
+}
+
+
+impl std::convert::TryFrom<AuthorizationState> for AuthorizationStateClosed {
+  type Error = AuthorizationState;
+  fn try_from(t: AuthorizationState) -> Result<Self, Self::Error> { if let AuthorizationState::Closed(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<AuthorizationStateReady> for AuthorizationState {
+  fn from(t: AuthorizationStateReady) -> Self { AuthorizationState::Ready(t) }
+}
+
+
+impl std::convert::TryFrom<AuthorizationState> for AuthorizationStateReady {
+  type Error = AuthorizationState;
+  fn try_from(t: AuthorizationState) -> Result<Self, Self::Error> { if let AuthorizationState::Ready(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<AuthorizationStateWaitTdlibParameters> for AuthorizationState {
+  fn from(t: AuthorizationStateWaitTdlibParameters) -> Self { AuthorizationState::WaitTdlibParameters(t) }
+}
+
+
+impl std::convert::TryFrom<AuthorizationState> for AuthorizationStateWaitTdlibParameters {
+  type Error = AuthorizationState;
+  fn try_from(t: AuthorizationState) -> Result<Self, Self::Error> { if let AuthorizationState::WaitTdlibParameters(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<GetAuthorizationState> for AuthorizationState {
+  fn from(t: GetAuthorizationState) -> Self { AuthorizationState::GetAuthorizationState(t) }
+}
+
+
+impl std::convert::TryFrom<AuthorizationState> for GetAuthorizationState {
+  type Error = AuthorizationState;
+  fn try_from(t: AuthorizationState) -> Result<Self, Self::Error> { if let AuthorizationState::GetAuthorizationState(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for AuthorizationState {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    use crate::arbitrary::{TdArbitrary, TdRng};
+    if depth == 0 { return AuthorizationState::Closed(TdArbitrary::td_arbitrary(rng, 0)); }
+    #[allow(unused_mut)]
+    let mut variants: Vec<fn(&mut TdRng, u32) -> Self> = vec![];
+     variants.push(|rng, depth| AuthorizationState::Closed(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| AuthorizationState::Ready(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| AuthorizationState::WaitTdlibParameters(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| AuthorizationState::GetAuthorizationState(TdArbitrary::td_arbitrary(rng, depth)));
+    if variants.is_empty() { return AuthorizationState::_Default(()); }
+    let variant = variants[rng.below(variants.len())];
+    variant(rng, depth.saturating_sub(1))
+  }
+}
+
+
+
+
+/// the variant name, e.g. `updateNewMessage`
+impl std::fmt::Display for AuthorizationState {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    if self._is_default() { f.write_str("default") } else { f.write_str(self.td_name()) }
+  }
+}
+
+
+
+
+
+
+
+/// TDLib client is in its final state. All databases are closed and all resources are released. No other updates will be received after this. All queries will be responded to with error code 500. To continue working, one should create a new instance of the TDLib client
+///
+/// [authorizationStateClosed](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1authorization_state_closed.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct AuthorizationStateClosed {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+
+impl RObject for AuthorizationStateClosed {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateClosed" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDAuthorizationState for AuthorizationStateClosed {}
+
+
+
+
+impl AuthorizationStateClosed {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDAuthorizationStateClosedBuilder {
+    let mut inner = AuthorizationStateClosed::default();
+    inner.td_name = "authorizationStateClosed".to_string();
+    RTDAuthorizationStateClosedBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDAuthorizationStateClosedBuilder { RTDAuthorizationStateClosedBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDAuthorizationStateClosedBuilder { RTDAuthorizationStateClosedBuilder { inner: self } }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDAuthorizationStateClosedBuilder {
+  inner: AuthorizationStateClosed
+}
+
+
+impl RTDAuthorizationStateClosedBuilder {
+  pub fn build(&self) -> AuthorizationStateClosed { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateClosed> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+
+impl AsRef<AuthorizationStateClosed> for AuthorizationStateClosed {
+  fn as_ref(&self) -> &AuthorizationStateClosed { self }
+}
+
+
+impl AsRef<AuthorizationStateClosed> for RTDAuthorizationStateClosedBuilder {
+  fn as_ref(&self) -> &AuthorizationStateClosed { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for AuthorizationStateClosed {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    AuthorizationStateClosed {
+      td_name: "authorizationStateClosed".to_string(),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// The user has been successfully authorized. TDLib is now ready to answer queries
+///
+/// [authorizationStateReady](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1authorization_state_ready.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct AuthorizationStateReady {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+
+impl RObject for AuthorizationStateReady {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateReady" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDAuthorizationState for AuthorizationStateReady {}
+
+
+
+
+impl AuthorizationStateReady {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDAuthorizationStateReadyBuilder {
+    let mut inner = AuthorizationStateReady::default();
+    inner.td_name = "authorizationStateReady".to_string();
+    RTDAuthorizationStateReadyBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDAuthorizationStateReadyBuilder { RTDAuthorizationStateReadyBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDAuthorizationStateReadyBuilder { RTDAuthorizationStateReadyBuilder { inner: self } }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDAuthorizationStateReadyBuilder {
+  inner: AuthorizationStateReady
+}
+
+
+impl RTDAuthorizationStateReadyBuilder {
+  pub fn build(&self) -> AuthorizationStateReady { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateReady> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+
+impl AsRef<AuthorizationStateReady> for AuthorizationStateReady {
+  fn as_ref(&self) -> &AuthorizationStateReady { self }
+}
+
+
+impl AsRef<AuthorizationStateReady> for RTDAuthorizationStateReadyBuilder {
+  fn as_ref(&self) -> &AuthorizationStateReady { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for AuthorizationStateReady {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    AuthorizationStateReady {
+      td_name: "authorizationStateReady".to_string(),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// TDLib needs TdlibParameters for initialization
+///
+/// [authorizationStateWaitTdlibParameters](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1authorization_state_wait_tdlib_parameters.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct AuthorizationStateWaitTdlibParameters {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+
+impl RObject for AuthorizationStateWaitTdlibParameters {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitTdlibParameters" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDAuthorizationState for AuthorizationStateWaitTdlibParameters {}
+
+
+
+
+impl AuthorizationStateWaitTdlibParameters {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDAuthorizationStateWaitTdlibParametersBuilder {
+    let mut inner = AuthorizationStateWaitTdlibParameters::default();
+    inner.td_name = "authorizationStateWaitTdlibParameters".to_string();
+    RTDAuthorizationStateWaitTdlibParametersBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDAuthorizationStateWaitTdlibParametersBuilder { RTDAuthorizationStateWaitTdlibParametersBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDAuthorizationStateWaitTdlibParametersBuilder { RTDAuthorizationStateWaitTdlibParametersBuilder { inner: self } }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDAuthorizationStateWaitTdlibParametersBuilder {
+  inner: AuthorizationStateWaitTdlibParameters
+}
+
+
+impl RTDAuthorizationStateWaitTdlibParametersBuilder {
+  pub fn build(&self) -> AuthorizationStateWaitTdlibParameters { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitTdlibParameters> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+
+impl AsRef<AuthorizationStateWaitTdlibParameters> for AuthorizationStateWaitTdlibParameters {
+  fn as_ref(&self) -> &AuthorizationStateWaitTdlibParameters { self }
+}
+
+
+impl AsRef<AuthorizationStateWaitTdlibParameters> for RTDAuthorizationStateWaitTdlibParametersBuilder {
+  fn as_ref(&self) -> &AuthorizationStateWaitTdlibParameters { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for AuthorizationStateWaitTdlibParameters {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    AuthorizationStateWaitTdlibParameters {
+      td_name: "authorizationStateWaitTdlibParameters".to_string(),
+    }
+  }
+}
+
+
+
+
+
@@ -0,0 +1,338 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+use std::fmt::Debug;
+use serde::de::{Deserialize, Deserializer};
+
+
+
+#[cfg(feature = "calls")]
+/// TRAIT | Describes the reason why a call was discarded
+pub trait TDCallDiscardReason: Debug + RObject {}
+
+#[cfg(feature = "calls")] 
+/// Describes the reason why a call was discarded
+///
+/// [CallDiscardReason](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1_call_discard_reason.html) of the tdlib reference
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum CallDiscardReason {
+  #[doc(hidden)] _Default(()),
+  #[cfg(feature = "calls")] 
+  /// The call wasn't discarded, or the reason is unknown
+  Empty(CallDiscardReasonEmpty),
+  #[cfg(feature = "calls")] 
+  /// The call was ended because one of the parties hung up
+  HungUp(CallDiscardReasonHungUp),
+
+}
+
+#[cfg(feature = "calls")]
+impl Default for CallDiscardReason {
+  fn default() -> Self { CallDiscardReason::_Default(()) }
+}
+
+#[cfg(feature = "calls")]
+impl<'de> Deserialize<'de> for CallDiscardReason {
+  fn deserialize<D>(deserializer: D) -> Result<CallDiscardReason, D::Error> where D: Deserializer<'de> {
+    use serde::de::Error;
+    rtd_enum_deserialize!(
+      CallDiscardReason,
+      #[cfg(feature = "calls")] (callDiscardReasonEmpty, Empty);
+      #[cfg(feature = "calls")] (callDiscardReasonHungUp, HungUp);
+      gated #[cfg(not(feature = "calls"))] callDiscardReasonEmpty;
+      gated #[cfg(not(feature = "calls"))] callDiscardReasonHungUp;
+
+    )(deserializer)
+  }
+}
+
+#[cfg(feature = "calls")]
+impl RObject for CallDiscardReason {
+  #[doc(hidden)] fn td_name(&self) -> &'static str {
+    match self {
+      #[cfg(feature = "calls")] CallDiscardReason::Empty(t) => t.td_name(),
+      #[cfg(feature = "calls")] CallDiscardReason::HungUp(t) => t.td_name(),
+
+      _ => "-1",
+    }
+  }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+#[cfg(feature = "calls")]
+impl CallDiscardReason {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallDiscardReason::_Default(_) = self { true } else { false } }
+
+  #[cfg(feature = "calls")] pub fn is_empty(&self) -> bool { if let CallDiscardReason::Empty(_) = self { true } else { false } }
+  #[cfg(feature = "calls")] pub fn is_hung_up(&self) -> bool { if let CallDiscardReason::HungUp(_) = self { true } else { false } }
+
+  #[cfg(feature = "calls")] pub fn on_empty<F: FnOnce(&CallDiscardReasonEmpty)>(&self, fnc: F) -> &Self { if let CallDiscardReason::Empty(t) = self { fnc(t) }; self }
+  #[cfg(feature = "calls")] pub fn on_hung_up<F: FnOnce(&CallDiscardReasonHungUp)>(&self, fnc: F) -> &Self { if let CallDiscardReason::HungUp(t) = self { fnc(t) }; self }
+
+  #[cfg(feature = "calls")] pub fn as_empty(&self) -> Option<&CallDiscardReasonEmpty> { if let CallDiscardReason::Empty(t) = self { return Some(t) } None }
+  #[cfg(feature = "calls")] pub fn as_hung_up(&self) -> Option<&CallDiscardReasonHungUp> { if let CallDiscardReason::HungUp(t) = self { return Some(t) } None }
+
+
+
+  #[cfg(feature = "calls")] pub fn empty<T: AsRef<CallDiscardReasonEmpty>>(t: T) -> Self { CallDiscardReason::Empty(t.as_ref().clone()) }
+
+  #[cfg(feature = "calls")] pub fn hung_up<T: AsRef<CallDiscardReasonHungUp>>(t: T) -> Self { CallDiscardReason::HungUp(t.as_ref().clone()) }
+
+}
+
+#[cfg(feature = "calls")]
+impl AsRef<CallDiscardReason> for CallDiscardReason {
+  fn as_ref(&self) -> &CallDiscardReason { self }
+}
+
+
+#[cfg(feature = "calls")]
+impl From<CallDiscardReasonEmpty> for CallDiscardReason {
+  fn from(t: CallDiscardReasonEmpty) -> Self { CallDiscardReason::Empty(t) }
+}
+
+#[cfg(feature = "calls")]
+impl std::convert::TryFrom<CallDiscardReason> for CallDiscardReasonEmpty {
+  type Error = CallDiscardReason;
+  fn try_from(t: CallDiscardReason) -> Result<Self, Self::Error> { if let CallDiscardReason::Empty(t) = t { Ok(t) } else { Err(t) } }
+}
+
+#[cfg(feature = "calls")]
+impl From<CallDiscardReasonHungUp> for CallDiscardReason {
+  fn from(t: CallDiscardReasonHungUp) -> Self { CallDiscardReason::HungUp(t) }
+}
+
+#[cfg(feature = "calls")]
+impl std::convert::TryFrom<CallDiscardReason> for CallDiscardReasonHungUp {
+  type Error = CallDiscardReason;
+  fn try_from(t: CallDiscardReason) -> Result<Self, Self::Error> { if let CallDiscardReason::HungUp(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+
+
+
+
+
+#[cfg(feature = "calls")]
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for CallDiscardReason {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    use crate::arbitrary::{TdArbitrary, TdRng};
+    #[allow(unused_mut)]
+    let mut variants: Vec<fn(&mut TdRng, u32) -> Self> = vec![];
+    #[cfg(feature = "calls")] variants.push(|rng, depth| CallDiscardReason::Empty(TdArbitrary::td_arbitrary(rng, depth)));
+    #[cfg(feature = "calls")] variants.push(|rng, depth| CallDiscardReason::HungUp(TdArbitrary::td_arbitrary(rng, depth)));
+    if variants.is_empty() { return CallDiscardReason::_Default(()); }
+    let variant = variants[rng.below(variants.len())];
+    variant(rng, depth.saturating_sub(1))
+  }
+}
+
+
+
+#[cfg(feature = "calls")]
+/// the variant name, e.g. `updateNewMessage`
+impl std::fmt::Display for CallDiscardReason {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    if self._is_default() { f.write_str("default") } else { f.write_str(self.td_name()) }
+  }
+}
+
+
+
+
+
+
+
+/// The call wasn't discarded, or the reason is unknown
+///
+/// [callDiscardReasonEmpty](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1call_discard_reason_empty.html) of the tdlib reference
+#[cfg(feature = "calls")] 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct CallDiscardReasonEmpty {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+#[cfg(feature = "calls")]
+impl RObject for CallDiscardReasonEmpty {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonEmpty" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+#[cfg(feature = "calls")]
+impl TDCallDiscardReason for CallDiscardReasonEmpty {}
+
+
+
+#[cfg(feature = "calls")]
+impl CallDiscardReasonEmpty {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDCallDiscardReasonEmptyBuilder {
+    let mut inner = CallDiscardReasonEmpty::default();
+    inner.td_name = "callDiscardReasonEmpty".to_string();
+    RTDCallDiscardReasonEmptyBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDCallDiscardReasonEmptyBuilder { RTDCallDiscardReasonEmptyBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDCallDiscardReasonEmptyBuilder { RTDCallDiscardReasonEmptyBuilder { inner: self } }
+
+}
+
+
+#[cfg(feature = "calls")]
+#[doc(hidden)]
+pub struct RTDCallDiscardReasonEmptyBuilder {
+  inner: CallDiscardReasonEmpty
+}
+
+#[cfg(feature = "calls")]
+impl RTDCallDiscardReasonEmptyBuilder {
+  pub fn build(&self) -> CallDiscardReasonEmpty { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<CallDiscardReasonEmpty> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+#[cfg(feature = "calls")]
+impl AsRef<CallDiscardReasonEmpty> for CallDiscardReasonEmpty {
+  fn as_ref(&self) -> &CallDiscardReasonEmpty { self }
+}
+
+#[cfg(feature = "calls")]
+impl AsRef<CallDiscardReasonEmpty> for RTDCallDiscardReasonEmptyBuilder {
+  fn as_ref(&self) -> &CallDiscardReasonEmpty { &self.inner }
+}
+
+
+
+
+
+#[cfg(feature = "calls")]
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for CallDiscardReasonEmpty {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    CallDiscardReasonEmpty {
+      td_name: "callDiscardReasonEmpty".to_string(),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// The call was ended because one of the parties hung up
+///
+/// [callDiscardReasonHungUp](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1call_discard_reason_hung_up.html) of the tdlib reference
+#[cfg(feature = "calls")] 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct CallDiscardReasonHungUp {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+#[cfg(feature = "calls")]
+impl RObject for CallDiscardReasonHungUp {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonHungUp" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+#[cfg(feature = "calls")]
+impl TDCallDiscardReason for CallDiscardReasonHungUp {}
+
+
+
+#[cfg(feature = "calls")]
+impl CallDiscardReasonHungUp {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDCallDiscardReasonHungUpBuilder {
+    let mut inner = CallDiscardReasonHungUp::default();
+    inner.td_name = "callDiscardReasonHungUp".to_string();
+    RTDCallDiscardReasonHungUpBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDCallDiscardReasonHungUpBuilder { RTDCallDiscardReasonHungUpBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDCallDiscardReasonHungUpBuilder { RTDCallDiscardReasonHungUpBuilder { inner: self } }
+
+}
+
+
+#[cfg(feature = "calls")]
+#[doc(hidden)]
+pub struct RTDCallDiscardReasonHungUpBuilder {
+  inner: CallDiscardReasonHungUp
+}
+
+#[cfg(feature = "calls")]
+impl RTDCallDiscardReasonHungUpBuilder {
+  pub fn build(&self) -> CallDiscardReasonHungUp { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<CallDiscardReasonHungUp> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+#[cfg(feature = "calls")]
+impl AsRef<CallDiscardReasonHungUp> for CallDiscardReasonHungUp {
+  fn as_ref(&self) -> &CallDiscardReasonHungUp { self }
+}
+
+#[cfg(feature = "calls")]
+impl AsRef<CallDiscardReasonHungUp> for RTDCallDiscardReasonHungUpBuilder {
+  fn as_ref(&self) -> &CallDiscardReasonHungUp { &self.inner }
+}
+
+
+
+
+
+#[cfg(feature = "calls")]
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for CallDiscardReasonHungUp {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    CallDiscardReasonHungUp {
+      td_name: "callDiscardReasonHungUp".to_string(),
+    }
+  }
+}
+
+
+
+
+
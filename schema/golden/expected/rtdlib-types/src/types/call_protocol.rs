@@ -0,0 +1,154 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// Specifies the supported call protocols
+///
+/// [callProtocol](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1call_protocol.html) of the tdlib reference
+#[cfg(feature = "calls")] 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct CallProtocol {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// True, if UDP peer-to-peer connections are supported
+  udp_p2p: bool,
+  /// True, if connection through UDP reflectors is supported
+  udp_reflector: bool,
+  /// The minimum supported API layer; use 65
+  min_layer: i64,
+  /// The maximum supported API layer; use 65
+  max_layer: i64,
+  /// List of supported libtgvoip versions
+  library_versions: Vec<String>,
+  
+}
+
+#[cfg(feature = "calls")]
+impl RObject for CallProtocol {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "callProtocol" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+#[cfg(feature = "calls")]
+impl CallProtocol {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDCallProtocolBuilder {
+    let mut inner = CallProtocol::default();
+    inner.td_name = "callProtocol".to_string();
+    RTDCallProtocolBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDCallProtocolBuilder { RTDCallProtocolBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDCallProtocolBuilder { RTDCallProtocolBuilder { inner: self } }
+
+  pub fn udp_p2p(&self) -> bool { self.udp_p2p }
+
+  pub fn udp_reflector(&self) -> bool { self.udp_reflector }
+
+  pub fn min_layer(&self) -> i64 { self.min_layer }
+
+  pub fn max_layer(&self) -> i64 { self.max_layer }
+
+  pub fn library_versions(&self) -> &Vec<String> { &self.library_versions }
+  pub fn into_library_versions(self) -> Vec<String> { self.library_versions }
+
+}
+
+
+#[cfg(feature = "calls")]
+#[doc(hidden)]
+pub struct RTDCallProtocolBuilder {
+  inner: CallProtocol
+}
+
+#[cfg(feature = "calls")]
+impl RTDCallProtocolBuilder {
+  pub fn build(&self) -> CallProtocol { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<CallProtocol> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn udp_p2p(&mut self, udp_p2p: bool) -> &mut Self {
+    self.inner.udp_p2p = udp_p2p;
+    self
+  }
+
+   
+  
+  pub fn udp_reflector(&mut self, udp_reflector: bool) -> &mut Self {
+    self.inner.udp_reflector = udp_reflector;
+    self
+  }
+
+   
+  
+  pub fn min_layer(&mut self, min_layer: i64) -> &mut Self {
+    self.inner.min_layer = min_layer;
+    self
+  }
+
+   
+  
+  pub fn max_layer(&mut self, max_layer: i64) -> &mut Self {
+    self.inner.max_layer = max_layer;
+    self
+  }
+
+   
+  
+  pub fn library_versions(&mut self, library_versions: Vec<String>) -> &mut Self {
+    self.inner.library_versions = library_versions;
+    self
+  }
+
+}
+
+#[cfg(feature = "calls")]
+impl AsRef<CallProtocol> for CallProtocol {
+  fn as_ref(&self) -> &CallProtocol { self }
+}
+
+#[cfg(feature = "calls")]
+impl AsRef<CallProtocol> for RTDCallProtocolBuilder {
+  fn as_ref(&self) -> &CallProtocol { &self.inner }
+}
+
+
+
+
+
+#[cfg(feature = "calls")]
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for CallProtocol {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    CallProtocol {
+      td_name: "callProtocol".to_string(),
+      udp_p2p: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      udp_reflector: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      min_layer: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      max_layer: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      library_versions: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
@@ -0,0 +1,120 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// Represents a single button in a bot keyboard
+///
+/// [keyboardButton](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1keyboard_button.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct KeyboardButton {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// Text of the button
+  text: String,
+  /// Type of the button
+  #[serde(rename(serialize = "type", deserialize = "type"))] type_: KeyboardButtonType,
+  
+}
+
+
+impl RObject for KeyboardButton {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButton" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    if self.type_._is_default() { missing.push("type"); }
+    missing
+  }
+}
+
+
+
+
+impl KeyboardButton {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDKeyboardButtonBuilder {
+    let mut inner = KeyboardButton::default();
+    inner.td_name = "keyboardButton".to_string();
+    RTDKeyboardButtonBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDKeyboardButtonBuilder { RTDKeyboardButtonBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDKeyboardButtonBuilder { RTDKeyboardButtonBuilder { inner: self } }
+
+  pub fn text(&self) -> &String { &self.text }
+  pub fn into_text(self) -> String { self.text }
+
+  pub fn type_(&self) -> &KeyboardButtonType { &self.type_ }
+  pub fn into_type(self) -> KeyboardButtonType { self.type_ }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDKeyboardButtonBuilder {
+  inner: KeyboardButton
+}
+
+
+impl RTDKeyboardButtonBuilder {
+  pub fn build(&self) -> KeyboardButton { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<KeyboardButton> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
+    self.inner.text = text.as_ref().to_string();
+    self
+  }
+
+   
+  
+  pub fn type_<T: AsRef<KeyboardButtonType>>(&mut self, type_: T) -> &mut Self {
+    self.inner.type_ = type_.as_ref().clone();
+    self
+  }
+
+}
+
+
+impl AsRef<KeyboardButton> for KeyboardButton {
+  fn as_ref(&self) -> &KeyboardButton { self }
+}
+
+
+impl AsRef<KeyboardButton> for RTDKeyboardButtonBuilder {
+  fn as_ref(&self) -> &KeyboardButton { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for KeyboardButton {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    KeyboardButton {
+      td_name: "keyboardButton".to_string(),
+      text: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      type_: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
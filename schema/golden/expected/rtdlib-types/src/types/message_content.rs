@@ -0,0 +1,708 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+use std::fmt::Debug;
+use serde::de::{Deserialize, Deserializer};
+
+
+
+
+/// TRAIT | Contains the content of a message
+pub trait TDMessageContent: Debug + RObject {}
+
+ 
+/// Contains the content of a message
+///
+/// [MessageContent](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1_message_content.html) of the tdlib reference
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum MessageContent {
+  #[doc(hidden)] _Default(()),
+  #[cfg(feature = "calls")] 
+  /// A message with information about an ended call
+  MessageCall(MessageCall),
+  #[cfg(feature = "payments")] 
+  /// A message with an invoice from a bot
+  MessageInvoice(MessageInvoice),
+  #[cfg(feature = "stickers")] 
+  /// A sticker message
+  MessageSticker(MessageSticker),
+   
+  /// A text message
+  MessageText(MessageText),
+
+}
+
+
+impl Default for MessageContent {
+  fn default() -> Self { MessageContent::_Default(()) }
+}
+
+
+impl<'de> Deserialize<'de> for MessageContent {
+  fn deserialize<D>(deserializer: D) -> Result<MessageContent, D::Error> where D: Deserializer<'de> {
+    use serde::de::Error;
+    rtd_enum_deserialize!(
+      MessageContent,
+      #[cfg(feature = "calls")] (messageCall, MessageCall);
+      #[cfg(feature = "payments")] (messageInvoice, MessageInvoice);
+      #[cfg(feature = "stickers")] (messageSticker, MessageSticker);
+       (messageText, MessageText);
+      gated #[cfg(not(feature = "calls"))] messageCall;
+      gated #[cfg(not(feature = "payments"))] messageInvoice;
+      gated #[cfg(not(feature = "stickers"))] messageSticker;
+
+    )(deserializer)
+  }
+}
+
+
+impl RObject for MessageContent {
+  #[doc(hidden)] fn td_name(&self) -> &'static str {
+    match self {
+      #[cfg(feature = "calls")] MessageContent::MessageCall(t) => t.td_name(),
+      #[cfg(feature = "payments")] MessageContent::MessageInvoice(t) => t.td_name(),
+      #[cfg(feature = "stickers")] MessageContent::MessageSticker(t) => t.td_name(),
+       MessageContent::MessageText(t) => t.td_name(),
+
+      _ => "-1",
+    }
+  }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+
+impl MessageContent {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageContent::_Default(_) = self { true } else { false } }
+
+  #[cfg(feature = "calls")] pub fn is_message_call(&self) -> bool { if let MessageContent::MessageCall(_) = self { true } else { false } }
+  #[cfg(feature = "payments")] pub fn is_message_invoice(&self) -> bool { if let MessageContent::MessageInvoice(_) = self { true } else { false } }
+  #[cfg(feature = "stickers")] pub fn is_message_sticker(&self) -> bool { if let MessageContent::MessageSticker(_) = self { true } else { false } }
+   pub fn is_message_text(&self) -> bool { if let MessageContent::MessageText(_) = self { true } else { false } }
+
+  #[cfg(feature = "calls")] pub fn on_message_call<F: FnOnce(&MessageCall)>(&self, fnc: F) -> &Self { if let MessageContent::MessageCall(t) = self { fnc(t) }; self }
+  #[cfg(feature = "payments")] pub fn on_message_invoice<F: FnOnce(&MessageInvoice)>(&self, fnc: F) -> &Self { if let MessageContent::MessageInvoice(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_message_sticker<F: FnOnce(&MessageSticker)>(&self, fnc: F) -> &Self { if let MessageContent::MessageSticker(t) = self { fnc(t) }; self }
+   pub fn on_message_text<F: FnOnce(&MessageText)>(&self, fnc: F) -> &Self { if let MessageContent::MessageText(t) = self { fnc(t) }; self }
+
+  #[cfg(feature = "calls")] pub fn as_message_call(&self) -> Option<&MessageCall> { if let MessageContent::MessageCall(t) = self { return Some(t) } None }
+  #[cfg(feature = "payments")] pub fn as_message_invoice(&self) -> Option<&MessageInvoice> { if let MessageContent::MessageInvoice(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_message_sticker(&self) -> Option<&MessageSticker> { if let MessageContent::MessageSticker(t) = self { return Some(t) } None }
+   pub fn as_message_text(&self) -> Option<&MessageText> { if let MessageContent::MessageText(t) = self { return Some(t) } None }
+
+
+
+  #[cfg(feature = "calls")] pub fn message_call<T: AsRef<MessageCall>>(t: T) -> Self { MessageContent::MessageCall(t.as_ref().clone()) }
+
+  #[cfg(feature = "payments")] pub fn message_invoice<T: AsRef<MessageInvoice>>(t: T) -> Self { MessageContent::MessageInvoice(t.as_ref().clone()) }
+
+  #[cfg(feature = "stickers")] pub fn message_sticker<T: AsRef<MessageSticker>>(t: T) -> Self { MessageContent::MessageSticker(t.as_ref().clone()) }
+
+   pub fn message_text<T: AsRef<MessageText>>(t: T) -> Self { MessageContent::MessageText(t.as_ref().clone()) }
+
+}
+
+
+impl AsRef<MessageContent> for MessageContent {
+  fn as_ref(&self) -> &MessageContent { self }
+}
+
+
+#[cfg(feature = "calls")]
+impl From<MessageCall> for MessageContent {
+  fn from(t: MessageCall) -> Self { MessageContent::MessageCall(t) }
+}
+
+#[cfg(feature = "calls")]
+impl std::convert::TryFrom<MessageContent> for MessageCall {
+  type Error = MessageContent;
+  fn try_from(t: MessageContent) -> Result<Self, Self::Error> { if let MessageContent::MessageCall(t) = t { Ok(t) } else { Err(t) } }
+}
+
+#[cfg(feature = "payments")]
+impl From<MessageInvoice> for MessageContent {
+  fn from(t: MessageInvoice) -> Self { MessageContent::MessageInvoice(t) }
+}
+
+#[cfg(feature = "payments")]
+impl std::convert::TryFrom<MessageContent> for MessageInvoice {
+  type Error = MessageContent;
+  fn try_from(t: MessageContent) -> Result<Self, Self::Error> { if let MessageContent::MessageInvoice(t) = t { Ok(t) } else { Err(t) } }
+}
+
+#[cfg(feature = "stickers")]
+impl From<MessageSticker> for MessageContent {
+  fn from(t: MessageSticker) -> Self { MessageContent::MessageSticker(t) }
+}
+
+#[cfg(feature = "stickers")]
+impl std::convert::TryFrom<MessageContent> for MessageSticker {
+  type Error = MessageContent;
+  fn try_from(t: MessageContent) -> Result<Self, Self::Error> { if let MessageContent::MessageSticker(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<MessageText> for MessageContent {
+  fn from(t: MessageText) -> Self { MessageContent::MessageText(t) }
+}
+
+
+impl std::convert::TryFrom<MessageContent> for MessageText {
+  type Error = MessageContent;
+  fn try_from(t: MessageContent) -> Result<Self, Self::Error> { if let MessageContent::MessageText(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+
+
+impl MessageContent {
+  /// the text of a text message
+  pub fn text(&self) -> Option<&FormattedText> {
+    match self {
+      MessageContent::MessageText(t) => Some(t.text()),
+      _ => None,
+    }
+  }
+
+  /// the caption of a message with a caption, e.g. a photo or a document
+  pub fn caption(&self) -> Option<&FormattedText> {
+    match self {
+       MessageContent::MessageAnimation(t) => Some(t.caption()),
+       MessageContent::MessageAudio(t) => Some(t.caption()),
+       MessageContent::MessageDocument(t) => Some(t.caption()),
+       MessageContent::MessagePhoto(t) => Some(t.caption()),
+       MessageContent::MessageVideo(t) => Some(t.caption()),
+       MessageContent::MessageVoiceNote(t) => Some(t.caption()),
+      _ => None,
+    }
+  }
+
+  /// the text of a text message or the caption of a message with a caption
+  pub fn text_or_caption(&self) -> Option<&FormattedText> {
+    self.text().or_else(|| self.caption())
+  }
+
+  /// the file of a media message, the largest size of a photo
+  pub fn file(&self) -> Option<&File> {
+    match self {
+       MessageContent::MessageAnimation(t) => Some(t.animation().animation()),
+       MessageContent::MessageAudio(t) => Some(t.audio().audio()),
+       MessageContent::MessageDocument(t) => Some(t.document().document()),
+       MessageContent::MessagePhoto(t) => t.photo().sizes().iter().max_by_key(|size| size.width() * size.height()).map(|size| size.photo()),
+      #[cfg(feature = "stickers")] MessageContent::MessageSticker(t) => Some(t.sticker().sticker()),
+       MessageContent::MessageVideo(t) => Some(t.video().video()),
+       MessageContent::MessageVideoNote(t) => Some(t.video_note().video()),
+       MessageContent::MessageVoiceNote(t) => Some(t.voice_note().voice()),
+      _ => None,
+    }
+  }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for MessageContent {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    use crate::arbitrary::{TdArbitrary, TdRng};
+    if depth == 0 { return MessageContent::MessageText(TdArbitrary::td_arbitrary(rng, 0)); }
+    #[allow(unused_mut)]
+    let mut variants: Vec<fn(&mut TdRng, u32) -> Self> = vec![];
+    #[cfg(feature = "calls")] variants.push(|rng, depth| MessageContent::MessageCall(TdArbitrary::td_arbitrary(rng, depth)));
+    #[cfg(feature = "payments")] variants.push(|rng, depth| MessageContent::MessageInvoice(TdArbitrary::td_arbitrary(rng, depth)));
+    #[cfg(feature = "stickers")] variants.push(|rng, depth| MessageContent::MessageSticker(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| MessageContent::MessageText(TdArbitrary::td_arbitrary(rng, depth)));
+    if variants.is_empty() { return MessageContent::_Default(()); }
+    let variant = variants[rng.below(variants.len())];
+    variant(rng, depth.saturating_sub(1))
+  }
+}
+
+
+
+
+/// the variant name, e.g. `updateNewMessage`
+impl std::fmt::Display for MessageContent {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    if self._is_default() { f.write_str("default") } else { f.write_str(self.td_name()) }
+  }
+}
+
+
+
+
+
+
+
+/// A message with information about an ended call
+///
+/// [messageCall](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1message_call.html) of the tdlib reference
+#[cfg(feature = "calls")] 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MessageCall {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// Reason why the call was discarded
+  discard_reason: CallDiscardReason,
+  /// Call duration, in seconds
+  duration: i64,
+  
+}
+
+#[cfg(feature = "calls")]
+impl RObject for MessageCall {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageCall" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    if self.discard_reason._is_default() { missing.push("discard_reason"); }
+    missing
+  }
+}
+
+
+#[cfg(feature = "calls")]
+impl TDMessageContent for MessageCall {}
+
+
+
+#[cfg(feature = "calls")]
+impl MessageCall {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDMessageCallBuilder {
+    let mut inner = MessageCall::default();
+    inner.td_name = "messageCall".to_string();
+    RTDMessageCallBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDMessageCallBuilder { RTDMessageCallBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDMessageCallBuilder { RTDMessageCallBuilder { inner: self } }
+
+  pub fn discard_reason(&self) -> &CallDiscardReason { &self.discard_reason }
+  pub fn into_discard_reason(self) -> CallDiscardReason { self.discard_reason }
+
+  pub fn duration(&self) -> i64 { self.duration }
+
+}
+
+
+#[cfg(feature = "calls")]
+#[doc(hidden)]
+pub struct RTDMessageCallBuilder {
+  inner: MessageCall
+}
+
+#[cfg(feature = "calls")]
+impl RTDMessageCallBuilder {
+  pub fn build(&self) -> MessageCall { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<MessageCall> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn discard_reason<T: AsRef<CallDiscardReason>>(&mut self, discard_reason: T) -> &mut Self {
+    self.inner.discard_reason = discard_reason.as_ref().clone();
+    self
+  }
+
+   
+  
+  pub fn duration(&mut self, duration: i64) -> &mut Self {
+    self.inner.duration = duration;
+    self
+  }
+
+}
+
+#[cfg(feature = "calls")]
+impl AsRef<MessageCall> for MessageCall {
+  fn as_ref(&self) -> &MessageCall { self }
+}
+
+#[cfg(feature = "calls")]
+impl AsRef<MessageCall> for RTDMessageCallBuilder {
+  fn as_ref(&self) -> &MessageCall { &self.inner }
+}
+
+
+
+
+
+#[cfg(feature = "calls")]
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for MessageCall {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    MessageCall {
+      td_name: "messageCall".to_string(),
+      discard_reason: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      duration: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// A message with an invoice from a bot
+///
+/// [messageInvoice](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1message_invoice.html) of the tdlib reference
+#[cfg(feature = "payments")] 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MessageInvoice {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// Product title
+  title: String,
+  /// Currency for the product price
+  currency: String,
+  /// Product total price in the minimal quantity of the currency
+  total_amount: i64,
+  
+}
+
+#[cfg(feature = "payments")]
+impl RObject for MessageInvoice {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageInvoice" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+#[cfg(feature = "payments")]
+impl TDMessageContent for MessageInvoice {}
+
+
+
+#[cfg(feature = "payments")]
+impl MessageInvoice {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDMessageInvoiceBuilder {
+    let mut inner = MessageInvoice::default();
+    inner.td_name = "messageInvoice".to_string();
+    RTDMessageInvoiceBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDMessageInvoiceBuilder { RTDMessageInvoiceBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDMessageInvoiceBuilder { RTDMessageInvoiceBuilder { inner: self } }
+
+  pub fn title(&self) -> &String { &self.title }
+  pub fn into_title(self) -> String { self.title }
+
+  pub fn currency(&self) -> &String { &self.currency }
+  pub fn into_currency(self) -> String { self.currency }
+
+  pub fn total_amount(&self) -> i64 { self.total_amount }
+
+}
+
+
+#[cfg(feature = "payments")]
+#[doc(hidden)]
+pub struct RTDMessageInvoiceBuilder {
+  inner: MessageInvoice
+}
+
+#[cfg(feature = "payments")]
+impl RTDMessageInvoiceBuilder {
+  pub fn build(&self) -> MessageInvoice { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<MessageInvoice> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
+    self.inner.title = title.as_ref().to_string();
+    self
+  }
+
+   
+  
+  pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
+    self.inner.currency = currency.as_ref().to_string();
+    self
+  }
+
+   
+  
+  pub fn total_amount(&mut self, total_amount: i64) -> &mut Self {
+    self.inner.total_amount = total_amount;
+    self
+  }
+
+}
+
+#[cfg(feature = "payments")]
+impl AsRef<MessageInvoice> for MessageInvoice {
+  fn as_ref(&self) -> &MessageInvoice { self }
+}
+
+#[cfg(feature = "payments")]
+impl AsRef<MessageInvoice> for RTDMessageInvoiceBuilder {
+  fn as_ref(&self) -> &MessageInvoice { &self.inner }
+}
+
+
+
+
+
+#[cfg(feature = "payments")]
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for MessageInvoice {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    MessageInvoice {
+      td_name: "messageInvoice".to_string(),
+      title: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      currency: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      total_amount: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// A sticker message
+///
+/// [messageSticker](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1message_sticker.html) of the tdlib reference
+#[cfg(feature = "stickers")] 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MessageSticker {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// The sticker description
+  sticker: Sticker,
+  
+}
+
+#[cfg(feature = "stickers")]
+impl RObject for MessageSticker {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSticker" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    if self.sticker._is_default() { missing.push("sticker"); }
+    missing
+  }
+}
+
+
+#[cfg(feature = "stickers")]
+impl TDMessageContent for MessageSticker {}
+
+
+
+#[cfg(feature = "stickers")]
+impl MessageSticker {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDMessageStickerBuilder {
+    let mut inner = MessageSticker::default();
+    inner.td_name = "messageSticker".to_string();
+    RTDMessageStickerBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDMessageStickerBuilder { RTDMessageStickerBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDMessageStickerBuilder { RTDMessageStickerBuilder { inner: self } }
+
+  pub fn sticker(&self) -> &Sticker { &self.sticker }
+  pub fn into_sticker(self) -> Sticker { self.sticker }
+
+}
+
+
+#[cfg(feature = "stickers")]
+#[doc(hidden)]
+pub struct RTDMessageStickerBuilder {
+  inner: MessageSticker
+}
+
+#[cfg(feature = "stickers")]
+impl RTDMessageStickerBuilder {
+  pub fn build(&self) -> MessageSticker { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<MessageSticker> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
+    self.inner.sticker = sticker.as_ref().clone();
+    self
+  }
+
+}
+
+#[cfg(feature = "stickers")]
+impl AsRef<MessageSticker> for MessageSticker {
+  fn as_ref(&self) -> &MessageSticker { self }
+}
+
+#[cfg(feature = "stickers")]
+impl AsRef<MessageSticker> for RTDMessageStickerBuilder {
+  fn as_ref(&self) -> &MessageSticker { &self.inner }
+}
+
+
+
+
+
+#[cfg(feature = "stickers")]
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for MessageSticker {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    MessageSticker {
+      td_name: "messageSticker".to_string(),
+      sticker: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// A text message
+///
+/// [messageText](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1message_text.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MessageText {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// Text of the message
+  text: FormattedText,
+  
+}
+
+
+impl RObject for MessageText {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageText" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text"); }
+    missing
+  }
+}
+
+
+
+impl TDMessageContent for MessageText {}
+
+
+
+
+impl MessageText {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDMessageTextBuilder {
+    let mut inner = MessageText::default();
+    inner.td_name = "messageText".to_string();
+    RTDMessageTextBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDMessageTextBuilder { RTDMessageTextBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDMessageTextBuilder { RTDMessageTextBuilder { inner: self } }
+
+  pub fn text(&self) -> &FormattedText { &self.text }
+  pub fn into_text(self) -> FormattedText { self.text }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDMessageTextBuilder {
+  inner: MessageText
+}
+
+
+impl RTDMessageTextBuilder {
+  pub fn build(&self) -> MessageText { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<MessageText> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
+    self.inner.text = text.as_ref().clone();
+    self
+  }
+
+}
+
+
+impl AsRef<MessageText> for MessageText {
+  fn as_ref(&self) -> &MessageText { self }
+}
+
+
+impl AsRef<MessageText> for RTDMessageTextBuilder {
+  fn as_ref(&self) -> &MessageText { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for MessageText {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    MessageText {
+      td_name: "messageText".to_string(),
+      text: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
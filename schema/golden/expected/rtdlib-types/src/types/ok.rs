@@ -0,0 +1,93 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// An object of this type is returned on a successful function call for certain functions
+///
+/// [ok](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1ok.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Ok {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+
+impl RObject for Ok {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "ok" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+
+impl Ok {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDOkBuilder {
+    let mut inner = Ok::default();
+    inner.td_name = "ok".to_string();
+    RTDOkBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDOkBuilder { RTDOkBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDOkBuilder { RTDOkBuilder { inner: self } }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDOkBuilder {
+  inner: Ok
+}
+
+
+impl RTDOkBuilder {
+  pub fn build(&self) -> Ok { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<Ok> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+
+impl AsRef<Ok> for Ok {
+  fn as_ref(&self) -> &Ok { self }
+}
+
+
+impl AsRef<Ok> for RTDOkBuilder {
+  fn as_ref(&self) -> &Ok { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for Ok {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    Ok {
+      td_name: "ok".to_string(),
+    }
+  }
+}
+
+
+
+
+
@@ -0,0 +1,371 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+use std::fmt::Debug;
+use serde::de::{Deserialize, Deserializer};
+
+
+
+
+/// TRAIT | Represents the value of an option
+pub trait TDOptionValue: Debug + RObject {}
+
+ 
+/// Represents the value of an option
+///
+/// [OptionValue](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1_option_value.html) of the tdlib reference
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum OptionValue {
+  #[doc(hidden)] _Default(()),
+   
+  /// Returns the value of an option by its name. (Check the list of available options on https://core.telegram.org/tdlib/options.) Can be called before authorization
+  GetOption(GetOption),
+   
+  /// Represents a boolean option
+  Boolean(OptionValueBoolean),
+   
+  /// Represents an unknown option or an option which has a default value
+  Empty(OptionValueEmpty),
+
+}
+
+
+impl Default for OptionValue {
+  fn default() -> Self { OptionValue::_Default(()) }
+}
+
+
+impl<'de> Deserialize<'de> for OptionValue {
+  fn deserialize<D>(deserializer: D) -> Result<OptionValue, D::Error> where D: Deserializer<'de> {
+    use serde::de::Error;
+    rtd_enum_deserialize!(
+      OptionValue,
+       (getOption, GetOption);
+       (optionValueBoolean, Boolean);
+       (optionValueEmpty, Empty);
+
+    )(deserializer)
+  }
+}
+
+
+impl RObject for OptionValue {
+  #[doc(hidden)] fn td_name(&self) -> &'static str {
+    match self {
+       OptionValue::GetOption(t) => t.td_name(),
+       OptionValue::Boolean(t) => t.td_name(),
+       OptionValue::Empty(t) => t.td_name(),
+
+      _ => "-1",
+    }
+  }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+
+impl OptionValue {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let OptionValue::_Default(_) = self { true } else { false } }
+
+   pub fn is_get_option(&self) -> bool { if let OptionValue::GetOption(_) = self { true } else { false } }
+   pub fn is_boolean(&self) -> bool { if let OptionValue::Boolean(_) = self { true } else { false } }
+   pub fn is_empty(&self) -> bool { if let OptionValue::Empty(_) = self { true } else { false } }
+
+   pub fn on_get_option<F: FnOnce(&GetOption)>(&self, fnc: F) -> &Self { if let OptionValue::GetOption(t) = self { fnc(t) }; self }
+   pub fn on_boolean<F: FnOnce(&OptionValueBoolean)>(&self, fnc: F) -> &Self { if let OptionValue::Boolean(t) = self { fnc(t) }; self }
+   pub fn on_empty<F: FnOnce(&OptionValueEmpty)>(&self, fnc: F) -> &Self { if let OptionValue::Empty(t) = self { fnc(t) }; self }
+
+   pub fn as_get_option(&self) -> Option<&GetOption> { if let OptionValue::GetOption(t) = self { return Some(t) } None }
+   pub fn as_boolean(&self) -> Option<&OptionValueBoolean> { if let OptionValue::Boolean(t) = self { return Some(t) } None }
+   pub fn as_empty(&self) -> Option<&OptionValueEmpty> { if let OptionValue::Empty(t) = self { return Some(t) } None }
+
+
+
+   pub fn get_option<T: AsRef<GetOption>>(t: T) -> Self { OptionValue::GetOption(t.as_ref().clone()) }
+
+   pub fn boolean<T: AsRef<OptionValueBoolean>>(t: T) -> Self { OptionValue::Boolean(t.as_ref().clone()) }
+
+   pub fn empty<T: AsRef<OptionValueEmpty>>(t: T) -> Self { OptionValue::Empty(t.as_ref().clone()) }
+
+}
+
+
+impl AsRef<OptionValue> for OptionValue {
+  fn as_ref(&self) -> &OptionValue { self }
+}
+
+
+
+impl From<GetOption> for OptionValue {
+  fn from(t: GetOption) -> Self { OptionValue::GetOption(t) }
+}
+
+
+impl std::convert::TryFrom<OptionValue> for GetOption {
+  type Error = OptionValue;
+  fn try_from(t: OptionValue) -> Result<Self, Self::Error> { if let OptionValue::GetOption(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<OptionValueBoolean> for OptionValue {
+  fn from(t: OptionValueBoolean) -> Self { OptionValue::Boolean(t) }
+}
+
+
+impl std::convert::TryFrom<OptionValue> for OptionValueBoolean {
+  type Error = OptionValue;
+  fn try_from(t: OptionValue) -> Result<Self, Self::Error> { if let OptionValue::Boolean(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<OptionValueEmpty> for OptionValue {
+  fn from(t: OptionValueEmpty) -> Self { OptionValue::Empty(t) }
+}
+
+
+impl std::convert::TryFrom<OptionValue> for OptionValueEmpty {
+  type Error = OptionValue;
+  fn try_from(t: OptionValue) -> Result<Self, Self::Error> { if let OptionValue::Empty(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for OptionValue {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    use crate::arbitrary::{TdArbitrary, TdRng};
+    if depth == 0 { return OptionValue::GetOption(TdArbitrary::td_arbitrary(rng, 0)); }
+    #[allow(unused_mut)]
+    let mut variants: Vec<fn(&mut TdRng, u32) -> Self> = vec![];
+     variants.push(|rng, depth| OptionValue::GetOption(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| OptionValue::Boolean(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| OptionValue::Empty(TdArbitrary::td_arbitrary(rng, depth)));
+    if variants.is_empty() { return OptionValue::_Default(()); }
+    let variant = variants[rng.below(variants.len())];
+    variant(rng, depth.saturating_sub(1))
+  }
+}
+
+
+
+
+/// the variant name, e.g. `updateNewMessage`
+impl std::fmt::Display for OptionValue {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    if self._is_default() { f.write_str("default") } else { f.write_str(self.td_name()) }
+  }
+}
+
+
+
+
+
+
+
+/// Represents a boolean option
+///
+/// [optionValueBoolean](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1option_value_boolean.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct OptionValueBoolean {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// The value of the option
+  value: bool,
+  
+}
+
+
+impl RObject for OptionValueBoolean {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "optionValueBoolean" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDOptionValue for OptionValueBoolean {}
+
+
+
+
+impl OptionValueBoolean {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDOptionValueBooleanBuilder {
+    let mut inner = OptionValueBoolean::default();
+    inner.td_name = "optionValueBoolean".to_string();
+    RTDOptionValueBooleanBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDOptionValueBooleanBuilder { RTDOptionValueBooleanBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDOptionValueBooleanBuilder { RTDOptionValueBooleanBuilder { inner: self } }
+
+  pub fn value(&self) -> bool { self.value }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDOptionValueBooleanBuilder {
+  inner: OptionValueBoolean
+}
+
+
+impl RTDOptionValueBooleanBuilder {
+  pub fn build(&self) -> OptionValueBoolean { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<OptionValueBoolean> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn value(&mut self, value: bool) -> &mut Self {
+    self.inner.value = value;
+    self
+  }
+
+}
+
+
+impl AsRef<OptionValueBoolean> for OptionValueBoolean {
+  fn as_ref(&self) -> &OptionValueBoolean { self }
+}
+
+
+impl AsRef<OptionValueBoolean> for RTDOptionValueBooleanBuilder {
+  fn as_ref(&self) -> &OptionValueBoolean { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for OptionValueBoolean {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    OptionValueBoolean {
+      td_name: "optionValueBoolean".to_string(),
+      value: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// Represents an unknown option or an option which has a default value
+///
+/// [optionValueEmpty](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1option_value_empty.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct OptionValueEmpty {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+
+impl RObject for OptionValueEmpty {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "optionValueEmpty" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDOptionValue for OptionValueEmpty {}
+
+
+
+
+impl OptionValueEmpty {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDOptionValueEmptyBuilder {
+    let mut inner = OptionValueEmpty::default();
+    inner.td_name = "optionValueEmpty".to_string();
+    RTDOptionValueEmptyBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDOptionValueEmptyBuilder { RTDOptionValueEmptyBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDOptionValueEmptyBuilder { RTDOptionValueEmptyBuilder { inner: self } }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDOptionValueEmptyBuilder {
+  inner: OptionValueEmpty
+}
+
+
+impl RTDOptionValueEmptyBuilder {
+  pub fn build(&self) -> OptionValueEmpty { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<OptionValueEmpty> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+
+impl AsRef<OptionValueEmpty> for OptionValueEmpty {
+  fn as_ref(&self) -> &OptionValueEmpty { self }
+}
+
+
+impl AsRef<OptionValueEmpty> for RTDOptionValueEmptyBuilder {
+  fn as_ref(&self) -> &OptionValueEmpty { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for OptionValueEmpty {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    OptionValueEmpty {
+      td_name: "optionValueEmpty".to_string(),
+    }
+  }
+}
+
+
+
+
+
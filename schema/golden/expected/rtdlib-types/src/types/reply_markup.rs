@@ -0,0 +1,398 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+use std::fmt::Debug;
+use serde::de::{Deserialize, Deserializer};
+
+
+
+
+/// TRAIT | Contains a description of a custom keyboard and actions that can be done with it to quickly reply to bots
+pub trait TDReplyMarkup: Debug + RObject {}
+
+ 
+/// Contains a description of a custom keyboard and actions that can be done with it to quickly reply to bots
+///
+/// [ReplyMarkup](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1_reply_markup.html) of the tdlib reference
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum ReplyMarkup {
+  #[doc(hidden)] _Default(()),
+   
+  /// Instructs application to remove the keyboard once this message has been received. This kind of keyboard can't be received in an incoming message; instead, UpdateChatReplyMarkup with message_id == 0 will be sent
+  RemoveKeyboard(ReplyMarkupRemoveKeyboard),
+   
+  /// Contains a custom keyboard layout to quickly reply to bots
+  ShowKeyboard(ReplyMarkupShowKeyboard),
+
+}
+
+
+impl Default for ReplyMarkup {
+  fn default() -> Self { ReplyMarkup::_Default(()) }
+}
+
+
+impl<'de> Deserialize<'de> for ReplyMarkup {
+  fn deserialize<D>(deserializer: D) -> Result<ReplyMarkup, D::Error> where D: Deserializer<'de> {
+    use serde::de::Error;
+    rtd_enum_deserialize!(
+      ReplyMarkup,
+       (replyMarkupRemoveKeyboard, RemoveKeyboard);
+       (replyMarkupShowKeyboard, ShowKeyboard);
+
+    )(deserializer)
+  }
+}
+
+
+impl RObject for ReplyMarkup {
+  #[doc(hidden)] fn td_name(&self) -> &'static str {
+    match self {
+       ReplyMarkup::RemoveKeyboard(t) => t.td_name(),
+       ReplyMarkup::ShowKeyboard(t) => t.td_name(),
+
+      _ => "-1",
+    }
+  }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+
+impl ReplyMarkup {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ReplyMarkup::_Default(_) = self { true } else { false } }
+
+   pub fn is_remove_keyboard(&self) -> bool { if let ReplyMarkup::RemoveKeyboard(_) = self { true } else { false } }
+   pub fn is_show_keyboard(&self) -> bool { if let ReplyMarkup::ShowKeyboard(_) = self { true } else { false } }
+
+   pub fn on_remove_keyboard<F: FnOnce(&ReplyMarkupRemoveKeyboard)>(&self, fnc: F) -> &Self { if let ReplyMarkup::RemoveKeyboard(t) = self { fnc(t) }; self }
+   pub fn on_show_keyboard<F: FnOnce(&ReplyMarkupShowKeyboard)>(&self, fnc: F) -> &Self { if let ReplyMarkup::ShowKeyboard(t) = self { fnc(t) }; self }
+
+   pub fn as_remove_keyboard(&self) -> Option<&ReplyMarkupRemoveKeyboard> { if let ReplyMarkup::RemoveKeyboard(t) = self { return Some(t) } None }
+   pub fn as_show_keyboard(&self) -> Option<&ReplyMarkupShowKeyboard> { if let ReplyMarkup::ShowKeyboard(t) = self { return Some(t) } None }
+
+
+
+   pub fn remove_keyboard<T: AsRef<ReplyMarkupRemoveKeyboard>>(t: T) -> Self { ReplyMarkup::RemoveKeyboard(t.as_ref().clone()) }
+
+   pub fn show_keyboard<T: AsRef<ReplyMarkupShowKeyboard>>(t: T) -> Self { ReplyMarkup::ShowKeyboard(t.as_ref().clone()) }
+
+}
+
+
+impl AsRef<ReplyMarkup> for ReplyMarkup {
+  fn as_ref(&self) -> &ReplyMarkup { self }
+}
+
+
+
+impl From<ReplyMarkupRemoveKeyboard> for ReplyMarkup {
+  fn from(t: ReplyMarkupRemoveKeyboard) -> Self { ReplyMarkup::RemoveKeyboard(t) }
+}
+
+
+impl std::convert::TryFrom<ReplyMarkup> for ReplyMarkupRemoveKeyboard {
+  type Error = ReplyMarkup;
+  fn try_from(t: ReplyMarkup) -> Result<Self, Self::Error> { if let ReplyMarkup::RemoveKeyboard(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<ReplyMarkupShowKeyboard> for ReplyMarkup {
+  fn from(t: ReplyMarkupShowKeyboard) -> Self { ReplyMarkup::ShowKeyboard(t) }
+}
+
+
+impl std::convert::TryFrom<ReplyMarkup> for ReplyMarkupShowKeyboard {
+  type Error = ReplyMarkup;
+  fn try_from(t: ReplyMarkup) -> Result<Self, Self::Error> { if let ReplyMarkup::ShowKeyboard(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for ReplyMarkup {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    use crate::arbitrary::{TdArbitrary, TdRng};
+    if depth == 0 { return ReplyMarkup::RemoveKeyboard(TdArbitrary::td_arbitrary(rng, 0)); }
+    #[allow(unused_mut)]
+    let mut variants: Vec<fn(&mut TdRng, u32) -> Self> = vec![];
+     variants.push(|rng, depth| ReplyMarkup::RemoveKeyboard(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| ReplyMarkup::ShowKeyboard(TdArbitrary::td_arbitrary(rng, depth)));
+    if variants.is_empty() { return ReplyMarkup::_Default(()); }
+    let variant = variants[rng.below(variants.len())];
+    variant(rng, depth.saturating_sub(1))
+  }
+}
+
+
+
+
+/// the variant name, e.g. `updateNewMessage`
+impl std::fmt::Display for ReplyMarkup {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    if self._is_default() { f.write_str("default") } else { f.write_str(self.td_name()) }
+  }
+}
+
+
+
+
+
+
+
+/// Instructs application to remove the keyboard once this message has been received. This kind of keyboard can't be received in an incoming message; instead, UpdateChatReplyMarkup with message_id == 0 will be sent
+///
+/// [replyMarkupRemoveKeyboard](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1reply_markup_remove_keyboard.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ReplyMarkupRemoveKeyboard {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// True, if the keyboard is removed only for the mentioned users or the target user of a reply
+  is_personal: bool,
+  
+}
+
+
+impl RObject for ReplyMarkupRemoveKeyboard {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "replyMarkupRemoveKeyboard" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDReplyMarkup for ReplyMarkupRemoveKeyboard {}
+
+
+
+
+impl ReplyMarkupRemoveKeyboard {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDReplyMarkupRemoveKeyboardBuilder {
+    let mut inner = ReplyMarkupRemoveKeyboard::default();
+    inner.td_name = "replyMarkupRemoveKeyboard".to_string();
+    RTDReplyMarkupRemoveKeyboardBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDReplyMarkupRemoveKeyboardBuilder { RTDReplyMarkupRemoveKeyboardBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDReplyMarkupRemoveKeyboardBuilder { RTDReplyMarkupRemoveKeyboardBuilder { inner: self } }
+
+  pub fn is_personal(&self) -> bool { self.is_personal }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDReplyMarkupRemoveKeyboardBuilder {
+  inner: ReplyMarkupRemoveKeyboard
+}
+
+
+impl RTDReplyMarkupRemoveKeyboardBuilder {
+  pub fn build(&self) -> ReplyMarkupRemoveKeyboard { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<ReplyMarkupRemoveKeyboard> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn is_personal(&mut self, is_personal: bool) -> &mut Self {
+    self.inner.is_personal = is_personal;
+    self
+  }
+
+}
+
+
+impl AsRef<ReplyMarkupRemoveKeyboard> for ReplyMarkupRemoveKeyboard {
+  fn as_ref(&self) -> &ReplyMarkupRemoveKeyboard { self }
+}
+
+
+impl AsRef<ReplyMarkupRemoveKeyboard> for RTDReplyMarkupRemoveKeyboardBuilder {
+  fn as_ref(&self) -> &ReplyMarkupRemoveKeyboard { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for ReplyMarkupRemoveKeyboard {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    ReplyMarkupRemoveKeyboard {
+      td_name: "replyMarkupRemoveKeyboard".to_string(),
+      is_personal: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// Contains a custom keyboard layout to quickly reply to bots
+///
+/// [replyMarkupShowKeyboard](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1reply_markup_show_keyboard.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ReplyMarkupShowKeyboard {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// A list of rows of bot keyboard buttons
+  rows: Vec<Vec<KeyboardButton>>,
+  /// True, if the application needs to resize the keyboard vertically
+  resize_keyboard: bool,
+  /// True, if the application needs to hide the keyboard after use
+  one_time: bool,
+  /// True, if the keyboard must automatically be shown to the current user. For outgoing messages, specify true to show the keyboard only for the mentioned users and for the target user of a reply
+  is_personal: bool,
+  
+}
+
+
+impl RObject for ReplyMarkupShowKeyboard {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "replyMarkupShowKeyboard" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDReplyMarkup for ReplyMarkupShowKeyboard {}
+
+
+
+
+impl ReplyMarkupShowKeyboard {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDReplyMarkupShowKeyboardBuilder {
+    let mut inner = ReplyMarkupShowKeyboard::default();
+    inner.td_name = "replyMarkupShowKeyboard".to_string();
+    RTDReplyMarkupShowKeyboardBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDReplyMarkupShowKeyboardBuilder { RTDReplyMarkupShowKeyboardBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDReplyMarkupShowKeyboardBuilder { RTDReplyMarkupShowKeyboardBuilder { inner: self } }
+
+  pub fn rows(&self) -> &Vec<Vec<KeyboardButton>> { &self.rows }
+  pub fn into_rows(self) -> Vec<Vec<KeyboardButton>> { self.rows }
+
+  pub fn resize_keyboard(&self) -> bool { self.resize_keyboard }
+
+  pub fn one_time(&self) -> bool { self.one_time }
+
+  pub fn is_personal(&self) -> bool { self.is_personal }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDReplyMarkupShowKeyboardBuilder {
+  inner: ReplyMarkupShowKeyboard
+}
+
+
+impl RTDReplyMarkupShowKeyboardBuilder {
+  pub fn build(&self) -> ReplyMarkupShowKeyboard { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<ReplyMarkupShowKeyboard> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn rows(&mut self, rows: Vec<Vec<KeyboardButton>>) -> &mut Self {
+    self.inner.rows = rows;
+    self
+  }
+
+   
+  
+  pub fn resize_keyboard(&mut self, resize_keyboard: bool) -> &mut Self {
+    self.inner.resize_keyboard = resize_keyboard;
+    self
+  }
+
+   
+  
+  pub fn one_time(&mut self, one_time: bool) -> &mut Self {
+    self.inner.one_time = one_time;
+    self
+  }
+
+   
+  
+  pub fn is_personal(&mut self, is_personal: bool) -> &mut Self {
+    self.inner.is_personal = is_personal;
+    self
+  }
+
+}
+
+
+impl AsRef<ReplyMarkupShowKeyboard> for ReplyMarkupShowKeyboard {
+  fn as_ref(&self) -> &ReplyMarkupShowKeyboard { self }
+}
+
+
+impl AsRef<ReplyMarkupShowKeyboard> for RTDReplyMarkupShowKeyboardBuilder {
+  fn as_ref(&self) -> &ReplyMarkupShowKeyboard { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for ReplyMarkupShowKeyboard {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    ReplyMarkupShowKeyboard {
+      td_name: "replyMarkupShowKeyboard".to_string(),
+      rows: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      resize_keyboard: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      one_time: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      is_personal: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
@@ -0,0 +1,364 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+use std::fmt::Debug;
+use serde::de::{Deserialize, Deserializer};
+
+
+
+
+/// TRAIT | Describes a text object inside an instant-view web page
+pub trait TDRichText: Debug + RObject {}
+
+ 
+/// Describes a text object inside an instant-view web page
+///
+/// [RichText](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1_rich_text.html) of the tdlib reference
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum RichText {
+  #[doc(hidden)] _Default(()),
+   
+  /// A bold rich text
+  Bold(RichTextBold),
+   
+  /// A plain text
+  Plain(RichTextPlain),
+
+}
+
+
+impl Default for RichText {
+  fn default() -> Self { RichText::_Default(()) }
+}
+
+
+impl<'de> Deserialize<'de> for RichText {
+  fn deserialize<D>(deserializer: D) -> Result<RichText, D::Error> where D: Deserializer<'de> {
+    use serde::de::Error;
+    rtd_enum_deserialize!(
+      RichText,
+       (richTextBold, Bold);
+       (richTextPlain, Plain);
+
+    )(deserializer)
+  }
+}
+
+
+impl RObject for RichText {
+  #[doc(hidden)] fn td_name(&self) -> &'static str {
+    match self {
+       RichText::Bold(t) => t.td_name(),
+       RichText::Plain(t) => t.td_name(),
+
+      _ => "-1",
+    }
+  }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+
+impl RichText {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let RichText::_Default(_) = self { true } else { false } }
+
+   pub fn is_bold(&self) -> bool { if let RichText::Bold(_) = self { true } else { false } }
+   pub fn is_plain(&self) -> bool { if let RichText::Plain(_) = self { true } else { false } }
+
+   pub fn on_bold<F: FnOnce(&RichTextBold)>(&self, fnc: F) -> &Self { if let RichText::Bold(t) = self { fnc(t) }; self }
+   pub fn on_plain<F: FnOnce(&RichTextPlain)>(&self, fnc: F) -> &Self { if let RichText::Plain(t) = self { fnc(t) }; self }
+
+   pub fn as_bold(&self) -> Option<&RichTextBold> { if let RichText::Bold(t) = self { return Some(t) } None }
+   pub fn as_plain(&self) -> Option<&RichTextPlain> { if let RichText::Plain(t) = self { return Some(t) } None }
+
+
+
+   pub fn bold<T: AsRef<RichTextBold>>(t: T) -> Self { RichText::Bold(t.as_ref().clone()) }
+
+   pub fn plain<T: AsRef<RichTextPlain>>(t: T) -> Self { RichText::Plain(t.as_ref().clone()) }
+
+}
+
+
+impl AsRef<RichText> for RichText {
+  fn as_ref(&self) -> &RichText { self }
+}
+
+
+
+impl From<RichTextBold> for RichText {
+  fn from(t: RichTextBold) -> Self { RichText::Bold(t) }
+}
+
+
+impl std::convert::TryFrom<RichText> for RichTextBold {
+  type Error = RichText;
+  fn try_from(t: RichText) -> Result<Self, Self::Error> { if let RichText::Bold(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<RichTextPlain> for RichText {
+  fn from(t: RichTextPlain) -> Self { RichText::Plain(t) }
+}
+
+
+impl std::convert::TryFrom<RichText> for RichTextPlain {
+  type Error = RichText;
+  fn try_from(t: RichText) -> Result<Self, Self::Error> { if let RichText::Plain(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for RichText {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    use crate::arbitrary::{TdArbitrary, TdRng};
+    if depth == 0 { return RichText::Plain(TdArbitrary::td_arbitrary(rng, 0)); }
+    #[allow(unused_mut)]
+    let mut variants: Vec<fn(&mut TdRng, u32) -> Self> = vec![];
+     variants.push(|rng, depth| RichText::Bold(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| RichText::Plain(TdArbitrary::td_arbitrary(rng, depth)));
+    if variants.is_empty() { return RichText::_Default(()); }
+    let variant = variants[rng.below(variants.len())];
+    variant(rng, depth.saturating_sub(1))
+  }
+}
+
+
+
+
+/// the variant name, e.g. `updateNewMessage`
+impl std::fmt::Display for RichText {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    if self._is_default() { f.write_str("default") } else { f.write_str(self.td_name()) }
+  }
+}
+
+
+
+
+
+
+
+/// A bold rich text
+///
+/// [richTextBold](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1rich_text_bold.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct RichTextBold {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// Text
+  text: Box<RichText>,
+  
+}
+
+
+impl RObject for RichTextBold {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextBold" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text"); }
+    missing
+  }
+}
+
+
+
+impl TDRichText for RichTextBold {}
+
+
+
+
+impl RichTextBold {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDRichTextBoldBuilder {
+    let mut inner = RichTextBold::default();
+    inner.td_name = "richTextBold".to_string();
+    RTDRichTextBoldBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDRichTextBoldBuilder { RTDRichTextBoldBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDRichTextBoldBuilder { RTDRichTextBoldBuilder { inner: self } }
+
+  pub fn text(&self) -> &Box<RichText> { &self.text }
+  pub fn into_text(self) -> Box<RichText> { self.text }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDRichTextBoldBuilder {
+  inner: RichTextBold
+}
+
+
+impl RTDRichTextBoldBuilder {
+  pub fn build(&self) -> RichTextBold { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<RichTextBold> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
+    self.inner.text = text.as_ref().clone();
+    self
+  }
+
+}
+
+
+impl AsRef<RichTextBold> for RichTextBold {
+  fn as_ref(&self) -> &RichTextBold { self }
+}
+
+
+impl AsRef<RichTextBold> for RTDRichTextBoldBuilder {
+  fn as_ref(&self) -> &RichTextBold { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for RichTextBold {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    RichTextBold {
+      td_name: "richTextBold".to_string(),
+      text: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// A plain text
+///
+/// [richTextPlain](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1rich_text_plain.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct RichTextPlain {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// Text
+  text: Box<RichText>,
+  
+}
+
+
+impl RObject for RichTextPlain {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextPlain" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDRichText for RichTextPlain {}
+
+
+
+
+impl RichTextPlain {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDRichTextPlainBuilder {
+    let mut inner = RichTextPlain::default();
+    inner.td_name = "richTextPlain".to_string();
+    RTDRichTextPlainBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDRichTextPlainBuilder { RTDRichTextPlainBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDRichTextPlainBuilder { RTDRichTextPlainBuilder { inner: self } }
+
+  pub fn text(&self) -> &Box<RichText> { &self.text }
+  pub fn into_text(self) -> Box<RichText> { self.text }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDRichTextPlainBuilder {
+  inner: RichTextPlain
+}
+
+
+impl RTDRichTextPlainBuilder {
+  pub fn build(&self) -> RichTextPlain { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<RichTextPlain> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
+    self.inner.text = text.as_ref().clone();
+    self
+  }
+
+}
+
+
+impl AsRef<RichTextPlain> for RichTextPlain {
+  fn as_ref(&self) -> &RichTextPlain { self }
+}
+
+
+impl AsRef<RichTextPlain> for RTDRichTextPlainBuilder {
+  fn as_ref(&self) -> &RichTextPlain { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for RichTextPlain {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    RichTextPlain {
+      td_name: "richTextPlain".to_string(),
+      text: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
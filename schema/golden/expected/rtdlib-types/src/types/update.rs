@@ -0,0 +1,378 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+use std::fmt::Debug;
+use serde::de::{Deserialize, Deserializer};
+
+
+
+
+/// TRAIT | Contains notifications about data changes
+pub trait TDUpdate: Debug + RObject {}
+
+ 
+/// Contains notifications about data changes
+///
+/// [Update](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1_update.html) of the tdlib reference
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum Update {
+  #[doc(hidden)] _Default(()),
+   
+  /// The user authorization state has changed
+  AuthorizationState(UpdateAuthorizationState),
+   
+  /// An option changed its value
+  Option(UpdateOption),
+
+}
+
+
+impl Default for Update {
+  fn default() -> Self { Update::_Default(()) }
+}
+
+
+impl<'de> Deserialize<'de> for Update {
+  fn deserialize<D>(deserializer: D) -> Result<Update, D::Error> where D: Deserializer<'de> {
+    use serde::de::Error;
+    rtd_enum_deserialize!(
+      Update,
+       (updateAuthorizationState, AuthorizationState);
+       (updateOption, Option);
+
+    )(deserializer)
+  }
+}
+
+
+impl RObject for Update {
+  #[doc(hidden)] fn td_name(&self) -> &'static str {
+    match self {
+       Update::AuthorizationState(t) => t.td_name(),
+       Update::Option(t) => t.td_name(),
+
+      _ => "-1",
+    }
+  }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+
+impl Update {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let Update::_Default(_) = self { true } else { false } }
+
+   pub fn is_authorization_state(&self) -> bool { if let Update::AuthorizationState(_) = self { true } else { false } }
+   pub fn is_option(&self) -> bool { if let Update::Option(_) = self { true } else { false } }
+
+   pub fn on_authorization_state<F: FnOnce(&UpdateAuthorizationState)>(&self, fnc: F) -> &Self { if let Update::AuthorizationState(t) = self { fnc(t) }; self }
+   pub fn on_option<F: FnOnce(&UpdateOption)>(&self, fnc: F) -> &Self { if let Update::Option(t) = self { fnc(t) }; self }
+
+   pub fn as_authorization_state(&self) -> Option<&UpdateAuthorizationState> { if let Update::AuthorizationState(t) = self { return Some(t) } None }
+   pub fn as_option(&self) -> Option<&UpdateOption> { if let Update::Option(t) = self { return Some(t) } None }
+
+
+
+   pub fn authorization_state<T: AsRef<UpdateAuthorizationState>>(t: T) -> Self { Update::AuthorizationState(t.as_ref().clone()) }
+
+   pub fn option<T: AsRef<UpdateOption>>(t: T) -> Self { Update::Option(t.as_ref().clone()) }
+
+}
+
+
+impl AsRef<Update> for Update {
+  fn as_ref(&self) -> &Update { self }
+}
+
+
+
+impl From<UpdateAuthorizationState> for Update {
+  fn from(t: UpdateAuthorizationState) -> Self { Update::AuthorizationState(t) }
+}
+
+
+impl std::convert::TryFrom<Update> for UpdateAuthorizationState {
+  type Error = Update;
+  fn try_from(t: Update) -> Result<Self, Self::Error> { if let Update::AuthorizationState(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<UpdateOption> for Update {
+  fn from(t: UpdateOption) -> Self { Update::Option(t) }
+}
+
+
+impl std::convert::TryFrom<Update> for UpdateOption {
+  type Error = Update;
+  fn try_from(t: Update) -> Result<Self, Self::Error> { if let Update::Option(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for Update {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    use crate::arbitrary::{TdArbitrary, TdRng};
+    if depth == 0 { return Update::AuthorizationState(TdArbitrary::td_arbitrary(rng, 0)); }
+    #[allow(unused_mut)]
+    let mut variants: Vec<fn(&mut TdRng, u32) -> Self> = vec![];
+     variants.push(|rng, depth| Update::AuthorizationState(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| Update::Option(TdArbitrary::td_arbitrary(rng, depth)));
+    if variants.is_empty() { return Update::_Default(()); }
+    let variant = variants[rng.below(variants.len())];
+    variant(rng, depth.saturating_sub(1))
+  }
+}
+
+
+
+
+/// the variant name, e.g. `updateNewMessage`
+impl std::fmt::Display for Update {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    if self._is_default() { f.write_str("default") } else { f.write_str(self.td_name()) }
+  }
+}
+
+
+
+
+
+
+
+/// The user authorization state has changed
+///
+/// [updateAuthorizationState](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1update_authorization_state.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct UpdateAuthorizationState {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// New authorization state
+  authorization_state: AuthorizationState,
+  
+}
+
+
+impl RObject for UpdateAuthorizationState {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateAuthorizationState" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    if self.authorization_state._is_default() { missing.push("authorization_state"); }
+    missing
+  }
+}
+
+
+
+impl TDUpdate for UpdateAuthorizationState {}
+
+
+
+
+impl UpdateAuthorizationState {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDUpdateAuthorizationStateBuilder {
+    let mut inner = UpdateAuthorizationState::default();
+    inner.td_name = "updateAuthorizationState".to_string();
+    RTDUpdateAuthorizationStateBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDUpdateAuthorizationStateBuilder { RTDUpdateAuthorizationStateBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDUpdateAuthorizationStateBuilder { RTDUpdateAuthorizationStateBuilder { inner: self } }
+
+  pub fn authorization_state(&self) -> &AuthorizationState { &self.authorization_state }
+  pub fn into_authorization_state(self) -> AuthorizationState { self.authorization_state }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDUpdateAuthorizationStateBuilder {
+  inner: UpdateAuthorizationState
+}
+
+
+impl RTDUpdateAuthorizationStateBuilder {
+  pub fn build(&self) -> UpdateAuthorizationState { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<UpdateAuthorizationState> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn authorization_state<T: AsRef<AuthorizationState>>(&mut self, authorization_state: T) -> &mut Self {
+    self.inner.authorization_state = authorization_state.as_ref().clone();
+    self
+  }
+
+}
+
+
+impl AsRef<UpdateAuthorizationState> for UpdateAuthorizationState {
+  fn as_ref(&self) -> &UpdateAuthorizationState { self }
+}
+
+
+impl AsRef<UpdateAuthorizationState> for RTDUpdateAuthorizationStateBuilder {
+  fn as_ref(&self) -> &UpdateAuthorizationState { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for UpdateAuthorizationState {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    UpdateAuthorizationState {
+      td_name: "updateAuthorizationState".to_string(),
+      authorization_state: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// An option changed its value
+///
+/// [updateOption](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1update_option.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct UpdateOption {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// The option name
+  name: String,
+  /// The new option value
+  value: OptionValue,
+  
+}
+
+
+impl RObject for UpdateOption {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateOption" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    if self.value._is_default() { missing.push("value"); }
+    missing
+  }
+}
+
+
+
+impl TDUpdate for UpdateOption {}
+
+
+
+
+impl UpdateOption {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDUpdateOptionBuilder {
+    let mut inner = UpdateOption::default();
+    inner.td_name = "updateOption".to_string();
+    RTDUpdateOptionBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDUpdateOptionBuilder { RTDUpdateOptionBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDUpdateOptionBuilder { RTDUpdateOptionBuilder { inner: self } }
+
+  pub fn name(&self) -> &String { &self.name }
+  pub fn into_name(self) -> String { self.name }
+
+  pub fn value(&self) -> &OptionValue { &self.value }
+  pub fn into_value(self) -> OptionValue { self.value }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDUpdateOptionBuilder {
+  inner: UpdateOption
+}
+
+
+impl RTDUpdateOptionBuilder {
+  pub fn build(&self) -> UpdateOption { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<UpdateOption> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
+    self.inner.name = name.as_ref().to_string();
+    self
+  }
+
+   
+  
+  pub fn value<T: AsRef<OptionValue>>(&mut self, value: T) -> &mut Self {
+    self.inner.value = value.as_ref().clone();
+    self
+  }
+
+}
+
+
+impl AsRef<UpdateOption> for UpdateOption {
+  fn as_ref(&self) -> &UpdateOption { self }
+}
+
+
+impl AsRef<UpdateOption> for RTDUpdateOptionBuilder {
+  fn as_ref(&self) -> &UpdateOption { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for UpdateOption {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    UpdateOption {
+      td_name: "updateOption".to_string(),
+      name: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      value: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
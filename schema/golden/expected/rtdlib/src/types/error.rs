@@ -0,0 +1,126 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// An object of this type can be returned on every function call, in case of an error
+///
+/// [error](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1error.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Error {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// Error code; subject to future changes. If the error code is 406, the error message must not be processed in any way and must not be displayed to the user
+  code: i64,
+  /// Error message; subject to future changes
+  message: String,
+  
+}
+
+
+impl RObject for Error {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "error" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+
+impl Error {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDErrorBuilder {
+    let mut inner = Error::default();
+    inner.td_name = "error".to_string();
+    RTDErrorBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDErrorBuilder { RTDErrorBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDErrorBuilder { RTDErrorBuilder { inner: self } }
+
+  pub fn code(&self) -> i64 { self.code }
+
+  pub fn message(&self) -> &String { &self.message }
+  pub fn into_message(self) -> String { self.message }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDErrorBuilder {
+  inner: Error
+}
+
+
+impl RTDErrorBuilder {
+  pub fn build(&self) -> Error { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<Error> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn code(&mut self, code: i64) -> &mut Self {
+    self.inner.code = code;
+    self
+  }
+
+   
+  
+  pub fn message<T: AsRef<str>>(&mut self, message: T) -> &mut Self {
+    self.inner.message = message.as_ref().to_string();
+    self
+  }
+
+}
+
+
+impl AsRef<Error> for Error {
+  fn as_ref(&self) -> &Error { self }
+}
+
+
+impl AsRef<Error> for RTDErrorBuilder {
+  fn as_ref(&self) -> &Error { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for Error {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    Error {
+      td_name: "error".to_string(),
+      code: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      message: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+/// `code: message`
+impl std::fmt::Display for Error {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}: {}", self.code, self.message)
+  }
+}
+
+
+
@@ -0,0 +1,119 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// A text with some entities
+///
+/// [formattedText](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1formatted_text.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct FormattedText {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// The text
+  text: String,
+  /// Entities contained in the text. Entities can be nested, but must not mutually intersect with each other. Pre, Code and PreCode entities can't contain other entities. Bold, Italic, Underline and Strikethrough entities can contain and to be contained in all other entities. All other entities can't contain each other
+  entities: Vec<TextEntity>,
+  
+}
+
+
+impl RObject for FormattedText {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "formattedText" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+
+impl FormattedText {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDFormattedTextBuilder {
+    let mut inner = FormattedText::default();
+    inner.td_name = "formattedText".to_string();
+    RTDFormattedTextBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDFormattedTextBuilder { RTDFormattedTextBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDFormattedTextBuilder { RTDFormattedTextBuilder { inner: self } }
+
+  pub fn text(&self) -> &String { &self.text }
+  pub fn into_text(self) -> String { self.text }
+
+  pub fn entities(&self) -> &Vec<TextEntity> { &self.entities }
+  pub fn into_entities(self) -> Vec<TextEntity> { self.entities }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDFormattedTextBuilder {
+  inner: FormattedText
+}
+
+
+impl RTDFormattedTextBuilder {
+  pub fn build(&self) -> FormattedText { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<FormattedText> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
+    self.inner.text = text.as_ref().to_string();
+    self
+  }
+
+   
+  
+  pub fn entities(&mut self, entities: Vec<TextEntity>) -> &mut Self {
+    self.inner.entities = entities;
+    self
+  }
+
+}
+
+
+impl AsRef<FormattedText> for FormattedText {
+  fn as_ref(&self) -> &FormattedText { self }
+}
+
+
+impl AsRef<FormattedText> for RTDFormattedTextBuilder {
+  fn as_ref(&self) -> &FormattedText { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for FormattedText {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    FormattedText {
+      td_name: "formattedText".to_string(),
+      text: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      entities: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
@@ -0,0 +1,605 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// Discards a call
+///
+/// [discardCall](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1discard_call.html) of the tdlib reference
+///
+/// # Arguments
+///
+/// * `call_id` - Call identifier
+/// * `is_disconnected` - True, if the user was disconnected
+/// * `duration` - The call duration, in seconds
+/// * `is_video` - True, if the call was a video call
+/// * `connection_id` - Identifier of the connection used during the call
+///
+/// Returns [`Ok`]
+#[cfg(feature = "calls")] 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DiscardCall {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// Call identifier
+  call_id: i64,
+  /// True, if the user was disconnected
+  is_disconnected: bool,
+  /// The call duration, in seconds
+  duration: i64,
+  /// True, if the call was a video call
+  is_video: bool,
+  /// Identifier of the connection used during the call
+  #[serde(with = "crate::types::_common::int64")] connection_id: isize,
+  
+}
+
+#[cfg(feature = "calls")]
+impl RObject for DiscardCall {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "discardCall" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+
+#[cfg(feature = "calls")]
+impl RFunction for DiscardCall {}
+
+#[cfg(feature = "calls")]
+impl DiscardCall {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDDiscardCallBuilder {
+    let mut inner = DiscardCall::default();
+    inner.td_name = "discardCall".to_string();
+    RTDDiscardCallBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDDiscardCallBuilder { RTDDiscardCallBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDDiscardCallBuilder { RTDDiscardCallBuilder { inner: self } }
+
+  pub fn call_id(&self) -> i64 { self.call_id }
+
+  pub fn is_disconnected(&self) -> bool { self.is_disconnected }
+
+  pub fn duration(&self) -> i64 { self.duration }
+
+  pub fn is_video(&self) -> bool { self.is_video }
+
+  pub fn connection_id(&self) -> isize { self.connection_id }
+
+}
+
+
+#[cfg(feature = "calls")]
+#[doc(hidden)]
+pub struct RTDDiscardCallBuilder {
+  inner: DiscardCall
+}
+
+#[cfg(feature = "calls")]
+impl RTDDiscardCallBuilder {
+  pub fn build(&self) -> DiscardCall { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<DiscardCall> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn call_id(&mut self, call_id: i64) -> &mut Self {
+    self.inner.call_id = call_id;
+    self
+  }
+
+   
+  
+  pub fn is_disconnected(&mut self, is_disconnected: bool) -> &mut Self {
+    self.inner.is_disconnected = is_disconnected;
+    self
+  }
+
+   
+  
+  pub fn duration(&mut self, duration: i64) -> &mut Self {
+    self.inner.duration = duration;
+    self
+  }
+
+   
+  
+  pub fn is_video(&mut self, is_video: bool) -> &mut Self {
+    self.inner.is_video = is_video;
+    self
+  }
+
+   
+  
+  pub fn connection_id(&mut self, connection_id: isize) -> &mut Self {
+    self.inner.connection_id = connection_id;
+    self
+  }
+
+}
+
+#[cfg(feature = "calls")]
+impl AsRef<DiscardCall> for DiscardCall {
+  fn as_ref(&self) -> &DiscardCall { self }
+}
+
+#[cfg(feature = "calls")]
+impl AsRef<DiscardCall> for RTDDiscardCallBuilder {
+  fn as_ref(&self) -> &DiscardCall { &self.inner }
+}
+
+
+
+
+
+#[cfg(feature = "calls")]
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for DiscardCall {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    DiscardCall {
+      td_name: "discardCall".to_string(),
+      call_id: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      is_disconnected: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      duration: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      is_video: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      connection_id: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// Returns the current authorization state; this is an offline request. For informational purposes only. Use updateAuthorizationState instead to maintain the current authorization state. Can be called before initialization
+///
+/// [getAuthorizationState](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1get_authorization_state.html) of the tdlib reference
+///
+/// Returns [`AuthorizationState`]
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct GetAuthorizationState {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+
+impl RObject for GetAuthorizationState {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "getAuthorizationState" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDAuthorizationState for GetAuthorizationState {}
+
+
+impl RFunction for GetAuthorizationState {}
+
+
+impl GetAuthorizationState {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDGetAuthorizationStateBuilder {
+    let mut inner = GetAuthorizationState::default();
+    inner.td_name = "getAuthorizationState".to_string();
+    RTDGetAuthorizationStateBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDGetAuthorizationStateBuilder { RTDGetAuthorizationStateBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDGetAuthorizationStateBuilder { RTDGetAuthorizationStateBuilder { inner: self } }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDGetAuthorizationStateBuilder {
+  inner: GetAuthorizationState
+}
+
+
+impl RTDGetAuthorizationStateBuilder {
+  pub fn build(&self) -> GetAuthorizationState { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<GetAuthorizationState> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+
+impl AsRef<GetAuthorizationState> for GetAuthorizationState {
+  fn as_ref(&self) -> &GetAuthorizationState { self }
+}
+
+
+impl AsRef<GetAuthorizationState> for RTDGetAuthorizationStateBuilder {
+  fn as_ref(&self) -> &GetAuthorizationState { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for GetAuthorizationState {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    GetAuthorizationState {
+      td_name: "getAuthorizationState".to_string(),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// Returns the value of an option by its name. (Check the list of available options on https://core.telegram.org/tdlib/options.) Can be called before authorization
+///
+/// [getOption](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1get_option.html) of the tdlib reference
+///
+/// # Arguments
+///
+/// * `name` - The name of the option
+///
+/// Returns [`OptionValue`]
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct GetOption {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// The name of the option
+  name: String,
+  
+}
+
+
+impl RObject for GetOption {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "getOption" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDOptionValue for GetOption {}
+
+
+impl RFunction for GetOption {}
+
+
+impl GetOption {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDGetOptionBuilder {
+    let mut inner = GetOption::default();
+    inner.td_name = "getOption".to_string();
+    RTDGetOptionBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDGetOptionBuilder { RTDGetOptionBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDGetOptionBuilder { RTDGetOptionBuilder { inner: self } }
+
+  pub fn name(&self) -> &String { &self.name }
+  pub fn into_name(self) -> String { self.name }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDGetOptionBuilder {
+  inner: GetOption
+}
+
+
+impl RTDGetOptionBuilder {
+  pub fn build(&self) -> GetOption { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<GetOption> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
+    self.inner.name = name.as_ref().to_string();
+    self
+  }
+
+}
+
+
+impl AsRef<GetOption> for GetOption {
+  fn as_ref(&self) -> &GetOption { self }
+}
+
+
+impl AsRef<GetOption> for RTDGetOptionBuilder {
+  fn as_ref(&self) -> &GetOption { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for GetOption {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    GetOption {
+      td_name: "getOption".to_string(),
+      name: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// Returns all entities (mentions, hashtags, cashtags, bot commands, bank card numbers, URLs, and email addresses) contained in the text. Can be called synchronously
+///
+/// [getTextEntities](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1get_text_entities.html) of the tdlib reference
+///
+/// # Arguments
+///
+/// * `text` - The text in which to look for entites
+///
+/// Returns [`TextEntities`]
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct GetTextEntities {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// The text in which to look for entites
+  text: String,
+  
+}
+
+
+impl RObject for GetTextEntities {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "getTextEntities" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+
+
+impl RFunction for GetTextEntities {}
+
+
+impl GetTextEntities {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDGetTextEntitiesBuilder {
+    let mut inner = GetTextEntities::default();
+    inner.td_name = "getTextEntities".to_string();
+    RTDGetTextEntitiesBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDGetTextEntitiesBuilder { RTDGetTextEntitiesBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDGetTextEntitiesBuilder { RTDGetTextEntitiesBuilder { inner: self } }
+
+  pub fn text(&self) -> &String { &self.text }
+  pub fn into_text(self) -> String { self.text }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDGetTextEntitiesBuilder {
+  inner: GetTextEntities
+}
+
+
+impl RTDGetTextEntitiesBuilder {
+  pub fn build(&self) -> GetTextEntities { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<GetTextEntities> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
+    self.inner.text = text.as_ref().to_string();
+    self
+  }
+
+}
+
+
+impl AsRef<GetTextEntities> for GetTextEntities {
+  fn as_ref(&self) -> &GetTextEntities { self }
+}
+
+
+impl AsRef<GetTextEntities> for RTDGetTextEntitiesBuilder {
+  fn as_ref(&self) -> &GetTextEntities { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for GetTextEntities {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    GetTextEntities {
+      td_name: "getTextEntities".to_string(),
+      text: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// Sets the parameters for TDLib initialization. Works only when the current authorization state is authorizationStateWaitTdlibParameters
+///
+/// [setTdlibParameters](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1set_tdlib_parameters.html) of the tdlib reference
+///
+/// # Arguments
+///
+/// * `parameters` - Parameters
+///
+/// Returns [`Ok`]
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SetTdlibParameters {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// Parameters
+  parameters: TdlibParameters,
+  
+}
+
+
+impl RObject for SetTdlibParameters {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "setTdlibParameters" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    if self.parameters._is_default() { missing.push("parameters"); }
+    missing
+  }
+}
+
+
+
+
+
+impl RFunction for SetTdlibParameters {}
+
+
+impl SetTdlibParameters {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDSetTdlibParametersBuilder {
+    let mut inner = SetTdlibParameters::default();
+    inner.td_name = "setTdlibParameters".to_string();
+    RTDSetTdlibParametersBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDSetTdlibParametersBuilder { RTDSetTdlibParametersBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDSetTdlibParametersBuilder { RTDSetTdlibParametersBuilder { inner: self } }
+
+  pub fn parameters(&self) -> &TdlibParameters { &self.parameters }
+  pub fn into_parameters(self) -> TdlibParameters { self.parameters }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDSetTdlibParametersBuilder {
+  inner: SetTdlibParameters
+}
+
+
+impl RTDSetTdlibParametersBuilder {
+  pub fn build(&self) -> SetTdlibParameters { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<SetTdlibParameters> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn parameters<T: AsRef<TdlibParameters>>(&mut self, parameters: T) -> &mut Self {
+    self.inner.parameters = parameters.as_ref().clone();
+    self
+  }
+
+}
+
+
+impl AsRef<SetTdlibParameters> for SetTdlibParameters {
+  fn as_ref(&self) -> &SetTdlibParameters { self }
+}
+
+
+impl AsRef<SetTdlibParameters> for RTDSetTdlibParametersBuilder {
+  fn as_ref(&self) -> &SetTdlibParameters { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for SetTdlibParameters {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    SetTdlibParameters {
+      td_name: "setTdlibParameters".to_string(),
+      parameters: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
@@ -0,0 +1,337 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+use std::fmt::Debug;
+use serde::de::{Deserialize, Deserializer};
+
+
+
+
+/// TRAIT | Describes a keyboard button type
+pub trait TDKeyboardButtonType: Debug + RObject {}
+
+ 
+/// Describes a keyboard button type
+///
+/// [KeyboardButtonType](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1_keyboard_button_type.html) of the tdlib reference
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum KeyboardButtonType {
+  #[doc(hidden)] _Default(()),
+   
+  /// A button that sends the user's location when pressed; available only in private chats
+  RequestLocation(KeyboardButtonTypeRequestLocation),
+   
+  /// A simple button, with text that should be sent when the button is pressed
+  Text(KeyboardButtonTypeText),
+
+}
+
+
+impl Default for KeyboardButtonType {
+  fn default() -> Self { KeyboardButtonType::_Default(()) }
+}
+
+
+impl<'de> Deserialize<'de> for KeyboardButtonType {
+  fn deserialize<D>(deserializer: D) -> Result<KeyboardButtonType, D::Error> where D: Deserializer<'de> {
+    use serde::de::Error;
+    rtd_enum_deserialize!(
+      KeyboardButtonType,
+       (keyboardButtonTypeRequestLocation, RequestLocation);
+       (keyboardButtonTypeText, Text);
+
+    )(deserializer)
+  }
+}
+
+
+impl RObject for KeyboardButtonType {
+  #[doc(hidden)] fn td_name(&self) -> &'static str {
+    match self {
+       KeyboardButtonType::RequestLocation(t) => t.td_name(),
+       KeyboardButtonType::Text(t) => t.td_name(),
+
+      _ => "-1",
+    }
+  }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+
+impl KeyboardButtonType {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let KeyboardButtonType::_Default(_) = self { true } else { false } }
+
+   pub fn is_request_location(&self) -> bool { if let KeyboardButtonType::RequestLocation(_) = self { true } else { false } }
+   pub fn is_text(&self) -> bool { if let KeyboardButtonType::Text(_) = self { true } else { false } }
+
+   pub fn on_request_location<F: FnOnce(&KeyboardButtonTypeRequestLocation)>(&self, fnc: F) -> &Self { if let KeyboardButtonType::RequestLocation(t) = self { fnc(t) }; self }
+   pub fn on_text<F: FnOnce(&KeyboardButtonTypeText)>(&self, fnc: F) -> &Self { if let KeyboardButtonType::Text(t) = self { fnc(t) }; self }
+
+   pub fn as_request_location(&self) -> Option<&KeyboardButtonTypeRequestLocation> { if let KeyboardButtonType::RequestLocation(t) = self { return Some(t) } None }
+   pub fn as_text(&self) -> Option<&KeyboardButtonTypeText> { if let KeyboardButtonType::Text(t) = self { return Some(t) } None }
+
+
+
+   pub fn request_location<T: AsRef<KeyboardButtonTypeRequestLocation>>(t: T) -> Self { KeyboardButtonType::RequestLocation(t.as_ref().clone()) }
+
+   pub fn text<T: AsRef<KeyboardButtonTypeText>>(t: T) -> Self { KeyboardButtonType::Text(t.as_ref().clone()) }
+
+}
+
+
+impl AsRef<KeyboardButtonType> for KeyboardButtonType {
+  fn as_ref(&self) -> &KeyboardButtonType { self }
+}
+
+
+
+impl From<KeyboardButtonTypeRequestLocation> for KeyboardButtonType {
+  fn from(t: KeyboardButtonTypeRequestLocation) -> Self { KeyboardButtonType::RequestLocation(t) }
+}
+
+
+impl std::convert::TryFrom<KeyboardButtonType> for KeyboardButtonTypeRequestLocation {
+  type Error = KeyboardButtonType;
+  fn try_from(t: KeyboardButtonType) -> Result<Self, Self::Error> { if let KeyboardButtonType::RequestLocation(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<KeyboardButtonTypeText> for KeyboardButtonType {
+  fn from(t: KeyboardButtonTypeText) -> Self { KeyboardButtonType::Text(t) }
+}
+
+
+impl std::convert::TryFrom<KeyboardButtonType> for KeyboardButtonTypeText {
+  type Error = KeyboardButtonType;
+  fn try_from(t: KeyboardButtonType) -> Result<Self, Self::Error> { if let KeyboardButtonType::Text(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for KeyboardButtonType {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    use crate::arbitrary::{TdArbitrary, TdRng};
+    if depth == 0 { return KeyboardButtonType::RequestLocation(TdArbitrary::td_arbitrary(rng, 0)); }
+    #[allow(unused_mut)]
+    let mut variants: Vec<fn(&mut TdRng, u32) -> Self> = vec![];
+     variants.push(|rng, depth| KeyboardButtonType::RequestLocation(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| KeyboardButtonType::Text(TdArbitrary::td_arbitrary(rng, depth)));
+    if variants.is_empty() { return KeyboardButtonType::_Default(()); }
+    let variant = variants[rng.below(variants.len())];
+    variant(rng, depth.saturating_sub(1))
+  }
+}
+
+
+
+
+/// the variant name, e.g. `updateNewMessage`
+impl std::fmt::Display for KeyboardButtonType {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    if self._is_default() { f.write_str("default") } else { f.write_str(self.td_name()) }
+  }
+}
+
+
+
+
+
+
+
+/// A button that sends the user's location when pressed; available only in private chats
+///
+/// [keyboardButtonTypeRequestLocation](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1keyboard_button_type_request_location.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct KeyboardButtonTypeRequestLocation {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+
+impl RObject for KeyboardButtonTypeRequestLocation {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButtonTypeRequestLocation" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDKeyboardButtonType for KeyboardButtonTypeRequestLocation {}
+
+
+
+
+impl KeyboardButtonTypeRequestLocation {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDKeyboardButtonTypeRequestLocationBuilder {
+    let mut inner = KeyboardButtonTypeRequestLocation::default();
+    inner.td_name = "keyboardButtonTypeRequestLocation".to_string();
+    RTDKeyboardButtonTypeRequestLocationBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDKeyboardButtonTypeRequestLocationBuilder { RTDKeyboardButtonTypeRequestLocationBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDKeyboardButtonTypeRequestLocationBuilder { RTDKeyboardButtonTypeRequestLocationBuilder { inner: self } }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDKeyboardButtonTypeRequestLocationBuilder {
+  inner: KeyboardButtonTypeRequestLocation
+}
+
+
+impl RTDKeyboardButtonTypeRequestLocationBuilder {
+  pub fn build(&self) -> KeyboardButtonTypeRequestLocation { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<KeyboardButtonTypeRequestLocation> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+
+impl AsRef<KeyboardButtonTypeRequestLocation> for KeyboardButtonTypeRequestLocation {
+  fn as_ref(&self) -> &KeyboardButtonTypeRequestLocation { self }
+}
+
+
+impl AsRef<KeyboardButtonTypeRequestLocation> for RTDKeyboardButtonTypeRequestLocationBuilder {
+  fn as_ref(&self) -> &KeyboardButtonTypeRequestLocation { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for KeyboardButtonTypeRequestLocation {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    KeyboardButtonTypeRequestLocation {
+      td_name: "keyboardButtonTypeRequestLocation".to_string(),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// A simple button, with text that should be sent when the button is pressed
+///
+/// [keyboardButtonTypeText](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1keyboard_button_type_text.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct KeyboardButtonTypeText {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+
+impl RObject for KeyboardButtonTypeText {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButtonTypeText" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDKeyboardButtonType for KeyboardButtonTypeText {}
+
+
+
+
+impl KeyboardButtonTypeText {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDKeyboardButtonTypeTextBuilder {
+    let mut inner = KeyboardButtonTypeText::default();
+    inner.td_name = "keyboardButtonTypeText".to_string();
+    RTDKeyboardButtonTypeTextBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDKeyboardButtonTypeTextBuilder { RTDKeyboardButtonTypeTextBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDKeyboardButtonTypeTextBuilder { RTDKeyboardButtonTypeTextBuilder { inner: self } }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDKeyboardButtonTypeTextBuilder {
+  inner: KeyboardButtonTypeText
+}
+
+
+impl RTDKeyboardButtonTypeTextBuilder {
+  pub fn build(&self) -> KeyboardButtonTypeText { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<KeyboardButtonTypeText> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+
+impl AsRef<KeyboardButtonTypeText> for KeyboardButtonTypeText {
+  fn as_ref(&self) -> &KeyboardButtonTypeText { self }
+}
+
+
+impl AsRef<KeyboardButtonTypeText> for RTDKeyboardButtonTypeTextBuilder {
+  fn as_ref(&self) -> &KeyboardButtonTypeText { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for KeyboardButtonTypeText {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    KeyboardButtonTypeText {
+      td_name: "keyboardButtonTypeText".to_string(),
+    }
+  }
+}
+
+
+
+
+
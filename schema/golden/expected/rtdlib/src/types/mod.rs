@@ -0,0 +1,164 @@
+// the generated code uses all td types, deprecated ones too
+#![allow(deprecated)]
+
+
+pub use self::_common::{
+  RObject,
+  RFunction,
+  TdBytes,
+  detect_td_type,
+  from_json,
+};
+
+#[macro_use] mod _common;
+#[cfg(all(test, feature = "test-gen"))]
+mod _roundtrip;
+
+/// TDLib version of the schema these types are generated from
+pub const TDLIB_VERSION: &str = "0.0.0";
+/// Commit of the schema these types are generated from, empty if unknown
+pub const TDLIB_SCHEMA_COMMIT: &str = "";
+/// Url the schema is downloaded from by `fantasy fetch`, empty if not fetched
+pub const TDLIB_SCHEMA_SOURCE: &str = "";
+
+ #[doc(hidden)] pub use self::_authorization_state::*;
+#[cfg(feature = "calls")] #[doc(hidden)] pub use self::_call_discard_reason::*;
+#[cfg(feature = "calls")] #[doc(hidden)] pub use self::_call_protocol::*;
+ #[doc(hidden)] pub use self::_error::*;
+ #[doc(hidden)] pub use self::_formatted_text::*;
+ #[doc(hidden)] pub use self::_functions::*;
+ #[doc(hidden)] pub use self::_keyboard_button::*;
+ #[doc(hidden)] pub use self::_keyboard_button_type::*;
+ #[doc(hidden)] pub use self::_message_content::*;
+ #[doc(hidden)] pub use self::_ok::*;
+ #[doc(hidden)] pub use self::_option_value::*;
+ #[doc(hidden)] pub use self::_reply_markup::*;
+ #[doc(hidden)] pub use self::_rich_text::*;
+#[cfg(feature = "stickers")] #[doc(hidden)] pub use self::_sticker::*;
+ #[doc(hidden)] pub use self::_tdlib_parameters::*;
+ #[doc(hidden)] pub use self::_text_entities::*;
+ #[doc(hidden)] pub use self::_text_entity::*;
+ #[doc(hidden)] pub use self::_text_entity_type::*;
+ #[doc(hidden)] pub use self::_update::*;
+
+
+
+
+ #[path = "authorization_state.rs"] mod _authorization_state;
+#[cfg(feature = "calls")] #[path = "call_discard_reason.rs"] mod _call_discard_reason;
+#[cfg(feature = "calls")] #[path = "call_protocol.rs"] mod _call_protocol;
+ #[path = "error.rs"] mod _error;
+ #[path = "formatted_text.rs"] mod _formatted_text;
+ #[path = "functions.rs"] mod _functions;
+ #[path = "keyboard_button.rs"] mod _keyboard_button;
+ #[path = "keyboard_button_type.rs"] mod _keyboard_button_type;
+ #[path = "message_content.rs"] mod _message_content;
+ #[path = "ok.rs"] mod _ok;
+ #[path = "option_value.rs"] mod _option_value;
+ #[path = "reply_markup.rs"] mod _reply_markup;
+ #[path = "rich_text.rs"] mod _rich_text;
+#[cfg(feature = "stickers")] #[path = "sticker.rs"] mod _sticker;
+ #[path = "tdlib_parameters.rs"] mod _tdlib_parameters;
+ #[path = "text_entities.rs"] mod _text_entities;
+ #[path = "text_entity.rs"] mod _text_entity;
+ #[path = "text_entity_type.rs"] mod _text_entity_type;
+ #[path = "update.rs"] mod _update;
+
+
+/// The most used types, `use rtdlib::types::prelude::*;`
+pub mod prelude {
+  pub use super::{
+    RObject,
+    RFunction,
+    Update,
+    AuthorizationState,
+    MessageContent,
+    FormattedText,
+    TextEntity,
+    Error,
+    Ok,
+  };
+}
+
+/// auth types
+pub mod auth {
+   pub use super::{AuthorizationState, TDAuthorizationState};
+   pub use super::{AuthorizationStateClosed, RTDAuthorizationStateClosedBuilder};
+   pub use super::{AuthorizationStateReady, RTDAuthorizationStateReadyBuilder};
+   pub use super::{AuthorizationStateWaitTdlibParameters, RTDAuthorizationStateWaitTdlibParametersBuilder};
+   pub use super::{GetAuthorizationState, RTDGetAuthorizationStateBuilder};
+   pub use super::{SetTdlibParameters, RTDSetTdlibParametersBuilder};
+   pub use super::{TdlibParameters, RTDTdlibParametersBuilder, TdlibParametersParts};
+}
+
+/// call types
+pub mod call {
+  #[cfg(feature = "calls")] pub use super::{CallDiscardReason, TDCallDiscardReason};
+  #[cfg(feature = "calls")] pub use super::{CallDiscardReasonEmpty, RTDCallDiscardReasonEmptyBuilder};
+  #[cfg(feature = "calls")] pub use super::{CallDiscardReasonHungUp, RTDCallDiscardReasonHungUpBuilder};
+  #[cfg(feature = "calls")] pub use super::{CallProtocol, RTDCallProtocolBuilder};
+  #[cfg(feature = "calls")] pub use super::{DiscardCall, RTDDiscardCallBuilder};
+  #[cfg(feature = "calls")] pub use super::{MessageCall, RTDMessageCallBuilder};
+}
+
+/// inline types
+pub mod inline {
+   pub use super::{KeyboardButtonType, TDKeyboardButtonType};
+   pub use super::{KeyboardButton, RTDKeyboardButtonBuilder};
+}
+
+/// message types
+pub mod message {
+   pub use super::{MessageContent, TDMessageContent};
+   pub use super::{ReplyMarkup, TDReplyMarkup};
+   pub use super::{RichText, TDRichText};
+   pub use super::{TextEntityType, TDTextEntityType};
+   pub use super::{FormattedText, RTDFormattedTextBuilder};
+   pub use super::{GetTextEntities, RTDGetTextEntitiesBuilder};
+   pub use super::{KeyboardButtonTypeRequestLocation, RTDKeyboardButtonTypeRequestLocationBuilder};
+   pub use super::{KeyboardButtonTypeText, RTDKeyboardButtonTypeTextBuilder};
+   pub use super::{MessageText, RTDMessageTextBuilder};
+   pub use super::{ReplyMarkupRemoveKeyboard, RTDReplyMarkupRemoveKeyboardBuilder};
+   pub use super::{ReplyMarkupShowKeyboard, RTDReplyMarkupShowKeyboardBuilder};
+   pub use super::{RichTextBold, RTDRichTextBoldBuilder};
+   pub use super::{RichTextPlain, RTDRichTextPlainBuilder};
+   pub use super::{TextEntities, RTDTextEntitiesBuilder};
+   pub use super::{TextEntity, RTDTextEntityBuilder};
+   pub use super::{TextEntityTypeBold, RTDTextEntityTypeBoldBuilder};
+   pub use super::{TextEntityTypeUrl, RTDTextEntityTypeUrlBuilder};
+}
+
+/// misc types
+pub mod misc {
+   pub use super::{Error, RTDErrorBuilder};
+   pub use super::{Ok, RTDOkBuilder};
+}
+
+/// payment types
+pub mod payment {
+  #[cfg(feature = "payments")] pub use super::{MessageInvoice, RTDMessageInvoiceBuilder};
+   pub use super::{TextEntityTypeBankCardNumber, RTDTextEntityTypeBankCardNumberBuilder};
+}
+
+/// settings types
+pub mod settings {
+   pub use super::{OptionValue, TDOptionValue};
+   pub use super::{GetOption, RTDGetOptionBuilder};
+   pub use super::{OptionValueBoolean, RTDOptionValueBooleanBuilder};
+   pub use super::{OptionValueEmpty, RTDOptionValueEmptyBuilder};
+}
+
+/// sticker types
+pub mod sticker {
+  #[cfg(feature = "stickers")] pub use super::{MessageSticker, RTDMessageStickerBuilder};
+  #[cfg(feature = "stickers")] pub use super::{Sticker, RTDStickerBuilder};
+}
+
+/// update types
+pub mod update {
+   pub use super::{Update, TDUpdate};
+   pub use super::{UpdateAuthorizationState, RTDUpdateAuthorizationStateBuilder};
+   pub use super::{UpdateOption, RTDUpdateOptionBuilder};
+}
+
+
@@ -0,0 +1,119 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// Describes a sticker
+///
+/// [sticker](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1sticker.html) of the tdlib reference
+#[cfg(feature = "stickers")] 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Sticker {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// The identifier of the sticker set to which the sticker belongs; 0 if none
+  #[serde(with = "crate::types::_common::int64")] #[serde(default)] set_id: isize,
+  /// Emoji corresponding to the sticker
+  emoji: String,
+  
+}
+
+#[cfg(feature = "stickers")]
+impl RObject for Sticker {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "sticker" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+#[cfg(feature = "stickers")]
+impl Sticker {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDStickerBuilder {
+    let mut inner = Sticker::default();
+    inner.td_name = "sticker".to_string();
+    RTDStickerBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDStickerBuilder { RTDStickerBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDStickerBuilder { RTDStickerBuilder { inner: self } }
+
+  /// `None` if the value is 0
+  pub fn set_id(&self) -> Option<isize> { if self.set_id == 0 { None } else { Some(self.set_id) } }
+
+  pub fn emoji(&self) -> &String { &self.emoji }
+  pub fn into_emoji(self) -> String { self.emoji }
+
+}
+
+
+#[cfg(feature = "stickers")]
+#[doc(hidden)]
+pub struct RTDStickerBuilder {
+  inner: Sticker
+}
+
+#[cfg(feature = "stickers")]
+impl RTDStickerBuilder {
+  pub fn build(&self) -> Sticker { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<Sticker> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn set_id(&mut self, set_id: isize) -> &mut Self {
+    self.inner.set_id = set_id;
+    self
+  }
+
+   
+  
+  pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
+    self.inner.emoji = emoji.as_ref().to_string();
+    self
+  }
+
+}
+
+#[cfg(feature = "stickers")]
+impl AsRef<Sticker> for Sticker {
+  fn as_ref(&self) -> &Sticker { self }
+}
+
+#[cfg(feature = "stickers")]
+impl AsRef<Sticker> for RTDStickerBuilder {
+  fn as_ref(&self) -> &Sticker { &self.inner }
+}
+
+
+
+
+
+#[cfg(feature = "stickers")]
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for Sticker {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    Sticker {
+      td_name: "sticker".to_string(),
+      set_id: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      emoji: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
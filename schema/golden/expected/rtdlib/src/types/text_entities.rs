@@ -0,0 +1,106 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// Contains a list of text entities
+///
+/// [textEntities](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1text_entities.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct TextEntities {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// List of text entities
+  entities: Vec<TextEntity>,
+  
+}
+
+
+impl RObject for TextEntities {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntities" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+
+impl TextEntities {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDTextEntitiesBuilder {
+    let mut inner = TextEntities::default();
+    inner.td_name = "textEntities".to_string();
+    RTDTextEntitiesBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDTextEntitiesBuilder { RTDTextEntitiesBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDTextEntitiesBuilder { RTDTextEntitiesBuilder { inner: self } }
+
+  pub fn entities(&self) -> &Vec<TextEntity> { &self.entities }
+  pub fn into_entities(self) -> Vec<TextEntity> { self.entities }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDTextEntitiesBuilder {
+  inner: TextEntities
+}
+
+
+impl RTDTextEntitiesBuilder {
+  pub fn build(&self) -> TextEntities { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<TextEntities> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn entities(&mut self, entities: Vec<TextEntity>) -> &mut Self {
+    self.inner.entities = entities;
+    self
+  }
+
+}
+
+
+impl AsRef<TextEntities> for TextEntities {
+  fn as_ref(&self) -> &TextEntities { self }
+}
+
+
+impl AsRef<TextEntities> for RTDTextEntitiesBuilder {
+  fn as_ref(&self) -> &TextEntities { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for TextEntities {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    TextEntities {
+      td_name: "textEntities".to_string(),
+      entities: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
@@ -0,0 +1,131 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// Represents a part of the text that needs to be formatted in some unusual way
+///
+/// [textEntity](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1text_entity.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct TextEntity {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  /// Offset of the entity, in UTF-16 code units
+  offset: i64,
+  /// Length of the entity, in UTF-16 code units
+  length: i64,
+  /// Type of the entity
+  #[serde(rename(serialize = "type", deserialize = "type"))] type_: TextEntityType,
+  
+}
+
+
+impl RObject for TextEntity {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntity" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    if self.type_._is_default() { missing.push("type"); }
+    missing
+  }
+}
+
+
+
+
+impl TextEntity {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDTextEntityBuilder {
+    let mut inner = TextEntity::default();
+    inner.td_name = "textEntity".to_string();
+    RTDTextEntityBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDTextEntityBuilder { RTDTextEntityBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDTextEntityBuilder { RTDTextEntityBuilder { inner: self } }
+
+  pub fn offset(&self) -> i64 { self.offset }
+
+  pub fn length(&self) -> i64 { self.length }
+
+  pub fn type_(&self) -> &TextEntityType { &self.type_ }
+  pub fn into_type(self) -> TextEntityType { self.type_ }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDTextEntityBuilder {
+  inner: TextEntity
+}
+
+
+impl RTDTextEntityBuilder {
+  pub fn build(&self) -> TextEntity { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<TextEntity> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+   
+  
+  pub fn offset(&mut self, offset: i64) -> &mut Self {
+    self.inner.offset = offset;
+    self
+  }
+
+   
+  
+  pub fn length(&mut self, length: i64) -> &mut Self {
+    self.inner.length = length;
+    self
+  }
+
+   
+  
+  pub fn type_<T: AsRef<TextEntityType>>(&mut self, type_: T) -> &mut Self {
+    self.inner.type_ = type_.as_ref().clone();
+    self
+  }
+
+}
+
+
+impl AsRef<TextEntity> for TextEntity {
+  fn as_ref(&self) -> &TextEntity { self }
+}
+
+
+impl AsRef<TextEntity> for RTDTextEntityBuilder {
+  fn as_ref(&self) -> &TextEntity { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for TextEntity {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    TextEntity {
+      td_name: "textEntity".to_string(),
+      offset: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      length: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+      type_: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+    }
+  }
+}
+
+
+
+
+
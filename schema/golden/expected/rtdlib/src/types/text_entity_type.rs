@@ -0,0 +1,453 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+use std::fmt::Debug;
+use serde::de::{Deserialize, Deserializer};
+
+
+
+
+/// TRAIT | Represents a part of the text which must be formatted differently
+pub trait TDTextEntityType: Debug + RObject {}
+
+ 
+/// Represents a part of the text which must be formatted differently
+///
+/// [TextEntityType](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1_text_entity_type.html) of the tdlib reference
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum TextEntityType {
+  #[doc(hidden)] _Default(()),
+   
+  /// A bank card number. The getBankCardInfo method can be used to get information about the bank card
+  BankCardNumber(TextEntityTypeBankCardNumber),
+   
+  /// A bold text
+  Bold(TextEntityTypeBold),
+   
+  /// An HTTP URL
+  Url(TextEntityTypeUrl),
+
+}
+
+
+impl Default for TextEntityType {
+  fn default() -> Self { TextEntityType::_Default(()) }
+}
+
+
+impl<'de> Deserialize<'de> for TextEntityType {
+  fn deserialize<D>(deserializer: D) -> Result<TextEntityType, D::Error> where D: Deserializer<'de> {
+    use serde::de::Error;
+    rtd_enum_deserialize!(
+      TextEntityType,
+       (textEntityTypeBankCardNumber, BankCardNumber);
+       (textEntityTypeBold, Bold);
+       (textEntityTypeUrl, Url);
+
+    )(deserializer)
+  }
+}
+
+
+impl RObject for TextEntityType {
+  #[doc(hidden)] fn td_name(&self) -> &'static str {
+    match self {
+       TextEntityType::BankCardNumber(t) => t.td_name(),
+       TextEntityType::Bold(t) => t.td_name(),
+       TextEntityType::Url(t) => t.td_name(),
+
+      _ => "-1",
+    }
+  }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+
+impl TextEntityType {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TextEntityType::_Default(_) = self { true } else { false } }
+
+   pub fn is_bank_card_number(&self) -> bool { if let TextEntityType::BankCardNumber(_) = self { true } else { false } }
+   pub fn is_bold(&self) -> bool { if let TextEntityType::Bold(_) = self { true } else { false } }
+   pub fn is_url(&self) -> bool { if let TextEntityType::Url(_) = self { true } else { false } }
+
+   pub fn on_bank_card_number<F: FnOnce(&TextEntityTypeBankCardNumber)>(&self, fnc: F) -> &Self { if let TextEntityType::BankCardNumber(t) = self { fnc(t) }; self }
+   pub fn on_bold<F: FnOnce(&TextEntityTypeBold)>(&self, fnc: F) -> &Self { if let TextEntityType::Bold(t) = self { fnc(t) }; self }
+   pub fn on_url<F: FnOnce(&TextEntityTypeUrl)>(&self, fnc: F) -> &Self { if let TextEntityType::Url(t) = self { fnc(t) }; self }
+
+   pub fn as_bank_card_number(&self) -> Option<&TextEntityTypeBankCardNumber> { if let TextEntityType::BankCardNumber(t) = self { return Some(t) } None }
+   pub fn as_bold(&self) -> Option<&TextEntityTypeBold> { if let TextEntityType::Bold(t) = self { return Some(t) } None }
+   pub fn as_url(&self) -> Option<&TextEntityTypeUrl> { if let TextEntityType::Url(t) = self { return Some(t) } None }
+
+
+
+   pub fn bank_card_number<T: AsRef<TextEntityTypeBankCardNumber>>(t: T) -> Self { TextEntityType::BankCardNumber(t.as_ref().clone()) }
+
+   pub fn bold<T: AsRef<TextEntityTypeBold>>(t: T) -> Self { TextEntityType::Bold(t.as_ref().clone()) }
+
+   pub fn url<T: AsRef<TextEntityTypeUrl>>(t: T) -> Self { TextEntityType::Url(t.as_ref().clone()) }
+
+}
+
+
+impl AsRef<TextEntityType> for TextEntityType {
+  fn as_ref(&self) -> &TextEntityType { self }
+}
+
+
+
+impl From<TextEntityTypeBankCardNumber> for TextEntityType {
+  fn from(t: TextEntityTypeBankCardNumber) -> Self { TextEntityType::BankCardNumber(t) }
+}
+
+
+impl std::convert::TryFrom<TextEntityType> for TextEntityTypeBankCardNumber {
+  type Error = TextEntityType;
+  fn try_from(t: TextEntityType) -> Result<Self, Self::Error> { if let TextEntityType::BankCardNumber(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<TextEntityTypeBold> for TextEntityType {
+  fn from(t: TextEntityTypeBold) -> Self { TextEntityType::Bold(t) }
+}
+
+
+impl std::convert::TryFrom<TextEntityType> for TextEntityTypeBold {
+  type Error = TextEntityType;
+  fn try_from(t: TextEntityType) -> Result<Self, Self::Error> { if let TextEntityType::Bold(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+impl From<TextEntityTypeUrl> for TextEntityType {
+  fn from(t: TextEntityTypeUrl) -> Self { TextEntityType::Url(t) }
+}
+
+
+impl std::convert::TryFrom<TextEntityType> for TextEntityTypeUrl {
+  type Error = TextEntityType;
+  fn try_from(t: TextEntityType) -> Result<Self, Self::Error> { if let TextEntityType::Url(t) = t { Ok(t) } else { Err(t) } }
+}
+
+
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for TextEntityType {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    use crate::arbitrary::{TdArbitrary, TdRng};
+    if depth == 0 { return TextEntityType::BankCardNumber(TdArbitrary::td_arbitrary(rng, 0)); }
+    #[allow(unused_mut)]
+    let mut variants: Vec<fn(&mut TdRng, u32) -> Self> = vec![];
+     variants.push(|rng, depth| TextEntityType::BankCardNumber(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| TextEntityType::Bold(TdArbitrary::td_arbitrary(rng, depth)));
+     variants.push(|rng, depth| TextEntityType::Url(TdArbitrary::td_arbitrary(rng, depth)));
+    if variants.is_empty() { return TextEntityType::_Default(()); }
+    let variant = variants[rng.below(variants.len())];
+    variant(rng, depth.saturating_sub(1))
+  }
+}
+
+
+
+
+/// the variant name, e.g. `updateNewMessage`
+impl std::fmt::Display for TextEntityType {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    if self._is_default() { f.write_str("default") } else { f.write_str(self.td_name()) }
+  }
+}
+
+
+
+
+
+
+
+/// A bank card number. The getBankCardInfo method can be used to get information about the bank card
+///
+/// [textEntityTypeBankCardNumber](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1text_entity_type_bank_card_number.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct TextEntityTypeBankCardNumber {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+
+impl RObject for TextEntityTypeBankCardNumber {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeBankCardNumber" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDTextEntityType for TextEntityTypeBankCardNumber {}
+
+
+
+
+impl TextEntityTypeBankCardNumber {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDTextEntityTypeBankCardNumberBuilder {
+    let mut inner = TextEntityTypeBankCardNumber::default();
+    inner.td_name = "textEntityTypeBankCardNumber".to_string();
+    RTDTextEntityTypeBankCardNumberBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDTextEntityTypeBankCardNumberBuilder { RTDTextEntityTypeBankCardNumberBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDTextEntityTypeBankCardNumberBuilder { RTDTextEntityTypeBankCardNumberBuilder { inner: self } }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDTextEntityTypeBankCardNumberBuilder {
+  inner: TextEntityTypeBankCardNumber
+}
+
+
+impl RTDTextEntityTypeBankCardNumberBuilder {
+  pub fn build(&self) -> TextEntityTypeBankCardNumber { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeBankCardNumber> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+
+impl AsRef<TextEntityTypeBankCardNumber> for TextEntityTypeBankCardNumber {
+  fn as_ref(&self) -> &TextEntityTypeBankCardNumber { self }
+}
+
+
+impl AsRef<TextEntityTypeBankCardNumber> for RTDTextEntityTypeBankCardNumberBuilder {
+  fn as_ref(&self) -> &TextEntityTypeBankCardNumber { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for TextEntityTypeBankCardNumber {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    TextEntityTypeBankCardNumber {
+      td_name: "textEntityTypeBankCardNumber".to_string(),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// A bold text
+///
+/// [textEntityTypeBold](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1text_entity_type_bold.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct TextEntityTypeBold {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+
+impl RObject for TextEntityTypeBold {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeBold" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDTextEntityType for TextEntityTypeBold {}
+
+
+
+
+impl TextEntityTypeBold {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDTextEntityTypeBoldBuilder {
+    let mut inner = TextEntityTypeBold::default();
+    inner.td_name = "textEntityTypeBold".to_string();
+    RTDTextEntityTypeBoldBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDTextEntityTypeBoldBuilder { RTDTextEntityTypeBoldBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDTextEntityTypeBoldBuilder { RTDTextEntityTypeBoldBuilder { inner: self } }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDTextEntityTypeBoldBuilder {
+  inner: TextEntityTypeBold
+}
+
+
+impl RTDTextEntityTypeBoldBuilder {
+  pub fn build(&self) -> TextEntityTypeBold { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeBold> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+
+impl AsRef<TextEntityTypeBold> for TextEntityTypeBold {
+  fn as_ref(&self) -> &TextEntityTypeBold { self }
+}
+
+
+impl AsRef<TextEntityTypeBold> for RTDTextEntityTypeBoldBuilder {
+  fn as_ref(&self) -> &TextEntityTypeBold { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for TextEntityTypeBold {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    TextEntityTypeBold {
+      td_name: "textEntityTypeBold".to_string(),
+    }
+  }
+}
+
+
+
+
+
+
+
+
+
+/// An HTTP URL
+///
+/// [textEntityTypeUrl](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1text_entity_type_url.html) of the tdlib reference
+ 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct TextEntityTypeUrl {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  
+}
+
+
+impl RObject for TextEntityTypeUrl {
+  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeUrl" }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+    missing
+  }
+}
+
+
+
+impl TDTextEntityType for TextEntityTypeUrl {}
+
+
+
+
+impl TextEntityTypeUrl {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn builder() -> RTDTextEntityTypeUrlBuilder {
+    let mut inner = TextEntityTypeUrl::default();
+    inner.td_name = "textEntityTypeUrl".to_string();
+    RTDTextEntityTypeUrlBuilder { inner }
+  }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTDTextEntityTypeUrlBuilder { RTDTextEntityTypeUrlBuilder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTDTextEntityTypeUrlBuilder { RTDTextEntityTypeUrlBuilder { inner: self } }
+
+}
+
+
+
+#[doc(hidden)]
+pub struct RTDTextEntityTypeUrlBuilder {
+  inner: TextEntityTypeUrl
+}
+
+
+impl RTDTextEntityTypeUrlBuilder {
+  pub fn build(&self) -> TextEntityTypeUrl { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeUrl> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
+
+}
+
+
+impl AsRef<TextEntityTypeUrl> for TextEntityTypeUrl {
+  fn as_ref(&self) -> &TextEntityTypeUrl { self }
+}
+
+
+impl AsRef<TextEntityTypeUrl> for RTDTextEntityTypeUrlBuilder {
+  fn as_ref(&self) -> &TextEntityTypeUrl { &self.inner }
+}
+
+
+
+
+
+
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for TextEntityTypeUrl {
+  fn td_arbitrary(_rng: &mut crate::arbitrary::TdRng, _depth: u32) -> Self {
+    TextEntityTypeUrl {
+      td_name: "textEntityTypeUrl".to_string(),
+    }
+  }
+}
+
+
+
+
+
@@ -0,0 +1,89 @@
+//! Log in to the Telegram test environment, e.g. an end-to-end check of a bot in CI.
+//!
+//! ```text
+//! API_ID=12345 API_HASH=0123456789abcdef cargo run --example test_dc
+//! ```
+//!
+//! `TEST_DC` is the datacenter, 2 by default, and `TEST_DC_NUMBER` the account, random by default.
+//! The account is registered if it's new. Exit with 1 if the login failed.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rtdlib::types::*;
+use telegram_client::api::Api;
+use telegram_client::auth::{handle_auth_state, AuthCode, AuthPassword, AuthStateHandler};
+use telegram_client::client::Client;
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+fn env<T: std::str::FromStr>(name: &str) -> Option<T> {
+  std::env::var(name).ok().and_then(|value| value.parse().ok())
+}
+
+fn main() {
+  let (api_id, api_hash) = match (env::<i64>("API_ID"), env::<String>("API_HASH")) {
+    (Some(api_id), Some(api_hash)) => (api_id, api_hash),
+    _ => {
+      eprintln!("Set API_ID and API_HASH, see https://my.telegram.org");
+      std::process::exit(1);
+    }
+  };
+  let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.subsec_nanos());
+  let account = TestDc::new(env("TEST_DC").unwrap_or(2), env("TEST_DC_NUMBER").unwrap_or((nanos % 10000) as u16));
+  let database = std::env::temp_dir().join(format!("tdlib-test-dc-{}", account.phone_number()));
+  println!("Log in to the test dc as {}", account.phone_number());
+
+  Client::set_log_verbosity_level(1).unwrap();
+  let api = Api::default();
+  // subscribe before the client starts, the first authorization state is not missed
+  let auth = api.subscribe(&["updateAuthorizationState"]);
+  Client::new(api.clone()).start();
+
+  let parameters = TdlibParameters::test_dc(api_id, api_hash, database.to_string_lossy()).build();
+  let parameters = SetTdlibParameters::builder().parameters(&parameters).build();
+  let handler = TestDcAuth { api: api.clone(), account, parameters };
+  loop {
+    let state = match auth.recv_timeout(TIMEOUT) {
+      Some(Ok(Update::AuthorizationState(update))) => update.authorization_state().clone(),
+      Some(_) => continue,
+      None => fail(&api, "Timeout waiting the authorization state"),
+    };
+    if let Err(e) = handle_auth_state(&api, &handler, &state) {
+      fail(&api, &e.to_string());
+    }
+    if state.is_ready() { break; }
+    if state.is_closed() { fail(&api, "The client is closed"); }
+  }
+
+  let me: User = match api.request(GetMe::builder().build()).and_then(|pending| pending.wait_timeout(TIMEOUT)) {
+    Ok(me) => me,
+    Err(e) => fail(&api, &e.to_string()),
+  };
+  println!("Logged in as {} {}", me.id(), me.phone_number());
+  let _ = api.send(Close::builder().build());
+}
+
+/// Logs in as the test account, registered if it's new
+struct TestDcAuth {
+  api: Api,
+  account: TestDc,
+  parameters: SetTdlibParameters,
+}
+
+impl AuthStateHandler for TestDcAuth {
+  fn tdlib_parameters(&self) -> SetTdlibParameters { self.parameters.clone() }
+  fn phone_number(&self, _state: &AuthorizationStateWaitPhoneNumber) -> String { self.account.phone_number() }
+  fn code(&self, _state: &AuthorizationStateWaitCode) -> AuthCode { AuthCode::Code(self.account.code()) }
+  fn password(&self, _state: &AuthorizationStateWaitPassword) -> AuthPassword {
+    fail(&self.api, "The account has a password, use another TEST_DC_NUMBER")
+  }
+  fn registration(&self, _state: &AuthorizationStateWaitRegistration) -> (String, String) {
+    ("Test".to_string(), self.account.number().to_string())
+  }
+}
+
+fn fail(api: &Api, message: &str) -> ! {
+  eprintln!("{}", message);
+  let _ = api.send(Close::builder().build());
+  std::process::exit(1);
+}
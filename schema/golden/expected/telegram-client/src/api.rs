@@ -0,0 +1,593 @@
+use core::borrow::Borrow;
+use std::sync::{Arc, Mutex};
+
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use regex::Regex;
+use rtdlib::errors::*;
+use rtdlib::Tdlib;
+use rtdlib::types::*;
+use serde::de::DeserializeOwned;
+
+use crate::batch::Batch;
+use crate::cache::Cache;
+use crate::database_key::DatabaseKey;
+use crate::downloads::DownloadLru;
+use crate::filter::FilterSet;
+#[cfg(feature = "journal")]
+use crate::journal::Journal;
+use crate::me::Me;
+use crate::notifications::Notifications;
+use crate::observer::{Observer, ObserverStats, Pending, PendingRequest};
+use crate::options::Options;
+use crate::presence::{Presence, PresenceKeeper};
+#[cfg(feature = "outbox")]
+use crate::outbox::Outbox;
+use crate::queue::{OverflowPolicy, UpdateQueue, UpdateQueueStats};
+use crate::storage::{StorageCleanup, StoragePolicy};
+use crate::transport::TdLibClient;
+
+#[derive(Debug, Clone)]
+pub struct ApiBuilder {
+  inner: Api
+}
+
+impl ApiBuilder {
+  pub fn new() -> Self {
+    Self {
+      inner: Api {
+        tdlib: None,
+        log: true,
+        unsafe_log: false,
+        observer: Arc::new(Observer::new()),
+        cache: None,
+        notifications: None,
+        #[cfg(feature = "journal")]
+        journal: None,
+        #[cfg(feature = "outbox")]
+        outbox: None,
+        filter: None,
+        receive_timeout: 2.0,
+        idle_backoff: IdleBackoff::None,
+        updates: Arc::new(UpdateQueue::unbounded()),
+        options: Arc::new(Options::new()),
+        me: Arc::new(Me::new()),
+        database_key: Arc::new(Mutex::new(None)),
+        ready_options: Arc::new(vec![]),
+        presence: None,
+        storage_cleanup: None,
+        downloads: None,
+        version_check: None,
+      }
+    }
+  }
+
+  /// Build the api; tdlib is created here if no transport was set, so each build creates its own tdlib client.
+  pub fn build(&self) -> Api {
+    let mut api = self.inner.clone();
+    if api.tdlib.is_none() {
+      api.tdlib = Some(Arc::new(Tdlib::new()));
+    }
+    api
+  }
+
+  /// Preset of a bot: the inline thumbnails and the persistent network statistics are disabled. A bot needs no
+  /// message database, the tdlib parameters answered by the `AuthStateHandler` are `bot_defaults` of rtdlib, which
+  /// disable it; this preset has no tdlib parameters.
+  ///
+  /// The outgoing messages are not filtered, the helpers watching them, like `Api::share_live_location`
+  /// (`updateMessageSendSucceeded`), don't receive the discarded updates; filter them in the listener instead.
+  pub fn bot_defaults() -> Self {
+    let mut builder = ApiBuilder::new();
+    builder
+      .option("ignore_inline_thumbnails", self::boolean(true))
+      .option("disable_persistent_network_statistics", self::boolean(true));
+    builder
+  }
+
+  /// Preset of a userbot, a user account run by a program: the users, groups and chats are cached, the user
+  /// status and typing updates are discarded, the account isn't shown online, and the top chats and the
+  /// notifications of the contacts joined telegram are disabled.
+  pub fn userbot_defaults() -> Self {
+    let mut builder = ApiBuilder::new();
+    builder
+      .cache(true)
+      .filter(FilterSet::builder()
+        .deny_type("updateUserStatus")
+        .deny_type("updateUserChatAction")
+        .deny_type("updateChatAction"))
+      .option("online", self::boolean(false))
+      .option("ignore_inline_thumbnails", self::boolean(true))
+      .option("disable_top_chats", self::boolean(true))
+      .option("disable_contact_registered_notifications", self::boolean(true));
+    builder
+  }
+
+  /// Use an existing tdlib client instead of creating a new one, e.g. a client created by rtdlib
+  /// directly which already holds tdlib state; its authorization state is kept.
+  pub fn tdlib(&mut self, tdlib: Tdlib) -> &mut Self {
+    self.transport(tdlib)
+  }
+
+  /// Connect to tdlib by this transport instead of tdlib, e.g. a `RecordingTdLibClient` or a `ReplayTdLibClient`
+  pub fn transport<T: TdLibClient + 'static>(&mut self, transport: T) -> &mut Self {
+    self.inner.tdlib = Some(Arc::new(transport));
+    self
+  }
+
+  /// Connect to tdlib by a transport selected at runtime
+  pub fn transport_dyn(&mut self, transport: Arc<dyn TdLibClient>) -> &mut Self {
+    self.inner.tdlib = Some(transport);
+    self
+  }
+
+  pub fn log(&mut self, open: bool) -> &mut Self {
+    self.inner.log = open;
+    self
+  }
+
+  pub fn unsafe_log(&mut self, unsafe_log: bool) -> &mut Self {
+    self.inner.unsafe_log = unsafe_log;
+    self
+  }
+
+  /// Encryption key of the local database, answered to tdlib by `Api::authorize` instead of
+  /// `AuthStateHandler::encryption_key`; change it by `Api::rotate_database_key`.
+  pub fn database_key(&mut self, key: DatabaseKey) -> &mut Self {
+    self.inner.database_key = Arc::new(Mutex::new(Some(key)));
+    self
+  }
+
+  /// Keep the latest users, groups and chats received from updates in memory, see `Api::cache`.
+  pub fn cache(&mut self, open: bool) -> &mut Self {
+    self.inner.cache = if open { Some(Arc::new(Cache::new())) } else { None };
+    self
+  }
+
+  /// Number of shards of the waiting requests, default is `observer::DEFAULT_OBSERVER_SHARDS`;
+  /// more shards less lock contention between threads sending many requests.
+  pub fn observer_shards(&mut self, shards: usize) -> &mut Self {
+    self.inner.observer = Arc::new(Observer::with_shards(shards).with_ttl(self.inner.observer.ttl()));
+    self
+  }
+
+  /// Expire the requests without response after this time, the `Pending` fail with a timeout error
+  /// and a warning is logged; requests never expire by default.
+  pub fn request_ttl(&mut self, ttl: Duration) -> &mut Self {
+    self.inner.observer = Arc::new(Observer::with_shards(self.inner.observer.shards()).with_ttl(Some(ttl)));
+    self
+  }
+
+  /// Bound the queue of objects waiting for the listener, the receiver thread applies this policy when
+  /// the queue is full; the queue is unbounded by default.
+  pub fn update_queue(&mut self, capacity: usize, policy: OverflowPolicy) -> &mut Self {
+    self.inner.updates = Arc::new(UpdateQueue::new(Some(capacity), policy));
+    self
+  }
+
+  /// Timeout of each receive from tdlib, default is 2 seconds; the receive returns as soon as an object
+  /// is received, a short timeout lets the receive loop stop sooner.
+  pub fn receive_timeout(&mut self, timeout: Duration) -> &mut Self {
+    self.inner.receive_timeout = timeout.as_secs_f64();
+    self
+  }
+
+  /// Sleep between receives when nothing is received, default is no sleep
+  pub fn idle_backoff(&mut self, idle_backoff: IdleBackoff) -> &mut Self {
+    self.inner.idle_backoff = idle_backoff;
+    self
+  }
+
+  /// Discard the updates not let through by the filters, before they reach the listener
+  pub fn filter<T: AsRef<FilterSet>>(&mut self, filter: T) -> &mut Self {
+    self.inner.filter = Some(Arc::new(filter.as_ref().clone()));
+    self
+  }
+
+  /// Set this tdlib option when the authorization is ready, e.g. `online`; the options are set in the order added
+  pub fn option<S: AsRef<str>>(&mut self, name: S, value: OptionValue) -> &mut Self {
+    Arc::make_mut(&mut self.inner.ready_options).push((name.as_ref().to_string(), value));
+    self
+  }
+
+  /// Keep the online status of the account after the authorization is ready, it's set again every
+  /// `presence::PRESENCE_REFRESH`; it replaces the `online` option of `ApiBuilder::option`.
+  pub fn presence(&mut self, presence: Presence) -> &mut Self {
+    Arc::make_mut(&mut self.inner.ready_options).retain(|(name, _)| name != "online");
+    self.inner.presence = Some(Arc::new(PresenceKeeper::new(presence)));
+    self
+  }
+
+  /// Delete the files of the policy every `interval` after the authorization is ready, see `Api::prune_storage`
+  pub fn storage_cleanup<P: AsRef<StoragePolicy>>(&mut self, policy: P, interval: Duration) -> &mut Self {
+    self.inner.storage_cleanup = Some(Arc::new(StorageCleanup::new(policy.as_ref().clone(), interval)));
+    self
+  }
+
+  /// Keep the files downloaded by `Api::download_stream` in this size, the least recently used are deleted, see
+  /// `Api::downloads`
+  pub fn download_budget(&mut self, bytes: u64) -> &mut Self {
+    self.inner.downloads = Some(Arc::new(DownloadLru::new(bytes)));
+    self
+  }
+
+  /// Check the version of the linked tdlib after the client starts, waiting at most `timeout` for it, see
+  /// `Api::check_tdlib_version`; not checked by default.
+  pub fn tdlib_version_check(&mut self, timeout: Duration) -> &mut Self {
+    self.inner.version_check = Some(timeout);
+    self
+  }
+
+  /// Keep the active notifications received from updates in memory, see `Api::notifications`.
+  pub fn notifications(&mut self, open: bool) -> &mut Self {
+    self.inner.notifications = if open { Some(Arc::new(Notifications::new())) } else { None };
+    self
+  }
+
+  /// Write the objects received for the listener to this journal, the objects not handled by the listener
+  /// are handled again after the client starts, see `Journal`.
+  #[cfg(feature = "journal")]
+  pub fn journal(&mut self, journal: Journal) -> &mut Self {
+    self.inner.journal = Some(Arc::new(journal));
+    self
+  }
+
+  /// Store the requests of `Api::enqueue` in this outbox, they are sent again after the client starts
+  /// until tdlib returned their response, see `Outbox`.
+  #[cfg(feature = "outbox")]
+  pub fn outbox(&mut self, outbox: Outbox) -> &mut Self {
+    self.inner.outbox = Some(Arc::new(outbox));
+    self
+  }
+}
+
+
+fn boolean(value: bool) -> OptionValue {
+  OptionValue::boolean(OptionValueBoolean::builder().value(value))
+}
+
+/// How long the receive loop sleeps when nothing is received, see `ApiBuilder::idle_backoff`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IdleBackoff {
+  /// Receive again immediately
+  None,
+  /// Sleep the same time after every empty receive
+  Fixed(Duration),
+  /// Sleep `initial` after the first empty receive, double it after every next one up to `max`,
+  /// reset when an object is received
+  Exponential { initial: Duration, max: Duration },
+}
+
+impl IdleBackoff {
+  /// the sleep after an empty receive, the previous sleep is `last`
+  pub fn next(&self, last: Option<Duration>) -> Option<Duration> {
+    match *self {
+      IdleBackoff::None => None,
+      IdleBackoff::Fixed(sleep) => Some(sleep),
+      IdleBackoff::Exponential { initial, max } => Some(last.map_or(initial, |last| last.checked_mul(2).map_or(max, |last| last.min(max)))),
+    }
+  }
+}
+
+
+#[derive(Debug, Clone)]
+pub struct Api {
+  /// set by `ApiBuilder::build`
+  tdlib: Option<Arc<dyn TdLibClient>>,
+  log: bool,
+  unsafe_log: bool,
+  observer: Arc<Observer>,
+  cache: Option<Arc<Cache>>,
+  notifications: Option<Arc<Notifications>>,
+  #[cfg(feature = "journal")]
+  journal: Option<Arc<Journal>>,
+  #[cfg(feature = "outbox")]
+  outbox: Option<Arc<Outbox>>,
+  filter: Option<Arc<FilterSet>>,
+  receive_timeout: f64,
+  idle_backoff: IdleBackoff,
+  updates: Arc<UpdateQueue>,
+  options: Arc<Options>,
+  me: Arc<Me>,
+  database_key: Arc<Mutex<Option<DatabaseKey>>>,
+  ready_options: Arc<Vec<(String, OptionValue)>>,
+  presence: Option<Arc<PresenceKeeper>>,
+  storage_cleanup: Option<Arc<StorageCleanup>>,
+  downloads: Option<Arc<DownloadLru>>,
+  version_check: Option<Duration>,
+}
+
+impl Default for Api {
+  fn default() -> Self {
+    ApiBuilder::new().build()
+  }
+}
+
+impl Api {
+
+  pub fn builder() -> ApiBuilder {
+    ApiBuilder::new()
+  }
+
+  pub fn new(tdlib: Tdlib) -> Self {
+    ApiBuilder::new().tdlib(tdlib).build()
+  }
+
+  /// The transport connected to tdlib, see `ApiBuilder::transport`
+  pub fn transport(&self) -> &dyn TdLibClient {
+    self.tdlib.as_deref().expect("The transport is set by ApiBuilder::build")
+  }
+
+  /// In-memory cache of users, groups and chats; `None` if not opened by `ApiBuilder::cache`
+  pub fn cache(&self) -> Option<&Cache> {
+    self.cache.as_ref().map(|cache| cache.borrow())
+  }
+
+  /// Active notifications; `None` if not opened by `ApiBuilder::notifications`
+  pub fn notifications(&self) -> Option<&Notifications> {
+    self.notifications.as_ref().map(|notifications| notifications.borrow())
+  }
+
+  /// Journal of the objects received for the listener; `None` if not set by `ApiBuilder::journal`
+  #[cfg(feature = "journal")]
+  pub fn journal(&self) -> Option<&Journal> {
+    self.journal.as_ref().map(|journal| journal.borrow())
+  }
+
+  /// Outbox of the requests of `Api::enqueue`; `None` if not set by `ApiBuilder::outbox`
+  #[cfg(feature = "outbox")]
+  pub fn outbox(&self) -> Option<&Outbox> {
+    self.outbox.as_ref().map(|outbox| outbox.borrow())
+  }
+
+  /// The downloaded files kept in the budget; `None` if not set by `ApiBuilder::download_budget`
+  pub fn downloads(&self) -> Option<&DownloadLru> {
+    self.downloads.as_ref().map(|downloads| downloads.borrow())
+  }
+
+  /// Values of tdlib options received from `updateOption`
+  pub fn options(&self) -> &Options {
+    self.options.borrow()
+  }
+
+  pub(crate) fn me_cache(&self) -> &Me {
+    self.me.borrow()
+  }
+
+  pub(crate) fn presence_keeper(&self) -> Option<&PresenceKeeper> {
+    self.presence.as_ref().map(|presence| presence.borrow())
+  }
+
+  pub(crate) fn storage_cleanup(&self) -> Option<&StorageCleanup> {
+    self.storage_cleanup.as_ref().map(|cleanup| cleanup.borrow())
+  }
+
+  /// the timeout of `ApiBuilder::tdlib_version_check`
+  pub(crate) fn version_check(&self) -> Option<Duration> {
+    self.version_check
+  }
+
+  /// the options of `ApiBuilder::option`
+  pub(crate) fn ready_options(&self) -> &[(String, OptionValue)] {
+    &self.ready_options
+  }
+
+  pub(crate) fn database_key(&self) -> &Mutex<Option<DatabaseKey>> {
+    self.database_key.borrow()
+  }
+
+  pub(crate) fn observer(&self) -> &Observer {
+    self.observer.borrow()
+  }
+
+  /// All requests waiting for the response, the oldest first; a request waiting long may be leaked
+  pub fn pending_requests(&self) -> Vec<PendingRequest> {
+    self.observer.pending_requests()
+  }
+
+  pub(crate) fn receive_timeout(&self) -> f64 { self.receive_timeout }
+
+  pub(crate) fn idle_backoff(&self) -> &IdleBackoff { &self.idle_backoff }
+
+  pub(crate) fn filter(&self) -> Option<&FilterSet> {
+    self.filter.as_ref().map(|filter| filter.borrow())
+  }
+
+  pub(crate) fn update_queue(&self) -> &UpdateQueue {
+    self.updates.borrow()
+  }
+
+  /// Metrics of the queue of objects waiting for the listener
+  pub fn update_queue_stats(&self) -> UpdateQueueStats {
+    self.updates.stats()
+  }
+
+  /// Number of requests waiting for the response and watchers of updates
+  pub fn observer_stats(&self) -> ObserverStats {
+    self.observer.stats()
+  }
+
+  fn safe_log(&self, text: &String) -> String {
+    if self.unsafe_log {
+      return text.clone();
+    }
+    if text.contains("api_id") || text.contains("api_hash") {
+      let regex_api_id = Regex::new(r#"api_id":\d*"#).expect("Regex fail");
+      let hide_api_id = regex_api_id.replace_all(text, r#"api_id":"****""#);
+      let regex_api_hash = Regex::new(r#"api_hash":"[0-9|a-f]*""#).expect("Regex fail");
+      let hide_api_hash = regex_api_hash.replace_all(&hide_api_id, r#"api_hash":"**********""#);
+      hide_api_hash.into_owned()
+    } else {
+      text.clone()
+    }
+  }
+
+  pub fn send<Fnc: RFunction>(&self, fnc: Fnc) -> RTDResult<()> {
+    self.authorized()?;
+    let json = fnc.to_json()?;
+    self.send_json(&json);
+    Ok(())
+  }
+
+  pub(crate) fn send_json(&self, json: &String) {
+    if self.log {
+      info!("===> {}", self.safe_log(json));
+    }
+    if let Some(presence) = self.presence_keeper() {
+      presence.touch(json);
+    }
+    self.transport().send(&json[..]);
+  }
+
+  /// Send a request to tdlib with a unique `@extra`, the returned `Pending` can wait the response of this request.
+  ///
+  /// `T` is the return type of this function, the response will not be handled by listener.
+  pub fn request<Fnc: RFunction, T: DeserializeOwned>(&self, fnc: Fnc) -> RTDResult<Pending<T>> {
+    self.authorized()?;
+    let (extra, json) = self.with_extra(&fnc)?;
+    Ok(self.send_observed(extra, fnc.td_name(), &json))
+  }
+
+  /// Send the requests back-to-back, the returned `Batch` yields each response as soon as it's received.
+  ///
+  /// `T` is the return type of these functions.
+  pub fn request_batch<Fnc: RFunction, T: DeserializeOwned>(&self, fncs: Vec<Fnc>) -> RTDResult<Batch<T>> {
+    self.authorized()?;
+    let (sender, receiver) = mpsc::channel();
+    let mut requests = Vec::with_capacity(fncs.len());
+    for fnc in &fncs {
+      let (extra, json) = self.with_extra(fnc)?;
+      requests.push((extra, fnc.td_name(), json));
+    }
+    let extras: Vec<String> = requests.iter().map(|(extra, _, _)| extra.clone()).collect();
+    for (extra, td_name, json) in requests {
+      self.observer.subscribe_with(&extra, td_name, sender.clone());
+      self.send_json(&json);
+    }
+    Ok(Batch::new(extras, receiver, self.observer.clone()))
+  }
+
+  /// Store a request in the outbox, it's sent when the authorization is ready and sent again after a restart
+  /// until tdlib returned its response; return its id in the outbox, see `Outbox`.
+  #[cfg(feature = "outbox")]
+  pub fn enqueue<Fnc: RFunction>(&self, fnc: Fnc) -> RTDResult<u64> {
+    match self.outbox() {
+      Some(outbox) => outbox.push(&fnc),
+      None => Err(RTDError::custom("No outbox, set it by ApiBuilder::outbox")),
+    }
+  }
+
+  /// Send a request built as json, the response is returned as json; for the functions of a newer
+  /// tdlib not generated yet. The `@type` of the request is required, an error response fails the `Pending`.
+  pub fn request_raw(&self, value: serde_json::Value) -> RTDResult<Pending<serde_json::Value>> {
+    self.authorized()?;
+    let td_type = match value.get("@type").and_then(|td_type| td_type.as_str()) {
+      Some(td_type) => td_type.to_string(),
+      None => return Err(RTDError::custom("The request has no @type")),
+    };
+    let (extra, json) = self.insert_extra(value)?;
+    Ok(self.send_observed(extra, td_type, &json))
+  }
+
+  /// `RTDError::NotAuthorized` after the client is logged out by `Api::sign_out`, no response would be received
+  fn authorized(&self) -> RTDResult<()> {
+    if self.observer.is_closed() {
+      return Err(RTDError::NotAuthorized);
+    }
+    Ok(())
+  }
+
+  /// the json of this function with a new unique `@extra`
+  fn with_extra<Fnc: RFunction>(&self, fnc: &Fnc) -> RTDResult<(String, String)> {
+    self.insert_extra(serde_json::from_str(&fnc.to_json()?[..])?)
+  }
+
+  /// insert a new unique `@extra` to the json object of a request, return the extra and the json
+  fn insert_extra(&self, mut value: serde_json::Value) -> RTDResult<(String, String)> {
+    let extra = self.observer.next_extra();
+    match value.as_object_mut() {
+      Some(map) => map.insert("@extra".to_string(), serde_json::Value::String(extra.clone())),
+      None => return Err(RTDError::custom("The request is not a json object")),
+    };
+    Ok((extra, value.to_string()))
+  }
+
+  /// send a request with its `@extra`, the returned `Pending` receives the response observed of this extra
+  fn send_observed<T: DeserializeOwned, S: AsRef<str>>(&self, extra: String, td_type: S, json: &String) -> Pending<T> {
+    let receiver = self.observer.subscribe(&extra, td_type);
+    let pending = Pending::new(extra, receiver, self.observer.clone());
+    self.send_json(json);
+    pending
+  }
+
+  /// Receive a copy of every `td_type` object returned by tdlib, the listener still handles them.
+  pub(crate) fn watch<S: AsRef<str>>(&self, td_type: S) -> Receiver<String> {
+    self.observer.watch(td_type)
+  }
+
+  pub fn receive(&self, timeout: f64) -> Option<String> {
+    let receive = self.transport().receive(timeout);
+    if self.log {
+      if receive.is_some() {
+        info!("<=== {}", receive.clone().map_or("<NONE>".to_string(), |v| self.safe_log(&v)));
+      }
+    }
+    receive
+  }
+
+  pub fn execute<Fnc: RFunction>(&self, fnc: Fnc) -> RTDResult<Option<String>> {
+    let json = fnc.to_json()?;
+    if self.log {
+      info!("===>>> {}", self.safe_log(&json));
+    }
+    Ok(self.transport().execute(&json[..]))
+  }
+
+
+
+
+  #[cfg(feature = "calls")] 
+  /// Discards a call
+  ///
+  /// see [`DiscardCall`], [discardCall](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1discard_call.html) of the tdlib reference
+  pub fn discard_call<C: AsRef<DiscardCall>>(&self, discard_call: C) -> RTDResult<()> {
+    self.send(discard_call.as_ref())
+  }
+
+   
+  /// Returns the current authorization state; this is an offline request. For informational purposes only. Use updateAuthorizationState instead to maintain the current authorization state. Can be called before initialization
+  ///
+  /// see [`GetAuthorizationState`], [getAuthorizationState](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1get_authorization_state.html) of the tdlib reference
+  pub fn get_authorization_state<C: AsRef<GetAuthorizationState>>(&self, get_authorization_state: C) -> RTDResult<()> {
+    self.send(get_authorization_state.as_ref())
+  }
+
+   
+  /// Returns the value of an option by its name. (Check the list of available options on https://core.telegram.org/tdlib/options.) Can be called before authorization
+  ///
+  /// see [`GetOption`], [getOption](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1get_option.html) of the tdlib reference
+  pub fn get_option<C: AsRef<GetOption>>(&self, get_option: C) -> RTDResult<()> {
+    self.send(get_option.as_ref())
+  }
+
+   
+  /// Returns all entities (mentions, hashtags, cashtags, bot commands, bank card numbers, URLs, and email addresses) contained in the text. Can be called synchronously
+  ///
+  /// see [`GetTextEntities`], [getTextEntities](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1get_text_entities.html) of the tdlib reference
+  pub fn get_text_entities<C: AsRef<GetTextEntities>>(&self, get_text_entities: C) -> RTDResult<()> {
+    self.send(get_text_entities.as_ref())
+  }
+
+   
+  /// Sets the parameters for TDLib initialization. Works only when the current authorization state is authorizationStateWaitTdlibParameters
+  ///
+  /// see [`SetTdlibParameters`], [setTdlibParameters](https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1set_tdlib_parameters.html) of the tdlib reference
+  pub fn set_tdlib_parameters<C: AsRef<SetTdlibParameters>>(&self, set_tdlib_parameters: C) -> RTDResult<()> {
+    self.send(set_tdlib_parameters.as_ref())
+  }
+
+
+
+}
+
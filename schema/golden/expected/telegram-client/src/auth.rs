@@ -0,0 +1,120 @@
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+use crate::subscription::Subscription;
+
+/// The answer to `AuthStateHandler::code`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthCode {
+  /// The code received
+  Code(String),
+  /// Send the code again, by the `next_type` of the code info; the handler is asked again by the new code info
+  Resend,
+}
+
+/// The answer to `AuthStateHandler::password`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthPassword {
+  /// The 2-step verification password
+  Password(String),
+  /// Send a recovery code to the recovery email address; the handler is asked again, the
+  /// `recovery_email_address_pattern` of the state is the address the code is sent to
+  RequestRecovery,
+  /// The recovery code received, it removes the password
+  RecoveryCode(String),
+}
+
+/// The answers to the authorization states, see `Api::authorize`.
+///
+/// A state without its own callback, e.g. `Ready` or a state added by a newer tdlib, goes to `handle_other`.
+pub trait AuthStateHandler {
+  /// `setTdlibParameters` of the client
+   fn tdlib_parameters(&self) -> SetTdlibParameters;
+
+  /// Any other state, nothing is sent by default
+  fn handle_other(&self, _api: &Api, _state: &AuthorizationState) -> RTDResult<()> { Ok(()) }
+
+  /// The answer to a state is refused by tdlib, e.g. a wrong code; `Ok` asks the handler again, the error
+  /// stops `Api::authorize` by default
+  fn refused(&self, _state: &AuthorizationState, error: RTDError) -> RTDResult<()> { Err(error) }
+}
+
+/// Send the answer of the handler to an authorization state and wait for it, the arms are generated from the
+/// `AuthorizationState` of the schema.
+///
+/// A tdlib error, e.g. a wrong code, is returned as `RTDError::TdlibError`, the state is still the same.
+pub fn handle_auth_state<H: AuthStateHandler + ?Sized>(api: &Api, handler: &H, state: &AuthorizationState) -> RTDResult<()> {
+  match state {
+     AuthorizationState::Closed(_) => handler.handle_other(api, state),
+     AuthorizationState::Ready(_) => handler.handle_other(api, state),
+     AuthorizationState::WaitTdlibParameters(_) =>
+      self::set_parameters(api, handler.tdlib_parameters()),
+    _ => handler.handle_other(api, state),
+  }
+}
+
+/// the parameters of the handler are validated before they are sent, tdlib refuses them later and less clear
+fn set_parameters(api: &Api, parameters: SetTdlibParameters) -> RTDResult<()> {
+  parameters.parameters().validate()?;
+  self::answer(api, parameters)
+}
+
+fn answer<Fnc: RFunction>(api: &Api, fnc: Fnc) -> RTDResult<()> {
+  let _: Ok = api.request(fnc)?.wait()?;
+  Ok(())
+}
+
+
+impl Api {
+  /// Answer the authorization states by the handler until the client is ready, fails if it's closed.
+  ///
+  /// The updates are of `api.subscribe(&["updateAuthorizationState"])` before the client starts, so the first
+  /// state isn't missed. A state answered by a tdlib error is asked again if `AuthStateHandler::refused` allows it.
+  pub fn authorize<H: AuthStateHandler + ?Sized>(&self, updates: &Subscription, handler: &H) -> RTDResult<()> {
+    while let Some(update) = updates.recv() {
+      let state = match update {
+        Ok(Update::AuthorizationState(update)) => update.authorization_state().clone(),
+        Ok(_) => continue,
+        Err(e) => {
+          warn!("Can not read the authorization state: {}", e);
+          continue;
+        }
+      };
+      loop {
+        match self::handle_auth_state(self, handler, &state) {
+          Ok(()) => break,
+          Err(e @ RTDError::TdlibError(_)) => handler.refused(&state, e)?,
+          Err(e) => return Err(e),
+        }
+      }
+      if state.is_ready() { return Ok(()); }
+      if state.is_closed() { return Err(RTDError::custom("The client is closed before it's authorized")); }
+    }
+    Err(RTDError::custom("The receive loop is stopped before the client is authorized"))
+  }
+
+  /// Run `f` on its own thread once the authorization is ready, the thread stops without running it if the
+  /// client stops before. Called before the client starts, so the first authorization state isn't missed.
+  pub(crate) fn on_ready<F: FnOnce(Api) + Send + 'static>(&self, f: F) -> JoinHandle<()> {
+    let auth = self.watch("updateAuthorizationState");
+    let api = self.clone();
+    thread::spawn(move || {
+      while !api.update_queue().is_closed() {
+        match auth.recv_timeout(READY_TICK) {
+          Ok(json) => if json.contains("\"authorizationStateReady\"") { return f(api); },
+          Err(RecvTimeoutError::Timeout) => continue,
+          Err(RecvTimeoutError::Disconnected) => return,
+        }
+      }
+    })
+  }
+}
+
+/// how often `Api::on_ready` checks the client is stopped while waiting for the authorization
+const READY_TICK: Duration = Duration::from_millis(200);
+
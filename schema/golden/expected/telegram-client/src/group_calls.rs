@@ -0,0 +1,4 @@
+//! Group calls (voice and video chats) of the schemas with the json payload of `joinGroupCall`, empty for an
+//! older schema.
+
+
@@ -0,0 +1,75 @@
+use rtdlib::types as rtd_types;
+
+use crate::api::Api;
+use crate::listener::Lout;
+use crate::scan;
+use crate::errors::TGError;
+use crate::tip;
+
+pub struct Handler<'a> {
+  api: &'a Api,
+  lout: &'a Lout,
+}
+
+macro_rules! event_handler {
+  ($event_name:ident, $td_type:ident) => {
+    |api: &Api, lout: &Lout, json: &String| {
+      if let Some(ev) = lout.$event_name() {
+        match scan::decode::<rtd_types::$td_type>(json) {
+          Ok(t) => {
+            if let Err(_e) = ev((api, &t)) {
+              if let Some(ev) = lout.exception() { ev((api, &TGError::new("EVENT_HANDLER_ERROR"))); }
+            }
+          }
+          Err(e) => {
+            error!("{}", tip::data_fail_with_json(json));
+            eprintln!("{:?}", e);
+            if let Some(ev) = lout.exception() { ev((api, &TGError::new("DESERIALIZE_JSON_FAIL"))); }
+          }
+        }
+        return;
+      }
+      warn!("{}", tip::un_register_listener(stringify!($event_name)));
+    }
+  };
+}
+
+impl<'a> Handler<'a> {
+  pub(crate) fn new(api: &'a Api, lout: &'a Lout) -> Self {
+    Self {
+      api,
+      lout,
+    }
+  }
+
+  pub fn handle(&self, td_type: &str, json: &'a String) {
+    if !self.lout.is_support(td_type) {
+      warn!("{}", tip::not_have_listener(td_type));
+      return;
+    }
+
+    if let Some(ev) = self.lout.receive() {
+      if let Err(e) = ev((self.api, json)) {
+        if let Some(ev) = self.lout.exception() { ev((self.api, &e)); }
+      }
+    }
+
+    match td_type {
+       "updateAuthorizationState" => event_handler!(update_authorization_state, UpdateAuthorizationState)(self.api, self.lout, json),
+       "updateOption" => event_handler!(update_option, UpdateOption)(self.api, self.lout, json),
+
+       "AuthorizationState" => event_handler!(authorization_state, AuthorizationState)(self.api, self.lout, json),
+       "OptionValue" => event_handler!(option_value, OptionValue)(self.api, self.lout, json),
+       "ok" => event_handler!(ok, Ok)(self.api, self.lout, json),
+       "textEntities" => event_handler!(text_entities, TextEntities)(self.api, self.lout, json),
+
+
+      _ => {
+        warn!("{}", tip::data_fail_with_json(json))
+      }
+    }
+  }
+
+}
+
+
@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use rtdlib::types::*;
+use crate::errors::*;
+use crate::api::Api;
+
+
+/// Telegram client event listener
+#[derive(Clone, Default)]
+pub struct Listener {
+  exception: Option<Arc<dyn Fn((&Api, &TGError)) + Send + Sync + 'static>>,
+  receive: Option<Arc<dyn Fn((&Api, &String)) -> TGResult<()> + Send + Sync + 'static>>,
+
+
+
+   update_authorization_state: Option<Arc<dyn Fn((&Api, &UpdateAuthorizationState)) -> TGResult<()> + Send + Sync + 'static>>,
+   update_option: Option<Arc<dyn Fn((&Api, &UpdateOption)) -> TGResult<()> + Send + Sync + 'static>>,
+
+
+   authorization_state: Option<Arc<dyn Fn((&Api, &AuthorizationState)) -> TGResult<()> + Send + Sync + 'static>>,
+   option_value: Option<Arc<dyn Fn((&Api, &OptionValue)) -> TGResult<()> + Send + Sync + 'static>>,
+   ok: Option<Arc<dyn Fn((&Api, &Ok)) -> TGResult<()> + Send + Sync + 'static>>,
+   text_entities: Option<Arc<dyn Fn((&Api, &TextEntities)) -> TGResult<()> + Send + Sync + 'static>>,
+
+}
+
+
+impl Listener {
+  pub fn new() -> Self { Listener::default() }
+
+  pub(crate) fn has_receive_listen(&self) -> bool { self.receive.is_some() }
+
+  pub(crate) fn lout(&self) -> Lout { Lout::new(self.clone()) }
+
+
+  /// when receive data from tdlib
+  pub fn on_receive<F>(&mut self, fnc: F) -> &mut Self where F: Fn((&Api, &String)) -> TGResult<()> + Send + Sync + 'static {
+    self.receive = Some(Arc::new(fnc));
+    self
+  }
+
+  /// when telegram client throw exception
+  pub fn on_exception<F>(&mut self, fnc: F) -> &mut Self where F: Fn((&Api, &TGError)) + Send + Sync + 'static {
+    self.exception = Some(Arc::new(fnc));
+    self
+  }
+
+
+
+
+
+
+   
+  /// The user authorization state has changed
+  pub fn on_update_authorization_state<F>(&mut self, fnc: F) -> &mut Self
+    where F: Fn((&Api, &UpdateAuthorizationState)) -> TGResult<()> + Send + Sync + 'static {
+    self.update_authorization_state = Some(Arc::new(fnc));
+    self
+  }
+
+   
+  /// An option changed its value
+  pub fn on_update_option<F>(&mut self, fnc: F) -> &mut Self
+    where F: Fn((&Api, &UpdateOption)) -> TGResult<()> + Send + Sync + 'static {
+    self.update_option = Some(Arc::new(fnc));
+    self
+  }
+
+
+
+   
+  /// Represents the current authorization state of the TDLib client
+  pub fn on_authorization_state<F>(&mut self, fnc: F) -> &mut Self
+    where F: Fn((&Api, &AuthorizationState)) -> TGResult<()> + Send + Sync + 'static {
+    self.authorization_state = Some(Arc::new(fnc));
+    self
+  }
+
+   
+  /// Represents the value of an option
+  pub fn on_option_value<F>(&mut self, fnc: F) -> &mut Self
+    where F: Fn((&Api, &OptionValue)) -> TGResult<()> + Send + Sync + 'static {
+    self.option_value = Some(Arc::new(fnc));
+    self
+  }
+
+   
+  /// An object of this type is returned on a successful function call for certain functions
+  pub fn on_ok<F>(&mut self, fnc: F) -> &mut Self
+    where F: Fn((&Api, &Ok)) -> TGResult<()> + Send + Sync + 'static {
+    self.ok = Some(Arc::new(fnc));
+    self
+  }
+
+   
+  /// Contains a list of text entities
+  pub fn on_text_entities<F>(&mut self, fnc: F) -> &mut Self
+    where F: Fn((&Api, &TextEntities)) -> TGResult<()> + Send + Sync + 'static {
+    self.text_entities = Some(Arc::new(fnc));
+    self
+  }
+
+}
+
+
+/// Get listener
+pub struct Lout {
+  listener: Listener,
+  supports: Vec<&'static str>
+}
+
+impl Lout {
+  fn new(listener: Listener) -> Self {
+    let supports = vec![
+
+
+      "updateAuthorizationState",
+      "updateOption",
+
+
+      "AuthorizationState",
+      "OptionValue",
+      "ok",
+      "textEntities",
+
+
+    ];
+    Self { listener, supports }
+  }
+
+  pub fn is_support<S: AsRef<str>>(&self, name: S) -> bool {
+    self.supports.iter()
+      .find(|&&item| item == name.as_ref())
+      .is_some()
+  }
+
+  /// when telegram client throw exception
+  pub fn exception(&self) -> &Option<Arc<dyn Fn((&Api, &TGError)) + Send + Sync + 'static>> {
+    &self.listener.exception
+  }
+
+  /// when receive data from tdlib
+  pub fn receive(&self) -> &Option<Arc<dyn Fn((&Api, &String)) -> TGResult<()> + Send + Sync + 'static>> {
+    &self.listener.receive
+  }
+
+
+
+
+
+  
+  /// The user authorization state has changed
+  pub fn update_authorization_state(&self) -> &Option<Arc<dyn Fn((&Api, &UpdateAuthorizationState)) -> TGResult<()> + Send + Sync + 'static>> {
+    &self.listener.update_authorization_state
+  }
+
+  
+  /// An option changed its value
+  pub fn update_option(&self) -> &Option<Arc<dyn Fn((&Api, &UpdateOption)) -> TGResult<()> + Send + Sync + 'static>> {
+    &self.listener.update_option
+  }
+
+
+
+  
+  /// Represents the current authorization state of the TDLib client
+  pub fn authorization_state(&self) -> &Option<Arc<dyn Fn((&Api, &AuthorizationState)) -> TGResult<()> + Send + Sync + 'static>> {
+    &self.listener.authorization_state
+  }
+
+  
+  /// Represents the value of an option
+  pub fn option_value(&self) -> &Option<Arc<dyn Fn((&Api, &OptionValue)) -> TGResult<()> + Send + Sync + 'static>> {
+    &self.listener.option_value
+  }
+
+  
+  /// An object of this type is returned on a successful function call for certain functions
+  pub fn ok(&self) -> &Option<Arc<dyn Fn((&Api, &Ok)) -> TGResult<()> + Send + Sync + 'static>> {
+    &self.listener.ok
+  }
+
+  
+  /// Contains a list of text entities
+  pub fn text_entities(&self) -> &Option<Arc<dyn Fn((&Api, &TextEntities)) -> TGResult<()> + Send + Sync + 'static>> {
+    &self.listener.text_entities
+  }
+
+
+}
+
+
+
@@ -0,0 +1,968 @@
+//! Conversions of the proto messages from and to the tdlib json, the server sends the json by `Api::request_raw`.
+
+use serde_json::{Map, Value};
+
+use crate::pb;
+
+/// The tdlib json of a proto message
+pub trait ToTd {
+  fn to_td(&self) -> Value;
+}
+
+/// A proto message of the tdlib json, a missing field is the default value
+pub trait FromTd: Sized {
+  fn from_td(value: &Value) -> Self;
+}
+
+impl ToTd for i32 {
+  fn to_td(&self) -> Value { Value::from(*self) }
+}
+
+impl FromTd for i32 {
+  fn from_td(value: &Value) -> Self { value.as_i64().unwrap_or_default() as i32 }
+}
+
+/// tdlib accepts an int64 as a number, and returns it as a string
+impl ToTd for i64 {
+  fn to_td(&self) -> Value { Value::from(*self) }
+}
+
+impl FromTd for i64 {
+  fn from_td(value: &Value) -> Self {
+    match value {
+      Value::String(text) => text.parse().unwrap_or_default(),
+      _ => value.as_i64().unwrap_or_default(),
+    }
+  }
+}
+
+impl ToTd for f64 {
+  fn to_td(&self) -> Value { Value::from(*self) }
+}
+
+impl FromTd for f64 {
+  fn from_td(value: &Value) -> Self { value.as_f64().unwrap_or_default() }
+}
+
+impl ToTd for bool {
+  fn to_td(&self) -> Value { Value::from(*self) }
+}
+
+impl FromTd for bool {
+  fn from_td(value: &Value) -> Self { value.as_bool().unwrap_or_default() }
+}
+
+impl ToTd for String {
+  fn to_td(&self) -> Value { Value::from(&self[..]) }
+}
+
+impl FromTd for String {
+  fn from_td(value: &Value) -> Self { value.as_str().unwrap_or_default().to_string() }
+}
+
+/// bytes are base64 in the tdlib json
+impl ToTd for Vec<u8> {
+  fn to_td(&self) -> Value { Value::from(base64::encode(self)) }
+}
+
+impl FromTd for Vec<u8> {
+  fn from_td(value: &Value) -> Self {
+    value.as_str().and_then(|text| base64::decode(text).ok()).unwrap_or_default()
+  }
+}
+
+impl<T: ToTd> ToTd for Vec<T> {
+  fn to_td(&self) -> Value { Value::Array(self.iter().map(ToTd::to_td).collect()) }
+}
+
+impl<T: FromTd> FromTd for Vec<T> {
+  fn from_td(value: &Value) -> Self {
+    value.as_array().map_or_else(Vec::new, |items| items.iter().map(T::from_td).collect())
+  }
+}
+
+impl<T: ToTd> ToTd for Option<T> {
+  fn to_td(&self) -> Value { self.as_ref().map_or(Value::Null, ToTd::to_td) }
+}
+
+impl<T: FromTd> FromTd for Option<T> {
+  fn from_td(value: &Value) -> Self {
+    if value.is_null() { None } else { Some(T::from_td(value)) }
+  }
+}
+
+/// prost boxes the recursive messages
+impl<T: ToTd> ToTd for Box<T> {
+  fn to_td(&self) -> Value { (**self).to_td() }
+}
+
+impl<T: FromTd> FromTd for Box<T> {
+  fn from_td(value: &Value) -> Self { Box::new(T::from_td(value)) }
+}
+
+impl ToTd for pb::KeyboardButtonVector {
+  fn to_td(&self) -> Value { self.items.to_td() }
+}
+
+impl FromTd for pb::KeyboardButtonVector {
+  fn from_td(value: &Value) -> Self { Self { items: FromTd::from_td(value) } }
+}
+
+impl ToTd for pb::AuthorizationState {
+  fn to_td(&self) -> Value {
+    match &self.kind {
+      Some(pb::authorization_state::Kind::AuthorizationStateClosed(value)) => value.to_td(),
+      Some(pb::authorization_state::Kind::AuthorizationStateReady(value)) => value.to_td(),
+      Some(pb::authorization_state::Kind::AuthorizationStateWaitTdlibParameters(value)) => value.to_td(),
+      Some(pb::authorization_state::Kind::GetAuthorizationState(value)) => value.to_td(),
+      None => Value::Null,
+    }
+  }
+}
+
+impl FromTd for pb::AuthorizationState {
+  fn from_td(value: &Value) -> Self {
+    let kind = match value["@type"].as_str() {
+      Some("authorizationStateClosed") => Some(pb::authorization_state::Kind::AuthorizationStateClosed(FromTd::from_td(value))),
+      Some("authorizationStateReady") => Some(pb::authorization_state::Kind::AuthorizationStateReady(FromTd::from_td(value))),
+      Some("authorizationStateWaitTdlibParameters") => Some(pb::authorization_state::Kind::AuthorizationStateWaitTdlibParameters(FromTd::from_td(value))),
+      Some("getAuthorizationState") => Some(pb::authorization_state::Kind::GetAuthorizationState(FromTd::from_td(value))),
+      _ => None,
+    };
+    Self { kind }
+  }
+}
+
+impl ToTd for pb::CallDiscardReason {
+  fn to_td(&self) -> Value {
+    match &self.kind {
+      Some(pb::call_discard_reason::Kind::CallDiscardReasonEmpty(value)) => value.to_td(),
+      Some(pb::call_discard_reason::Kind::CallDiscardReasonHungUp(value)) => value.to_td(),
+      None => Value::Null,
+    }
+  }
+}
+
+impl FromTd for pb::CallDiscardReason {
+  fn from_td(value: &Value) -> Self {
+    let kind = match value["@type"].as_str() {
+      Some("callDiscardReasonEmpty") => Some(pb::call_discard_reason::Kind::CallDiscardReasonEmpty(FromTd::from_td(value))),
+      Some("callDiscardReasonHungUp") => Some(pb::call_discard_reason::Kind::CallDiscardReasonHungUp(FromTd::from_td(value))),
+      _ => None,
+    };
+    Self { kind }
+  }
+}
+
+impl ToTd for pb::KeyboardButtonType {
+  fn to_td(&self) -> Value {
+    match &self.kind {
+      Some(pb::keyboard_button_type::Kind::KeyboardButtonTypeRequestLocation(value)) => value.to_td(),
+      Some(pb::keyboard_button_type::Kind::KeyboardButtonTypeText(value)) => value.to_td(),
+      None => Value::Null,
+    }
+  }
+}
+
+impl FromTd for pb::KeyboardButtonType {
+  fn from_td(value: &Value) -> Self {
+    let kind = match value["@type"].as_str() {
+      Some("keyboardButtonTypeRequestLocation") => Some(pb::keyboard_button_type::Kind::KeyboardButtonTypeRequestLocation(FromTd::from_td(value))),
+      Some("keyboardButtonTypeText") => Some(pb::keyboard_button_type::Kind::KeyboardButtonTypeText(FromTd::from_td(value))),
+      _ => None,
+    };
+    Self { kind }
+  }
+}
+
+impl ToTd for pb::MessageContent {
+  fn to_td(&self) -> Value {
+    match &self.kind {
+      Some(pb::message_content::Kind::MessageCall(value)) => value.to_td(),
+      Some(pb::message_content::Kind::MessageInvoice(value)) => value.to_td(),
+      Some(pb::message_content::Kind::MessageSticker(value)) => value.to_td(),
+      Some(pb::message_content::Kind::MessageText(value)) => value.to_td(),
+      None => Value::Null,
+    }
+  }
+}
+
+impl FromTd for pb::MessageContent {
+  fn from_td(value: &Value) -> Self {
+    let kind = match value["@type"].as_str() {
+      Some("messageCall") => Some(pb::message_content::Kind::MessageCall(FromTd::from_td(value))),
+      Some("messageInvoice") => Some(pb::message_content::Kind::MessageInvoice(FromTd::from_td(value))),
+      Some("messageSticker") => Some(pb::message_content::Kind::MessageSticker(FromTd::from_td(value))),
+      Some("messageText") => Some(pb::message_content::Kind::MessageText(FromTd::from_td(value))),
+      _ => None,
+    };
+    Self { kind }
+  }
+}
+
+impl ToTd for pb::OptionValue {
+  fn to_td(&self) -> Value {
+    match &self.kind {
+      Some(pb::option_value::Kind::GetOption(value)) => value.to_td(),
+      Some(pb::option_value::Kind::OptionValueBoolean(value)) => value.to_td(),
+      Some(pb::option_value::Kind::OptionValueEmpty(value)) => value.to_td(),
+      None => Value::Null,
+    }
+  }
+}
+
+impl FromTd for pb::OptionValue {
+  fn from_td(value: &Value) -> Self {
+    let kind = match value["@type"].as_str() {
+      Some("getOption") => Some(pb::option_value::Kind::GetOption(FromTd::from_td(value))),
+      Some("optionValueBoolean") => Some(pb::option_value::Kind::OptionValueBoolean(FromTd::from_td(value))),
+      Some("optionValueEmpty") => Some(pb::option_value::Kind::OptionValueEmpty(FromTd::from_td(value))),
+      _ => None,
+    };
+    Self { kind }
+  }
+}
+
+impl ToTd for pb::ReplyMarkup {
+  fn to_td(&self) -> Value {
+    match &self.kind {
+      Some(pb::reply_markup::Kind::ReplyMarkupRemoveKeyboard(value)) => value.to_td(),
+      Some(pb::reply_markup::Kind::ReplyMarkupShowKeyboard(value)) => value.to_td(),
+      None => Value::Null,
+    }
+  }
+}
+
+impl FromTd for pb::ReplyMarkup {
+  fn from_td(value: &Value) -> Self {
+    let kind = match value["@type"].as_str() {
+      Some("replyMarkupRemoveKeyboard") => Some(pb::reply_markup::Kind::ReplyMarkupRemoveKeyboard(FromTd::from_td(value))),
+      Some("replyMarkupShowKeyboard") => Some(pb::reply_markup::Kind::ReplyMarkupShowKeyboard(FromTd::from_td(value))),
+      _ => None,
+    };
+    Self { kind }
+  }
+}
+
+impl ToTd for pb::RichText {
+  fn to_td(&self) -> Value {
+    match &self.kind {
+      Some(pb::rich_text::Kind::RichTextBold(value)) => value.to_td(),
+      Some(pb::rich_text::Kind::RichTextPlain(value)) => value.to_td(),
+      None => Value::Null,
+    }
+  }
+}
+
+impl FromTd for pb::RichText {
+  fn from_td(value: &Value) -> Self {
+    let kind = match value["@type"].as_str() {
+      Some("richTextBold") => Some(pb::rich_text::Kind::RichTextBold(FromTd::from_td(value))),
+      Some("richTextPlain") => Some(pb::rich_text::Kind::RichTextPlain(FromTd::from_td(value))),
+      _ => None,
+    };
+    Self { kind }
+  }
+}
+
+impl ToTd for pb::TextEntityType {
+  fn to_td(&self) -> Value {
+    match &self.kind {
+      Some(pb::text_entity_type::Kind::TextEntityTypeBankCardNumber(value)) => value.to_td(),
+      Some(pb::text_entity_type::Kind::TextEntityTypeBold(value)) => value.to_td(),
+      Some(pb::text_entity_type::Kind::TextEntityTypeUrl(value)) => value.to_td(),
+      None => Value::Null,
+    }
+  }
+}
+
+impl FromTd for pb::TextEntityType {
+  fn from_td(value: &Value) -> Self {
+    let kind = match value["@type"].as_str() {
+      Some("textEntityTypeBankCardNumber") => Some(pb::text_entity_type::Kind::TextEntityTypeBankCardNumber(FromTd::from_td(value))),
+      Some("textEntityTypeBold") => Some(pb::text_entity_type::Kind::TextEntityTypeBold(FromTd::from_td(value))),
+      Some("textEntityTypeUrl") => Some(pb::text_entity_type::Kind::TextEntityTypeUrl(FromTd::from_td(value))),
+      _ => None,
+    };
+    Self { kind }
+  }
+}
+
+impl ToTd for pb::Update {
+  fn to_td(&self) -> Value {
+    match &self.kind {
+      Some(pb::update::Kind::UpdateAuthorizationState(value)) => value.to_td(),
+      Some(pb::update::Kind::UpdateOption(value)) => value.to_td(),
+      None => Value::Null,
+    }
+  }
+}
+
+impl FromTd for pb::Update {
+  fn from_td(value: &Value) -> Self {
+    let kind = match value["@type"].as_str() {
+      Some("updateAuthorizationState") => Some(pb::update::Kind::UpdateAuthorizationState(FromTd::from_td(value))),
+      Some("updateOption") => Some(pb::update::Kind::UpdateOption(FromTd::from_td(value))),
+      _ => None,
+    };
+    Self { kind }
+  }
+}
+
+impl ToTd for pb::AuthorizationStateClosed {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("authorizationStateClosed"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::AuthorizationStateClosed {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::AuthorizationStateReady {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("authorizationStateReady"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::AuthorizationStateReady {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::AuthorizationStateWaitTdlibParameters {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("authorizationStateWaitTdlibParameters"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::AuthorizationStateWaitTdlibParameters {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::CallDiscardReasonEmpty {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("callDiscardReasonEmpty"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::CallDiscardReasonEmpty {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::CallDiscardReasonHungUp {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("callDiscardReasonHungUp"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::CallDiscardReasonHungUp {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::CallProtocol {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("callProtocol"));
+    map.insert("udp_p2p".to_string(), self.udp_p2p.to_td());
+    map.insert("udp_reflector".to_string(), self.udp_reflector.to_td());
+    map.insert("min_layer".to_string(), self.min_layer.to_td());
+    map.insert("max_layer".to_string(), self.max_layer.to_td());
+    map.insert("library_versions".to_string(), self.library_versions.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::CallProtocol {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      udp_p2p: FromTd::from_td(&value["udp_p2p"]),
+      udp_reflector: FromTd::from_td(&value["udp_reflector"]),
+      min_layer: FromTd::from_td(&value["min_layer"]),
+      max_layer: FromTd::from_td(&value["max_layer"]),
+      library_versions: FromTd::from_td(&value["library_versions"]),
+    }
+  }
+}
+
+impl ToTd for pb::DiscardCall {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("discardCall"));
+    map.insert("call_id".to_string(), self.call_id.to_td());
+    map.insert("is_disconnected".to_string(), self.is_disconnected.to_td());
+    map.insert("duration".to_string(), self.duration.to_td());
+    map.insert("is_video".to_string(), self.is_video.to_td());
+    map.insert("connection_id".to_string(), self.connection_id.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::DiscardCall {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      call_id: FromTd::from_td(&value["call_id"]),
+      is_disconnected: FromTd::from_td(&value["is_disconnected"]),
+      duration: FromTd::from_td(&value["duration"]),
+      is_video: FromTd::from_td(&value["is_video"]),
+      connection_id: FromTd::from_td(&value["connection_id"]),
+    }
+  }
+}
+
+impl ToTd for pb::Error {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("error"));
+    map.insert("code".to_string(), self.code.to_td());
+    map.insert("message".to_string(), self.message.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::Error {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      code: FromTd::from_td(&value["code"]),
+      message: FromTd::from_td(&value["message"]),
+    }
+  }
+}
+
+impl ToTd for pb::FormattedText {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("formattedText"));
+    map.insert("text".to_string(), self.text.to_td());
+    map.insert("entities".to_string(), self.entities.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::FormattedText {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      text: FromTd::from_td(&value["text"]),
+      entities: FromTd::from_td(&value["entities"]),
+    }
+  }
+}
+
+impl ToTd for pb::GetAuthorizationState {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("getAuthorizationState"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::GetAuthorizationState {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::GetOption {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("getOption"));
+    map.insert("name".to_string(), self.name.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::GetOption {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      name: FromTd::from_td(&value["name"]),
+    }
+  }
+}
+
+impl ToTd for pb::GetTextEntities {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("getTextEntities"));
+    map.insert("text".to_string(), self.text.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::GetTextEntities {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      text: FromTd::from_td(&value["text"]),
+    }
+  }
+}
+
+impl ToTd for pb::KeyboardButton {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("keyboardButton"));
+    map.insert("text".to_string(), self.text.to_td());
+    map.insert("type".to_string(), self.r#type.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::KeyboardButton {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      text: FromTd::from_td(&value["text"]),
+      r#type: FromTd::from_td(&value["type"]),
+    }
+  }
+}
+
+impl ToTd for pb::KeyboardButtonTypeRequestLocation {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("keyboardButtonTypeRequestLocation"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::KeyboardButtonTypeRequestLocation {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::KeyboardButtonTypeText {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("keyboardButtonTypeText"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::KeyboardButtonTypeText {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::MessageCall {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("messageCall"));
+    map.insert("discard_reason".to_string(), self.discard_reason.to_td());
+    map.insert("duration".to_string(), self.duration.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::MessageCall {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      discard_reason: FromTd::from_td(&value["discard_reason"]),
+      duration: FromTd::from_td(&value["duration"]),
+    }
+  }
+}
+
+impl ToTd for pb::MessageInvoice {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("messageInvoice"));
+    map.insert("title".to_string(), self.title.to_td());
+    map.insert("currency".to_string(), self.currency.to_td());
+    map.insert("total_amount".to_string(), self.total_amount.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::MessageInvoice {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      title: FromTd::from_td(&value["title"]),
+      currency: FromTd::from_td(&value["currency"]),
+      total_amount: FromTd::from_td(&value["total_amount"]),
+    }
+  }
+}
+
+impl ToTd for pb::MessageSticker {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("messageSticker"));
+    map.insert("sticker".to_string(), self.sticker.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::MessageSticker {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      sticker: FromTd::from_td(&value["sticker"]),
+    }
+  }
+}
+
+impl ToTd for pb::MessageText {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("messageText"));
+    map.insert("text".to_string(), self.text.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::MessageText {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      text: FromTd::from_td(&value["text"]),
+    }
+  }
+}
+
+impl ToTd for pb::Ok {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("ok"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::Ok {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::OptionValueBoolean {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("optionValueBoolean"));
+    map.insert("value".to_string(), self.value.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::OptionValueBoolean {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      value: FromTd::from_td(&value["value"]),
+    }
+  }
+}
+
+impl ToTd for pb::OptionValueEmpty {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("optionValueEmpty"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::OptionValueEmpty {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::ReplyMarkupRemoveKeyboard {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("replyMarkupRemoveKeyboard"));
+    map.insert("is_personal".to_string(), self.is_personal.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::ReplyMarkupRemoveKeyboard {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      is_personal: FromTd::from_td(&value["is_personal"]),
+    }
+  }
+}
+
+impl ToTd for pb::ReplyMarkupShowKeyboard {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("replyMarkupShowKeyboard"));
+    map.insert("rows".to_string(), self.rows.to_td());
+    map.insert("resize_keyboard".to_string(), self.resize_keyboard.to_td());
+    map.insert("one_time".to_string(), self.one_time.to_td());
+    map.insert("is_personal".to_string(), self.is_personal.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::ReplyMarkupShowKeyboard {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      rows: FromTd::from_td(&value["rows"]),
+      resize_keyboard: FromTd::from_td(&value["resize_keyboard"]),
+      one_time: FromTd::from_td(&value["one_time"]),
+      is_personal: FromTd::from_td(&value["is_personal"]),
+    }
+  }
+}
+
+impl ToTd for pb::RichTextBold {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("richTextBold"));
+    map.insert("text".to_string(), self.text.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::RichTextBold {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      text: FromTd::from_td(&value["text"]),
+    }
+  }
+}
+
+impl ToTd for pb::RichTextPlain {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("richTextPlain"));
+    map.insert("text".to_string(), self.text.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::RichTextPlain {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      text: FromTd::from_td(&value["text"]),
+    }
+  }
+}
+
+impl ToTd for pb::SetTdlibParameters {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("setTdlibParameters"));
+    map.insert("parameters".to_string(), self.parameters.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::SetTdlibParameters {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      parameters: FromTd::from_td(&value["parameters"]),
+    }
+  }
+}
+
+impl ToTd for pb::Sticker {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("sticker"));
+    map.insert("set_id".to_string(), self.set_id.to_td());
+    map.insert("emoji".to_string(), self.emoji.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::Sticker {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      set_id: FromTd::from_td(&value["set_id"]),
+      emoji: FromTd::from_td(&value["emoji"]),
+    }
+  }
+}
+
+impl ToTd for pb::TdlibParameters {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("tdlibParameters"));
+    map.insert("use_test_dc".to_string(), self.use_test_dc.to_td());
+    map.insert("database_directory".to_string(), self.database_directory.to_td());
+    map.insert("files_directory".to_string(), self.files_directory.to_td());
+    map.insert("use_file_database".to_string(), self.use_file_database.to_td());
+    map.insert("use_chat_info_database".to_string(), self.use_chat_info_database.to_td());
+    map.insert("use_message_database".to_string(), self.use_message_database.to_td());
+    map.insert("use_secret_chats".to_string(), self.use_secret_chats.to_td());
+    map.insert("api_id".to_string(), self.api_id.to_td());
+    map.insert("api_hash".to_string(), self.api_hash.to_td());
+    map.insert("system_language_code".to_string(), self.system_language_code.to_td());
+    map.insert("device_model".to_string(), self.device_model.to_td());
+    map.insert("system_version".to_string(), self.system_version.to_td());
+    map.insert("application_version".to_string(), self.application_version.to_td());
+    map.insert("enable_storage_optimizer".to_string(), self.enable_storage_optimizer.to_td());
+    map.insert("ignore_file_names".to_string(), self.ignore_file_names.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::TdlibParameters {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      use_test_dc: FromTd::from_td(&value["use_test_dc"]),
+      database_directory: FromTd::from_td(&value["database_directory"]),
+      files_directory: FromTd::from_td(&value["files_directory"]),
+      use_file_database: FromTd::from_td(&value["use_file_database"]),
+      use_chat_info_database: FromTd::from_td(&value["use_chat_info_database"]),
+      use_message_database: FromTd::from_td(&value["use_message_database"]),
+      use_secret_chats: FromTd::from_td(&value["use_secret_chats"]),
+      api_id: FromTd::from_td(&value["api_id"]),
+      api_hash: FromTd::from_td(&value["api_hash"]),
+      system_language_code: FromTd::from_td(&value["system_language_code"]),
+      device_model: FromTd::from_td(&value["device_model"]),
+      system_version: FromTd::from_td(&value["system_version"]),
+      application_version: FromTd::from_td(&value["application_version"]),
+      enable_storage_optimizer: FromTd::from_td(&value["enable_storage_optimizer"]),
+      ignore_file_names: FromTd::from_td(&value["ignore_file_names"]),
+    }
+  }
+}
+
+impl ToTd for pb::TextEntities {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("textEntities"));
+    map.insert("entities".to_string(), self.entities.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::TextEntities {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      entities: FromTd::from_td(&value["entities"]),
+    }
+  }
+}
+
+impl ToTd for pb::TextEntity {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("textEntity"));
+    map.insert("offset".to_string(), self.offset.to_td());
+    map.insert("length".to_string(), self.length.to_td());
+    map.insert("type".to_string(), self.r#type.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::TextEntity {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      offset: FromTd::from_td(&value["offset"]),
+      length: FromTd::from_td(&value["length"]),
+      r#type: FromTd::from_td(&value["type"]),
+    }
+  }
+}
+
+impl ToTd for pb::TextEntityTypeBankCardNumber {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("textEntityTypeBankCardNumber"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::TextEntityTypeBankCardNumber {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::TextEntityTypeBold {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("textEntityTypeBold"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::TextEntityTypeBold {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::TextEntityTypeUrl {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("textEntityTypeUrl"));
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::TextEntityTypeUrl {
+  fn from_td(_: &Value) -> Self {
+    Self {
+    }
+  }
+}
+
+impl ToTd for pb::UpdateAuthorizationState {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("updateAuthorizationState"));
+    map.insert("authorization_state".to_string(), self.authorization_state.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::UpdateAuthorizationState {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      authorization_state: FromTd::from_td(&value["authorization_state"]),
+    }
+  }
+}
+
+impl ToTd for pb::UpdateOption {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("updateOption"));
+    map.insert("name".to_string(), self.name.to_td());
+    map.insert("value".to_string(), self.value.to_td());
+    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::UpdateOption {
+  fn from_td(value: &Value) -> Self {
+    Self {
+      name: FromTd::from_td(&value["name"]),
+      value: FromTd::from_td(&value["value"]),
+    }
+  }
+}
+
+
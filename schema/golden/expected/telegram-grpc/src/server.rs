@@ -0,0 +1,113 @@
+//! The tonic service of the tdlib functions, generated from `proto/tdlib.proto`.
+
+use std::pin::Pin;
+use std::time::Duration;
+
+use telegram_client::api::Api;
+use rtdlib::errors::{RTDError, TdErrorKind};
+use tokio::sync::mpsc;
+use tokio_stream::Stream;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+
+use crate::convert::{FromTd, ToTd};
+use crate::pb;
+
+/// Each rpc sends the request to tdlib by `Api::request_raw` and waits the response, the `Client` of
+/// the api must be started to receive the responses.
+#[derive(Debug, Clone)]
+pub struct TdlibService {
+  api: Api,
+  timeout: Duration,
+}
+
+impl TdlibService {
+  pub fn new(api: Api) -> Self {
+    Self { api, timeout: Duration::from_secs(60) }
+  }
+
+  /// Wait the response of a request at most this time, 60 seconds by default
+  pub fn timeout(mut self, timeout: Duration) -> Self {
+    self.timeout = timeout;
+    self
+  }
+
+  /// The tonic service, e.g. for `tonic::transport::Server::add_service`
+  pub fn into_server(self) -> pb::tdlib_server::TdlibServer<Self> {
+    pb::tdlib_server::TdlibServer::new(self)
+  }
+
+  async fn call<Req: ToTd, Resp: FromTd + Send + 'static>(&self, request: Request<Req>) -> Result<Response<Resp>, Status> {
+    let json = request.into_inner().to_td();
+    let (api, timeout) = (self.api.clone(), self.timeout);
+    let value = tokio::task::spawn_blocking(move || api.request_raw(json)?.wait_timeout(timeout))
+      .await
+      .map_err(|e| Status::internal(e.to_string()))?
+      .map_err(status)?;
+    Ok(Response::new(Resp::from_td(&value)))
+  }
+}
+
+/// the status of an error of a request, a tdlib error is mapped by its kind
+fn status(error: RTDError) -> Status {
+  let e = match error {
+    RTDError::TdlibError(e) => e,
+    other => return Status::internal(other.to_string()),
+  };
+  let message = format!("{}: {}", e.code(), e.message());
+  match e.kind() {
+    TdErrorKind::FloodWait(_) => Status::resource_exhausted(message),
+    TdErrorKind::Unauthorized => Status::unauthenticated(message),
+    TdErrorKind::Forbidden => Status::permission_denied(message),
+    TdErrorKind::ChatNotFound | TdErrorKind::MessageNotFound | TdErrorKind::UserNotFound | TdErrorKind::NotFound => Status::not_found(message),
+    TdErrorKind::Internal | TdErrorKind::Unknown => Status::internal(message),
+    TdErrorKind::Timeout => Status::deadline_exceeded(message),
+    _ => Status::invalid_argument(message),
+  }
+}
+
+#[tonic::async_trait]
+impl pb::tdlib_server::Tdlib for TdlibService {
+  async fn discard_call(&self, request: Request<pb::DiscardCall>) -> Result<Response<pb::Ok>, Status> {
+    self.call(request).await
+  }
+
+  async fn get_authorization_state(&self, request: Request<pb::GetAuthorizationState>) -> Result<Response<pb::AuthorizationState>, Status> {
+    self.call(request).await
+  }
+
+  async fn get_option(&self, request: Request<pb::GetOption>) -> Result<Response<pb::OptionValue>, Status> {
+    self.call(request).await
+  }
+
+  async fn get_text_entities(&self, request: Request<pb::GetTextEntities>) -> Result<Response<pb::TextEntities>, Status> {
+    self.call(request).await
+  }
+
+  async fn set_tdlib_parameters(&self, request: Request<pb::SetTdlibParameters>) -> Result<Response<pb::Ok>, Status> {
+    self.call(request).await
+  }
+
+  type ReceiveUpdatesStream = Pin<Box<dyn Stream<Item = Result<pb::Update, Status>> + Send>>;
+
+  async fn receive_updates(&self, request: Request<pb::UpdatesRequest>) -> Result<Response<Self::ReceiveUpdatesStream>, Status> {
+    let td_types = request.into_inner().td_types;
+    let subscription = if td_types.is_empty() { self.api.subscribe_all() } else { self.api.subscribe(&td_types[..]) };
+    let (sender, receiver) = mpsc::channel(128);
+    std::thread::spawn(move || {
+      while let Some(json) = subscription.recv_json() {
+        let update = match serde_json::from_str::<serde_json::Value>(&json) {
+          Ok(value) => pb::Update::from_td(&value),
+          Err(e) => {
+            warn!("Can not parse the update: {}", e);
+            continue;
+          }
+        };
+        // the stream is dropped when the client disconnected
+        if sender.blocking_send(Ok(update)).is_err() { break; }
+      }
+    });
+    Ok(Response::new(Box::pin(ReceiverStream::new(receiver))))
+  }
+}
+
@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate serde_derive;
+
+pub mod types;
+pub mod errors;
+#[cfg(feature = "botapi")]
+pub mod botapi;
+#[cfg(feature = "test-gen")]
+pub mod arbitrary;
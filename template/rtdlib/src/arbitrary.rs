@@ -0,0 +1,145 @@
+//! Arbitrary td objects for property tests, `test-gen` feature.
+//!
+//! Every td type implements `TdArbitrary`, `td_strategy` is a proptest strategy of a td type and
+//! `check_round_trip` checks its json serialization; the crate tests it for every type.
+
+use std::fmt::Debug;
+
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestCaseError, TestRunner};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::types::TdBytes;
+
+/// the depth of the td objects nested in an arbitrary td object
+pub const DEPTH: u32 = 3;
+
+/// Random numbers of an arbitrary td object, seeded by proptest
+#[derive(Debug, Clone)]
+pub struct TdRng {
+  state: u64,
+}
+
+impl TdRng {
+  pub fn new(seed: u64) -> Self {
+    Self { state: seed }
+  }
+
+  /// splitmix64
+  pub fn next_u64(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = self.state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+  }
+
+  /// a number from 0 to `bound` excluded
+  pub fn below(&mut self, bound: usize) -> usize {
+    if bound == 0 { 0 } else { (self.next_u64() % bound as u64) as usize }
+  }
+
+  pub fn chance(&mut self) -> bool {
+    self.next_u64() & 1 == 1
+  }
+}
+
+/// An arbitrary value of a td type, `depth` is how many td objects can be nested in it; an empty vector,
+/// `None` and the leaf item of a trait end the nesting at depth 0.
+pub trait TdArbitrary: Sized {
+  fn td_arbitrary(rng: &mut TdRng, depth: u32) -> Self;
+}
+
+impl TdArbitrary for bool {
+  fn td_arbitrary(rng: &mut TdRng, _depth: u32) -> Self { rng.chance() }
+}
+
+impl TdArbitrary for i32 {
+  fn td_arbitrary(rng: &mut TdRng, _depth: u32) -> Self { rng.next_u64() as i32 }
+}
+
+impl TdArbitrary for i64 {
+  fn td_arbitrary(rng: &mut TdRng, _depth: u32) -> Self { rng.next_u64() as i64 }
+}
+
+impl TdArbitrary for isize {
+  fn td_arbitrary(rng: &mut TdRng, _depth: u32) -> Self { rng.next_u64() as isize }
+}
+
+impl TdArbitrary for u8 {
+  fn td_arbitrary(rng: &mut TdRng, _depth: u32) -> Self { rng.next_u64() as u8 }
+}
+
+impl TdArbitrary for u16 {
+  fn td_arbitrary(rng: &mut TdRng, _depth: u32) -> Self { rng.next_u64() as u16 }
+}
+
+impl TdArbitrary for f32 {
+  // a json number is read back to the same float only if it's short
+  fn td_arbitrary(rng: &mut TdRng, _depth: u32) -> Self { (rng.next_u64() % 2_000_001) as f32 / 100.0 - 10_000.0 }
+}
+
+impl TdArbitrary for f64 {
+  fn td_arbitrary(rng: &mut TdRng, _depth: u32) -> Self { (rng.next_u64() % 2_000_001) as f64 / 100.0 - 10_000.0 }
+}
+
+impl TdArbitrary for String {
+  fn td_arbitrary(rng: &mut TdRng, _depth: u32) -> Self {
+    const CHARS: &[char] = &['a', 'Z', '0', ' ', '_', '"', '\\', '\n', 'é', 'Я', '中', '😀'];
+    (0..rng.below(12)).map(|_| CHARS[rng.below(CHARS.len())]).collect()
+  }
+}
+
+impl TdArbitrary for TdBytes {
+  fn td_arbitrary(rng: &mut TdRng, _depth: u32) -> Self {
+    (0..rng.below(16)).map(|_| rng.next_u64() as u8).collect::<Vec<u8>>().into()
+  }
+}
+
+impl<T: TdArbitrary> TdArbitrary for Vec<T> {
+  fn td_arbitrary(rng: &mut TdRng, depth: u32) -> Self {
+    if depth == 0 { return vec![]; }
+    (0..rng.below(3)).map(|_| T::td_arbitrary(rng, depth - 1)).collect()
+  }
+}
+
+impl<T: TdArbitrary> TdArbitrary for Option<T> {
+  fn td_arbitrary(rng: &mut TdRng, depth: u32) -> Self {
+    if depth == 0 || !rng.chance() { return None; }
+    Some(T::td_arbitrary(rng, depth - 1))
+  }
+}
+
+impl<T: TdArbitrary> TdArbitrary for Box<T> {
+  fn td_arbitrary(rng: &mut TdRng, depth: u32) -> Self {
+    Box::new(T::td_arbitrary(rng, depth.saturating_sub(1)))
+  }
+}
+
+/// The proptest strategy of a td type, a shrunk value is a value of a smaller seed
+pub fn td_strategy<T: TdArbitrary + Debug>() -> BoxedStrategy<T> {
+  any::<u64>().prop_map(|seed| T::td_arbitrary(&mut TdRng::new(seed), DEPTH)).boxed()
+}
+
+/// Check that arbitrary values of a td type are the same after serialized to json and deserialized, panic if not
+pub fn check_round_trip<T>(cases: u32)
+  where T: TdArbitrary + Serialize + DeserializeOwned + PartialEq + Debug {
+  check_seeds(cases, &|seed| {
+    let value = T::td_arbitrary(&mut TdRng::new(seed), DEPTH);
+    let json = serde_json::to_string(&value).map_err(|e| e.to_string())?;
+    let read: T = serde_json::from_str(&json).map_err(|e| format!("{}: {}", e, json))?;
+    if value != read {
+      return Err(format!("read {:?} from {}, expected {:?}", read, json, value));
+    }
+    Ok(())
+  });
+}
+
+/// run the check by the seeds of proptest, not generic over the td types, compiled once
+fn check_seeds(cases: u32, check: &dyn Fn(u64) -> Result<(), String>) {
+  let mut runner = TestRunner::new(Config { cases, failure_persistence: None, ..Config::default() });
+  if let Err(e) = runner.run(&any::<u64>(), |seed| check(seed).map_err(TestCaseError::fail)) {
+    panic!("{}", e);
+  }
+}
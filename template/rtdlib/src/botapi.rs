@@ -0,0 +1,228 @@
+//! Bot API shaped types, serialized like the objects of the Bot API, e.g. to pass tdlib data to teloxide or frankenstein by json.
+//!
+//! tdlib chat ids are the chat ids of the Bot API, tdlib message ids of the server messages are Bot API message ids shifted by 20 bits.
+
+use crate::types::{self as td, ChatIdKind, RObject};
+
+/// Bot API message id of a tdlib message id, `None` if it isn't a server message
+pub fn message_id(td_message_id: i64) -> Option<i64> {
+  if td_message_id & ((1 << 20) - 1) == 0 { Some(td_message_id >> 20) } else { None }
+}
+
+/// tdlib message id of a Bot API message id
+pub fn td_message_id(message_id: i64) -> i64 {
+  message_id << 20
+}
+
+/// [User](https://core.telegram.org/bots/api#user)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct User {
+  pub id: i64,
+  pub is_bot: bool,
+  pub first_name: String,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub last_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub username: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub language_code: Option<String>,
+}
+
+/// [Chat](https://core.telegram.org/bots/api#chat), tdlib chats only have a title, it's the first name of a private chat
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Chat {
+  pub id: i64,
+  /// `private`, `group`, `supergroup` or `channel`
+  #[serde(rename = "type")]
+  pub type_: String,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub title: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub first_name: Option<String>,
+}
+
+/// [MessageEntity](https://core.telegram.org/bots/api#messageentity), offset and length are in UTF-16 code units in both apis
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MessageEntity {
+  #[serde(rename = "type")]
+  pub type_: String,
+  pub offset: i64,
+  pub length: i64,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub url: Option<String>,
+  /// only the id of the user is known by a tdlib entity
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub user: Option<User>,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub language: Option<String>,
+}
+
+/// The text and entities of a Bot API message
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FormattedText {
+  pub text: String,
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub entities: Vec<MessageEntity>,
+}
+
+/// [Message](https://core.telegram.org/bots/api#message) with the sender, the chat and the text or caption
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+  pub message_id: i64,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub from: Option<User>,
+  pub chat: Chat,
+  pub date: i64,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub edit_date: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub reply_to_message_id: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub text: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub entities: Vec<MessageEntity>,
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub caption: Option<String>,
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub caption_entities: Vec<MessageEntity>,
+}
+
+fn non_empty(text: &str) -> Option<String> {
+  if text.is_empty() { None } else { Some(text.to_string()) }
+}
+
+impl From<&td::User> for User {
+  fn from(user: &td::User) -> Self {
+    Self {
+      id: user.id(),
+      is_bot: user.type_().is_bot(),
+      first_name: user.first_name().clone(),
+      last_name: non_empty(user.last_name()),
+      username: non_empty(user.username()),
+      language_code: non_empty(user.language_code()),
+    }
+  }
+}
+
+impl From<&td::Chat> for Chat {
+  fn from(chat: &td::Chat) -> Self {
+    let type_ = match chat.type_() {
+      td::ChatType::BasicGroup(_) => "group",
+      td::ChatType::Supergroup(t) if t.is_channel() => "channel",
+      td::ChatType::Supergroup(_) => "supergroup",
+      _ => "private",
+    };
+    let (title, first_name) = if type_ == "private" { (None, Some(chat.title().clone())) } else { (Some(chat.title().clone()), None) };
+    Self { id: chat.id(), type_: type_.to_string(), title, first_name }
+  }
+}
+
+impl From<&td::TextEntity> for MessageEntity {
+  fn from(entity: &td::TextEntity) -> Self {
+    let mut ret = Self { offset: entity.offset(), length: entity.length(), ..Default::default() };
+    ret.type_ = match entity.type_() {
+      td::TextEntityType::Mention(_) => "mention",
+      td::TextEntityType::Hashtag(_) => "hashtag",
+      td::TextEntityType::Cashtag(_) => "cashtag",
+      td::TextEntityType::BotCommand(_) => "bot_command",
+      td::TextEntityType::Url(_) => "url",
+      td::TextEntityType::EmailAddress(_) => "email",
+      td::TextEntityType::PhoneNumber(_) => "phone_number",
+      td::TextEntityType::Bold(_) => "bold",
+      td::TextEntityType::Italic(_) => "italic",
+      td::TextEntityType::Underline(_) => "underline",
+      td::TextEntityType::Strikethrough(_) => "strikethrough",
+      td::TextEntityType::Code(_) => "code",
+      td::TextEntityType::Pre(_) => "pre",
+      td::TextEntityType::PreCode(t) => {
+        ret.language = Some(t.language().clone());
+        "pre"
+      }
+      td::TextEntityType::TextUrl(t) => {
+        ret.url = Some(t.url().clone());
+        "text_link"
+      }
+      td::TextEntityType::MentionName(t) => {
+        ret.user = Some(User { id: t.user_id(), ..Default::default() });
+        "text_mention"
+      }
+      other => other.td_name(),
+    }.to_string();
+    ret
+  }
+}
+
+impl From<&MessageEntity> for td::TextEntity {
+  /// an entity of an unknown type is the default entity type, it's rejected by tdlib
+  fn from(entity: &MessageEntity) -> Self {
+    let type_ = match &entity.type_[..] {
+      "mention" => td::TextEntityType::Mention(td::TextEntityTypeMention::builder().build()),
+      "hashtag" => td::TextEntityType::Hashtag(td::TextEntityTypeHashtag::builder().build()),
+      "cashtag" => td::TextEntityType::Cashtag(td::TextEntityTypeCashtag::builder().build()),
+      "bot_command" => td::TextEntityType::BotCommand(td::TextEntityTypeBotCommand::builder().build()),
+      "url" => td::TextEntityType::Url(td::TextEntityTypeUrl::builder().build()),
+      "email" => td::TextEntityType::EmailAddress(td::TextEntityTypeEmailAddress::builder().build()),
+      "phone_number" => td::TextEntityType::PhoneNumber(td::TextEntityTypePhoneNumber::builder().build()),
+      "bold" => td::TextEntityType::Bold(td::TextEntityTypeBold::builder().build()),
+      "italic" => td::TextEntityType::Italic(td::TextEntityTypeItalic::builder().build()),
+      "underline" => td::TextEntityType::Underline(td::TextEntityTypeUnderline::builder().build()),
+      "strikethrough" => td::TextEntityType::Strikethrough(td::TextEntityTypeStrikethrough::builder().build()),
+      "code" => td::TextEntityType::Code(td::TextEntityTypeCode::builder().build()),
+      "pre" => match &entity.language {
+        Some(language) => td::TextEntityType::PreCode(td::TextEntityTypePreCode::builder().language(language).build()),
+        None => td::TextEntityType::Pre(td::TextEntityTypePre::builder().build()),
+      },
+      "text_link" => td::TextEntityType::TextUrl(td::TextEntityTypeTextUrl::builder().url(entity.url.clone().unwrap_or_default()).build()),
+      "text_mention" => td::TextEntityType::MentionName(td::TextEntityTypeMentionName::builder().user_id(entity.user.as_ref().map_or(0, |user| user.id)).build()),
+      _ => td::TextEntityType::default(),
+    };
+    td::TextEntity::builder().offset(entity.offset).length(entity.length).type_(type_).build()
+  }
+}
+
+impl From<&td::FormattedText> for FormattedText {
+  fn from(text: &td::FormattedText) -> Self {
+    Self { text: text.text().clone(), entities: text.entities().iter().map(MessageEntity::from).collect() }
+  }
+}
+
+impl From<&FormattedText> for td::FormattedText {
+  fn from(text: &FormattedText) -> Self {
+    td::FormattedText::builder()
+      .text(&text.text)
+      .entities(text.entities.iter().map(td::TextEntity::from).collect::<Vec<td::TextEntity>>())
+      .build()
+  }
+}
+
+impl Message {
+  /// the Bot API message of a tdlib message in this chat, sent by `from`, `None` if it isn't a server message
+  pub fn from_td(message: &td::Message, chat: &td::Chat, from: Option<&td::User>) -> Option<Self> {
+    let message_id = self::message_id(message.id())?;
+    let mut ret = Self {
+      message_id,
+      from: from.map(User::from),
+      chat: Chat::from(chat),
+      date: message.date(),
+      edit_date: Some(message.edit_date()).filter(|date| *date != 0),
+      reply_to_message_id: Some(message.reply_to_message_id()).filter(|id| *id != 0).and_then(self::message_id),
+      ..Default::default()
+    };
+    let content = message.content();
+    if let Some(text) = content.text() {
+      let text = FormattedText::from(text);
+      ret.text = Some(text.text);
+      ret.entities = text.entities;
+    } else if let Some(caption) = content.caption() {
+      let caption = FormattedText::from(caption);
+      ret.caption = non_empty(&caption.text);
+      ret.caption_entities = caption.entities;
+    }
+    Some(ret)
+  }
+
+  /// the owner of the chat, see `ChatIdKind`
+  pub fn chat_id_kind(&self) -> Option<ChatIdKind> {
+    ChatIdKind::from_chat_id(self.chat.id)
+  }
+}
@@ -1,11 +1,18 @@
 
 use std::{io, fmt, error};
 
+use crate::types::Error;
+
 #[derive(Debug)]
 pub enum RTDError {
   Io(io::Error),
   SerdeJson(serde_json::Error),
+  TdlibError(TdError),
   Custom(&'static str),
+  /// the td type and its non-nullable fields never set
+  MissingFields(&'static str, Vec<&'static str>),
+  /// the client is logged out, no request is answered any more
+  NotAuthorized,
 }
 
 pub type RTDResult<T> = Result<T, RTDError>;
@@ -19,7 +26,10 @@ impl fmt::Display for RTDError {
     match *self {
       RTDError::Io(ref err) => write!(f, "IO error: {}", err),
       RTDError::SerdeJson(ref err) => write!(f, "Serde json error: {}", err),
+      RTDError::TdlibError(ref err) => write!(f, "Tdlib error: {}", err),
       RTDError::Custom(msg) => write!(f, "{}", msg),
+      RTDError::MissingFields(td_name, ref fields) => write!(f, "{} missing required fields: {}", td_name, fields.join(", ")),
+      RTDError::NotAuthorized => write!(f, "Not authorized, the client is logged out"),
     }
   }
 }
@@ -29,7 +39,10 @@ impl error::Error for RTDError {
     match *self {
       RTDError::Io(ref err) => err.description(),
       RTDError::SerdeJson(ref err) => err.description(),
+      RTDError::TdlibError(ref err) => err.message(),
       RTDError::Custom(msg) => msg,
+      RTDError::MissingFields(..) => "missing required fields",
+      RTDError::NotAuthorized => "not authorized",
     }
   }
 
@@ -37,11 +50,20 @@ impl error::Error for RTDError {
     match *self {
       RTDError::Io(ref err) => Some(err),
       RTDError::SerdeJson(ref err) => Some(err),
-      RTDError::Custom(_) => None
+      RTDError::TdlibError(ref err) => Some(err),
+      RTDError::Custom(_) => None,
+      RTDError::MissingFields(..) => None,
+      RTDError::NotAuthorized => None,
     }
   }
 }
 
+impl From<TdError> for RTDError {
+  fn from(err: TdError) -> RTDError {
+    RTDError::TdlibError(err)
+  }
+}
+
 impl From<io::Error> for RTDError {
   fn from(err: io::Error) -> RTDError {
     RTDError::Io(err)
@@ -57,3 +79,122 @@ impl From<serde_json::Error> for RTDError {
 //impl From<str> for
 
 
+/// An error returned by tdlib, the whole `error` object with the known cases parsed into `TdErrorKind`
+#[derive(Debug, Clone)]
+pub struct TdError {
+  error: Error,
+  extra: Option<String>,
+  kind: TdErrorKind,
+}
+
+/// Well-known cases of tdlib errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TdErrorKind {
+  /// Too many requests, retry after this many seconds
+  FloodWait(i64),
+  PhoneNumberInvalid,
+  PhoneCodeInvalid,
+  PhoneCodeExpired,
+  PasswordHashInvalid,
+  ChatNotFound,
+  MessageNotFound,
+  UserNotFound,
+  /// The authorization is required or lost
+  Unauthorized,
+  /// Other error with code 400
+  BadRequest,
+  /// Other error with code 403
+  Forbidden,
+  /// Other error with code 404
+  NotFound,
+  /// Other error with code 500
+  Internal,
+  /// No response received in time, code 408
+  Timeout,
+  Unknown,
+}
+
+impl TdError {
+  pub fn new<S: AsRef<str>>(code: i64, message: S) -> Self {
+    Self::from(Error::builder().code(code).message(message.as_ref()).build())
+  }
+
+  /// The `@extra` of the request which failed
+  pub fn with_extra<S: AsRef<str>>(mut self, extra: S) -> Self {
+    self.extra = Some(extra.as_ref().to_string());
+    self
+  }
+
+  /// The `error` object returned by tdlib
+  pub fn error(&self) -> &Error { &self.error }
+  /// The `@extra` of the request which failed, to correlate the error with the request
+  pub fn extra(&self) -> Option<&String> { self.extra.as_ref() }
+  /// Error code, like the HTTP status codes
+  pub fn code(&self) -> i64 { self.error.code() }
+  /// Error message
+  pub fn message(&self) -> &String { self.error.message() }
+  /// The parsed case of the error
+  pub fn kind(&self) -> TdErrorKind { self.kind }
+  /// Seconds to wait before retrying, if the error is `TdErrorKind::FloodWait`
+  pub fn retry_after(&self) -> Option<i64> {
+    match self.kind {
+      TdErrorKind::FloodWait(seconds) => Some(seconds),
+      _ => None,
+    }
+  }
+}
+
+impl From<Error> for TdError {
+  fn from(error: Error) -> Self {
+    let kind = TdErrorKind::parse(error.code(), error.message());
+    Self { error, extra: None, kind }
+  }
+}
+
+impl PartialEq for TdError {
+  fn eq(&self, other: &Self) -> bool {
+    self.code() == other.code() && self.message() == other.message() && self.extra == other.extra
+  }
+}
+
+impl fmt::Display for TdError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "[{}] {}", self.code(), self.message())
+  }
+}
+
+impl error::Error for TdError {
+  fn description(&self) -> &str { self.message() }
+}
+
+impl TdErrorKind {
+  fn parse(code: i64, message: &str) -> Self {
+    // tdlib reports flood wait as "Too Many Requests: retry after N", the server as "FLOOD_WAIT_N"
+    if let Some(seconds) = message.strip_prefix("Too Many Requests: retry after ")
+      .or_else(|| message.strip_prefix("FLOOD_WAIT_")) {
+      return TdErrorKind::FloodWait(seconds.trim().parse().unwrap_or(0));
+    }
+    match message {
+      "PHONE_NUMBER_INVALID" => return TdErrorKind::PhoneNumberInvalid,
+      "PHONE_CODE_INVALID" => return TdErrorKind::PhoneCodeInvalid,
+      "PHONE_CODE_EXPIRED" => return TdErrorKind::PhoneCodeExpired,
+      "PASSWORD_HASH_INVALID" => return TdErrorKind::PasswordHashInvalid,
+      "Chat not found" | "CHAT_ID_INVALID" => return TdErrorKind::ChatNotFound,
+      "Message not found" => return TdErrorKind::MessageNotFound,
+      "User not found" | "USER_ID_INVALID" => return TdErrorKind::UserNotFound,
+      _ => {}
+    }
+    match code {
+      429 => TdErrorKind::FloodWait(0),
+      401 => TdErrorKind::Unauthorized,
+      400 => TdErrorKind::BadRequest,
+      403 => TdErrorKind::Forbidden,
+      404 => TdErrorKind::NotFound,
+      500 => TdErrorKind::Internal,
+      408 => TdErrorKind::Timeout,
+      _ => TdErrorKind::Unknown,
+    }
+  }
+}
+
+
@@ -6,3 +6,7 @@ pub use rtdlib_sys::Tdlib;
 
 pub mod types;
 pub mod errors;
+#[cfg(feature = "botapi")]
+pub mod botapi;
+#[cfg(feature = "test-gen")]
+pub mod arbitrary;
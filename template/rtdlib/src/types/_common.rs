@@ -4,11 +4,13 @@ use crate::errors::*;
 use crate::types::*;
 
 macro_rules! rtd_enum_deserialize {
-  ($type_name:ident, $(($td_name:ident, $enum_item:ident));*;) => {
+  ($type_name:ident, $($(#[$item_attr:meta])* ($td_name:ident, $enum_item:ident));*; $(gated $(#[$gated_attr:meta])* $gated_name:ident;)*) => {
     // example json
     // {"@type":"authorizationStateWaitEncryptionKey","is_encrypted":false}
     |deserializer: D| -> Result<$type_name, D::Error> {
       let rtd_trait_value: serde_json::Value = Deserialize::deserialize(deserializer)?;
+      // the default item is serialized to null
+      if rtd_trait_value.is_null() { return Ok($type_name::_Default(())); }
       // the `rtd_trait_value` variable type is &serde_json::Value, tdlib trait will return a object, convert this type to object `&Map<String, Value>`
       let rtd_trait_map = match rtd_trait_value.as_object() {
         Some(map) => map,
@@ -26,11 +28,17 @@ macro_rules! rtd_enum_deserialize {
 
       let obj = match rtd_trait_type {
         $(
+          $(#[$item_attr])*
           stringify!($td_name) => $type_name::$enum_item(match serde_json::from_value(rtd_trait_value.clone()) {
             Ok(t) => t,
             Err(_e) => return Err(D::Error::unknown_field(stringify!("{} can't deserialize to {}::{}", $td_name, $type_name, $enum_item, _e), &[stringify!("{:?}", _e)]))
           }),
         )*
+        // the item is gated by a disabled cargo feature or removed by `[select]` of fantasy
+        $(
+          $(#[$gated_attr])*
+          stringify!($gated_name) => $type_name::_Default(()),
+        )*
         _ => return Err(D::Error::missing_field(stringify!($field)))
       };
       Ok(obj)
@@ -64,6 +72,150 @@ macro_rules! rtd_enum_deserialize {
 //  };
 //}
 
+/// (de)serialize an int64 field, tdlib sends it as a json string, e.g. `"id":"3193525115240425385"`,
+/// but a json number is accepted too
+pub(crate) mod int64 {
+  use std::fmt::Display;
+  use std::str::FromStr;
+
+  use serde::de::{self, Deserialize, Deserializer};
+  use serde::Serializer;
+
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum NumberOrString {
+    Number(serde_json::Number),
+    String(String),
+  }
+
+  pub(crate) fn parse<T, E>(value: serde_json::Value) -> Result<T, E> where T: FromStr, T::Err: Display, E: de::Error {
+    let text = match serde_json::from_value(value).map_err(E::custom)? {
+      NumberOrString::Number(number) => number.to_string(),
+      NumberOrString::String(text) => text,
+    };
+    text.parse::<T>().map_err(E::custom)
+  }
+
+  pub fn serialize<T: Display, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+  }
+
+  pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where T: FromStr, T::Err: Display, D: Deserializer<'de> {
+    parse(serde_json::Value::deserialize(deserializer)?)
+  }
+}
+
+/// (de)serialize a vector<int64> field of any depth, e.g. `vector<vector<int64>>`, see `int64`; unused if the
+/// features gate off all types of such a field
+#[allow(dead_code)]
+pub(crate) mod vec_int64 {
+  use serde::de::{self, Deserialize, Deserializer};
+  use serde::ser::{SerializeSeq, Serializer};
+  use serde_json::Value;
+
+  /// an int64 or a vector of them
+  pub trait Int64Json: Sized {
+    fn to_json(&self) -> Value;
+    fn from_json<E: de::Error>(value: Value) -> Result<Self, E>;
+  }
+
+  macro_rules! int64_json {
+    ($($int:ty),*) => {$(
+      impl Int64Json for $int {
+        fn to_json(&self) -> Value { Value::String(self.to_string()) }
+        fn from_json<E: de::Error>(value: Value) -> Result<Self, E> { super::int64::parse(value) }
+      }
+    )*};
+  }
+
+  int64_json!(i64, isize);
+
+  impl<T: Int64Json> Int64Json for Vec<T> {
+    fn to_json(&self) -> Value { Value::Array(self.iter().map(Int64Json::to_json).collect()) }
+    fn from_json<E: de::Error>(value: Value) -> Result<Self, E> {
+      match value {
+        Value::Array(values) => values.into_iter().map(T::from_json).collect(),
+        value => Err(E::custom(format!("expected an array of int64, found {}", value))),
+      }
+    }
+  }
+
+  pub fn serialize<T: Int64Json, S: Serializer>(values: &[T], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(values.len()))?;
+    for value in values {
+      seq.serialize_element(&value.to_json())?;
+    }
+    seq.end()
+  }
+
+  pub fn deserialize<'de, T: Int64Json, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<T>, D::Error> {
+    Vec::<Value>::deserialize(deserializer)?
+      .into_iter()
+      .map(T::from_json)
+      .collect()
+  }
+}
+
+/// bytes of tdlib, sent in json as a base64 string
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TdBytes(Vec<u8>);
+
+impl TdBytes {
+  pub fn new(bytes: Vec<u8>) -> Self { TdBytes(bytes) }
+
+  pub fn as_slice(&self) -> &[u8] { &self.0 }
+
+  pub fn into_vec(self) -> Vec<u8> { self.0 }
+
+  /// base64 of the bytes, as sent to tdlib
+  pub fn to_base64(&self) -> String { base64::encode(&self.0) }
+
+  pub fn from_base64<S: AsRef<str>>(text: S) -> RTDResult<Self> {
+    base64::decode(text.as_ref())
+      .map(TdBytes)
+      .map_err(|_| RTDError::custom("Bytes are not base64 encoded"))
+  }
+}
+
+impl std::ops::Deref for TdBytes {
+  type Target = [u8];
+  fn deref(&self) -> &[u8] { &self.0 }
+}
+
+impl From<Vec<u8>> for TdBytes {
+  fn from(bytes: Vec<u8>) -> Self { TdBytes(bytes) }
+}
+
+impl From<&[u8]> for TdBytes {
+  fn from(bytes: &[u8]) -> Self { TdBytes(bytes.to_vec()) }
+}
+
+impl From<TdBytes> for Vec<u8> {
+  fn from(bytes: TdBytes) -> Self { bytes.0 }
+}
+
+impl AsRef<[u8]> for TdBytes {
+  fn as_ref(&self) -> &[u8] { &self.0 }
+}
+
+impl AsRef<TdBytes> for TdBytes {
+  fn as_ref(&self) -> &TdBytes { self }
+}
+
+impl serde::Serialize for TdBytes {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&self.to_base64())
+  }
+}
+
+impl<'de> serde::Deserialize<'de> for TdBytes {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    base64::decode(&text).map(TdBytes).map_err(serde::de::Error::custom)
+  }
+}
+
 pub fn detect_td_type<S: AsRef<str>>(json: S) -> Option<String> {
   let result: Result<serde_json::Value, serde_json::Error> = serde_json::from_str::<serde_json::Value>(json.as_ref());
   if let Err(_) = result { return None }
@@ -85,6 +237,8 @@ pub trait RObject: Debug {
   fn td_name(&self) -> &'static str;
   /// Return td type to json string
   fn to_json(&self) -> RTDResult<String>;
+  /// The non-nullable object fields never set, tdlib rejects a request with them
+  fn missing_fields(&self) -> Vec<&'static str> { vec![] }
 }
 
 pub trait RFunction: Debug + RObject {}
@@ -93,19 +247,23 @@ pub trait RFunction: Debug + RObject {}
 impl<'a, RObj: RObject> RObject for &'a RObj {
   fn td_name(&self) -> &'static str { (*self).td_name() }
   fn to_json(&self) -> RTDResult<String> { (*self).to_json() }
+  fn missing_fields(&self) -> Vec<&'static str> { (*self).missing_fields() }
 }
 
 impl<'a, RObj: RObject> RObject for &'a mut RObj {
   fn td_name(&self) -> &'static str { (**self).td_name() }
   fn to_json(&self) -> RTDResult<String> { (**self).to_json() }
+  fn missing_fields(&self) -> Vec<&'static str> { (**self).missing_fields() }
 }
 
 
 impl<'a, Fnc: RFunction> RFunction for &'a Fnc {}
 impl<'a, Fnc: RFunction> RFunction for &'a mut Fnc {}
 
-{% for token in tokens %}{% if token.type_ == 'Trait' %}
+{% for token in tokens %}{% if token.type_ == 'Trait' %}{% set cfg = td_cfg(name=token.name) %}
+{{cfg}}
 impl<'a, {{token.name | upper}}: TD{{token.name | to_camel}}> TD{{token.name | to_camel}} for &'a {{token.name | upper}} {}
+{{cfg}}
 impl<'a, {{token.name | upper}}: TD{{token.name | to_camel}}> TD{{token.name | to_camel}} for &'a mut {{token.name | upper}} {}
 {% endif %}{% endfor %}
 
@@ -0,0 +1,12 @@
+//! json round trip of arbitrary values of every td type, `cargo test --features test-gen`
+
+use crate::arbitrary::check_round_trip;
+use crate::types::*;
+
+/// cases of every td type
+const CASES: u32 = 32;
+{% for token in tokens %}{% if token.name != "vector" and token.name != "double" and token.name != "string" and token.name != "int32" and token.name != "int53" and token.name != "int64" and token.name != "bytes" and token.name != "boolFalse" and token.name != "boolTrue" %}
+{{td_cfg(name=token.name)}}
+#[test]
+fn {{token.name | to_snake | td_safe_field}}() { check_round_trip::<{{token.name | to_camel}}>(CASES); }
+{% endif %}{% endfor %}
@@ -1,14 +1,27 @@
+// the generated code uses all td types, deprecated ones too
+#![allow(deprecated)]
+
 
 pub use self::_common::{
   RObject,
   RFunction,
+  TdBytes,
   detect_td_type,
   from_json,
 };
 
 #[macro_use] mod _common;
+#[cfg(all(test, feature = "test-gen"))]
+mod _roundtrip;
+
+/// TDLib version of the schema these types are generated from
+pub const TDLIB_VERSION: &str = "{{tdlib_version}}";
+/// Commit of the schema these types are generated from, empty if unknown
+pub const TDLIB_SCHEMA_COMMIT: &str = "{{schema_commit}}";
+/// Url the schema is downloaded from by `fantasy fetch`, empty if not fetched
+pub const TDLIB_SCHEMA_SOURCE: &str = "{{schema_source}}";
 
-{% for key, value in file_obj_map %}pub use self::{{key}}::*;
+{% for key, value in file_obj_map %}{{td_cfg(file=key)}} #[doc(hidden)] pub use self::_{{key}}::*;
 {% endfor %}
 
 {#
@@ -20,5 +33,20 @@ pub use self::_common::{
 //{% endfor %}
 #}
 
-{% for key, value in file_obj_map %}mod {{key}};
+{% for key, value in file_obj_map %}{{td_cfg(file=key)}} #[path = "{{key}}.rs"] mod _{{key}};
+{% endfor %}
+
+/// The most used types, `use rtdlib::types::prelude::*;`
+pub mod prelude {
+  pub use super::{
+    RObject,
+    RFunction,
+{% for name in prelude %}    {{name}},
+{% endfor %}  };
+}
+{% for domain, value in domain_map %}
+/// {{domain}} types
+pub mod {{domain}} {
+{% for token in value %}{% set parts = has_parts(name=token.name) %}  {{td_cfg(name=token.name)}} pub use super::{% raw %}{{% endraw %}{{token.name | to_camel}}, {% if token.type_ == "Trait" %}TD{{token.name | to_camel}}{% else %}RTD{{token.name | to_camel}}Builder{% endif %}{% if parts %}, {{token.name | to_camel}}Parts{% endif %}{% raw %}}{% endraw %};
+{% endfor %}}
 {% endfor %}
@@ -10,6 +10,12 @@ use serde::de::{Deserialize, Deserializer};
 {% endif %}
 
 {% include "rtdlib/src/types/td_type_trait.rs" %}
+{% if token.name == "MessageContent" %}{% include "rtdlib/src/types/td_type_message_content.rs" %}{% endif %}
+{% if token.name == "ChatType" %}{% include "rtdlib/src/types/td_type_chat_type.rs" %}{% endif %}
 {% else %}
 {% include "rtdlib/src/types/td_type_struct.rs" %}
+{% if token.name == "tdlibParameters" %}{% include "rtdlib/src/types/td_type_tdlib_parameters.rs" %}{% endif %}{% if token.name == "setTdlibParameters" %}{% set nested_parameters = has_token(token_name="tdlibParameters") %}{% if not nested_parameters %}{% include "rtdlib/src/types/td_type_tdlib_parameters.rs" %}{% endif %}{% endif %}
 {% endif %}
+
+{% include "rtdlib/src/types/td_type_arbitrary.rs" %}
+{% include "rtdlib/src/types/td_type_display.rs" %}
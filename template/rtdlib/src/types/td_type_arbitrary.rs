@@ -0,0 +1,27 @@
+{% if token.type_ == "Trait" %}{% set trait_name = token.name | to_camel %}{% set cfg = td_cfg(name=token.name) %}{% set leaf = td_leaf_variant(name=token.name) %}
+{{cfg}}
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for {{trait_name}} {
+  fn td_arbitrary(rng: &mut crate::arbitrary::TdRng, depth: u32) -> Self {
+    use crate::arbitrary::{TdArbitrary, TdRng};
+{% for subt in sub_tokens(token=token) %}{% if subt.name == leaf %}    if depth == 0 { return {{trait_name}}::{{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}}(TdArbitrary::td_arbitrary(rng, 0)); }
+{% endif %}{% endfor %}    #[allow(unused_mut)]
+    let mut variants: Vec<fn(&mut TdRng, u32) -> Self> = vec![];
+{% for subt in sub_tokens(token=token) %}    {{td_cfg(name=subt.name)}} variants.push(|rng, depth| {{trait_name}}::{{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}}(TdArbitrary::td_arbitrary(rng, depth)));
+{% endfor %}    if variants.is_empty() { return {{trait_name}}::_Default(()); }
+    let variant = variants[rng.below(variants.len())];
+    variant(rng, depth.saturating_sub(1))
+  }
+}
+{% else %}{% set struct_name = token.name | to_camel %}{% set cfg = td_cfg(name=token.name) %}
+{{cfg}}
+#[cfg(feature = "test-gen")]
+impl crate::arbitrary::TdArbitrary for {{struct_name}} {
+  fn td_arbitrary({% if token.arguments %}rng{% else %}_rng{% endif %}: &mut crate::arbitrary::TdRng, {% if token.arguments %}depth{% else %}_depth{% endif %}: u32) -> Self {
+    {{struct_name}} {
+      td_name: "{{token.name}}".to_string(),
+{% for field in token.arguments %}      {{field.sign_name | td_safe_field}}: crate::arbitrary::TdArbitrary::td_arbitrary(rng, depth),
+{% endfor %}    }
+  }
+}
+{% endif %}
@@ -0,0 +1,75 @@
+
+impl ChatType {
+  /// is a channel, not a supergroup
+  pub fn is_channel(&self) -> bool { if let ChatType::Supergroup(t) = self { t.is_channel() } else { false } }
+  /// the user of a private or secret chat
+  pub fn user_id(&self) -> Option<i64> {
+    match self {
+      ChatType::Private(t) => Some(t.user_id()),
+      ChatType::Secret(t) => Some(t.user_id()),
+      _ => None,
+    }
+  }
+  pub fn basic_group_id(&self) -> Option<i64> { if let ChatType::BasicGroup(t) = self { Some(t.basic_group_id()) } else { None } }
+  pub fn supergroup_id(&self) -> Option<i64> { if let ChatType::Supergroup(t) = self { Some(t.supergroup_id()) } else { None } }
+  pub fn secret_chat_id(&self) -> Option<i64> { if let ChatType::Secret(t) = self { Some(t.secret_chat_id()) } else { None } }
+  /// the owner of the chat, its chat id is `ChatIdKind::chat_id`
+  pub fn id_kind(&self) -> Option<ChatIdKind> {
+    match self {
+      ChatType::Private(t) => Some(ChatIdKind::User(t.user_id())),
+      ChatType::BasicGroup(t) => Some(ChatIdKind::BasicGroup(t.basic_group_id())),
+      ChatType::Supergroup(t) => Some(ChatIdKind::Supergroup(t.supergroup_id())),
+      ChatType::Secret(t) => Some(ChatIdKind::SecretChat(t.secret_chat_id())),
+      _ => None,
+    }
+  }
+}
+
+impl Chat {
+  pub fn is_private(&self) -> bool { self.type_().is_private() }
+  pub fn is_basic_group(&self) -> bool { self.type_().is_basic_group() }
+  pub fn is_supergroup(&self) -> bool { self.type_().is_supergroup() }
+  pub fn is_channel(&self) -> bool { self.type_().is_channel() }
+  pub fn is_secret(&self) -> bool { self.type_().is_secret() }
+  pub fn user_id(&self) -> Option<i64> { self.type_().user_id() }
+  pub fn basic_group_id(&self) -> Option<i64> { self.type_().basic_group_id() }
+  pub fn supergroup_id(&self) -> Option<i64> { self.type_().supergroup_id() }
+  pub fn secret_chat_id(&self) -> Option<i64> { self.type_().secret_chat_id() }
+}
+
+/// The owner of a chat id, tdlib chat ids are the chat ids of the bot api: a user id, `-basic_group_id`,
+/// `-100` followed by the supergroup id, secret chats are out of the bot api
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChatIdKind {
+  User(i64),
+  BasicGroup(i64),
+  Supergroup(i64),
+  SecretChat(i64),
+}
+
+impl ChatIdKind {
+  const SUPERGROUP_ZERO: i64 = -1_000_000_000_000;
+  const SECRET_CHAT_ZERO: i64 = -2_000_000_000_000;
+
+  /// the owner of the chat id, `None` if it's invalid
+  pub fn from_chat_id(chat_id: i64) -> Option<Self> {
+    let secret_chat_max = Self::SECRET_CHAT_ZERO + (1 << 31);
+    match chat_id {
+      id if id > 0 => Some(ChatIdKind::User(id)),
+      id if id < 0 && id > Self::SUPERGROUP_ZERO => Some(ChatIdKind::BasicGroup(-id)),
+      id if id < Self::SUPERGROUP_ZERO && id >= secret_chat_max => Some(ChatIdKind::Supergroup(Self::SUPERGROUP_ZERO - id)),
+      id if id < secret_chat_max && id >= Self::SECRET_CHAT_ZERO - (1 << 31) && id != Self::SECRET_CHAT_ZERO => Some(ChatIdKind::SecretChat(id - Self::SECRET_CHAT_ZERO)),
+      _ => None,
+    }
+  }
+
+  /// the tdlib chat id, also the bot api chat id except of secret chats
+  pub fn chat_id(&self) -> i64 {
+    match *self {
+      ChatIdKind::User(id) => id,
+      ChatIdKind::BasicGroup(id) => -id,
+      ChatIdKind::Supergroup(id) => Self::SUPERGROUP_ZERO - id,
+      ChatIdKind::SecretChat(id) => Self::SECRET_CHAT_ZERO + id,
+    }
+  }
+}
@@ -0,0 +1,17 @@
+{% set display_name = token.name | to_camel %}{% set display_cfg = td_cfg(name=token.name) %}{% if token.type_ == "Trait" %}
+{{display_cfg}}
+/// the variant name, e.g. `updateNewMessage`
+impl std::fmt::Display for {{display_name}} {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    if self._is_default() { f.write_str("default") } else { f.write_str(self.td_name()) }
+  }
+}
+{% elif token.name == "error" %}
+{{display_cfg}}
+/// `code: message`
+impl std::fmt::Display for {{display_name}} {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}: {}", self.code, self.message)
+  }
+}
+{% endif %}
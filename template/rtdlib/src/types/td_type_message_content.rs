@@ -0,0 +1,44 @@
+{% set animation = td_cfg(name="messageAnimation") %}{% set audio = td_cfg(name="messageAudio") %}{% set document = td_cfg(name="messageDocument") %}{% set photo = td_cfg(name="messagePhoto") %}{% set sticker = td_cfg(name="messageSticker") %}{% set video = td_cfg(name="messageVideo") %}{% set video_note = td_cfg(name="messageVideoNote") %}{% set voice_note = td_cfg(name="messageVoiceNote") %}
+{{td_cfg(name="MessageContent")}}
+impl MessageContent {
+  /// the text of a text message
+  pub fn text(&self) -> Option<&FormattedText> {
+    match self {
+      MessageContent::MessageText(t) => Some(t.text()),
+      _ => None,
+    }
+  }
+
+  /// the caption of a message with a caption, e.g. a photo or a document
+  pub fn caption(&self) -> Option<&FormattedText> {
+    match self {
+      {{animation}} MessageContent::MessageAnimation(t) => Some(t.caption()),
+      {{audio}} MessageContent::MessageAudio(t) => Some(t.caption()),
+      {{document}} MessageContent::MessageDocument(t) => Some(t.caption()),
+      {{photo}} MessageContent::MessagePhoto(t) => Some(t.caption()),
+      {{video}} MessageContent::MessageVideo(t) => Some(t.caption()),
+      {{voice_note}} MessageContent::MessageVoiceNote(t) => Some(t.caption()),
+      _ => None,
+    }
+  }
+
+  /// the text of a text message or the caption of a message with a caption
+  pub fn text_or_caption(&self) -> Option<&FormattedText> {
+    self.text().or_else(|| self.caption())
+  }
+
+  /// the file of a media message, the largest size of a photo
+  pub fn file(&self) -> Option<&File> {
+    match self {
+      {{animation}} MessageContent::MessageAnimation(t) => Some(t.animation().animation()),
+      {{audio}} MessageContent::MessageAudio(t) => Some(t.audio().audio()),
+      {{document}} MessageContent::MessageDocument(t) => Some(t.document().document()),
+      {{photo}} MessageContent::MessagePhoto(t) => t.photo().sizes().iter().max_by_key(|size| size.width() * size.height()).map(|size| size.photo()),
+      {{sticker}} MessageContent::MessageSticker(t) => Some(t.sticker().sticker()),
+      {{video}} MessageContent::MessageVideo(t) => Some(t.video().video()),
+      {{video_note}} MessageContent::MessageVideoNote(t) => Some(t.video_note().video()),
+      {{voice_note}} MessageContent::MessageVoiceNote(t) => Some(t.voice_note().voice()),
+      _ => None,
+    }
+  }
+}
@@ -1,25 +1,44 @@
-{% set struct_name = token.name | to_camel %}
+{% set struct_name = token.name | to_camel %}{% set cfg = td_cfg(name=token.name) %}{% set parts = has_parts(name=token.name) %}
 /// {{token.description}}
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+///
+/// [{{token.name}}]({{token.name | td_doc_url}}) of the tdlib reference
+{% if token.type_ == 'Function' %}{% if token.arguments %}///
+/// # Arguments
+///
+{% for field in token.arguments %}/// * `{{field.sign_name}}` - {{field.description}}
+{% endfor %}{% endif %}///
+/// Returns [`{{token.blood | to_camel}}`]
+{% endif %}{{cfg}} {{td_deprecated(note=token.deprecated)}}
+#[derive({{td_derive(name=token.name)}})]
 pub struct {{struct_name}} {
   #[doc(hidden)]
   #[serde(rename(serialize = "@type", deserialize = "@type"))]
   td_name: String,
   {% for field in token.arguments %}/// {{field.description}}
-  {% for macro_ in td_macros(arg=field, token=token) %}{{macro_}} {% endfor %}{% if field.sign_name == 'type' %}#[serde(rename(serialize = "type", deserialize = "type"))] {% endif %}{{field.sign_name | td_safe_field}}: {{td_arg(arg=field, token=token)}},
+  {% for macro_ in td_macros(arg=field, token=token) %}{{macro_}} {% endfor %}{% set zero_absent = is_zero_absent(arg=field, token=token) %}{% if zero_absent %}#[serde(default)] {% endif %}{% if field.sign_name == 'type' %}#[serde(rename(serialize = "type", deserialize = "type"))] {% endif %}{{field.sign_name | td_safe_field}}: {{td_arg(arg=field, token=token)}},
   {% endfor %}
 }
 
+{{cfg}}
 impl RObject for {{struct_name}} {
   #[doc(hidden)] fn td_name(&self) -> &'static str { "{{token.name}}" }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+  fn missing_fields(&self) -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut missing = vec![];
+{% for field in token.arguments %}{% set required = is_required(arg=field, token=token) %}{% if required %}    if self.{{field.sign_name | td_safe_field}}._is_default() { missing.push("{{field.sign_name}}"); }
+{% endif %}{% endfor %}    missing
+  }
 }
 {% if token.blood and token.blood | to_snake != token.name | to_snake %}
 {% set blood_token = find_token(token_name=token.blood) %}
-{% if blood_token.type_ == 'Trait' %}impl TD{{token.blood | to_camel}} for {{struct_name}} {}{% endif %}
+{% if blood_token.type_ == 'Trait' %}{{td_cfg(name=token.name, also=token.blood)}}
+impl TD{{token.blood | to_camel}} for {{struct_name}} {}{% endif %}
 {% endif %}
-{% if token.type_ == 'Function' %}impl RFunction for {{struct_name}} {}{% endif %}
+{% if token.type_ == 'Function' %}{{cfg}}
+impl RFunction for {{struct_name}} {}{% endif %}
 
+{{cfg}}
 impl {{struct_name}} {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   pub fn builder() -> RTD{{struct_name}}Builder {
@@ -27,20 +46,53 @@ impl {{struct_name}} {
     inner.td_name = "{{token.name}}".to_string();
     RTD{{struct_name}}Builder { inner }
   }
-{% for field in token.arguments %}{% set field_type = td_arg(arg=field, token=token) %}{% set is_primitive = is_primitive(type_ = field_type) %}
-  pub fn {{field.sign_name | td_safe_field}}(&self) -> {% if not is_primitive %}&{% endif %}{{field_type}} { {% if not is_primitive %}&{% endif %}self.{{field.sign_name | td_safe_field}} }
-{% endfor %}
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { self.td_name.is_empty() }
+  /// a builder of a copy of this object, e.g. to reuse a received object in a request
+  pub fn to_builder(&self) -> RTD{{struct_name}}Builder { RTD{{struct_name}}Builder { inner: self.clone() } }
+  pub fn into_builder(self) -> RTD{{struct_name}}Builder { RTD{{struct_name}}Builder { inner: self } }
+{% for field in token.arguments %}{% set field_type = td_arg(arg=field, token=token) %}{% set is_primitive = is_primitive(type_ = field_type) %}{% set field_name = field.sign_name | td_safe_field %}{% set zero_absent = is_zero_absent(arg=field, token=token) %}
+{% set deprecated = td_deprecated(note=field.deprecated) %}{% if deprecated %}  {{deprecated}}
+{% endif %}{% if zero_absent %}  /// `None` if the value is 0
+  pub fn {{field_name}}(&self) -> Option<{{field_type}}> { if self.{{field_name}} == 0 { None } else { Some(self.{{field_name}}) } }
+{% else %}  pub fn {{field_name}}(&self) -> {% if not is_primitive %}&{% endif %}{{field_type}} { {% if not is_primitive %}&{% endif %}self.{{field_name}} }
+{% endif %}{% if not is_primitive %}  pub fn into_{{field.sign_name}}(self) -> {{field_type}} { self.{{field_name}} }
+{% endif %}{% endfor %}{% if parts %}
+  /// move out all fields
+  pub fn into_parts(self) -> {{struct_name}}Parts {
+    {{struct_name}}Parts {
+{% for field in token.arguments %}{% set field_name = field.sign_name | td_safe_field %}      {{field_name}}: self.{{field_name}},
+{% endfor %}    }
+  }
+{% endif %}
 }
+{% if parts %}
+{{cfg}}
+/// The fields of `{{struct_name}}`, see `{{struct_name}}::into_parts`
+#[derive(Debug, Clone)]{% if non_exhaustive %}
+#[non_exhaustive]{% endif %}
+pub struct {{struct_name}}Parts {
+{% for field in token.arguments %}  /// {{field.description}}
+  pub {{field.sign_name | td_safe_field}}: {{td_arg(arg=field, token=token)}},
+{% endfor %}}
+{% endif %}
 
+{{cfg}}
 #[doc(hidden)]
 pub struct RTD{{struct_name}}Builder {
   inner: {{struct_name}}
 }
 
+{{cfg}}
 impl RTD{{struct_name}}Builder {
   pub fn build(&self) -> {{struct_name}} { self.inner.clone() }
+  /// build it, error with the non-nullable fields never set
+  pub fn try_build(&self) -> RTDResult<{{struct_name}}> {
+    let missing = self.inner.missing_fields();
+    if missing.is_empty() { Ok(self.build()) } else { Err(RTDError::MissingFields(self.inner.td_name(), missing)) }
+  }
 {% for field in token.arguments %}
 {% set builder_field_type=td_arg(arg=field, token=token, builder_arg=true) %} {% set sign_name = field.sign_name | td_safe_field %} {% set is_optional = is_optional(type_=td_arg(arg=field, token=token)) %} {% set is_builder_ref = is_builder_ref(type_ = builder_field_type) %}
+  {{td_deprecated(note=field.deprecated)}}
   pub fn {{sign_name}}{%if is_builder_ref%}<T: AsRef<{% if builder_field_type == 'String' %}str{% else %}{{builder_field_type}}{% endif %}>>{%endif%}(&mut self, {{sign_name}}: {%if is_builder_ref%}T{%else%}{{builder_field_type}}{%endif%}) -> &mut Self {
     self.inner.{{sign_name}} = {% if is_optional %}Some({% endif %}{{sign_name}}{%if is_builder_ref %}.as_ref(){% if builder_field_type == 'String' %}.to_string(){% else %}.clone(){% endif %}{% endif %}{% if is_optional %}){% endif %};
     self
@@ -48,10 +100,12 @@ impl RTD{{struct_name}}Builder {
 {% endfor %}
 }
 
+{{cfg}}
 impl AsRef<{{struct_name}}> for {{struct_name}} {
   fn as_ref(&self) -> &{{struct_name}} { self }
 }
 
+{{cfg}}
 impl AsRef<{{struct_name}}> for RTD{{struct_name}}Builder {
   fn as_ref(&self) -> &{{struct_name}} { &self.inner }
 }
@@ -0,0 +1,258 @@
+
+impl {{token.name | to_camel}} {
+  /// Parameters of the Telegram test environment, the account of a `TestDc` phone number logs in without SMS.
+  ///
+  /// The databases are enabled like an usual client, the other parameters can be changed by the returned builder.
+  pub fn test_dc<S: AsRef<str>, D: AsRef<str>>(api_id: i64, api_hash: S, database_directory: D) -> RTD{{token.name | to_camel}}Builder {
+    let mut builder = {{token.name | to_camel}}::builder();
+    builder
+      .use_test_dc(true)
+      .database_directory(database_directory)
+      .use_file_database(true)
+      .use_chat_info_database(true)
+      .use_message_database(true)
+      .api_id(api_id)
+      .api_hash(api_hash)
+      .system_language_code("en")
+      .device_model("Test")
+      .system_version("Unknown")
+      .application_version(env!("CARGO_PKG_VERSION"));
+    builder
+  }
+
+  /// Parameters of a bot, paired with `ApiBuilder::bot_defaults` of telegram-client: a bot needs no message database,
+  /// the file and chat info databases are enabled. The other parameters can be changed by the returned builder.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use rtdlib::types::*;
+  /// let parameters = {{token.name | to_camel}}::bot_defaults(94575, "a3406de8d171bb422bb6ddf3bbd800e2", "tdlib").build();
+  /// assert!(!parameters.use_test_dc());
+  /// assert!(!parameters.use_message_database());
+  /// assert!(parameters.use_chat_info_database());
+  /// assert!(parameters.use_file_database());
+  /// assert_eq!(parameters.database_directory(), "tdlib");
+  /// assert!(parameters.validate().is_ok());
+  /// ```
+  pub fn bot_defaults<S: AsRef<str>, D: AsRef<str>>(api_id: i64, api_hash: S, database_directory: D) -> RTD{{token.name | to_camel}}Builder {
+    let mut builder = Self::test_dc(api_id, api_hash, database_directory);
+    builder
+      .use_test_dc(false)
+      .use_message_database(false)
+      .device_model("Bot");
+    builder
+  }
+
+  /// Parameters of the environment variables, an usual client with the databases enabled:
+  ///
+  /// - `API_ID`, `API_HASH`: required, see https://my.telegram.org
+  /// - `TD_DATABASE_DIR`: `tdlib` by default, `TD_FILES_DIR`: the database directory by default
+  /// - `TD_USE_TEST_DC`: `true` or `1` for the Telegram test environment
+  /// - `TD_LANGUAGE_CODE`: `en` by default, `TD_DEVICE_MODEL`: `Desktop` by default, `TD_SYSTEM_VERSION`:
+  ///   `Unknown` by default, `TD_APPLICATION_VERSION`: the version of rtdlib by default
+  pub fn from_env() -> RTDResult<RTD{{token.name | to_camel}}Builder> {
+    Self::from_values(|key| {
+      let name = match key {
+        "api_id" => "API_ID",
+        "api_hash" => "API_HASH",
+        "database_directory" => "TD_DATABASE_DIR",
+        "files_directory" => "TD_FILES_DIR",
+        "use_test_dc" => "TD_USE_TEST_DC",
+        "system_language_code" => "TD_LANGUAGE_CODE",
+        "device_model" => "TD_DEVICE_MODEL",
+        "system_version" => "TD_SYSTEM_VERSION",
+        "application_version" => "TD_APPLICATION_VERSION",
+        _ => return None,
+      };
+      std::env::var(name).ok()
+    })
+  }
+
+  /// Parameters of a file, e.g. `tdlib.toml`, the defaults are of `from_env`; the keys are the names of the
+  /// fields, `api_id`, `api_hash`, `database_directory`, `files_directory`, `use_test_dc`, `system_language_code`,
+  /// `device_model`, `system_version` and `application_version`.
+  ///
+  /// Only `key = value` lines of toml are read: a value is a string quoted by `"` with escapes or by `'`, a number
+  /// or a boolean, a `# comment` can follow it. Any other line, e.g. a table or a multi-line string, fails with an
+  /// `InvalidData` error of its line.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use rtdlib::types::*;
+  /// let path = std::env::temp_dir().join(format!("rtdlib-parameters-{}.toml", std::process::id()));
+  /// std::fs::write(&path, "# my.telegram.org\napi_id = 94575 # the app\napi_hash = 'a3406de8d171bb422bb6ddf3bbd800e2'\n\
+  ///   device_model = \"Desktop \\\"#1\\\"\" # quoted\nuse_test_dc = true\n").unwrap();
+  /// let parameters = {{token.name | to_camel}}::from_file(&path).unwrap().build();
+  /// assert_eq!(parameters.api_id(), 94575);
+  /// assert_eq!(parameters.api_hash(), "a3406de8d171bb422bb6ddf3bbd800e2");
+  /// assert_eq!(parameters.device_model(), "Desktop \"#1\"");
+  /// assert!(parameters.use_test_dc());
+  ///
+  /// for text in ["api_id = 94575\napi_hash = \"\"\"a3406de8\nd171bb42\"\"\"\n", "[tdlib]\napi_id = 94575\n", "api_hash = \"a3406de8\" tail\n"] {
+  ///   std::fs::write(&path, text).unwrap();
+  ///   assert!({{token.name | to_camel}}::from_file(&path).is_err(), "{}", text);
+  /// }
+  /// std::fs::remove_file(&path).unwrap();
+  /// ```
+  pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> RTDResult<RTD{{token.name | to_camel}}Builder> {
+    let text = std::fs::read_to_string(path)?;
+    let mut values = std::collections::HashMap::new();
+    for (number, line) in text.lines().enumerate() {
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') { continue; }
+      let parsed = line.split_once('=')
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .filter(|(key, _)| !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+        .and_then(|(key, value)| Self::file_value(value).map(|value| (key, value)));
+      match parsed {
+        Some((key, value)) => { values.insert(key.to_string(), value); }
+        None => return Err(RTDError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData,
+          format!("The line {} of the tdlib parameters file is not `key = value`: {}", number + 1, line)))),
+      }
+    }
+    Self::from_values(|key| values.get(key).cloned())
+  }
+
+  /// the value of a line of `from_file` and its optional comment; `None` if it's another toml value
+  fn file_value(value: &str) -> Option<String> {
+    let mut chars = value.chars();
+    let (parsed, rest) = match chars.next()? {
+      '"' if !value.starts_with("\"\"\"") => {
+        let mut parsed = String::new();
+        loop {
+          match chars.next()? {
+            '"' => break,
+            '\\' => parsed.push(match chars.next()? {
+              '"' => '"',
+              '\\' => '\\',
+              'n' => '\n',
+              't' => '\t',
+              'r' => '\r',
+              'u' => {
+                let hex: String = chars.by_ref().take(4).collect();
+                char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+              }
+              _ => return None,
+            }),
+            c => parsed.push(c),
+          }
+        }
+        (parsed, chars.as_str())
+      }
+      '\'' if !value.starts_with("'''") => {
+        let end = value[1..].find('\'')? + 1;
+        (value[1..end].to_string(), &value[end + 1..])
+      }
+      '"' | '\'' => return None,
+      _ => {
+        let end = value.find('#').unwrap_or(value.len());
+        let bare = value[..end].trim();
+        // a number or a boolean
+        if !bare.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '+' || c == '.') { return None; }
+        (bare.to_string(), &value[end..])
+      }
+    };
+    let rest = rest.trim();
+    if rest.is_empty() || rest.starts_with('#') { Some(parsed) } else { None }
+  }
+
+  /// the parameters of `from_env` and `from_file`, the value of a field by its name
+  fn from_values<F: Fn(&str) -> Option<String>>(value: F) -> RTDResult<RTD{{token.name | to_camel}}Builder> {
+    let api_id = match value("api_id") {
+      Some(api_id) => api_id.trim().parse::<i64>().map_err(|_| RTDError::custom("The api_id of the tdlib parameters is not a number"))?,
+      None => return Err(RTDError::custom("The api_id of the tdlib parameters is not set, API_ID")),
+    };
+    let api_hash = match value("api_hash") {
+      Some(api_hash) => api_hash,
+      None => return Err(RTDError::custom("The api_hash of the tdlib parameters is not set, API_HASH")),
+    };
+    let text = |key: &str, default: &str| value(key).unwrap_or_else(|| default.to_string());
+    let database_directory = text("database_directory", "tdlib");
+    let mut builder = {{token.name | to_camel}}::builder();
+    builder
+      .use_test_dc(value("use_test_dc").is_some_and(|flag| flag == "true" || flag == "1"))
+      .files_directory(text("files_directory", &database_directory))
+      .database_directory(database_directory)
+      .use_file_database(true)
+      .use_chat_info_database(true)
+      .use_message_database(true)
+      .api_id(api_id)
+      .api_hash(api_hash)
+      .system_language_code(text("system_language_code", "en"))
+      .device_model(text("device_model", "Desktop"))
+      .system_version(text("system_version", "Unknown"))
+      .application_version(text("application_version", env!("CARGO_PKG_VERSION")))
+      .enable_storage_optimizer(true);
+    Ok(builder)
+  }
+
+  /// Check the parameters before they are sent, tdlib returns these errors later and less clear: the `api_id` and
+  /// `api_hash` are set, the database directory is a writable directory or can be created, and a database is
+  /// enabled with the databases it needs, the message database needs the chat info database and that one the
+  /// file database.
+  pub fn validate(&self) -> RTDResult<()> {
+    if self.api_id == 0 {
+      return Err(RTDError::custom("The api_id of the tdlib parameters is not set"));
+    }
+    if self.api_hash.is_empty() {
+      return Err(RTDError::custom("The api_hash of the tdlib parameters is not set"));
+    }
+    if self.use_message_database && !self.use_chat_info_database {
+      return Err(RTDError::custom("The message database of the tdlib parameters needs use_chat_info_database"));
+    }
+    if self.use_chat_info_database && !self.use_file_database {
+      return Err(RTDError::custom("The chat info database of the tdlib parameters needs use_file_database"));
+    }
+    let directory = if self.database_directory.is_empty() { "." } else { &self.database_directory[..] };
+    // the directory or its nearest parent, tdlib creates the missing directories
+    let existing = std::path::Path::new(directory).ancestors()
+      .find(|path| path.exists())
+      .unwrap_or_else(|| std::path::Path::new("."));
+    match existing.metadata() {
+      Ok(metadata) if metadata.is_dir() && !metadata.permissions().readonly() => Ok(()),
+      Ok(metadata) if metadata.is_dir() => Err(RTDError::custom("The database directory of the tdlib parameters is read only")),
+      Ok(_) => Err(RTDError::custom("The database directory of the tdlib parameters is not a directory")),
+      Err(e) => Err(RTDError::Io(e)),
+    }
+  }
+}
+
+impl RTD{{token.name | to_camel}}Builder {
+  /// build it, error with the parameters tdlib would refuse, see `{{token.name | to_camel}}::validate`
+  pub fn build_valid(&self) -> RTDResult<{{token.name | to_camel}}> {
+    let parameters = self.try_build()?;
+    parameters.validate()?;
+    Ok(parameters)
+  }
+}
+
+/// An account of the Telegram test environment, see `{{token.name | to_camel}}::test_dc`.
+///
+/// The phone number is `99966XYYYY`, X is the datacenter from 1 to 3 and YYYY are random digits; no SMS is sent,
+/// the code is X repeated 5 times. Anyone can log in to these accounts, don't store anything private.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TestDc {
+  dc_id: u8,
+  number: u16,
+}
+
+impl TestDc {
+  /// The account `number` (0 to 9999) of the datacenter `dc_id` (1 to 3)
+  pub fn new(dc_id: u8, number: u16) -> Self {
+    Self { dc_id: dc_id.clamp(1, 3), number: number % 10000 }
+  }
+
+  pub fn dc_id(&self) -> u8 { self.dc_id }
+  pub fn number(&self) -> u16 { self.number }
+
+  pub fn phone_number(&self) -> String {
+    format!("99966{}{:04}", self.dc_id, self.number)
+  }
+
+  /// The authentication code of the account
+  pub fn code(&self) -> String {
+    self.dc_id.to_string().repeat(5)
+  }
+}
@@ -1,36 +1,47 @@
-{% set trait_name = token.name | to_camel %}
+{% set trait_name = token.name | to_camel %}{% set cfg = td_cfg(name=token.name) %}
+{{cfg}}
 /// TRAIT | {{token.description}}
 pub trait TD{{trait_name}}: Debug + RObject {}
 
+{{cfg}} {{td_deprecated(note=token.deprecated)}}
 /// {{token.description}}
-#[derive(Debug, Clone, Serialize)]
-#[serde(untagged)]
+///
+/// [{{token.name}}]({{token.name | td_doc_url}}) of the tdlib reference
+#[derive({{td_derive(name=token.name)}})]
+#[serde(untagged)]{% if non_exhaustive %}
+#[non_exhaustive]{% endif %}
 pub enum {{trait_name}} {
   #[doc(hidden)] _Default(()),
-{% for subt in sub_tokens(token=token) %}  /// {{subt.description}}
+{% for subt in sub_tokens(token=token) %}  {{td_cfg(name=subt.name)}} {{td_deprecated(note=subt.deprecated)}}
+  /// {{subt.description}}
   {{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}}({{subt.name | to_camel}}),
 {% endfor %}
 }
 
+{{cfg}}
 impl Default for {{trait_name}} {
   fn default() -> Self { {{trait_name}}::_Default(()) }
 }
 
+{{cfg}}
 impl<'de> Deserialize<'de> for {{trait_name}} {
   fn deserialize<D>(deserializer: D) -> Result<{{trait_name}}, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
       {{trait_name}},
-{% for subt in sub_tokens(token=token) %}      ({{subt.name}}, {{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}});
+{% for subt in sub_tokens(token=token) %}      {{td_cfg(name=subt.name)}} ({{subt.name}}, {{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}});
+{% endfor %}{% for subt in sub_tokens(token=token) %}{% set gated = td_cfg(name=subt.name, not=true) %}{% if gated %}      gated {{gated}} {{subt.name}};
+{% endif %}{% endfor %}{% for removed in removed_items(name=token.name) %}      gated {{removed}};
 {% endfor %}
     )(deserializer)
   }
 }
 
+{{cfg}}
 impl RObject for {{trait_name}} {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-{% for subt in sub_tokens(token=token) %}      {{trait_name}}::{{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}}(t) => t.td_name(),
+{% for subt in sub_tokens(token=token) %}      {{td_cfg(name=subt.name)}} {{trait_name}}::{{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}}(t) => t.td_name(),
 {% endfor %}
       _ => "-1",
     }
@@ -38,22 +49,37 @@ impl RObject for {{trait_name}} {
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
+{{cfg}}
 impl {{trait_name}} {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let {{trait_name}}::_Default(_) = self { true } else { false } }
 
-{% for subt in sub_tokens(token=token) %}  pub fn is_{{subt.name | td_remove_prefix(prefix=trait_name) | to_snake}}(&self) -> bool { if let {{trait_name}}::{{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}}(_) = self { true } else { false } }
+{% for subt in sub_tokens(token=token) %}  {{td_cfg(name=subt.name)}} pub fn is_{{subt.name | td_remove_prefix(prefix=trait_name) | to_snake}}(&self) -> bool { if let {{trait_name}}::{{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}}(_) = self { true } else { false } }
 {% endfor %}
-{% for subt in sub_tokens(token=token) %}  pub fn on_{{subt.name | td_remove_prefix(prefix=trait_name) | to_snake}}<F: FnOnce(&{{subt.name | to_camel}})>(&self, fnc: F) -> &Self { if let {{trait_name}}::{{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}}(t) = self { fnc(t) }; self }
+{% for subt in sub_tokens(token=token) %}  {{td_cfg(name=subt.name)}} pub fn on_{{subt.name | td_remove_prefix(prefix=trait_name) | to_snake}}<F: FnOnce(&{{subt.name | to_camel}})>(&self, fnc: F) -> &Self { if let {{trait_name}}::{{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}}(t) = self { fnc(t) }; self }
 {% endfor %}
-{% for subt in sub_tokens(token=token) %}  pub fn as_{{subt.name | td_remove_prefix(prefix=trait_name) | to_snake}}(&self) -> Option<&{{subt.name | to_camel}}> { if let {{trait_name}}::{{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}}(t) = self { return Some(t) } None }
+{% for subt in sub_tokens(token=token) %}  {{td_cfg(name=subt.name)}} pub fn as_{{subt.name | td_remove_prefix(prefix=trait_name) | to_snake}}(&self) -> Option<&{{subt.name | to_camel}}> { if let {{trait_name}}::{{subt.name | td_remove_prefix(prefix=trait_name) | to_camel}}(t) = self { return Some(t) } None }
 {% endfor %}
 
 {% for subt in sub_tokens(token=token) %}{% set item_name = subt.name | td_remove_prefix(prefix=trait_name) | to_camel %}
-  pub fn {{item_name | to_snake | td_safe_field}}<T: AsRef<{{subt.name | to_camel}}>>(t: T) -> Self { {{trait_name}}::{{item_name}}(t.as_ref().clone()) }
+  {{td_cfg(name=subt.name)}} pub fn {{item_name | to_snake | td_safe_field}}<T: AsRef<{{subt.name | to_camel}}>>(t: T) -> Self { {{trait_name}}::{{item_name}}(t.as_ref().clone()) }
 {% endfor %}
 }
 
+{{cfg}}
 impl AsRef<{{trait_name}}> for {{trait_name}} {
   fn as_ref(&self) -> &{{trait_name}} { self }
 }
+
+{% for subt in sub_tokens(token=token) %}{% set item_name = subt.name | td_remove_prefix(prefix=trait_name) | to_camel %}{% set item_cfg = td_cfg(name=token.name, also=subt.name) %}
+{{item_cfg}}
+impl From<{{subt.name | to_camel}}> for {{trait_name}} {
+  fn from(t: {{subt.name | to_camel}}) -> Self { {{trait_name}}::{{item_name}}(t) }
+}
+
+{{item_cfg}}
+impl std::convert::TryFrom<{{trait_name}}> for {{subt.name | to_camel}} {
+  type Error = {{trait_name}};
+  fn try_from(t: {{trait_name}}) -> Result<Self, Self::Error> { if let {{trait_name}}::{{item_name}}(t) = t { Ok(t) } else { Err(t) } }
+}
+{% endfor %}
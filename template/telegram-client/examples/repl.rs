@@ -0,0 +1,186 @@
+//! A REPL to exercise a tdlib build, authenticate and then type commands.
+//!
+//! ```text
+//! API_ID=12345 API_HASH=0123456789abcdef cargo run --example repl [database directory]
+//! ```
+//!
+//! The parameters are of the environment, see `TdlibParameters::from_env`, e.g. `TD_USE_TEST_DC=1`; the database
+//! directory is `tdlib` by default. The database is encrypted by the key in the file of `DATABASE_KEY_FILE` if it's set.
+//!
+//! Commands:
+//!
+//! - `chats [limit]`: the chats of the main chat list
+//! - `history <chat id> [limit]`: the last messages of a chat
+//! - `send <chat id> <text>`: send a text message
+//! - `me`: the current user
+//! - `{"@type": "getOption", "name": "version"}`: any request as the tdlib json, print the json response
+//! - `help`, `quit`
+
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+use telegram_client::api::Api;
+use telegram_client::auth::{describe_code, AuthCode, AuthPassword, AuthStateHandler};
+use telegram_client::client::Client;
+use telegram_client::database_key::DatabaseKey;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+fn main() {
+{% set nested_parameters = has_token(token_name="tdlibParameters") %}{% if nested_parameters %}  let mut parameters = match TdlibParameters::from_env() {
+{% else %}  let mut parameters = match SetTdlibParameters::from_env() {
+{% endif %}    Ok(parameters) => parameters,
+    Err(e) => {
+      eprintln!("{}, see https://my.telegram.org", e);
+      std::process::exit(1);
+    }
+  };
+  if let Some(database) = std::env::args().nth(1) {
+    parameters.database_directory(database);
+  }
+  let parameters = parameters.use_secret_chats(false).build();
+{% if nested_parameters %}  let parameters = SetTdlibParameters::builder().parameters(&parameters).build();
+{% endif %}
+  Client::set_log_verbosity_level(1).unwrap();
+  let mut builder = Api::builder();
+  if let Ok(path) = std::env::var("DATABASE_KEY_FILE") {
+    match DatabaseKey::from_file(&path) {
+      Ok(key) => { builder.database_key(key); }
+      Err(e) => {
+        eprintln!("Can not read the database key {}: {}", path, e);
+        std::process::exit(1);
+      }
+    }
+  }
+  let api = builder.build();
+  // subscribe before the client starts, the first authorization state is not missed
+  let auth = api.subscribe(&["updateAuthorizationState"]);
+  Client::new(api.clone()).start();
+
+  if let Err(e) = api.authorize(&auth, &Prompt { parameters }) {
+    eprintln!("{}", e);
+    return;
+  }
+
+  println!("Authorized, type `help` for the commands");
+  loop {
+    let line = prompt(">");
+    if line.is_empty() { continue; }
+    if line == "quit" { break; }
+    if let Err(e) = execute(&api, &line) {
+      eprintln!("{}", e);
+    }
+  }
+  let _ = api.send(Close::builder().build());
+}
+
+/// Answers the authorization states by the lines typed
+struct Prompt {
+  parameters: SetTdlibParameters,
+}
+
+impl AuthStateHandler for Prompt {
+  fn tdlib_parameters(&self) -> SetTdlibParameters { self.parameters.clone() }
+  fn phone_number(&self, _state: &AuthorizationStateWaitPhoneNumber) -> String { prompt("phone number") }
+{% set email_states = has_token(token_name="authorizationStateWaitEmailCode") %}{% if email_states %}  fn email_address(&self, _state: &AuthorizationStateWaitEmailAddress) -> String { prompt("email address") }
+  fn email_code(&self, _state: &AuthorizationStateWaitEmailCode) -> String { prompt("email code") }
+{% endif %}  fn code(&self, state: &AuthorizationStateWaitCode) -> AuthCode {
+    println!("The code is sent by {}", describe_code(state.code_info()));
+    match prompt("code (or `resend`)") {
+      code if code == "resend" => AuthCode::Resend,
+      code => AuthCode::Code(code),
+    }
+  }
+  fn password(&self, state: &AuthorizationStateWaitPassword) -> AuthPassword {
+    if !state.recovery_email_address_pattern().is_empty() {
+      return AuthPassword::RecoveryCode(prompt(&format!("recovery code sent to {}", state.recovery_email_address_pattern())));
+    }
+    match prompt(&format!("password, hint `{}` (or `recover`)", state.password_hint())) {
+      password if password == "recover" && state.has_recovery_email_address() => AuthPassword::RequestRecovery,
+      password => AuthPassword::Password(password),
+    }
+  }
+  fn registration(&self, _state: &AuthorizationStateWaitRegistration) -> (String, String) {
+    (prompt("first name"), prompt("last name"))
+  }
+
+  // a wrong code or password is typed again
+  fn refused(&self, _state: &AuthorizationState, error: RTDError) -> RTDResult<()> {
+    eprintln!("{}", error);
+    Ok(())
+  }
+}
+
+fn prompt(label: &str) -> String {
+  print!("{} ", label);
+  let _ = io::stdout().flush();
+  let mut line = String::new();
+  if io::stdin().lock().read_line(&mut line).unwrap_or(0) == 0 {
+    // end of input
+    std::process::exit(0);
+  }
+  line.trim().to_string()
+}
+
+fn execute(api: &Api, line: &str) -> RTDResult<()> {
+  if line.starts_with('{') {
+    let request = serde_json::from_str(line)?;
+    let response = api.request_raw(request)?.wait_timeout(TIMEOUT)?;
+    println!("{}", serde_json::to_string_pretty(&response)?);
+    return Ok(());
+  }
+  let mut words = line.splitn(3, ' ');
+  let command = words.next().unwrap_or("");
+  let (first, second) = (words.next(), words.next());
+  match command {
+    "chats" => {
+      let limit = number(first, 20)? as usize;
+      for chat in api.iter_chats(ChatList::main(ChatListMain::builder())).take(limit) {
+        let chat = chat?;
+        println!("{:>16}  {}", chat.id(), chat.title());
+      }
+    }
+    "history" => {
+      let chat_id = number(first, 0)?;
+      let limit = number(second, 20)? as usize;
+      let mut messages = api.iter_chat_history(chat_id).take(limit).collect::<RTDResult<Vec<Message>>>()?;
+      // oldest first, like a chat
+      messages.reverse();
+      for message in messages {
+        let content = message.content();
+        let text = content.text().or_else(|| content.caption()).map(|text| text.text().clone());
+        println!("{:>12}  {:>10}  {}", message.id(), message.sender_user_id().unwrap_or(0),
+                 text.unwrap_or_else(|| format!("[{}]", content.td_name())));
+      }
+    }
+    "send" => {
+      let chat_id = number(first, 0)?;
+      let text = second.unwrap_or("");
+      let send_message = SendMessage::builder()
+        .chat_id(chat_id)
+        .input_message_content(InputMessageContent::input_message_text(InputMessageText::builder()
+          .text(FormattedText::builder().text(text).build())
+          .clear_draft(true)))
+        .build();
+      let message: Message = api.request(send_message)?.wait_timeout(TIMEOUT)?;
+      println!("sent {}", message.id());
+    }
+    "me" => {
+      let me: User = api.request(GetMe::builder().build())?.wait_timeout(TIMEOUT)?;
+      println!("{}  {} {}  @{}", me.id(), me.first_name(), me.last_name(), me.username());
+    }
+    "help" => println!("chats [limit] | history <chat id> [limit] | send <chat id> <text> | me | <tdlib json> | quit"),
+    _ => println!("Unknown command `{}`, type `help` for the commands", command),
+  }
+  Ok(())
+}
+
+fn number(word: Option<&str>, default: i64) -> RTDResult<i64> {
+  match word {
+    Some(word) => word.trim().parse().map_err(|_| RTDError::custom("Expected a number")),
+    None if default != 0 => Ok(default),
+    None => Err(RTDError::custom("Expected a chat id")),
+  }
+}
\ No newline at end of file
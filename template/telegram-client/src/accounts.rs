@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+use crate::auth::AuthStateHandler;
+use crate::client::Client;
+use crate::observer::ALL_UPDATES;
+use crate::scan::decode;
+use crate::subscription::Subscription;
+
+/// How often the update forwarder of an account checks it's removed
+const FORWARD_POLL: Duration = Duration::from_millis(500);
+
+/// An update of an account of `AccountManager`, tagged with the id of the account
+#[derive(Debug)]
+pub struct AccountUpdate {
+  account: String,
+  update: RTDResult<Update>,
+}
+
+impl AccountUpdate {
+  pub fn account(&self) -> &String { &self.account }
+
+  pub fn update(&self) -> &RTDResult<Update> { &self.update }
+
+  pub fn into_update(self) -> RTDResult<Update> { self.update }
+}
+
+/// An account of `AccountManager`, its own tdlib client started by `AccountManager::add_account`
+#[derive(Debug)]
+pub struct Account {
+  id: String,
+  api: Api,
+  parameters: SetTdlibParameters,
+  auth: Subscription,
+  removed: Arc<AtomicBool>,
+}
+
+impl Account {
+  pub fn id(&self) -> &String { &self.id }
+
+  pub fn api(&self) -> &Api { &self.api }
+
+  /// The parameters of this account, the directories are of its id; the answer of `AuthStateHandler::tdlib_parameters`
+  pub fn tdlib_parameters(&self) -> &SetTdlibParameters { &self.parameters }
+
+  /// Answer the authorization states of this account by the handler until it's ready, see `Api::authorize`
+  pub fn authorize<H: AuthStateHandler + ?Sized>(&self, handler: &H) -> RTDResult<()> {
+    self.api.authorize(&self.auth, handler)
+  }
+}
+
+/// Several accounts in one process, e.g. of a userbot host.
+///
+/// Every account has its own tdlib client and receive loop, and the same parameters but the database and files
+/// directories, `<base dir>/<account id>`. The updates of all accounts are received in one channel by
+/// `AccountManager::recv`, tagged with the id of their account.
+#[derive(Debug)]
+pub struct AccountManager {
+  base_dir: PathBuf,
+  parameters: SetTdlibParameters,
+  accounts: BTreeMap<String, Account>,
+  sender: Sender<AccountUpdate>,
+  updates: Receiver<AccountUpdate>,
+}
+
+impl AccountManager {
+  /// `parameters` are the parameters of every account, their directories are replaced by the account directory
+  pub fn new<P: AsRef<Path>>(base_dir: P, parameters: SetTdlibParameters) -> Self {
+    let (sender, updates) = mpsc::channel();
+    Self {
+      base_dir: base_dir.as_ref().to_path_buf(),
+      parameters,
+      accounts: BTreeMap::new(),
+      sender,
+      updates,
+    }
+  }
+
+  /// The database and files directory of an account
+  pub fn account_dir<S: AsRef<str>>(&self, id: S) -> PathBuf {
+    self.base_dir.join(id.as_ref())
+  }
+
+  /// Start the tdlib client of a new account, then authorize it by `Account::authorize`; the id is the name of
+  /// its directory
+  pub fn add_account<S: AsRef<str>>(&mut self, id: S) -> RTDResult<&Account> {
+    let id = id.as_ref();
+    if id.is_empty() || id == "." || id == ".." || id.contains(['/', '\\']) {
+      return Err(RTDError::custom("The account id is not a directory name"));
+    }
+    if self.accounts.contains_key(id) {
+      return Err(RTDError::custom("The account is already added"));
+    }
+    let parameters = self.parameters_of(&self.account_dir(id));
+
+    let api = Api::default();
+    // subscribe before the client starts, the first objects are not missed
+    let auth = api.subscribe(&["updateAuthorizationState"]);
+    let updates = api.observer().watch(ALL_UPDATES);
+    Client::new(api.clone()).start();
+
+    let removed = Arc::new(AtomicBool::new(false));
+    self::forward(id.to_string(), updates, self.sender.clone(), removed.clone());
+    let account = Account { id: id.to_string(), api, parameters, auth, removed };
+    Ok(self.accounts.entry(id.to_string()).or_insert(account))
+  }
+
+  /// Close the tdlib client of an account and stop forwarding its updates, its directory is kept; log out
+  /// before by `Api::sign_out` to remove the account from telegram
+  pub fn remove_account<S: AsRef<str>>(&mut self, id: S) -> RTDResult<()> {
+    let account = match self.accounts.remove(id.as_ref()) {
+      Some(account) => account,
+      None => return Err(RTDError::custom("No such account")),
+    };
+    account.removed.store(true, Ordering::SeqCst);
+    let _: Ok = account.api.request(Close::builder().build())?.wait()?;
+    Ok(())
+  }
+
+  pub fn account<S: AsRef<str>>(&self, id: S) -> Option<&Account> {
+    self.accounts.get(id.as_ref())
+  }
+
+  /// All accounts, ordered by id
+  pub fn accounts(&self) -> Vec<&Account> {
+    self.accounts.values().collect()
+  }
+
+  /// Block until the next update of any account
+  pub fn recv(&self) -> Option<AccountUpdate> {
+    self.updates.recv().ok()
+  }
+
+  /// Block until the next update of any account or timeout
+  pub fn recv_timeout(&self, timeout: Duration) -> Option<AccountUpdate> {
+    self.updates.recv_timeout(timeout).ok()
+  }
+
+  /// the parameters of all accounts in this directory
+  fn parameters_of(&self, dir: &Path) -> SetTdlibParameters {
+    let dir = dir.to_string_lossy();
+{% set nested_parameters = has_token(token_name="tdlibParameters") %}{% if nested_parameters %}    let parameters = self.parameters.parameters().to_builder()
+      .database_directory(&dir)
+      .files_directory(&dir)
+      .build();
+    SetTdlibParameters::builder().parameters(&parameters).build()
+{% else %}    self.parameters.to_builder()
+      .database_directory(&dir)
+      .files_directory(&dir)
+      .build()
+{% endif %}  }
+}
+
+/// send the updates of an account to the channel of the manager until it's removed
+fn forward(account: String, updates: Receiver<String>, sender: Sender<AccountUpdate>, removed: Arc<AtomicBool>) {
+  thread::spawn(move || {
+    while !removed.load(Ordering::SeqCst) {
+      let json = match updates.recv_timeout(FORWARD_POLL) {
+        Ok(json) => json,
+        Err(RecvTimeoutError::Timeout) => continue,
+        Err(RecvTimeoutError::Disconnected) => break,
+      };
+      let update = AccountUpdate { account: account.clone(), update: decode(&json) };
+      if sender.send(update).is_err() {
+        break;
+      }
+    }
+  });
+}
\ No newline at end of file
@@ -0,0 +1,234 @@
+use std::fs;
+use std::path::Path;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+/// Number of the values of a waveform made by `VoiceNoteFile::from_ogg`, like the waveforms of the Telegram apps
+pub const WAVEFORM_LENGTH: usize = 100;
+
+/// The largest value of a waveform, the values are 5-bit
+pub const WAVEFORM_MAX: u8 = 31;
+
+/// Sample rate of the granule positions of Opus in OGG, whatever the rate of the input
+const OPUS_GRANULE_RATE: f64 = 48000.0;
+
+/// A local OGG Opus file to be sent as a voice note, with its duration and waveform read from the file.
+///
+/// The waveform is estimated by the sizes of the Opus packets, a loud or complex part is coded by larger
+/// packets; the file is not decoded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoiceNoteFile {
+  path: String,
+  duration: i64,
+  waveform: Vec<u8>,
+}
+
+impl VoiceNoteFile {
+  /// Read the duration and the waveform of an OGG Opus file
+  pub fn from_ogg<P: AsRef<Path>>(path: P) -> RTDResult<Self> {
+    let data = fs::read(path.as_ref())?;
+    let ogg = self::read_ogg(&data)?;
+    let samples = (ogg.granule_position - ogg.pre_skip as i64).max(0);
+    Ok(Self {
+      path: path.as_ref().to_string_lossy().into_owned(),
+      duration: (samples as f64 / OPUS_GRANULE_RATE).round() as i64,
+      waveform: self::pack_waveform(&self::levels(&ogg.packet_sizes, WAVEFORM_LENGTH)),
+    })
+  }
+
+  pub fn path(&self) -> &String { &self.path }
+
+  /// Duration in seconds
+  pub fn duration(&self) -> i64 { self.duration }
+
+  /// The waveform packed in 5-bit values, see `pack_waveform`
+  pub fn waveform(&self) -> &Vec<u8> { &self.waveform }
+
+  /// The content to be sent by `sendMessage`
+  pub fn to_input_message_content(&self, caption: Option<FormattedText>) -> InputMessageContent {
+    let mut voice_note = InputMessageVoiceNote::builder();
+    voice_note
+      .voice_note(InputFile::local(InputFileLocal::builder().path(&self.path)))
+      .duration(self.duration)
+      .waveform(TdBytes::new(self.waveform.clone()));
+    if let Some(caption) = caption {
+      voice_note.caption(caption);
+    }
+    InputMessageContent::input_message_voice_note(voice_note)
+  }
+}
+
+/// A local MP4 file to be sent as a video note, with its duration and length read from the file.
+///
+/// A video note is square and up to a minute long, its length is the smaller side of the video track.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoNoteFile {
+  path: String,
+  duration: i64,
+  length: i64,
+}
+
+impl VideoNoteFile {
+  /// Read the duration and the size of the video track of an MP4 file
+  pub fn from_mp4<P: AsRef<Path>>(path: P) -> RTDResult<Self> {
+    let data = fs::read(path.as_ref())?;
+    let moov = self::child_atom(&data, b"moov").ok_or(RTDError::custom("No moov atom, not an MP4 file"))?;
+    let mvhd = self::child_atom(moov, b"mvhd").ok_or(RTDError::custom("No mvhd atom in the MP4 file"))?;
+    let (timescale, duration) = self::movie_duration(mvhd).ok_or(RTDError::custom("Invalid mvhd atom in the MP4 file"))?;
+    let (width, height) = self::atoms(moov)
+      .filter(|(name, _)| name == b"trak")
+      .filter_map(|(_, trak)| self::child_atom(trak, b"tkhd"))
+      .filter_map(self::track_size)
+      .find(|(width, height)| *width > 0 && *height > 0)
+      .ok_or(RTDError::custom("No video track in the MP4 file"))?;
+    Ok(Self {
+      path: path.as_ref().to_string_lossy().into_owned(),
+      duration: if timescale == 0 { 0 } else { (duration as f64 / timescale as f64).round() as i64 },
+      length: width.min(height) as i64,
+    })
+  }
+
+  pub fn path(&self) -> &String { &self.path }
+
+  /// Duration in seconds
+  pub fn duration(&self) -> i64 { self.duration }
+
+  /// Width and height of the video, in pixels
+  pub fn length(&self) -> i64 { self.length }
+
+  /// The content to be sent by `sendMessage`
+  pub fn to_input_message_content(&self) -> InputMessageContent {
+    InputMessageContent::input_message_video_note(InputMessageVideoNote::builder()
+      .video_note(InputFile::local(InputFileLocal::builder().path(&self.path)))
+      .duration(self.duration)
+      .length(self.length))
+  }
+}
+
+/// Pack the values of a waveform, 0-31 and a larger value is 31, in the 5-bit format of tdlib: the bits of every
+/// value follow the previous value from the least significant bit of a byte
+pub fn pack_waveform(values: &[u8]) -> Vec<u8> {
+  let mut packed = vec![0u8; (values.len() * 5).div_ceil(8)];
+  for (index, value) in values.iter().enumerate() {
+    let value = (*value).min(WAVEFORM_MAX) as u16;
+    let bit = index * 5;
+    let shifted = value << (bit % 8);
+    packed[bit / 8] |= shifted as u8;
+    if bit / 8 + 1 < packed.len() {
+      packed[bit / 8 + 1] |= (shifted >> 8) as u8;
+    }
+  }
+  packed
+}
+
+/// `count` values 0-31 of the sizes of the packets, the average of every part scaled to the largest one
+fn levels(sizes: &[usize], count: usize) -> Vec<u8> {
+  if sizes.is_empty() { return vec![0; count]; }
+  let averages: Vec<f64> = (0..count)
+    .map(|index| {
+      let start = index * sizes.len() / count;
+      let end = ((index + 1) * sizes.len() / count).max(start + 1).min(sizes.len());
+      let part = &sizes[start..end];
+      part.iter().sum::<usize>() as f64 / part.len() as f64
+    })
+    .collect();
+  let max = averages.iter().cloned().fold(0.0, f64::max);
+  if max <= 0.0 { return vec![0; count]; }
+  averages.iter().map(|average| (average / max * WAVEFORM_MAX as f64).round() as u8).collect()
+}
+
+/// the pages of an OGG Opus stream read for a voice note
+#[derive(Debug)]
+struct OggOpus {
+  pre_skip: u16,
+  granule_position: i64,
+  packet_sizes: Vec<usize>,
+}
+
+fn read_ogg(data: &[u8]) -> RTDResult<OggOpus> {
+  let mut packets: Vec<Vec<u8>> = vec![];
+  let mut packet: Vec<u8> = vec![];
+  let mut granule_position = 0;
+  let mut offset = 0;
+  while offset < data.len() {
+    let header = data.get(offset..offset + 27).ok_or(RTDError::custom("Truncated OGG page"))?;
+    if &header[..4] != b"OggS" { return Err(RTDError::custom("No OGG page, not an OGG file")); }
+    let mut position = [0u8; 8];
+    position.copy_from_slice(&header[6..14]);
+    let position = i64::from_le_bytes(position);
+    // -1 if no packet ends on this page
+    if position >= 0 { granule_position = position; }
+    let segments = header[26] as usize;
+    let table = data.get(offset + 27..offset + 27 + segments).ok_or(RTDError::custom("Truncated OGG page"))?;
+    let mut body = offset + 27 + segments;
+    for size in table {
+      let segment = data.get(body..body + *size as usize).ok_or(RTDError::custom("Truncated OGG page"))?;
+      packet.extend_from_slice(segment);
+      body += *size as usize;
+      // a packet continues in the next segment after a segment of 255 bytes
+      if *size < 255 {
+        packets.push(packet.split_off(0));
+      }
+    }
+    offset = body;
+  }
+  let head = packets.first().ok_or(RTDError::custom("Empty OGG file"))?;
+  if head.len() < 19 || &head[..8] != b"OpusHead" {
+    return Err(RTDError::custom("Not an Opus stream, a voice note is OGG Opus"));
+  }
+  Ok(OggOpus {
+    pre_skip: u16::from_le_bytes([head[10], head[11]]),
+    granule_position,
+    // the headers, OpusHead and OpusTags, are not audio
+    packet_sizes: packets.iter().skip(2).map(|packet| packet.len()).collect(),
+  })
+}
+
+/// the name and the content of every atom of this MP4 data
+fn atoms(data: &[u8]) -> impl Iterator<Item = ([u8; 4], &[u8])> + '_ {
+  let mut offset = 0;
+  std::iter::from_fn(move || {
+    let header = data.get(offset..offset + 8)?;
+    let mut name = [0u8; 4];
+    name.copy_from_slice(&header[4..8]);
+    let (header_size, size) = match u32::from_be_bytes([header[0], header[1], header[2], header[3]]) {
+      // to the end of the data
+      0 => (8, data.len() - offset),
+      // the 64-bit size follows the name
+      1 => {
+        let mut large = [0u8; 8];
+        large.copy_from_slice(data.get(offset + 8..offset + 16)?);
+        (16, u64::from_be_bytes(large) as usize)
+      }
+      size => (8, size as usize),
+    };
+    if size < header_size { return None; }
+    let content = data.get(offset + header_size..offset.checked_add(size)?)?;
+    offset += size;
+    Some((name, content))
+  })
+}
+
+fn child_atom<'a>(data: &'a [u8], name: &[u8; 4]) -> Option<&'a [u8]> {
+  self::atoms(data).find(|(atom, _)| atom == name).map(|(_, content)| content)
+}
+
+/// the timescale and the duration of a `mvhd` atom
+fn movie_duration(mvhd: &[u8]) -> Option<(u64, u64)> {
+  let be = |bytes: &[u8]| bytes.iter().fold(0u64, |value, byte| (value << 8) | *byte as u64);
+  match mvhd.first()? {
+    0 => Some((be(mvhd.get(12..16)?), be(mvhd.get(16..20)?))),
+    1 => Some((be(mvhd.get(20..24)?), be(mvhd.get(24..32)?))),
+    _ => None,
+  }
+}
+
+/// the width and the height of a `tkhd` atom, 0 of a track not a video; they are its last 16.16 fixed-point numbers
+fn track_size(tkhd: &[u8]) -> Option<(u32, u32)> {
+  if tkhd.len() < 84 { return None; }
+  let size = &tkhd[tkhd.len() - 8..];
+  let width = u32::from_be_bytes([size[0], size[1], size[2], size[3]]) >> 16;
+  let height = u32::from_be_bytes([size[4], size[5], size[6], size[7]]) >> 16;
+  Some((width, height))
+}
@@ -0,0 +1,184 @@
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+use crate::subscription::Subscription;
+{% set auth_state = find_token(token_name="AuthorizationState") %}
+/// The answer to `AuthStateHandler::code`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthCode {
+  /// The code received
+  Code(String),
+  /// Send the code again, by the `next_type` of the code info; the handler is asked again by the new code info
+  Resend,
+}
+
+/// The answer to `AuthStateHandler::password`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthPassword {
+  /// The 2-step verification password
+  Password(String),
+  /// Send a recovery code to the recovery email address; the handler is asked again, the
+  /// `recovery_email_address_pattern` of the state is the address the code is sent to
+  RequestRecovery,
+  /// The recovery code received, it removes the password
+  RecoveryCode(String),
+}
+
+/// The answers to the authorization states, see `Api::authorize`.
+///
+/// A state without its own callback, e.g. `Ready` or a state added by a newer tdlib, goes to `handle_other`.
+pub trait AuthStateHandler {
+{% for state in sub_tokens(token=auth_state) %}{% if state.name == "authorizationStateWaitTdlibParameters" %}  /// `setTdlibParameters` of the client
+  {{td_cfg(name=state.name)}} fn tdlib_parameters(&self) -> SetTdlibParameters;
+{% elif state.name == "authorizationStateWaitEncryptionKey" %}  /// Encryption key of the local database, empty if it isn't encrypted; unused if `ApiBuilder::database_key` is set
+  {{td_cfg(name=state.name)}} fn encryption_key(&self, _state: &AuthorizationStateWaitEncryptionKey) -> TdBytes { TdBytes::new(vec![]) }
+{% elif state.name == "authorizationStateWaitPhoneNumber" %}  /// Phone number of the user, in the international format
+  {{td_cfg(name=state.name)}} fn phone_number(&self, state: &AuthorizationStateWaitPhoneNumber) -> String;
+{% elif state.name == "authorizationStateWaitEmailAddress" %}  /// Email address of the user, the code is sent to it
+  {{td_cfg(name=state.name)}} fn email_address(&self, state: &AuthorizationStateWaitEmailAddress) -> String;
+{% elif state.name == "authorizationStateWaitEmailCode" %}  /// The authentication code sent to the email address, `state.code_info()`
+  {{td_cfg(name=state.name)}} fn email_code(&self, state: &AuthorizationStateWaitEmailCode) -> String;
+{% elif state.name == "authorizationStateWaitCode" %}  /// The authentication code sent as `state.code_info()`, see `describe_code`, or `AuthCode::Resend`
+  {{td_cfg(name=state.name)}} fn code(&self, state: &AuthorizationStateWaitCode) -> AuthCode;
+{% elif state.name == "authorizationStateWaitPassword" %}  /// The 2-step verification password, `state.password_hint()` is its hint, or its recovery by the recovery email
+  /// address if `state.has_recovery_email_address()`, see `AuthPassword`
+  {{td_cfg(name=state.name)}} fn password(&self, state: &AuthorizationStateWaitPassword) -> AuthPassword;
+{% elif state.name == "authorizationStateWaitRegistration" %}  /// First and last name of a new user
+  {{td_cfg(name=state.name)}} fn registration(&self, state: &AuthorizationStateWaitRegistration) -> (String, String);
+{% endif %}{% endfor %}
+  /// Any other state, nothing is sent by default
+  fn handle_other(&self, _api: &Api, _state: &AuthorizationState) -> RTDResult<()> { Ok(()) }
+
+  /// The answer to a state is refused by tdlib, e.g. a wrong code; `Ok` asks the handler again, the error
+  /// stops `Api::authorize` by default
+  fn refused(&self, _state: &AuthorizationState, error: RTDError) -> RTDResult<()> { Err(error) }
+}
+
+/// Send the answer of the handler to an authorization state and wait for it, the arms are generated from the
+/// `AuthorizationState` of the schema.
+///
+/// A tdlib error, e.g. a wrong code, is returned as `RTDError::TdlibError`, the state is still the same.
+pub fn handle_auth_state<H: AuthStateHandler + ?Sized>(api: &Api, handler: &H, state: &AuthorizationState) -> RTDResult<()> {
+  match state {
+{% for state in sub_tokens(token=auth_state) %}{% if state.type_ != "Function" %}{% set variant = state.name | td_remove_prefix(prefix="AuthorizationState") | to_camel %}    {{td_cfg(name=state.name)}} AuthorizationState::{{variant}}({% if state.name == "authorizationStateWaitTdlibParameters" %}_) =>
+      self::set_parameters(api, handler.tdlib_parameters()),
+{% elif state.name == "authorizationStateWaitEncryptionKey" %}wait) => {
+      let key = api.database_key_bytes().unwrap_or_else(|| handler.encryption_key(wait));
+      self::answer(api, CheckDatabaseEncryptionKey::builder().encryption_key(key).build())
+    }
+{% elif state.name == "authorizationStateWaitPhoneNumber" %}wait) =>
+      self::answer(api, SetAuthenticationPhoneNumber::builder().phone_number(handler.phone_number(wait)).build()),
+{% elif state.name == "authorizationStateWaitEmailAddress" %}wait) =>
+      self::answer(api, SetAuthenticationEmailAddress::builder().email_address(handler.email_address(wait)).build()),
+{% elif state.name == "authorizationStateWaitEmailCode" %}wait) => {
+      let code = EmailAddressAuthenticationCode::builder().code(handler.email_code(wait)).build();
+      self::answer(api, CheckAuthenticationEmailCode::builder().code(EmailAddressAuthentication::code(code)).build())
+    }
+{% elif state.name == "authorizationStateWaitCode" %}wait) => match handler.code(wait) {
+      AuthCode::Code(code) => self::answer(api, CheckAuthenticationCode::builder().code(code).build()),
+      AuthCode::Resend => self::answer(api, ResendAuthenticationCode::builder().build()),
+    }
+{% elif state.name == "authorizationStateWaitPassword" %}wait) => match handler.password(wait) {
+      AuthPassword::Password(password) => self::answer(api, CheckAuthenticationPassword::builder().password(password).build()),
+      AuthPassword::RequestRecovery => self::answer(api, RequestAuthenticationPasswordRecovery::builder().build()),
+      AuthPassword::RecoveryCode(code) => self::answer(api, RecoverAuthenticationPassword::builder().recovery_code(code).build()),
+    }
+{% elif state.name == "authorizationStateWaitRegistration" %}wait) => {
+      let (first_name, last_name) = handler.registration(wait);
+      self::answer(api, RegisterUser::builder().first_name(first_name).last_name(last_name).build())
+    }
+{% else %}_) => handler.handle_other(api, state),
+{% endif %}{% endif %}{% endfor %}    _ => handler.handle_other(api, state),
+  }
+}
+
+{% set code_info = has_token(token_name="authenticationCodeInfo") %}{% if code_info %}/// How the code of `AuthorizationStateWaitCode` is sent, e.g. `sms, 5 digits, then call in 60s`, for the prompt of a UI
+pub fn describe_code(info: &AuthenticationCodeInfo) -> String {
+  let sent = self::code_type(info.type_());
+  match info.next_type() {
+    Some(next_type) => format!("{}, then {} in {}s", sent, self::code_type(next_type), info.timeout()),
+    None => sent,
+  }
+}
+{% set code_type = find_token(token_name="AuthenticationCodeType") %}
+fn code_type(code_type: &AuthenticationCodeType) -> String {
+  let (name, length) = match code_type {
+{% for item in sub_tokens(token=code_type) %}{% if item.type_ != "Function" %}{% set name = item.name | td_remove_prefix(prefix="AuthenticationCodeType") %}{% set length = has_token(token_name=item.name, field="length") %}    {{td_cfg(name=item.name)}} AuthenticationCodeType::{{name | to_camel}}({% if length %}item) => ("{{name | to_snake | replace(from="_", to=" ")}}", Some(item.length())),
+{% else %}_) => ("{{name | to_snake | replace(from="_", to=" ")}}", None),
+{% endif %}{% endif %}{% endfor %}    _ => (code_type.td_name(), None),
+  };
+  match length {
+    Some(length) if length > 0 => format!("{}, {} digits", name, length),
+    _ => name.to_string(),
+  }
+}
+
+{% endif %}/// the parameters of the handler are validated before they are sent, tdlib refuses them later and less clear
+fn set_parameters(api: &Api, parameters: SetTdlibParameters) -> RTDResult<()> {
+{% set nested_parameters = has_token(token_name="tdlibParameters") %}{% set parameters_key = has_token(token_name="setTdlibParameters", field="database_encryption_key") %}{% if nested_parameters %}  parameters.parameters().validate()?;
+{% else %}  parameters.validate()?;
+{% endif %}{% if parameters_key %}  match api.database_key_bytes() {
+    Some(key) => self::answer(api, parameters.to_builder().database_encryption_key(key).build()),
+    None => self::answer(api, parameters),
+  }
+{% else %}  self::answer(api, parameters)
+{% endif %}}
+
+fn answer<Fnc: RFunction>(api: &Api, fnc: Fnc) -> RTDResult<()> {
+  let _: Ok = api.request(fnc)?.wait()?;
+  Ok(())
+}
+
+
+impl Api {
+  /// Answer the authorization states by the handler until the client is ready, fails if it's closed.
+  ///
+  /// The updates are of `api.subscribe(&["updateAuthorizationState"])` before the client starts, so the first
+  /// state isn't missed. A state answered by a tdlib error is asked again if `AuthStateHandler::refused` allows it.
+  pub fn authorize<H: AuthStateHandler + ?Sized>(&self, updates: &Subscription, handler: &H) -> RTDResult<()> {
+    while let Some(update) = updates.recv() {
+      let state = match update {
+        Ok(Update::AuthorizationState(update)) => update.authorization_state().clone(),
+        Ok(_) => continue,
+        Err(e) => {
+          warn!("Can not read the authorization state: {}", e);
+          continue;
+        }
+      };
+      loop {
+        match self::handle_auth_state(self, handler, &state) {
+          Ok(()) => break,
+          Err(e @ RTDError::TdlibError(_)) => handler.refused(&state, e)?,
+          Err(e) => return Err(e),
+        }
+      }
+      if state.is_ready() { return Ok(()); }
+      if state.is_closed() { return Err(RTDError::custom("The client is closed before it's authorized")); }
+    }
+    Err(RTDError::custom("The receive loop is stopped before the client is authorized"))
+  }
+
+  /// Run `f` on its own thread once the authorization is ready, the thread stops without running it if the
+  /// client stops before. Called before the client starts, so the first authorization state isn't missed.
+  pub(crate) fn on_ready<F: FnOnce(Api) + Send + 'static>(&self, f: F) -> JoinHandle<()> {
+    let auth = self.watch("updateAuthorizationState");
+    let api = self.clone();
+    thread::spawn(move || {
+      while !api.update_queue().is_closed() {
+        match auth.recv_timeout(READY_TICK) {
+          Ok(json) => if json.contains("\"authorizationStateReady\"") { return f(api); },
+          Err(RecvTimeoutError::Timeout) => continue,
+          Err(RecvTimeoutError::Disconnected) => return,
+        }
+      }
+    })
+  }
+}
+
+/// how often `Api::on_ready` checks the client is stopped while waiting for the authorization
+const READY_TICK: Duration = Duration::from_millis(200);
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use rtdlib::errors::*;
+use serde::de::DeserializeOwned;
+
+use crate::observer::{parse_response, Observer};
+use crate::scan::scan;
+
+/// Requests sent together by `Api::request_batch`, the responses are not yet received.
+///
+/// Iterate it to get the response of each request as soon as it's received, with the index of the
+/// request in the batch, so the responses are not in the order of the requests.
+/// Dropping the batch stops waiting the responses not yet received.
+#[derive(Debug)]
+pub struct Batch<T> {
+  extras: Vec<String>,
+  indexes: HashMap<String, usize>,
+  receiver: Receiver<String>,
+  observer: Arc<Observer>,
+  _marker: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> Batch<T> {
+  pub(crate) fn new(extras: Vec<String>, receiver: Receiver<String>, observer: Arc<Observer>) -> Self {
+    let indexes = extras.iter().enumerate().map(|(index, extra)| (extra.clone(), index)).collect();
+    Self { extras, indexes, receiver, observer, _marker: PhantomData }
+  }
+
+  /// Number of requests in the batch
+  pub fn len(&self) -> usize { self.extras.len() }
+
+  /// True, if the batch has no request
+  pub fn is_empty(&self) -> bool { self.extras.is_empty() }
+
+  /// Number of responses not yet received
+  pub fn remaining(&self) -> usize { self.indexes.len() }
+
+  /// Block until the next response, `None` if all responses are received
+  pub fn next_response(&mut self) -> Option<(usize, RTDResult<T>)> {
+    self.next_response_until(None)
+  }
+
+  /// Block until the next response or timeout
+  pub fn next_response_timeout(&mut self, timeout: Duration) -> Option<(usize, RTDResult<T>)> {
+    self.next_response_until(Some(timeout))
+  }
+
+  /// Block until all responses are received, return them in the order of the requests
+  pub fn wait_all(mut self) -> Vec<RTDResult<T>> {
+    let mut responses: Vec<Option<RTDResult<T>>> = self.extras.iter().map(|_| None).collect();
+    while let Some((index, response)) = self.next_response() {
+      responses[index] = Some(response);
+    }
+    responses.into_iter()
+      .map(|response| response.unwrap_or_else(|| Err(RTDError::custom("No response received"))))
+      .collect()
+  }
+
+  fn next_response_until(&mut self, timeout: Option<Duration>) -> Option<(usize, RTDResult<T>)> {
+    loop {
+      if self.indexes.is_empty() { return None; }
+      let json = match timeout {
+        Some(timeout) => match self.receiver.recv_timeout(timeout) {
+          Ok(json) => json,
+          Err(RecvTimeoutError::Timeout) => return None,
+          Err(RecvTimeoutError::Disconnected) => return self.fail_remaining(),
+        },
+        None => match self.receiver.recv() {
+          Ok(json) => json,
+          Err(_) => return self.fail_remaining(),
+        },
+      };
+      let extra = scan(&json).extra().map(|extra| extra.to_string()).unwrap_or_default();
+      // only the requests of this batch share the channel
+      if let Some(index) = self.indexes.remove(&extra) {
+        return Some((index, parse_response(&json, &extra)));
+      }
+    }
+  }
+
+  fn fail_remaining(&mut self) -> Option<(usize, RTDResult<T>)> {
+    let index = *self.indexes.values().next()?;
+    let extra = self.extras[index].clone();
+    self.indexes.remove(&extra);
+    Some((index, Err(RTDError::custom("The receive loop is stopped, no response will be received"))))
+  }
+}
+
+impl<T: DeserializeOwned> Iterator for Batch<T> {
+  type Item = (usize, RTDResult<T>);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.next_response()
+  }
+}
+
+impl<T> Drop for Batch<T> {
+  fn drop(&mut self) {
+    for extra in self.indexes.keys() {
+      self.observer.unsubscribe(extra);
+    }
+  }
+}
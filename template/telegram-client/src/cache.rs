@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use rtdlib::types::*;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// In-memory cache of the objects tdlib requires the client to remember.
+///
+/// Fed by `updateUser`, `updateBasicGroup`, `updateSupergroup`, `updateSecretChat`, `updateNewChat`,
+/// `updateChat*` and the full info updates in the receive loop, so the latest state can be looked up
+/// without a round trip to tdlib. Enable it by `ApiBuilder::cache`.
+///
+/// The chat positions changed by `updateChatPosition`, `updateChatLastMessage` and `updateChatDraftMessage`
+/// are applied as well, so `Cache::chat_list` can return the ordered chats of a chat list.
+#[derive(Debug, Default)]
+pub struct Cache {
+  users: RwLock<HashMap<i64, Value>>,
+  user_full_infos: RwLock<HashMap<i64, Value>>,
+  basic_groups: RwLock<HashMap<i64, Value>>,
+  basic_group_full_infos: RwLock<HashMap<i64, Value>>,
+  supergroups: RwLock<HashMap<i64, Value>>,
+  supergroup_full_infos: RwLock<HashMap<i64, Value>>,
+  secret_chats: RwLock<HashMap<i64, Value>>,
+  chats: RwLock<HashMap<i64, Value>>,
+}
+
+impl Cache {
+  pub fn new() -> Self { Cache::default() }
+
+  /// The latest known user
+  pub fn user(&self, user_id: i64) -> Option<User> { find(&self.users, user_id) }
+
+  /// The latest known full info of user
+  pub fn user_full_info(&self, user_id: i64) -> Option<UserFullInfo> { find(&self.user_full_infos, user_id) }
+
+  /// The latest known basic group
+  pub fn basic_group(&self, basic_group_id: i64) -> Option<BasicGroup> { find(&self.basic_groups, basic_group_id) }
+
+  /// The latest known full info of basic group
+  pub fn basic_group_full_info(&self, basic_group_id: i64) -> Option<BasicGroupFullInfo> { find(&self.basic_group_full_infos, basic_group_id) }
+
+  /// The latest known supergroup
+  pub fn supergroup(&self, supergroup_id: i64) -> Option<Supergroup> { find(&self.supergroups, supergroup_id) }
+
+  /// The latest known full info of supergroup
+  pub fn supergroup_full_info(&self, supergroup_id: i64) -> Option<SupergroupFullInfo> { find(&self.supergroup_full_infos, supergroup_id) }
+
+  /// The latest known secret chat
+  pub fn secret_chat(&self, secret_chat_id: i64) -> Option<SecretChat> { find(&self.secret_chats, secret_chat_id) }
+
+  /// The latest known chat
+  pub fn chat(&self, chat_id: i64) -> Option<Chat> { find(&self.chats, chat_id) }
+
+  /// Identifiers of all known chats
+  pub fn chat_ids(&self) -> Vec<i64> { self.chats.read().unwrap().keys().cloned().collect() }
+
+  /// Snapshot of the chats in this chat list, ordered like tdlib, the first is the top of the list
+  pub fn chat_list<T: AsRef<ChatList>>(&self, chat_list: T) -> Vec<ChatListEntry> {
+    let list = match serde_json::to_value(chat_list.as_ref()) {
+      Ok(list) => list,
+      Err(_) => return vec![]
+    };
+    let mut entries: Vec<ChatListEntry> = self.chats.read().unwrap().iter()
+      .filter_map(|(&chat_id, chat)| {
+        chat["positions"].as_array()?.iter()
+          .find(|position| same_list(&position["list"], &list))
+          .map(|position| ChatListEntry {
+            chat_id,
+            order: int64(&position["order"]),
+            is_pinned: position["is_pinned"].as_bool().unwrap_or(false),
+          })
+      })
+      .filter(|entry| entry.order != 0)
+      .collect();
+    // ordered by (order, chat_id) in descending order
+    entries.sort_by_key(|entry| std::cmp::Reverse((entry.order, entry.chat_id)));
+    entries
+  }
+
+  /// Identifiers of all known users
+  pub fn user_ids(&self) -> Vec<i64> { self.users.read().unwrap().keys().cloned().collect() }
+
+  /// the cache is changed by the objects of this td type
+  pub(crate) fn keeps(td_type: &str) -> bool {
+    match td_type {
+      "updateUser" | "updateUserStatus" | "updateUserFullInfo" | "updateBasicGroup" | "updateBasicGroupFullInfo"
+      | "updateSupergroup" | "updateSupergroupFullInfo" | "updateSecretChat" | "updateNewChat" => true,
+      "updateChatOnlineMemberCount" => false,
+      _ => td_type.starts_with("updateChat"),
+    }
+  }
+
+  /// apply an object returned by tdlib to the cache, the receive loop parses it only if `Cache::keeps` its type
+  pub(crate) fn handle(&self, td_type: &str, value: &Value) {
+    match td_type {
+      "updateUser" => insert(&self.users, &value["user"]),
+      "updateUserStatus" => update(&self.users, &value["user_id"], |user| user["status"] = value["status"].clone()),
+      "updateUserFullInfo" => insert_with(&self.user_full_infos, &value["user_id"], &value["user_full_info"]),
+      "updateBasicGroup" => insert(&self.basic_groups, &value["basic_group"]),
+      "updateBasicGroupFullInfo" => insert_with(&self.basic_group_full_infos, &value["basic_group_id"], &value["basic_group_full_info"]),
+      "updateSupergroup" => insert(&self.supergroups, &value["supergroup"]),
+      "updateSupergroupFullInfo" => insert_with(&self.supergroup_full_infos, &value["supergroup_id"], &value["supergroup_full_info"]),
+      "updateSecretChat" => insert(&self.secret_chats, &value["secret_chat"]),
+      "updateNewChat" => insert(&self.chats, &value["chat"]),
+      "updateChatPosition" => update(&self.chats, &value["chat_id"], |chat| set_position(chat, &value["position"])),
+      // not a field of chat
+      "updateChatOnlineMemberCount" => {}
+      _ if td_type.starts_with("updateChat") => update(&self.chats, &value["chat_id"], |chat| {
+        // other updateChat* updates have the same field names as chat
+        if let (Some(chat), Some(fields)) = (chat.as_object_mut(), value.as_object()) {
+          for (key, field) in fields {
+            if key == "@type" || key == "chat_id" { continue; }
+            chat.insert(key.clone(), field.clone());
+          }
+        }
+      }),
+      _ => {}
+    }
+  }
+}
+
+/// A chat in a chat list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChatListEntry {
+  chat_id: i64,
+  order: i64,
+  is_pinned: bool,
+}
+
+impl ChatListEntry {
+  /// Chat identifier
+  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// A parameter used to determine order of the chat in the chat list
+  pub fn order(&self) -> i64 { self.order }
+  /// True, if the chat is pinned in the chat list
+  pub fn is_pinned(&self) -> bool { self.is_pinned }
+}
+
+
+fn find<T: DeserializeOwned>(map: &RwLock<HashMap<i64, Value>>, id: i64) -> Option<T> {
+  map.read().unwrap().get(&id).and_then(|value| serde_json::from_value(value.clone()).ok())
+}
+
+fn insert(map: &RwLock<HashMap<i64, Value>>, object: &Value) {
+  insert_with(map, &object["id"], object)
+}
+
+fn insert_with(map: &RwLock<HashMap<i64, Value>>, id: &Value, object: &Value) {
+  if let Some(id) = id.as_i64() {
+    map.write().unwrap().insert(id, object.clone());
+  }
+}
+
+fn update<F: FnOnce(&mut Value)>(map: &RwLock<HashMap<i64, Value>>, id: &Value, fnc: F) {
+  if let Some(id) = id.as_i64() {
+    if let Some(object) = map.write().unwrap().get_mut(&id) {
+      fnc(object);
+    }
+  }
+}
+
+/// replace the chat position in the same chat list, a position with order 0 removes the chat from the list
+fn set_position(chat: &mut Value, position: &Value) {
+  let removed = int64(&position["order"]) == 0;
+  if !chat["positions"].is_array() {
+    chat["positions"] = Value::Array(vec![]);
+  }
+  if let Some(positions) = chat["positions"].as_array_mut() {
+    positions.retain(|other| !same_list(&other["list"], &position["list"]));
+    if !removed {
+      positions.push(position.clone());
+    }
+  }
+}
+
+fn same_list(a: &Value, b: &Value) -> bool {
+  a["@type"] == b["@type"] && a["chat_filter_id"] == b["chat_filter_id"]
+}
+
+/// tdlib returns int64 as string
+fn int64(value: &Value) -> i64 {
+  match value {
+    Value::String(text) => text.parse().unwrap_or(0),
+    _ => value.as_i64().unwrap_or(0),
+  }
+}
@@ -0,0 +1,217 @@
+use std::collections::{HashSet, VecDeque};
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+
+/// Iterator of all chats in a chat list, created by `Api::iter_chats`.
+///
+/// Drives `getChats` page by page until tdlib returns no more chats, and yields the fully loaded
+/// `Chat` (`getChat`) in the order of the chat list.
+#[derive(Debug)]
+pub struct ChatsIter {
+  api: Api,
+  chat_list: ChatList,
+  page_size: i64,
+  offset_order: isize,
+  offset_chat_id: i64,
+  buffer: VecDeque<i64>,
+  seen: HashSet<i64>,
+  finished: bool,
+}
+
+impl Api {
+  /// Iterate all chats in this chat list, e.g. `ChatList::main(ChatListMain::builder())`.
+  pub fn iter_chats<C: AsRef<ChatList>>(&self, chat_list: C) -> ChatsIter {
+    ChatsIter {
+      api: self.clone(),
+      chat_list: chat_list.as_ref().clone(),
+      page_size: 100,
+      offset_order: isize::MAX,
+      offset_chat_id: 0,
+      buffer: VecDeque::new(),
+      seen: HashSet::new(),
+      finished: false,
+    }
+  }
+}
+
+impl ChatsIter {
+  /// Number of chats requested by one `getChats`, default is 100.
+  pub fn page_size(mut self, page_size: i64) -> Self {
+    self.page_size = page_size;
+    self
+  }
+
+  fn load_page(&mut self) -> RTDResult<()> {
+    let get_chats = GetChats::builder()
+      .chat_list(&self.chat_list)
+      .offset_order(self.offset_order)
+      .offset_chat_id(self.offset_chat_id)
+      .limit(self.page_size)
+      .build();
+    let chats: Chats = self.api.request(get_chats)?.wait()?;
+    let seen = &mut self.seen;
+    self.buffer.extend(chats.chat_ids().iter().filter(|&&chat_id| seen.insert(chat_id)));
+    // no new chat returned, this is the end of the chat list
+    if self.buffer.is_empty() {
+      self.finished = true;
+    }
+    Ok(())
+  }
+
+  fn next_chat(&mut self) -> RTDResult<Option<Chat>> {
+    if self.buffer.is_empty() {
+      self.load_page()?;
+    }
+    let chat_id = match self.buffer.pop_front() {
+      Some(chat_id) => chat_id,
+      None => return Ok(None),
+    };
+    let chat: Chat = self.api.request(GetChat::builder().chat_id(chat_id).build())?.wait()?;
+    self.offset_chat_id = chat.id();
+    if let Some(position) = chat.positions().iter().find(|position| same_chat_list(position.list(), &self.chat_list)) {
+      self.offset_order = position.order();
+    }
+    Ok(Some(chat))
+  }
+}
+
+impl Iterator for ChatsIter {
+  type Item = RTDResult<Chat>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.finished { return None; }
+    match self.next_chat() {
+      Ok(Some(chat)) => Some(Ok(chat)),
+      Ok(None) => None,
+      Err(e) => {
+        self.finished = true;
+        Some(Err(e))
+      }
+    }
+  }
+}
+
+
+
+/// Iterator of the members of a basic group or supergroup chat, created by `Api::iter_chat_members`.
+///
+/// Basic group members are returned by `getBasicGroupFullInfo` at once, supergroup members are
+/// requested page by page by `getSupergroupMembers`. Private and secret chats have no members to iterate.
+#[derive(Debug)]
+pub struct ChatMembersIter {
+  api: Api,
+  chat_id: i64,
+  filter: SupergroupMembersFilter,
+  page_size: i64,
+  supergroup_id: Option<i64>,
+  offset: i64,
+  buffer: VecDeque<ChatMember>,
+  started: bool,
+  finished: bool,
+}
+
+impl Api {
+  /// Iterate members of this basic group or supergroup chat.
+  pub fn iter_chat_members(&self, chat_id: i64) -> ChatMembersIter {
+    ChatMembersIter {
+      api: self.clone(),
+      chat_id,
+      filter: SupergroupMembersFilter::recent(SupergroupMembersFilterRecent::builder()),
+      page_size: 200,
+      supergroup_id: None,
+      offset: 0,
+      buffer: VecDeque::new(),
+      started: false,
+      finished: false,
+    }
+  }
+}
+
+impl ChatMembersIter {
+  /// Filter of supergroup members, default is `SupergroupMembersFilterRecent`.
+  /// Basic groups don't support filter, all members are returned.
+  pub fn filter<T: AsRef<SupergroupMembersFilter>>(mut self, filter: T) -> Self {
+    self.filter = filter.as_ref().clone();
+    self
+  }
+
+  /// Number of members requested by one `getSupergroupMembers`, tdlib allows up to 200.
+  pub fn page_size(mut self, page_size: i64) -> Self {
+    self.page_size = page_size;
+    self
+  }
+
+  fn start(&mut self) -> RTDResult<()> {
+    self.started = true;
+    let chat: Chat = self.api.request(GetChat::builder().chat_id(self.chat_id).build())?.wait()?;
+    if let Some(basic_group) = chat.type_().as_basic_group() {
+      let get_full_info = GetBasicGroupFullInfo::builder().basic_group_id(basic_group.basic_group_id()).build();
+      let full_info: BasicGroupFullInfo = self.api.request(get_full_info)?.wait()?;
+      self.buffer.extend(full_info.members().iter().cloned());
+      self.finished = true;
+      return Ok(());
+    }
+    if let Some(supergroup) = chat.type_().as_supergroup() {
+      self.supergroup_id = Some(supergroup.supergroup_id());
+      return Ok(());
+    }
+    Err(RTDError::custom("Only basic group and supergroup chats have members"))
+  }
+
+  fn load_page(&mut self, supergroup_id: i64) -> RTDResult<()> {
+    let get_members = GetSupergroupMembers::builder()
+      .supergroup_id(supergroup_id)
+      .filter(&self.filter)
+      .offset(self.offset)
+      .limit(self.page_size)
+      .build();
+    let members: ChatMembers = self.api.request(get_members)?.wait()?;
+    let count = members.members().len() as i64;
+    self.offset += count;
+    self.buffer.extend(members.members().iter().cloned());
+    // tdlib limits the number of members can be get, it may return less than total count
+    if count == 0 || self.offset >= members.total_count() {
+      self.finished = true;
+    }
+    Ok(())
+  }
+
+  fn next_member(&mut self) -> RTDResult<Option<ChatMember>> {
+    if !self.started {
+      self.start()?;
+    }
+    if self.buffer.is_empty() && !self.finished {
+      if let Some(supergroup_id) = self.supergroup_id {
+        self.load_page(supergroup_id)?;
+      }
+    }
+    Ok(self.buffer.pop_front())
+  }
+}
+
+impl Iterator for ChatMembersIter {
+  type Item = RTDResult<ChatMember>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    match self.next_member() {
+      Ok(member) => member.map(Ok),
+      Err(e) => {
+        self.finished = true;
+        self.buffer.clear();
+        Some(Err(e))
+      }
+    }
+  }
+}
+
+/// Is the two chat lists the same list
+pub(crate) fn same_chat_list(a: &ChatList, b: &ChatList) -> bool {
+  if a.td_name() != b.td_name() { return false; }
+  match (a.as_filter(), b.as_filter()) {
+    (Some(a), Some(b)) => a.chat_filter_id() == b.chat_filter_id(),
+    _ => true,
+  }
+}
@@ -1,5 +1,5 @@
 use std::sync::{Arc, Mutex};
-use std::thread::JoinHandle;
+use std::thread::{self, JoinHandle};
 
 use rtdlib::Tdlib;
 
@@ -106,6 +106,9 @@ impl Client {
 
   /// Start a Client.
   ///
+  /// After start the version of the linked tdlib is checked if set by `ApiBuilder::tdlib_version_check`. The returned
+  /// handle finishes after the receive loop and the threads waiting for the authorization stop.
+  ///
   /// # Examples
   ///
   /// ```
@@ -116,7 +119,32 @@ impl Client {
   pub fn start(self) -> JoinHandle<()> {
     let lout = self.listener.lout();
     let tdrecv = TdRecv::new();
-    tdrecv.start(Arc::new(self.api), self.stop_flag.clone(), Arc::new(lout))
+    let api = self.api.clone();
+    // the threads waiting for the authorization, they stop with the client
+    #[allow(unused_mut)]
+    let mut on_ready: Vec<JoinHandle<()>> = vec![crate::options::start(&api), crate::presence::start(&api), crate::storage::start(&api)]
+      .into_iter().flatten().collect();
+    #[cfg(feature = "outbox")]
+    on_ready.extend(crate::outbox::start(&api));
+    let receiver = tdrecv.start(Arc::new(self.api), self.stop_flag.clone(), Arc::new(lout));
+    if let Some(timeout) = api.version_check() {
+      std::thread::spawn(move || {
+        if let Err(e) = api.check_tdlib_version(timeout) {
+          warn!("Can not check the tdlib version: {}", e);
+        }
+      });
+    }
+    thread::spawn(move || {
+      let received = receiver.join();
+      for handle in on_ready {
+        if handle.join().is_err() {
+          error!("A thread waiting for the authorization panicked");
+        }
+      }
+      if let Err(panic) = received {
+        std::panic::resume_unwind(panic);
+      }
+    })
   }
 
   /// Start a daemon Client.
@@ -151,4 +179,10 @@ impl Client {
   pub fn listener(&mut self) -> &mut Listener {
     &mut self.listener
   }
+
+  /// Store a request in the outbox of the api, see `Api::enqueue`
+  #[cfg(feature = "outbox")]
+  pub fn enqueue<Fnc: rtdlib::types::RFunction>(&self, fnc: Fnc) -> rtdlib::errors::RTDResult<u64> {
+    self.api.enqueue(fnc)
+  }
 }
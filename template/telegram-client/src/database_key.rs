@@ -0,0 +1,76 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{compiler_fence, Ordering};
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+
+/// Encryption key of the local tdlib database, see `ApiBuilder::database_key`.
+///
+/// The key is never printed, and its memory is overwritten by zeros when it's dropped; the copy serialized
+/// into the request to tdlib is not.
+pub struct DatabaseKey {
+  key: Vec<u8>,
+}
+
+impl DatabaseKey {
+  pub fn new(key: Vec<u8>) -> Self {
+    Self { key }
+  }
+
+  /// An empty key, the database isn't encrypted
+  pub fn empty() -> Self {
+    Self::new(vec![])
+  }
+
+  /// The whole content of a file is the key, e.g. `head -c 32 /dev/urandom > tdlib.key`
+  pub fn from_file<P: AsRef<Path>>(path: P) -> RTDResult<Self> {
+    Ok(Self::new(fs::read(path)?))
+  }
+
+  /// The key loaded by a callback, e.g. from the keyring of the OS
+  pub fn from_source<F: FnOnce() -> RTDResult<Vec<u8>>>(source: F) -> RTDResult<Self> {
+    Ok(Self::new(source()?))
+  }
+
+  pub fn is_empty(&self) -> bool { self.key.is_empty() }
+
+  /// A copy of the key as sent to tdlib
+  pub fn td_bytes(&self) -> TdBytes {
+    TdBytes::new(self.key.clone())
+  }
+}
+
+impl fmt::Debug for DatabaseKey {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "DatabaseKey(<{} bytes>)", self.key.len())
+  }
+}
+
+impl Drop for DatabaseKey {
+  fn drop(&mut self) {
+    for byte in self.key.iter_mut() {
+      // volatile, the writes to the memory freed next are not optimized out
+      unsafe { std::ptr::write_volatile(byte, 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+  }
+}
+
+impl Api {
+  /// The key of `ApiBuilder::database_key` as sent to tdlib, `None` if the handler answers it
+  pub(crate) fn database_key_bytes(&self) -> Option<TdBytes> {
+    self.database_key().lock().unwrap().as_ref().map(|key| key.td_bytes())
+  }
+
+  /// Change the encryption key of the database (`setDatabaseEncryptionKey`) after the authorization and wait
+  /// for it; the old key is zeroized, the new one is kept as `ApiBuilder::database_key`.
+  pub fn rotate_database_key(&self, key: DatabaseKey) -> RTDResult<()> {
+    let _: Ok = self.request(SetDatabaseEncryptionKey::builder().new_encryption_key(key.td_bytes()).build())?.wait()?;
+    *self.database_key().lock().unwrap() = Some(key);
+    Ok(())
+  }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use rtdlib::types::*;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::api::Api;
+
+/// A downloaded file deleted by `DownloadLru`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvictedFile {
+  file_id: i64,
+  path: String,
+  size: i64,
+}
+
+impl EvictedFile {
+  pub fn file_id(&self) -> i64 { self.file_id }
+
+  /// The local path the file had
+  pub fn path(&self) -> &String { &self.path }
+
+  /// Size of the file in bytes
+  pub fn size(&self) -> i64 { self.size }
+}
+
+#[derive(Debug)]
+struct Download {
+  path: String,
+  size: i64,
+  completed: bool,
+  last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct DownloadState {
+  files: HashMap<i64, Download>,
+  clock: u64,
+  listeners: Vec<Sender<EvictedFile>>,
+}
+
+/// The files downloaded by `Api::download_stream`, the least recently used are deleted (`deleteFile`) when their
+/// total size is over the budget, see `ApiBuilder::download_budget`.
+///
+/// Only the completed downloads are deleted, the files downloaded otherwise are never tracked. The sizes are
+/// kept up to date by `updateFile` in the receive loop.
+#[derive(Debug)]
+pub struct DownloadLru {
+  budget: u64,
+  state: Mutex<DownloadState>,
+}
+
+impl DownloadLru {
+  pub fn new(budget: u64) -> Self {
+    Self { budget, state: Mutex::new(DownloadState::default()) }
+  }
+
+  /// Total size of the downloaded files kept, in bytes
+  pub fn budget(&self) -> u64 { self.budget }
+
+  /// Total size of the tracked files, in bytes
+  pub fn used(&self) -> u64 {
+    self.state.lock().unwrap().files.values().map(|download| download.size.max(0) as u64).sum()
+  }
+
+  /// Number of the tracked files
+  pub fn len(&self) -> usize { self.state.lock().unwrap().files.len() }
+
+  pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+  /// Track this file or mark it as used now, it's deleted after the files used before
+  pub fn touch(&self, file_id: i64) {
+    let mut state = self.state.lock().unwrap();
+    state.clock += 1;
+    let clock = state.clock;
+    state.files.entry(file_id)
+      .or_insert(Download { path: String::new(), size: 0, completed: false, last_used: clock })
+      .last_used = clock;
+  }
+
+  /// Receive the files deleted from now on, stop by drop the receiver
+  pub fn evictions(&self) -> Receiver<EvictedFile> {
+    let (sender, receiver) = mpsc::channel();
+    self.state.lock().unwrap().listeners.push(sender);
+    receiver
+  }
+
+  /// apply an `updateFile` of a tracked file, then delete the files over the budget
+  pub(crate) fn handle(&self, api: &Api, value: &Value) {
+    let update = match UpdateFile::deserialize(value) {
+      Ok(update) => update,
+      Err(e) => {
+        warn!("Can not deserialize updateFile: {:?}", e);
+        return;
+      }
+    };
+    let file = update.file();
+    let mut state = self.state.lock().unwrap();
+    let local = file.local();
+    if local.path().is_empty() && !local.is_downloading_active() {
+      // deleted, or never downloaded
+      state.files.remove(&file.id());
+      return;
+    }
+    match state.files.get_mut(&file.id()) {
+      Some(download) => {
+        download.path = local.path().clone();
+        download.size = local.downloaded_size();
+        download.completed = local.is_downloading_completed();
+      }
+      None => return,
+    }
+    self.evict(api, &mut state, file.id());
+  }
+
+  /// delete the least recently used completed files but the `current` one until the total size is in the budget
+  fn evict(&self, api: &Api, state: &mut DownloadState, current: i64) {
+    let mut used: u64 = state.files.values().map(|download| download.size.max(0) as u64).sum();
+    while used > self.budget {
+      let oldest = state.files.iter()
+        .filter(|(file_id, download)| download.completed && **file_id != current)
+        .min_by_key(|(_, download)| download.last_used)
+        .map(|(file_id, _)| *file_id);
+      let file_id = match oldest {
+        Some(file_id) => file_id,
+        None => return,
+      };
+      let download = match state.files.remove(&file_id) {
+        Some(download) => download,
+        None => return,
+      };
+      used -= download.size.max(0) as u64;
+      // the receive loop can't wait for the response
+      if let Err(e) = api.send(DeleteFile::builder().file_id(file_id).build()) {
+        warn!("Can not delete the file {}: {}", file_id, e);
+        continue;
+      }
+      let evicted = EvictedFile { file_id, path: download.path, size: download.size };
+      state.listeners.retain(|listener| listener.send(evicted.clone()).is_ok());
+    }
+  }
+}
@@ -0,0 +1,251 @@
+use std::io;
+use std::path::Path;
+use std::sync::mpsc::Receiver;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+use crate::observer::Pending;
+
+/// Transfer progress of a file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+  transferred: i64,
+  total: i64,
+}
+
+impl Progress {
+  pub(crate) fn new(transferred: i64, total: i64) -> Self {
+    Self { transferred, total }
+  }
+
+  fn uploaded(file: &File) -> Self {
+    Self {
+      transferred: file.remote().uploaded_size().unwrap_or(0),
+      total: file.size().unwrap_or_else(|| file.expected_size()),
+    }
+  }
+
+  pub(crate) fn downloaded(file: &File) -> Self {
+    Self {
+      transferred: file.local().downloaded_size(),
+      total: file.size().unwrap_or_else(|| file.expected_size()),
+    }
+  }
+
+  /// Transferred size in bytes
+  pub fn transferred(&self) -> i64 { self.transferred }
+
+  /// Total size in bytes; 0 if unknown
+  pub fn total(&self) -> i64 { self.total }
+
+  /// Transferred percent (0-100); 0 if total size is unknown
+  pub fn percent(&self) -> f64 {
+    if self.total <= 0 { return 0.0; }
+    (self.transferred as f64 / self.total as f64) * 100.0
+  }
+}
+
+
+/// A local file uploading to telegram cloud, created by `Api::upload`.
+///
+/// Iterate it to get the upload progress, the iteration finishes when the upload is completed.
+#[derive(Debug)]
+pub struct Uploading {
+  api: Api,
+  pending: Option<Pending<File>>,
+  updates: Receiver<String>,
+  file: Option<File>,
+  finished: bool,
+}
+
+impl Api {
+  /// Asynchronously uploads a file to the cloud without sending it in a message (`uploadFile`).
+  ///
+  /// The uploaded file can be sent later by `InputFileId` with the id of the `File` returned by `Uploading::wait`,
+  /// so large media can be uploaded ahead of message sending.
+  pub fn upload<P: AsRef<Path>, T: AsRef<FileType>>(&self, path: P, file_type: T) -> RTDResult<Uploading> {
+    let path = path.as_ref().to_string_lossy().into_owned();
+    let upload_file = UploadFile::builder()
+      .file(InputFile::local(InputFileLocal::builder().path(path)))
+      .file_type(file_type)
+      .priority(1)
+      .build();
+    // watch before send, otherwise the first updates may be missed
+    let updates = self.watch("updateFile");
+    let pending = self.request(upload_file)?;
+    Ok(Uploading {
+      api: self.clone(),
+      pending: Some(pending),
+      updates,
+      file: None,
+      finished: false,
+    })
+  }
+}
+
+impl Uploading {
+  /// The latest known state of the uploading file; `None` until tdlib accepts the upload
+  pub fn file(&self) -> Option<&File> { self.file.as_ref() }
+
+  /// Block until the upload is completed, return the remote file.
+  pub fn wait(mut self) -> RTDResult<File> {
+    for progress in self.by_ref() {
+      progress?;
+    }
+    self.file.take().ok_or(RTDError::custom("Upload finished without file"))
+  }
+
+  /// Stop the upload (`cancelUploadFile`).
+  pub fn cancel(mut self) -> RTDResult<()> {
+    self.finished = true;
+    let file_id = match &self.file {
+      Some(file) => file.id(),
+      None => match self.pending.take() {
+        Some(pending) => pending.wait()?.id(),
+        None => return Ok(()),
+      }
+    };
+    self.api.cancel_upload_file(CancelUploadFile::builder().file_id(file_id).build())
+  }
+
+  fn next_file(&mut self) -> RTDResult<File> {
+    if let Some(pending) = self.pending.take() {
+      return pending.wait();
+    }
+    let file_id = self.file.as_ref().map_or(0, |file| file.id());
+    let file = next_update_of(&self.updates, file_id)?;
+    if !file.remote().is_uploading_active() && !file.remote().is_uploading_completed() {
+      return Err(RTDError::custom("Upload is canceled or failed"));
+    }
+    Ok(file)
+  }
+}
+
+impl Iterator for Uploading {
+  type Item = RTDResult<Progress>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.finished { return None; }
+    match self.next_file() {
+      Ok(file) => {
+        self.finished = file.remote().is_uploading_completed();
+        let progress = Progress::uploaded(&file);
+        self.file = Some(file);
+        Some(Ok(progress))
+      }
+      Err(e) => {
+        self.finished = true;
+        Some(Err(e))
+      }
+    }
+  }
+}
+
+
+/// A file downloading from telegram, created by `Api::download_stream`.
+///
+/// Implements `std::io::Read`, reads the part already downloaded by tdlib (`readFilePart`) and blocks
+/// until the next part is downloaded, so a large file can be piped before the download is completed.
+#[derive(Debug)]
+pub struct DownloadStream {
+  api: Api,
+  updates: Receiver<String>,
+  file: File,
+  position: i64,
+}
+
+impl Api {
+  /// Downloads a file from the cloud (`downloadFile`) and return a reader of it.
+  pub fn download_stream(&self, file_id: i64) -> RTDResult<DownloadStream> {
+    let download_file = DownloadFile::builder()
+      .file_id(file_id)
+      .priority(1)
+      .offset(0)
+      .limit(0)
+      .synchronous(false)
+      .build();
+    if let Some(downloads) = self.downloads() {
+      downloads.touch(file_id);
+    }
+    let updates = self.watch("updateFile");
+    let file = self.request(download_file)?.wait()?;
+    Ok(DownloadStream {
+      api: self.clone(),
+      updates,
+      file,
+      position: 0,
+    })
+  }
+}
+
+impl DownloadStream {
+  /// The latest known state of the downloading file
+  pub fn file(&self) -> &File { &self.file }
+
+  /// Current download progress
+  pub fn progress(&self) -> Progress { Progress::downloaded(&self.file) }
+
+  /// Stop the download (`cancelDownloadFile`), the local downloaded part is kept.
+  pub fn cancel(self) -> RTDResult<()> {
+    self.api.cancel_download_file(CancelDownloadFile::builder().file_id(self.file.id()).only_if_pending(false).build())
+  }
+
+  /// size of the continuous downloaded part starts from the beginning of the file
+  fn available(&self) -> i64 {
+    let local = self.file.local();
+    if local.download_offset() == 0 { local.downloaded_prefix_size() } else { 0 }
+  }
+
+  fn read_part(&mut self, buf: &mut [u8]) -> RTDResult<usize> {
+    loop {
+      let available = self.available();
+      if self.position < available {
+        let count = std::cmp::min(buf.len() as i64, available - self.position);
+        let read_file_part = ReadFilePart::builder()
+          .file_id(self.file.id())
+          .offset(self.position)
+          .count(count)
+          .build();
+        let file_part: FilePart = self.api.request(read_file_part)?.wait()?;
+        let data = file_part.data();
+        let size = std::cmp::min(data.len(), buf.len());
+        buf[..size].copy_from_slice(&data[..size]);
+        self.position += size as i64;
+        return Ok(size);
+      }
+
+      let local = self.file.local();
+      if local.is_downloading_completed() {
+        return Ok(0);
+      }
+      if !local.is_downloading_active() {
+        return Err(RTDError::custom("Download is canceled or failed"));
+      }
+      self.file = next_update_of(&self.updates, self.file.id())?;
+    }
+  }
+}
+
+impl io::Read for DownloadStream {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    if buf.is_empty() { return Ok(0); }
+    self.read_part(buf).map_err(|e| io::Error::other(e.to_string()))
+  }
+}
+
+
+/// block until receive the next `updateFile` of this file
+fn next_update_of(updates: &Receiver<String>, file_id: i64) -> RTDResult<File> {
+  loop {
+    let json = match updates.recv() {
+      Ok(json) => json,
+      Err(_) => return Err(RTDError::custom("The receive loop is stopped, file updates will not be received")),
+    };
+    let update = UpdateFile::from_json(json)?;
+    if update.file().id() == file_id {
+      return Ok(update.file().clone());
+    }
+  }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use crate::scan::Scanned;
+
+/// Which messages `FilterSet` lets through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageDirection {
+  All,
+  Incoming,
+  Outgoing,
+}
+
+/// Filters of the updates, applied by the receiver thread before the updates are queued for the
+/// listener or sent to subscriptions, see `ApiBuilder::filter`.
+///
+/// The filters only read the `@type`, `chat_id` and `message.is_outgoing` fields, a discarded update
+/// is never deserialized. Responses of requests are never filtered. The helpers watching updates,
+/// like `Api::upload` (`updateFile`), don't receive the discarded updates either.
+#[derive(Debug, Clone)]
+pub struct FilterSet {
+  allow_types: Option<HashSet<String>>,
+  deny_types: HashSet<String>,
+  allow_chats: Option<HashSet<i64>>,
+  deny_chats: HashSet<i64>,
+  direction: MessageDirection,
+}
+
+impl Default for FilterSet {
+  fn default() -> Self {
+    Self {
+      allow_types: None,
+      deny_types: HashSet::new(),
+      allow_chats: None,
+      deny_chats: HashSet::new(),
+      direction: MessageDirection::All,
+    }
+  }
+}
+
+impl FilterSet {
+  pub fn builder() -> FilterSetBuilder {
+    FilterSetBuilder { inner: FilterSet::default() }
+  }
+
+  /// Is the update let through
+  pub(crate) fn accept(&self, scanned: &Scanned) -> bool {
+    if let Some(td_type) = scanned.td_type() {
+      if self.deny_types.contains(td_type) { return false; }
+      if self.allow_types.as_ref().is_some_and(|types| !types.contains(td_type)) { return false; }
+    }
+    // updates without chat, like updateUser, are not filtered by chat
+    if let Some(chat_id) = scanned.chat_id() {
+      if self.deny_chats.contains(&chat_id) { return false; }
+      if self.allow_chats.as_ref().is_some_and(|chats| !chats.contains(&chat_id)) { return false; }
+    }
+    !matches!((self.direction, scanned.is_outgoing()),
+      (MessageDirection::Incoming, Some(true)) | (MessageDirection::Outgoing, Some(false)))
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct FilterSetBuilder {
+  inner: FilterSet
+}
+
+impl FilterSetBuilder {
+  pub fn build(&self) -> FilterSet { self.inner.clone() }
+
+  /// Only let through the allowed td types, e.g. `updateNewMessage`; all types by default
+  pub fn allow_type<S: AsRef<str>>(&mut self, td_type: S) -> &mut Self {
+    self.inner.allow_types.get_or_insert_with(HashSet::new).insert(td_type.as_ref().to_string());
+    self
+  }
+
+  /// Discard this td type
+  pub fn deny_type<S: AsRef<str>>(&mut self, td_type: S) -> &mut Self {
+    self.inner.deny_types.insert(td_type.as_ref().to_string());
+    self
+  }
+
+  /// Only let through the updates of the allowed chats; all chats by default
+  pub fn allow_chat(&mut self, chat_id: i64) -> &mut Self {
+    self.inner.allow_chats.get_or_insert_with(HashSet::new).insert(chat_id);
+    self
+  }
+
+  /// Discard the updates of this chat
+  pub fn deny_chat(&mut self, chat_id: i64) -> &mut Self {
+    self.inner.deny_chats.insert(chat_id);
+    self
+  }
+
+  /// Only let through incoming or outgoing messages; all messages by default
+  pub fn direction(&mut self, direction: MessageDirection) -> &mut Self {
+    self.inner.direction = direction;
+    self
+  }
+}
+
+impl AsRef<FilterSet> for FilterSet {
+  fn as_ref(&self) -> &FilterSet { self }
+}
+
+impl AsRef<FilterSet> for FilterSetBuilder {
+  fn as_ref(&self) -> &FilterSet { &self.inner }
+}
@@ -0,0 +1,304 @@
+//! Group calls (voice and video chats) of the schemas with the json payload of `joinGroupCall`, empty for an
+//! older schema.
+{% set group_calls = has_token(token_name="joinGroupCall", field="audio_source_id") %}{% if group_calls %}
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+use crate::scan::decode;
+
+/// How often the update forwarder of a joined group call checks it's left
+const GROUP_CALL_POLL: Duration = Duration::from_millis(500);
+
+/// Participants loaded at once after joining, the others are loaded by `GroupCallSession::load_participants`
+const GROUP_CALL_PARTICIPANTS: i64 = 100;
+
+/// The audio source and the payload an engine joins a group call with, see `GroupCallEngine::join_parameters`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupCallJoin {
+  audio_source_id: i64,
+  payload: String,
+}
+
+impl GroupCallJoin {
+  pub fn new<S: AsRef<str>>(audio_source_id: i64, payload: S) -> Self {
+    Self { audio_source_id, payload: payload.as_ref().to_string() }
+  }
+
+  /// Synchronization source of the audio channel of the engine
+  pub fn audio_source_id(&self) -> i64 { self.audio_source_id }
+
+  /// The join payload of the engine, e.g. the json of tgcalls
+  pub fn payload(&self) -> &String { &self.payload }
+}
+
+/// The media plane of a group call, plugged in by `Api::join_group_call_with`.
+///
+/// tdlib only signals the call, the audio and the video are sent and received by an engine, e.g. tgcalls or
+/// a WebRTC stack: it makes the join payload, connects by the response of tdlib and follows the participants.
+pub trait GroupCallEngine: Send {
+  /// The audio source and the join payload of the engine, sent by `joinGroupCall`
+  fn join_parameters(&mut self, group_call_id: i64) -> RTDResult<GroupCallJoin>;
+
+  /// Connect the media by the join response of tdlib, the payload for the engine
+  fn connect(&mut self, response: &str) -> RTDResult<()>;
+
+  /// The microphone of the current user is muted or unmuted by `GroupCallSession::set_muted`
+  fn set_muted(&mut self, _is_muted: bool) {}
+
+  /// A participant joined, changed or left, e.g. to map its audio source to a stream
+  fn participant_updated(&mut self, _participant: &GroupCallParticipant) {}
+
+  /// The group call changed; `GroupCall::need_rejoin` if the connection is lost, leave and join again
+  fn call_updated(&mut self, _group_call: &GroupCall) {}
+
+  /// Stop the media, the group call is left or ended
+  fn disconnect(&mut self);
+}
+
+/// An update of a group call, of `GroupCallUpdates`
+#[derive(Debug, Clone)]
+pub enum GroupCallEvent {
+  /// The group call changed (`updateGroupCall`)
+  Call(GroupCall),
+  /// A participant joined, changed or left (`updateGroupCallParticipant`)
+  Participant(GroupCallParticipant),
+}
+
+/// The updates of a group call, created by `Api::group_call_updates`; iterate it to receive them, drop it to
+/// unsubscribe.
+///
+/// tdlib sends the updates of the participants only after the group call is received by `Api::group_call`,
+/// and only if it's joined or being joined.
+#[derive(Debug)]
+pub struct GroupCallUpdates {
+  group_call_id: i64,
+  updates: Receiver<String>,
+}
+
+impl GroupCallUpdates {
+  pub fn group_call_id(&self) -> i64 { self.group_call_id }
+
+  /// Block until the next update of the group call or timeout, `None` at the timeout or if the receive loop is stopped
+  pub fn recv_timeout(&self, timeout: Duration) -> Option<RTDResult<GroupCallEvent>> {
+    loop {
+      let json = self.updates.recv_timeout(timeout).ok()?;
+      if let Some(event) = self::event(self.group_call_id, &json).transpose() {
+        return Some(event);
+      }
+    }
+  }
+}
+
+impl Iterator for GroupCallUpdates {
+  type Item = RTDResult<GroupCallEvent>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      let json = self.updates.recv().ok()?;
+      if let Some(event) = self::event(self.group_call_id, &json).transpose() {
+        return Some(event);
+      }
+    }
+  }
+}
+
+impl Api {
+  /// Information about a group call (`getGroupCall`), then tdlib sends its updates
+  pub fn group_call(&self, group_call_id: i64) -> RTDResult<GroupCall> {
+    self.request(GetGroupCall::builder().group_call_id(group_call_id).build())?.wait()
+  }
+
+  /// Subscribe the updates of a group call, `updateGroupCall` and `updateGroupCallParticipant`
+  pub fn group_call_updates(&self, group_call_id: i64) -> GroupCallUpdates {
+    GroupCallUpdates { group_call_id, updates: self.observer().watch_all(&["updateGroupCall", "updateGroupCallParticipant"]) }
+  }
+
+  /// Join an active group call as the current user with the media of this engine (`joinGroupCall`), load its
+  /// participants and forward its updates to the engine until it's left.
+  ///
+  /// The engine makes the join payload, then connects by the response; a failed join or connection disconnects
+  /// the engine and leaves the call.
+  pub fn join_group_call_with<E: GroupCallEngine + 'static>(&self, group_call_id: i64, is_muted: bool, engine: E) -> RTDResult<GroupCallSession> {
+    self.join_group_call_as(group_call_id, None, is_muted, "", engine)
+  }
+
+  /// Join an active group call like `Api::join_group_call_with`, as this participant, e.g. a chat of the current user
+  /// in a video chat, `None` as the current user; `invite_hash` if not empty joins without being muted by the
+  /// administrators.
+  pub fn join_group_call_as<S: AsRef<str>, E: GroupCallEngine + 'static>(&self, group_call_id: i64, participant_id: Option<MessageSender>,
+                                                                      is_muted: bool, invite_hash: S, engine: E) -> RTDResult<GroupCallSession> {
+    let mut engine: Box<dyn GroupCallEngine> = Box::new(engine);
+    // tdlib sends the updates of the participants of a group call it received, subscribed before
+    let updates = self.group_call_updates(group_call_id);
+    let group_call = self.group_call(group_call_id)?;
+    if !group_call.is_active() {
+      return Err(RTDError::custom("The group call is not active"));
+    }
+    let join = engine.join_parameters(group_call_id)?;
+    let mut request = JoinGroupCall::builder();
+    request
+      .group_call_id(group_call_id)
+      .audio_source_id(join.audio_source_id())
+      .payload(join.payload())
+      .is_muted(is_muted)
+      .invite_hash(invite_hash.as_ref());
+    if let Some(participant_id) = &participant_id {
+      request.participant_id(participant_id);
+    }
+    let response: Text = self.request(request.build())?.wait()?;
+    if let Err(e) = engine.connect(response.text()) {
+      engine.disconnect();
+      self::leave(self, group_call_id)?;
+      return Err(e);
+    }
+    let participant_id = match participant_id {
+      Some(participant_id) => participant_id,
+      None => MessageSender::user(MessageSenderUser::builder().user_id(self.my_id()?)),
+    };
+    let engine = Arc::new(Mutex::new(engine));
+    let left = Arc::new(AtomicBool::new(false));
+    let forwarder = self::forward(updates, engine.clone(), left.clone());
+    let session = GroupCallSession {
+      api: self.clone(),
+      group_call_id,
+      participant_id,
+      engine,
+      left,
+      forwarder: Some(forwarder),
+    };
+    session.load_participants()?;
+    Ok(session)
+  }
+
+  /// Mute or unmute a participant of a group call for all participants, or only for the current user if it
+  /// can't be muted for all (`toggleGroupCallParticipantIsMuted`)
+  pub fn mute_group_call_participant<P: AsRef<MessageSender>>(&self, group_call_id: i64, participant_id: P, is_muted: bool) -> RTDResult<()> {
+    let _: Ok = self.request(ToggleGroupCallParticipantIsMuted::builder()
+      .group_call_id(group_call_id)
+      .participant_id(participant_id)
+      .is_muted(is_muted)
+      .build())?.wait()?;
+    Ok(())
+  }
+}
+
+/// A group call joined by `Api::join_group_call_with`, with its engine; the call is left when dropped
+pub struct GroupCallSession {
+  api: Api,
+  group_call_id: i64,
+  participant_id: MessageSender,
+  engine: Arc<Mutex<Box<dyn GroupCallEngine>>>,
+  /// the call is left, or it ended and the forwarder disconnected the engine
+  left: Arc<AtomicBool>,
+  forwarder: Option<JoinHandle<()>>,
+}
+
+impl std::fmt::Debug for GroupCallSession {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    f.debug_struct("GroupCallSession")
+      .field("group_call_id", &self.group_call_id)
+      .field("participant_id", &self.participant_id)
+      .field("left", &self.left.load(Ordering::SeqCst))
+      .finish()
+  }
+}
+
+impl GroupCallSession {
+  pub fn group_call_id(&self) -> i64 { self.group_call_id }
+
+  /// The participant the call is joined as
+  pub fn participant_id(&self) -> &MessageSender { &self.participant_id }
+
+  /// Mute or unmute the microphone of the engine, then the participant for the others
+  pub fn set_muted(&self, is_muted: bool) -> RTDResult<()> {
+    self.engine.lock().unwrap().set_muted(is_muted);
+    self.api.mute_group_call_participant(self.group_call_id, &self.participant_id, is_muted)
+  }
+
+  /// Load more participants (`loadGroupCallParticipants`), they are received by the engine
+  pub fn load_participants(&self) -> RTDResult<()> {
+    let _: Ok = self.api.request(LoadGroupCallParticipants::builder()
+      .group_call_id(self.group_call_id)
+      .limit(GROUP_CALL_PARTICIPANTS)
+      .build())?.wait()?;
+    Ok(())
+  }
+
+  /// Leave the group call and disconnect the engine
+  pub fn leave(mut self) -> RTDResult<()> {
+    self.leave_and_join()
+  }
+
+  fn leave_and_join(&mut self) -> RTDResult<()> {
+    let left = self.left.swap(true, Ordering::SeqCst);
+    if let Some(forwarder) = self.forwarder.take() {
+      if forwarder.join().is_err() {
+        error!("The update forwarder of the group call {} panicked", self.group_call_id);
+      }
+    }
+    // left already, or the call ended
+    if left { return Ok(()); }
+    self.engine.lock().unwrap().disconnect();
+    self::leave(&self.api, self.group_call_id)
+  }
+}
+
+impl Drop for GroupCallSession {
+  fn drop(&mut self) {
+    if let Err(e) = self.leave_and_join() {
+      warn!("Can not leave the group call {}: {}", self.group_call_id, e);
+    }
+  }
+}
+
+/// leave the group call and wait for it (`leaveGroupCall`)
+fn leave(api: &Api, group_call_id: i64) -> RTDResult<()> {
+  let _: Ok = api.request(LeaveGroupCall::builder().group_call_id(group_call_id).build())?.wait()?;
+  Ok(())
+}
+
+/// the event of this update json if it's of the group call
+fn event(group_call_id: i64, json: &str) -> RTDResult<Option<GroupCallEvent>> {
+  Ok(match decode::<Update>(json)? {
+    Update::GroupCall(update) if update.group_call().id() == group_call_id => Some(GroupCallEvent::Call(update.group_call().clone())),
+    Update::GroupCallParticipant(update) if update.group_call_id() == group_call_id => Some(GroupCallEvent::Participant(update.participant().clone())),
+    _ => None,
+  })
+}
+
+/// send the updates of the group call to the engine until it's left or ended
+fn forward(updates: GroupCallUpdates, engine: Arc<Mutex<Box<dyn GroupCallEngine>>>, left: Arc<AtomicBool>) -> JoinHandle<()> {
+  thread::spawn(move || {
+    while !left.load(Ordering::SeqCst) {
+      let json = match updates.updates.recv_timeout(GROUP_CALL_POLL) {
+        Ok(json) => json,
+        Err(RecvTimeoutError::Timeout) => continue,
+        Err(RecvTimeoutError::Disconnected) => break,
+      };
+      match self::event(updates.group_call_id, &json) {
+        Ok(Some(GroupCallEvent::Call(group_call))) => {
+          let mut engine = engine.lock().unwrap();
+          engine.call_updated(&group_call);
+          if !group_call.is_active() {
+            // the session doesn't disconnect the engine and leave the ended call again
+            if !left.swap(true, Ordering::SeqCst) {
+              engine.disconnect();
+            }
+            break;
+          }
+        }
+        Ok(Some(GroupCallEvent::Participant(participant))) => engine.lock().unwrap().participant_updated(&participant),
+        Ok(None) => {}
+        Err(e) => warn!("Can not read an update of the group call {}: {}", updates.group_call_id, e),
+      }
+    }
+  })
+}
+{% endif %}
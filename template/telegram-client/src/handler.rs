@@ -2,6 +2,7 @@ use rtdlib::types as rtd_types;
 
 use crate::api::Api;
 use crate::listener::Lout;
+use crate::scan;
 use crate::errors::TGError;
 use crate::tip;
 
@@ -14,7 +15,7 @@ macro_rules! event_handler {
   ($event_name:ident, $td_type:ident) => {
     |api: &Api, lout: &Lout, json: &String| {
       if let Some(ev) = lout.$event_name() {
-        match rtd_types::from_json::<rtd_types::$td_type>(json) {
+        match scan::decode::<rtd_types::$td_type>(json) {
           Ok(t) => {
             if let Err(_e) = ev((api, &t)) {
               if let Some(ev) = lout.exception() { ev((api, &TGError::new("EVENT_HANDLER_ERROR"))); }
@@ -41,15 +42,8 @@ impl<'a> Handler<'a> {
     }
   }
 
-  pub fn handle(&self, json: &'a String) {
-    let td_type = match rtd_types::detect_td_type(json) {
-      Some(t) => t,
-      None => {
-        warn!("{}", tip::data_fail_with_json(json));
-        return;
-      }
-    };
-    if !self.lout.is_support(&td_type) {
+  pub fn handle(&self, td_type: &str, json: &'a String) {
+    if !self.lout.is_support(td_type) {
       warn!("{}", tip::not_have_listener(td_type));
       return;
     }
@@ -60,12 +54,12 @@ impl<'a> Handler<'a> {
       }
     }
 
-    match &td_type[..] {
-{% for token in tokens %}{% if token.blood and token.blood == 'Update' %}      "{{token.name}}" => event_handler!({{token.name  | to_snake}}, {{token.name | to_camel}})(self.api, self.lout, json),
+    match td_type {
+{% for token in tokens %}{% if token.blood and token.blood == 'Update' %}      {{td_cfg(name=token.name)}} "{{token.name}}" => event_handler!({{token.name  | to_snake}}, {{token.name | to_camel}})(self.api, self.lout, json),
 {% endif %}{% endfor %}
-{% for token in tokens %}{% if token.is_return_type %}      "{{token.name}}" => event_handler!({{token.name | to_snake}}, {{token.name | to_camel}})(self.api, self.lout, json),
+{% for token in tokens %}{% if token.is_return_type %}      {{td_cfg(name=token.name)}} "{{token.name}}" => event_handler!({{token.name | to_snake}}, {{token.name | to_camel}})(self.api, self.lout, json),
 {% endif %}{% endfor %}
-{% for name, td_type in listener %}{% set token = find_token(token_name = td_type) %}      "{{token.name | to_snake | to_camel_lowercase}}" => event_handler!({{name | to_snake}}, {{token.name | to_camel}})(self.api, self.lout, json),
+{% for name, td_type in listener %}{% set token = find_token(token_name = td_type) %}      {{td_cfg(name=token.name)}} "{{token.name | to_snake | to_camel_lowercase}}" => event_handler!({{name | to_snake}}, {{token.name | to_camel}})(self.api, self.lout, json),
 {% endfor %}
       _ => {
         warn!("{}", tip::data_fail_with_json(json))
@@ -0,0 +1,121 @@
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rtdlib::errors::*;
+
+const CURSOR: &[u8] = b"cursor";
+
+/// Append-only store of the objects received for the listener, see `ApiBuilder::journal`.
+///
+/// Every object gets a sequence number before it's queued for the listener, the cursor is moved to it
+/// after the listener handled it. The objects after the cursor are queued again when the client starts,
+/// so the updates buffered when the process stopped are handled at least once.
+#[derive(Debug)]
+pub struct Journal {
+  entries: sled::Tree,
+  meta: sled::Tree,
+  last_seq: AtomicU64,
+}
+
+/// An object of the journal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+  seq: u64,
+  td_type: String,
+  json: String,
+}
+
+impl JournalEntry {
+  /// Sequence number, increasing from 1
+  pub fn seq(&self) -> u64 { self.seq }
+  pub fn td_type(&self) -> &String { &self.td_type }
+  pub fn json(&self) -> &String { &self.json }
+}
+
+fn sled_error(err: sled::Error) -> RTDError {
+  RTDError::Io(io::Error::from(err))
+}
+
+impl Journal {
+  /// Open the journal in this directory, create it if not exists
+  pub fn open<P: AsRef<Path>>(path: P) -> RTDResult<Self> {
+    let db = sled::open(path).map_err(sled_error)?;
+    let entries = db.open_tree("entries").map_err(sled_error)?;
+    let meta = db.open_tree("meta").map_err(sled_error)?;
+    let last_seq = entries.last().map_err(sled_error)?
+      .map_or(0, |(key, _)| Self::decode_seq(&key));
+    let journal = Self { entries, meta, last_seq: AtomicU64::new(last_seq) };
+    // all entries may be truncated, never reuse a sequence number before the cursor
+    journal.last_seq.fetch_max(journal.cursor()?, Ordering::SeqCst);
+    Ok(journal)
+  }
+
+  /// append an object, return its sequence number
+  pub fn append(&self, td_type: &str, json: &str) -> RTDResult<u64> {
+    let seq = self.last_seq.fetch_add(1, Ordering::SeqCst) + 1;
+    // tdlib json has no line break, `td_type` has no tab
+    self.entries.insert(seq.to_be_bytes(), format!("{}\t{}", td_type, json).as_bytes()).map_err(sled_error)?;
+    Ok(seq)
+  }
+
+  /// The sequence number of the last object appended, 0 if empty
+  pub fn last_seq(&self) -> u64 {
+    self.last_seq.load(Ordering::SeqCst)
+  }
+
+  /// The sequence number of the last object handled by the listener, 0 if none
+  pub fn cursor(&self) -> RTDResult<u64> {
+    Ok(self.meta.get(CURSOR).map_err(sled_error)?.map_or(0, |value| Self::decode_seq(&value)))
+  }
+
+  /// Move the cursor, e.g. to `last_seq` to skip the objects not handled yet
+  pub fn set_cursor(&self, seq: u64) -> RTDResult<()> {
+    self.meta.insert(CURSOR, &seq.to_be_bytes()).map_err(sled_error)?;
+    Ok(())
+  }
+
+  /// The objects after the sequence number `after`, the oldest first
+  pub fn replay(&self, after: u64) -> impl Iterator<Item = RTDResult<JournalEntry>> {
+    self.entries.range((after + 1).to_be_bytes()..).map(|item| {
+      let (key, value) = item.map_err(sled_error)?;
+      let value = String::from_utf8_lossy(&value);
+      match value.find('\t') {
+        Some(index) => Ok(JournalEntry {
+          seq: Self::decode_seq(&key),
+          td_type: value[..index].to_string(),
+          json: value[index + 1..].to_string(),
+        }),
+        None => Err(RTDError::custom("Broken journal entry")),
+      }
+    })
+  }
+
+  /// The objects not handled by the listener, after the cursor
+  pub fn unhandled(&self) -> RTDResult<Vec<JournalEntry>> {
+    self.replay(self.cursor()?).collect()
+  }
+
+  /// Remove the objects until the sequence number `through`, return the count removed
+  pub fn truncate(&self, through: u64) -> RTDResult<usize> {
+    let mut removed = 0;
+    for key in self.entries.range(..=through.to_be_bytes()).keys() {
+      self.entries.remove(key.map_err(sled_error)?).map_err(sled_error)?;
+      removed += 1;
+    }
+    Ok(removed)
+  }
+
+  /// Write the journal to disk, sled also flushes periodically
+  pub fn flush(&self) -> RTDResult<()> {
+    self.entries.flush().map_err(sled_error)?;
+    self.meta.flush().map_err(sled_error)?;
+    Ok(())
+  }
+
+  fn decode_seq(bytes: &[u8]) -> u64 {
+    let mut seq = [0u8; 8];
+    seq.copy_from_slice(&bytes[..8]);
+    u64::from_be_bytes(seq)
+  }
+}
@@ -1,13 +1,63 @@
 
+// the generated code uses all td types, deprecated ones too
+#![allow(deprecated)]
+
 #[macro_use]
 extern crate log;
 
 mod rtd;
 mod handler;
 mod tip;
+mod me;
+mod scan;
 
 pub mod api;
+pub mod auth;
 pub mod client;
 pub mod listener;
 pub mod errors;
-
+pub mod files;
+pub mod observer;
+pub mod chats;
+pub mod messages;
+pub mod cache;
+pub mod options;
+pub mod notifications;
+pub mod secret_chats;
+pub mod polls;
+pub mod queue;
+pub mod subscription;
+pub mod filter;
+pub mod batch;
+pub mod transport;
+pub mod router;
+pub mod record;
+pub mod mocks;
+pub mod sessions;
+pub mod logout;
+pub mod database_key;
+pub mod accounts;
+pub mod presence;
+pub mod network;
+pub mod proxies;
+pub mod storage;
+pub mod downloads;
+pub mod live_location;
+#[cfg(feature = "stickers")]
+pub mod stickers;
+#[cfg(feature = "payments")]
+pub mod payments;
+#[cfg(feature = "passport")]
+pub mod passport;
+#[cfg(feature = "calls")]
+pub mod group_calls;
+#[cfg(feature = "audio")]
+pub mod audio;
+#[cfg(feature = "tower")]
+pub mod service;
+#[cfg(feature = "journal")]
+pub mod journal;
+#[cfg(feature = "outbox")]
+pub mod outbox;
+#[cfg(feature = "webhook")]
+pub mod webhook;
@@ -11,13 +11,13 @@ pub struct Listener {
   exception: Option<Arc<dyn Fn((&Api, &TGError)) + Send + Sync + 'static>>,
   receive: Option<Arc<dyn Fn((&Api, &String)) -> TGResult<()> + Send + Sync + 'static>>,
 
-{% for name, td_type in listener %}{% set token = find_token(token_name = td_type) %}  {{name | to_snake}}: Option<Arc<dyn Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static>>,
+{% for name, td_type in listener %}{% set token = find_token(token_name = td_type) %}  {{td_cfg(name=token.name)}} {{name | to_snake}}: Option<Arc<dyn Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static>>,
 {% endfor %}
 
-{% for token in tokens %}{% if token.blood and token.blood == 'Update' %}  {{token.name  | to_snake}}: Option<Arc<dyn Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static>>,
+{% for token in tokens %}{% if token.blood and token.blood == 'Update' %}  {{td_cfg(name=token.name)}} {{token.name  | to_snake}}: Option<Arc<dyn Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static>>,
 {% endif %}{% endfor %}
 
-{% for token in tokens %}{% if token.is_return_type %}  {{token.name | to_snake}}: Option<Arc<dyn Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static>>,
+{% for token in tokens %}{% if token.is_return_type %}  {{td_cfg(name=token.name)}} {{token.name | to_snake}}: Option<Arc<dyn Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static>>,
 {% endif %}{% endfor %}
 }
 
@@ -43,6 +43,7 @@ impl Listener {
   }
 
 {% for name, td_type in listener %}{% set token = find_token(token_name = td_type) %}
+  {{td_cfg(name=token.name)}} {{td_deprecated(note=token.deprecated)}}
   /// {{token.description}}
   pub fn on_{{name | to_snake}}<F>(&mut self, fnc: F) -> &mut Self where F: Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static {
     self.{{name}} = Some(Arc::new(fnc));
@@ -53,6 +54,7 @@ impl Listener {
 
 
 {% for token in tokens %}{% if token.blood and token.blood == 'Update' %}
+  {{td_cfg(name=token.name)}} {{td_deprecated(note=token.deprecated)}}
   /// {{token.description}}
   pub fn on_{{token.name  | to_snake}}<F>(&mut self, fnc: F) -> &mut Self
     where F: Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static {
@@ -62,6 +64,7 @@ impl Listener {
 {% endif %}{% endfor %}
 
 {% for token in tokens %}{% if token.is_return_type %}
+  {{td_cfg(name=token.name)}} {{td_deprecated(note=token.deprecated)}}
   /// {{token.description}}
   pub fn on_{{token.name | to_snake}}<F>(&mut self, fnc: F) -> &mut Self
     where F: Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static {
@@ -111,6 +114,7 @@ impl Lout {
   }
 
 {% for name, td_type in listener %}{% set token = find_token(token_name = td_type) %}
+  {{td_cfg(name=token.name)}}
   /// {{token.description}}
   pub fn {{name | to_snake}}(&self) -> &Option<Arc<dyn Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static>> {
     &self.listener.{{name | to_snake}}
@@ -119,6 +123,7 @@ impl Lout {
 
 
 {% for token in tokens %}{% if token.blood and token.blood == 'Update' %}
+  {{td_cfg(name=token.name)}}
   /// {{token.description}}
   pub fn {{token.name  | to_snake}}(&self) -> &Option<Arc<dyn Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static>> {
     &self.listener.{{token.name  | to_snake}}
@@ -126,6 +131,7 @@ impl Lout {
 {% endif %}{% endfor %}
 
 {% for token in tokens %}{% if token.is_return_type %}
+  {{td_cfg(name=token.name)}}
   /// {{token.description}}
   pub fn {{token.name | to_snake}}(&self) -> &Option<Arc<dyn Fn((&Api, &{{token.name | to_camel}})) -> TGResult<()> + Send + Sync + 'static>> {
     &self.listener.{{token.name | to_snake}}
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+use crate::scan::decode;
+
+/// How often the live location checks it's stopped
+const LIVE_LOCATION_TICK: Duration = Duration::from_millis(500);
+
+impl Api {
+  /// Send the first position as a live location to this chat for `live_period`, 60-86400 seconds, then edit it
+  /// by the next positions (`editMessageLiveLocation`) in a thread, at most every `interval` with the latest one.
+  ///
+  /// The sharing is stopped when the positions end, the live period expires or the returned `LiveLocation` is
+  /// stopped or dropped; the positions can block, e.g. the iterator of a `Receiver` of a GPS reader.
+  pub fn share_live_location<I>(&self, chat_id: i64, live_period: Duration, interval: Duration, positions: I) -> RTDResult<LiveLocation>
+    where I: IntoIterator<Item = (f64, f64)>,
+          I::IntoIter: Send + 'static {
+    let mut positions = positions.into_iter();
+    let first = positions.next().ok_or(RTDError::custom("No position to share"))?;
+    // watch before send, the message may be sent before the response is received
+    let succeeded = self.watch("updateMessageSendSucceeded");
+    let failed = self.watch("updateMessageSendFailed");
+    let message: Message = self.request(SendMessage::builder()
+      .chat_id(chat_id)
+      .options(MessageSendOptions::builder().build())
+      .input_message_content(InputMessageContent::input_message_location(InputMessageLocation::builder()
+        .location(self::location(first))
+        .live_period(live_period.as_secs() as i64)))
+      .build())?.wait()?;
+    let message_id = self::sent_message_id(&message, &succeeded, &failed)?;
+
+    let (sender, receiver) = mpsc::channel();
+    // the positions may block, they're read by another thread so the sharing still can be stopped
+    thread::spawn(move || {
+      for position in positions {
+        if sender.send(position).is_err() { break; }
+      }
+    });
+    let stopped = Arc::new(AtomicBool::new(false));
+    let session = LiveSession {
+      api: self.clone(),
+      chat_id,
+      message_id,
+      expires_at: Instant::now() + live_period,
+      interval,
+    };
+    let stop = stopped.clone();
+    let handle = thread::spawn(move || session.run(receiver, stop));
+    Ok(LiveLocation { chat_id, message_id, stopped, handle: Some(handle) })
+  }
+}
+
+/// The live location shared by `Api::share_live_location`, the sharing is stopped when dropped
+#[derive(Debug)]
+pub struct LiveLocation {
+  chat_id: i64,
+  message_id: i64,
+  stopped: Arc<AtomicBool>,
+  handle: Option<JoinHandle<()>>,
+}
+
+impl LiveLocation {
+  pub fn chat_id(&self) -> i64 { self.chat_id }
+
+  /// The message of the live location, sent to the server
+  pub fn message_id(&self) -> i64 { self.message_id }
+
+  /// Stop sharing the live location and wait for it
+  pub fn stop(mut self) {
+    self.stop_and_join();
+  }
+
+  fn stop_and_join(&mut self) {
+    self.stopped.store(true, Ordering::SeqCst);
+    if let Some(handle) = self.handle.take() {
+      if handle.join().is_err() {
+        error!("The live location thread panicked");
+      }
+    }
+  }
+}
+
+impl Drop for LiveLocation {
+  fn drop(&mut self) {
+    self.stop_and_join();
+  }
+}
+
+/// the message of a live location edited by the positions
+struct LiveSession {
+  api: Api,
+  chat_id: i64,
+  message_id: i64,
+  expires_at: Instant,
+  interval: Duration,
+}
+
+impl LiveSession {
+  fn run(&self, positions: Receiver<(f64, f64)>, stopped: Arc<AtomicBool>) {
+    // the latest position not sent yet
+    let mut latest: Option<(f64, f64)> = None;
+    let mut edited_at = Instant::now();
+    let mut ended = false;
+    while !stopped.load(Ordering::SeqCst) && !ended {
+      // tdlib stops an expired live location itself
+      if Instant::now() >= self.expires_at { return; }
+      match positions.recv_timeout(LIVE_LOCATION_TICK) {
+        Ok(position) => latest = Some(position),
+        Err(RecvTimeoutError::Timeout) => {}
+        Err(RecvTimeoutError::Disconnected) => ended = true,
+      }
+      if latest.is_some() && (ended || edited_at.elapsed() >= self.interval) {
+        if let Some(position) = latest.take() {
+          self.edit(Some(position));
+        }
+        edited_at = Instant::now();
+      }
+    }
+    self.edit(None);
+  }
+
+  /// move the live location to this position, stop it by `None`; a failure is logged
+  fn edit(&self, position: Option<(f64, f64)>) {
+    let edit = EditMessageLiveLocation::builder()
+      .chat_id(self.chat_id)
+      .message_id(self.message_id)
+      .location(self::location(position.unwrap_or((0.0, 0.0))))
+      .build();
+    let result = match position {
+      Some(_) => self.api.request::<_, Message>(edit).and_then(|pending| pending.wait()).map(|_| ()),
+      // tdlib stops the live location by a null location
+      None => serde_json::to_value(&edit)
+        .map_err(RTDError::from)
+        .and_then(|mut value| {
+          value["location"] = serde_json::Value::Null;
+          self.api.request_raw(value)
+        })
+        .and_then(|pending| pending.wait())
+        .map(|_| ()),
+    };
+    if let Err(e) = result {
+      warn!("Can not edit the live location of the message {} of the chat {}: {}", self.message_id, self.chat_id, e);
+    }
+  }
+}
+
+fn location((latitude, longitude): (f64, f64)) -> Location {
+  Location::builder().latitude(latitude as _).longitude(longitude as _).build()
+}
+
+/// the id of the sent message on the server, a message is returned by `sendMessage` before it's sent
+fn sent_message_id(message: &Message, succeeded: &Receiver<String>, failed: &Receiver<String>) -> RTDResult<i64> {
+  if message.sending_state().is_none() { return Ok(message.id()); }
+  loop {
+    while let Ok(json) = failed.try_recv() {
+      if let Update::MessageSendFailed(update) = decode::<Update>(&json)? {
+        if update.old_message_id() == message.id() {
+          warn!("Can not send the live location: {}", update.error_message());
+          return Err(RTDError::custom("The live location is not sent"));
+        }
+      }
+    }
+    match succeeded.recv_timeout(LIVE_LOCATION_TICK) {
+      Ok(json) => if let Update::MessageSendSucceeded(update) = decode::<Update>(&json)? {
+        if update.old_message_id() == message.id() {
+          return Ok(update.message().id());
+        }
+      },
+      Err(RecvTimeoutError::Timeout) => {}
+      Err(RecvTimeoutError::Disconnected) => {
+        return Err(RTDError::custom("The receive loop is stopped, the live location is not known as sent"));
+      }
+    }
+  }
+}
@@ -0,0 +1,59 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+
+impl Api {
+  /// Log out the current user (`logOut`) and wait until the client is closed, tdlib wipes the local database.
+  ///
+  /// Return the files still left in `database_directory`, the `database_directory` of `setTdlibParameters`;
+  /// empty if the database is wiped. After it the requests of every clone of this `Api` fail with
+  /// `RTDError::NotAuthorized`, also the requests still waiting; a new client is needed to log in again.
+  /// `Api::log_out` only sends `logOut`.
+  pub fn sign_out<P: AsRef<Path>>(&self, database_directory: P) -> RTDResult<Vec<PathBuf>> {
+    let updates = self.subscribe(&["updateAuthorizationState"]);
+    let _: Ok = self.request(LogOut::builder().build())?.wait()?;
+    loop {
+      match updates.recv() {
+        Some(Ok(Update::AuthorizationState(update))) => {
+          let state = update.authorization_state();
+          if state.is_closed() { break; }
+          debug!("Logging out, the authorization state is {}", state.td_name());
+        }
+        Some(Ok(_)) => continue,
+        Some(Err(e)) => warn!("Can not read the authorization state: {}", e),
+        None => return Err(RTDError::custom("The receive loop is stopped before the client is closed")),
+      }
+    }
+    let dropped = self.observer().close();
+    if dropped > 0 {
+      warn!("{} requests are dropped, the client is logged out", dropped);
+    }
+
+    let mut leftover = vec![];
+    self::leftover_files(database_directory.as_ref(), &mut leftover)?;
+    if !leftover.is_empty() {
+      warn!("{} files are left in the database directory after logging out: {:?}", leftover.len(), leftover);
+    }
+    Ok(leftover)
+  }
+}
+
+/// the files in this directory and its sub directories, none if the directory is removed
+fn leftover_files(dir: &Path, files: &mut Vec<PathBuf>) -> RTDResult<()> {
+  if !dir.exists() {
+    return Ok(());
+  }
+  for entry in fs::read_dir(dir)? {
+    let path = entry?.path();
+    if path.is_dir() {
+      self::leftover_files(&path, files)?;
+    } else {
+      files.push(path);
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,61 @@
+use std::sync::RwLock;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::api::Api;
+
+/// The current user, requested by `getMe` once and refreshed by `updateUser` of the same user.
+#[derive(Debug, Default)]
+pub(crate) struct Me {
+  user: RwLock<Option<User>>,
+}
+
+impl Me {
+  pub fn new() -> Self { Me::default() }
+
+  pub fn get(&self) -> Option<User> {
+    self.user.read().unwrap().clone()
+  }
+
+  pub fn set(&self, user: User) {
+    *self.user.write().unwrap() = Some(user);
+  }
+
+  /// apply an `updateUser`
+  pub fn handle(&self, value: &Value) {
+    let my_id = match self.user.read().unwrap().as_ref() {
+      Some(user) => user.id(),
+      None => return
+    };
+    if let Ok(update) = UpdateUser::deserialize(value) {
+      if update.user().id() == my_id {
+        self.set(update.user().clone());
+      }
+    }
+  }
+}
+
+
+impl Api {
+  /// The current user, `getMe` is only called the first time, the later calls return the cached user
+  /// which is kept up to date by `updateUser`.
+  pub fn me(&self) -> RTDResult<User> {
+    if let Some(user) = self.me_cache().get() {
+      return Ok(user);
+    }
+    let user: User = self.request(GetMe::builder().build())?.wait()?;
+    self.me_cache().set(user.clone());
+    Ok(user)
+  }
+
+  /// Identifier of the current user, read from the `my_id` option if it's already received.
+  pub fn my_id(&self) -> RTDResult<i64> {
+    match self.options().my_id() {
+      Some(my_id) => Ok(my_id),
+      None => self.me().map(|user| user.id()),
+    }
+  }
+}
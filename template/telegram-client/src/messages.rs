@@ -0,0 +1,520 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+
+/// Options of `Api::iter_search_messages`
+#[derive(Debug, Clone)]
+pub struct SearchMessagesOptions {
+  query: String,
+  chat_id: Option<i64>,
+  chat_list: ChatList,
+  sender_user_id: Option<i64>,
+  filter: SearchMessagesFilter,
+  min_date: Option<i64>,
+  max_date: Option<i64>,
+  page_size: i64,
+  limit: Option<usize>,
+}
+
+impl Default for SearchMessagesOptions {
+  fn default() -> Self {
+    Self {
+      query: "".to_string(),
+      chat_id: None,
+      chat_list: ChatList::main(ChatListMain::builder()),
+      sender_user_id: None,
+      filter: SearchMessagesFilter::empty(SearchMessagesFilterEmpty::builder()),
+      min_date: None,
+      max_date: None,
+      page_size: 100,
+      limit: None,
+    }
+  }
+}
+
+impl SearchMessagesOptions {
+  pub fn builder() -> SearchMessagesOptionsBuilder {
+    SearchMessagesOptionsBuilder { inner: SearchMessagesOptions::default() }
+  }
+
+  pub fn query(&self) -> &String { &self.query }
+  pub fn chat_id(&self) -> Option<i64> { self.chat_id }
+  pub fn chat_list(&self) -> &ChatList { &self.chat_list }
+  pub fn sender_user_id(&self) -> Option<i64> { self.sender_user_id }
+  pub fn filter(&self) -> &SearchMessagesFilter { &self.filter }
+  pub fn min_date(&self) -> Option<i64> { self.min_date }
+  pub fn max_date(&self) -> Option<i64> { self.max_date }
+  pub fn page_size(&self) -> i64 { self.page_size }
+  pub fn limit(&self) -> Option<usize> { self.limit }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchMessagesOptionsBuilder {
+  inner: SearchMessagesOptions
+}
+
+impl SearchMessagesOptionsBuilder {
+  pub fn build(&self) -> SearchMessagesOptions { self.inner.clone() }
+
+  /// Query to search for
+  pub fn query<S: AsRef<str>>(&mut self, query: S) -> &mut Self {
+    self.inner.query = query.as_ref().to_string();
+    self
+  }
+
+  /// Search in this chat only (`searchChatMessages`), otherwise search in all chats of the chat list (`searchMessages`)
+  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+    self.inner.chat_id = Some(chat_id);
+    self
+  }
+
+  /// Chat list to search in when no chat is specified, default is the main chat list
+  pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
+    self.inner.chat_list = chat_list.as_ref().clone();
+    self
+  }
+
+  /// Only return messages sent by this user; only supported when search in a chat
+  pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
+    self.inner.sender_user_id = Some(sender_user_id);
+    self
+  }
+
+  /// Server-side filter of messages; only supported when search in a chat
+  pub fn filter<T: AsRef<SearchMessagesFilter>>(&mut self, filter: T) -> &mut Self {
+    self.inner.filter = filter.as_ref().clone();
+    self
+  }
+
+  /// Only return messages sent at or after this unix time
+  pub fn min_date(&mut self, min_date: i64) -> &mut Self {
+    self.inner.min_date = Some(min_date);
+    self
+  }
+
+  /// Only return messages sent at or before this unix time
+  pub fn max_date(&mut self, max_date: i64) -> &mut Self {
+    self.inner.max_date = Some(max_date);
+    self
+  }
+
+  /// Number of messages requested by one search, tdlib allows up to 100
+  pub fn page_size(&mut self, page_size: i64) -> &mut Self {
+    self.inner.page_size = page_size;
+    self
+  }
+
+  /// Stop after this many messages are returned
+  pub fn limit(&mut self, limit: usize) -> &mut Self {
+    self.inner.limit = Some(limit);
+    self
+  }
+}
+
+impl AsRef<SearchMessagesOptions> for SearchMessagesOptions {
+  fn as_ref(&self) -> &SearchMessagesOptions { self }
+}
+
+impl AsRef<SearchMessagesOptions> for SearchMessagesOptionsBuilder {
+  fn as_ref(&self) -> &SearchMessagesOptions { &self.inner }
+}
+
+
+/// Iterator of searched messages, created by `Api::iter_search_messages`.
+///
+/// Messages are returned from the newest to the oldest, the next page is requested when the
+/// previous is consumed, until tdlib returns no more messages.
+#[derive(Debug)]
+pub struct SearchMessagesIter {
+  api: Api,
+  options: SearchMessagesOptions,
+  offset_date: i64,
+  offset_chat_id: i64,
+  offset_message_id: i64,
+  buffer: VecDeque<Message>,
+  returned: usize,
+  finished: bool,
+  viewer: Option<MessageViewer>,
+}
+
+impl Api {
+  /// Search messages, in a chat (`searchChatMessages`) or in all chats (`searchMessages`), page by page.
+  pub fn iter_search_messages<O: AsRef<SearchMessagesOptions>>(&self, options: O) -> SearchMessagesIter {
+    let options = options.as_ref().clone();
+    SearchMessagesIter {
+      api: self.clone(),
+      offset_date: options.max_date.unwrap_or(0),
+      options,
+      offset_chat_id: 0,
+      offset_message_id: 0,
+      buffer: VecDeque::new(),
+      returned: 0,
+      finished: false,
+      viewer: None,
+    }
+  }
+}
+
+impl SearchMessagesIter {
+  /// Mark the returned messages as viewed (`viewMessages`), see `MessageViewer`.
+  pub fn view_messages(mut self, force_read: bool) -> Self {
+    self.viewer = Some(MessageViewer::new(self.api.clone(), force_read));
+    self
+  }
+
+  fn load_page(&mut self) -> RTDResult<()> {
+    let options = &self.options;
+    let messages: Messages = match options.chat_id {
+      Some(chat_id) => {
+        let search = SearchChatMessages::builder()
+          .chat_id(chat_id)
+          .query(&options.query)
+          .sender_user_id(options.sender_user_id.unwrap_or(0))
+          .from_message_id(self.offset_message_id)
+          .offset(0)
+          .limit(options.page_size)
+          .filter(&options.filter)
+          .build();
+        self.api.request(search)?.wait()?
+      }
+      None => {
+        let search = SearchMessages::builder()
+          .chat_list(&options.chat_list)
+          .query(&options.query)
+          .offset_date(self.offset_date)
+          .offset_chat_id(self.offset_chat_id)
+          .offset_message_id(self.offset_message_id)
+          .limit(options.page_size)
+          .build();
+        self.api.request(search)?.wait()?
+      }
+    };
+
+    let messages: Vec<Message> = messages.messages().iter().filter_map(|message| message.clone()).collect();
+    let last = match messages.last() {
+      Some(last) => last,
+      None => {
+        self.finished = true;
+        return Ok(());
+      }
+    };
+    self.offset_date = last.date();
+    self.offset_chat_id = last.chat_id();
+    self.offset_message_id = last.id();
+
+    let (min_date, max_date) = (options.min_date, options.max_date);
+    for message in messages {
+      if max_date.is_some_and(|max_date| message.date() > max_date) { continue; }
+      if min_date.is_some_and(|min_date| message.date() < min_date) {
+        // messages are ordered by date, all the next messages are older
+        self.finished = true;
+        break;
+      }
+      self.buffer.push_back(message);
+    }
+    Ok(())
+  }
+
+  fn next_message(&mut self) -> RTDResult<Option<Message>> {
+    if self.options.limit.is_some_and(|limit| self.returned >= limit) {
+      return Ok(None);
+    }
+    if self.buffer.is_empty() {
+      if let Some(viewer) = self.viewer.as_mut() {
+        viewer.flush()?;
+      }
+    }
+    while self.buffer.is_empty() && !self.finished {
+      self.load_page()?;
+    }
+    let message = self.buffer.pop_front();
+    if let Some(message) = &message {
+      self.returned += 1;
+      if let Some(viewer) = self.viewer.as_mut() {
+        viewer.push(message);
+      }
+    }
+    Ok(message)
+  }
+}
+
+impl Iterator for SearchMessagesIter {
+  type Item = RTDResult<Message>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    match self.next_message() {
+      Ok(message) => message.map(Ok),
+      Err(e) => {
+        self.finished = true;
+        self.buffer.clear();
+        Some(Err(e))
+      }
+    }
+  }
+}
+
+
+/// Iterator of the history of a chat, created by `Api::iter_chat_history`.
+///
+/// Messages are returned from the newest to the oldest by `getChatHistory`, the next page is requested
+/// when the previous is consumed, until tdlib returns no more messages.
+#[derive(Debug)]
+pub struct ChatHistoryIter {
+  api: Api,
+  chat_id: i64,
+  from_message_id: i64,
+  page_size: i64,
+  only_local: bool,
+  buffer: VecDeque<Message>,
+  started: bool,
+  finished: bool,
+  viewer: Option<MessageViewer>,
+}
+
+impl Api {
+  /// Iterate the history of this chat, from the last message.
+  pub fn iter_chat_history(&self, chat_id: i64) -> ChatHistoryIter {
+    ChatHistoryIter {
+      api: self.clone(),
+      chat_id,
+      from_message_id: 0,
+      page_size: 100,
+      only_local: false,
+      buffer: VecDeque::new(),
+      started: false,
+      finished: false,
+      viewer: None,
+    }
+  }
+}
+
+impl ChatHistoryIter {
+  /// Start from this message (included), default is the last message of the chat
+  pub fn from_message_id(mut self, from_message_id: i64) -> Self {
+    self.from_message_id = from_message_id;
+    self
+  }
+
+  /// Number of messages requested by one `getChatHistory`, tdlib allows up to 100
+  pub fn page_size(mut self, page_size: i64) -> Self {
+    self.page_size = page_size;
+    self
+  }
+
+  /// Only return messages available locally, without network requests
+  pub fn only_local(mut self, only_local: bool) -> Self {
+    self.only_local = only_local;
+    self
+  }
+
+  /// Mark the returned messages as viewed (`viewMessages`), see `MessageViewer`.
+  pub fn view_messages(mut self, force_read: bool) -> Self {
+    self.viewer = Some(MessageViewer::new(self.api.clone(), force_read));
+    self
+  }
+
+  fn load_page(&mut self) -> RTDResult<()> {
+    let get_history = GetChatHistory::builder()
+      .chat_id(self.chat_id)
+      .from_message_id(self.from_message_id)
+      .offset(0)
+      .limit(self.page_size)
+      .only_local(self.only_local)
+      .build();
+    let messages: Messages = self.api.request(get_history)?.wait()?;
+    // the last message of the previous page is returned again as the first one, skip it
+    let skip_message_id = if self.started { self.from_message_id } else { 0 };
+    self.started = true;
+    let messages: Vec<Message> = messages.messages().iter()
+      .filter_map(|message| message.clone())
+      .filter(|message| message.id() != skip_message_id)
+      .collect();
+    match messages.last() {
+      Some(last) => self.from_message_id = last.id(),
+      None => self.finished = true,
+    }
+    self.buffer.extend(messages);
+    Ok(())
+  }
+
+  fn next_message(&mut self) -> RTDResult<Option<Message>> {
+    if self.buffer.is_empty() {
+      if let Some(viewer) = self.viewer.as_mut() {
+        viewer.flush()?;
+      }
+      if !self.finished {
+        self.load_page()?;
+      }
+    }
+    let message = self.buffer.pop_front();
+    if let (Some(message), Some(viewer)) = (&message, self.viewer.as_mut()) {
+      viewer.push(message);
+    }
+    Ok(message)
+  }
+}
+
+impl Iterator for ChatHistoryIter {
+  type Item = RTDResult<Message>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    match self.next_message() {
+      Ok(message) => message.map(Ok),
+      Err(e) => {
+        self.finished = true;
+        self.buffer.clear();
+        Some(Err(e))
+      }
+    }
+  }
+}
+
+
+/// Mark the messages returned by an iterator as viewed.
+///
+/// The messages are collected when returned and sent by `viewMessages` grouped by chat, when the
+/// current page is consumed or the iterator is dropped, to keep the unread counters consistent.
+/// tdlib only marks messages as read in an opened chat (`openChat`), unless `force_read` is true.
+#[derive(Debug)]
+pub struct MessageViewer {
+  api: Api,
+  force_read: bool,
+  pending: HashMap<i64, Vec<i64>>,
+}
+
+impl MessageViewer {
+  pub fn new(api: Api, force_read: bool) -> Self {
+    Self { api, force_read, pending: HashMap::new() }
+  }
+
+  /// Collect this message, it's viewed by the next flush
+  pub fn push(&mut self, message: &Message) {
+    self.pending.entry(message.chat_id()).or_default().push(message.id());
+  }
+
+  /// Send `viewMessages` for all collected messages
+  pub fn flush(&mut self) -> RTDResult<()> {
+    for (chat_id, message_ids) in self.pending.drain() {
+      self.api.view_messages(ViewMessages::builder()
+        .chat_id(chat_id)
+        .message_ids(message_ids)
+        .force_read(self.force_read)
+        .build())?;
+    }
+    Ok(())
+  }
+}
+
+impl Drop for MessageViewer {
+  fn drop(&mut self) {
+    if let Err(e) = self.flush() {
+      warn!("Can not view messages: {}", e);
+    }
+  }
+}
+
+
+/// Fetch many messages of a chat by identifier, created by `Api::fetch_messages`.
+///
+/// The identifiers are deduplicated and requested by `getMessages` in chunks, at most `concurrency`
+/// requests are waiting at the same time. The messages not returned by a chunk, or of a failed chunk,
+/// are retried one by one by `getMessage`.
+#[derive(Debug)]
+pub struct MessagesFetch {
+  api: Api,
+  chat_id: i64,
+  message_ids: Vec<i64>,
+  chunk_size: usize,
+  concurrency: usize,
+}
+
+/// Result of `MessagesFetch::run`
+#[derive(Debug, Clone)]
+pub struct FetchedMessages {
+  messages: Vec<Message>,
+  missing: Vec<i64>,
+}
+
+impl FetchedMessages {
+  /// The found messages, in the order of the requested identifiers
+  pub fn messages(&self) -> &Vec<Message> { &self.messages }
+  /// Identifiers of the messages not found
+  pub fn missing(&self) -> &Vec<i64> { &self.missing }
+}
+
+impl Api {
+  /// Fetch these messages of this chat, see `MessagesFetch`
+  pub fn fetch_messages(&self, chat_id: i64, message_ids: &[i64]) -> MessagesFetch {
+    let mut seen = HashSet::new();
+    MessagesFetch {
+      api: self.clone(),
+      chat_id,
+      message_ids: message_ids.iter().cloned().filter(|message_id| seen.insert(*message_id)).collect(),
+      chunk_size: 100,
+      concurrency: 4,
+    }
+  }
+}
+
+impl MessagesFetch {
+  /// Number of messages requested by one `getMessages`, default is 100
+  pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+    self.chunk_size = chunk_size.max(1);
+    self
+  }
+
+  /// Number of requests waiting at the same time, default is 4
+  pub fn concurrency(mut self, concurrency: usize) -> Self {
+    self.concurrency = concurrency.max(1);
+    self
+  }
+
+  /// Block until all messages are fetched
+  pub fn run(self) -> RTDResult<FetchedMessages> {
+    let mut found: HashMap<i64, Message> = HashMap::new();
+    let chunks: Vec<&[i64]> = self.message_ids.chunks(self.chunk_size).collect();
+    for wave in chunks.chunks(self.concurrency) {
+      let requests = wave.iter()
+        .map(|chunk| GetMessages::builder().chat_id(self.chat_id).message_ids(chunk.to_vec()).build())
+        .collect();
+      for (index, response) in self.api.request_batch::<GetMessages, Messages>(requests)?.wait_all().into_iter().enumerate() {
+        match response {
+          Ok(messages) => {
+            for message in messages.messages().iter().flatten() {
+              found.insert(message.id(), message.clone());
+            }
+          }
+          Err(e) => warn!("Can not get {} messages of chat {}, retry one by one: {}", wave[index].len(), self.chat_id, e),
+        }
+      }
+    }
+
+    let retries: Vec<i64> = self.message_ids.iter().cloned().filter(|message_id| !found.contains_key(message_id)).collect();
+    for wave in retries.chunks(self.concurrency) {
+      let requests = wave.iter()
+        .map(|&message_id| GetMessage::builder().chat_id(self.chat_id).message_id(message_id).build())
+        .collect();
+      for response in self.api.request_batch::<GetMessage, Message>(requests)?.wait_all() {
+        match response {
+          Ok(message) => { found.insert(message.id(), message); }
+          // not found or not accessible
+          Err(RTDError::TdlibError(_)) => {}
+          Err(e) => return Err(e),
+        }
+      }
+    }
+
+    let mut messages = Vec::with_capacity(found.len());
+    let mut missing = vec![];
+    for message_id in self.message_ids {
+      match found.remove(&message_id) {
+        Some(message) => messages.push(message),
+        None => missing.push(message_id),
+      }
+    }
+    Ok(FetchedMessages { messages, missing })
+  }
+}
@@ -0,0 +1,152 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use rtdlib::errors::*;
+use rtdlib::types::RObject;
+use serde_json::Value;
+
+use crate::transport::TdLibClient;
+
+#[derive(Debug, Default)]
+struct MockState {
+  responses: HashMap<String, VecDeque<String>>,
+  received: VecDeque<String>,
+  sent: Vec<String>,
+}
+
+/// A `TdLibClient` answering the requests by the responses queued, for tests of the code using `Api`.
+///
+/// A clone shares the same state, pass a clone to `ApiBuilder::transport` and keep one to queue the
+/// responses, push updates and assert on the requests sent.
+/// The response of a request is the next one queued for its td type, with the `@extra` of the request;
+/// a request without response queued is answered by an error 404.
+///
+/// ```ignore
+/// let mock = MockTdLibClient::new();
+/// mock.respond("getMe", r#"{"@type":"user","id":1, ...}"#);
+/// let api = Api::builder().transport(mock.clone()).build();
+/// Client::new(api.clone()).start();
+/// let me: User = api.request(GetMe::builder().build())?.wait()?;
+/// mock.assert_sent("getMe");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockTdLibClient {
+  state: Arc<Mutex<MockState>>,
+  condvar: Arc<Condvar>,
+}
+
+impl MockTdLibClient {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Queue the json response of the next request of this td type, e.g. `getMe`
+  pub fn respond<T: AsRef<str>, J: AsRef<str>>(&self, td_type: T, json: J) -> &Self {
+    self.state.lock().unwrap().responses
+      .entry(td_type.as_ref().to_string())
+      .or_default()
+      .push_back(json.as_ref().to_string());
+    self
+  }
+
+  /// Queue the response of the next request of this td type
+  pub fn respond_object<T: AsRef<str>, R: RObject>(&self, td_type: T, response: &R) -> RTDResult<&Self> {
+    Ok(self.respond(td_type, response.to_json()?))
+  }
+
+  /// Queue an error as the response of the next request of this td type
+  pub fn respond_error<T: AsRef<str>, M: AsRef<str>>(&self, td_type: T, code: i64, message: M) -> &Self {
+    let error = serde_json::json!({"@type": "error", "code": code, "message": message.as_ref()});
+    self.respond(td_type, error.to_string())
+  }
+
+  /// Push an update as received from tdlib, e.g. `updateAuthorizationState`
+  pub fn push_update<J: AsRef<str>>(&self, json: J) -> &Self {
+    self.state.lock().unwrap().received.push_back(json.as_ref().to_string());
+    self.condvar.notify_all();
+    self
+  }
+
+  /// Push an update as received from tdlib
+  pub fn push_object<R: RObject>(&self, update: &R) -> RTDResult<&Self> {
+    Ok(self.push_update(update.to_json()?))
+  }
+
+  /// The json of all requests sent, the oldest first, including the `@extra`
+  pub fn sent(&self) -> Vec<String> {
+    self.state.lock().unwrap().sent.clone()
+  }
+
+  /// The requests of this td type sent, the oldest first
+  pub fn sent_of<T: AsRef<str>>(&self, td_type: T) -> Vec<Value> {
+    self.sent().iter()
+      .filter_map(|json| serde_json::from_str::<Value>(json).ok())
+      .filter(|value| value.get("@type").and_then(|t| t.as_str()) == Some(td_type.as_ref()))
+      .collect()
+  }
+
+  /// The last request of this td type sent; panic if none is sent
+  pub fn assert_sent<T: AsRef<str>>(&self, td_type: T) -> Value {
+    match self.sent_of(&td_type).pop() {
+      Some(request) => request,
+      None => panic!("No {} is sent, sent: {:?}", td_type.as_ref(), self.sent()),
+    }
+  }
+
+  /// Forget the requests sent
+  pub fn clear_sent(&self) {
+    self.state.lock().unwrap().sent.clear();
+  }
+
+  /// the next response queued for this request, with its `@extra`
+  fn response(&self, state: &mut MockState, request: &str) -> String {
+    let value = serde_json::from_str::<Value>(request).unwrap_or(Value::Null);
+    let td_type = value.get("@type").and_then(|t| t.as_str()).unwrap_or("");
+    let json = state.responses.get_mut(td_type)
+      .and_then(|responses| responses.pop_front())
+      .unwrap_or_else(|| {
+        let message = format!("No mocked response of {}", td_type);
+        serde_json::json!({"@type": "error", "code": 404, "message": message}).to_string()
+      });
+    let mut response = match serde_json::from_str::<Value>(&json) {
+      Ok(response) => response,
+      Err(_) => return json,
+    };
+    if let (Some(map), Some(extra)) = (response.as_object_mut(), value.get("@extra")) {
+      map.insert("@extra".to_string(), extra.clone());
+    }
+    response.to_string()
+  }
+}
+
+impl TdLibClient for MockTdLibClient {
+  fn send(&self, request: &str) {
+    let mut state = self.state.lock().unwrap();
+    state.sent.push(request.to_string());
+    let response = self.response(&mut state, request);
+    state.received.push_back(response);
+    self.condvar.notify_all();
+  }
+
+  fn receive(&self, timeout: f64) -> Option<String> {
+    let deadline = Instant::now() + Duration::from_secs_f64(timeout.max(0.0));
+    let mut state = self.state.lock().unwrap();
+    loop {
+      if let Some(json) = state.received.pop_front() {
+        return Some(json);
+      }
+      let now = Instant::now();
+      if now >= deadline {
+        return None;
+      }
+      state = self.condvar.wait_timeout(state, deadline - now).unwrap().0;
+    }
+  }
+
+  fn execute(&self, request: &str) -> Option<String> {
+    let mut state = self.state.lock().unwrap();
+    state.sent.push(request.to_string());
+    Some(self.response(&mut state, request))
+  }
+}
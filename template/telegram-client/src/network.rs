@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+
+/// The network changes of the OS, e.g. of NetworkManager or of the reachability API of a platform, followed by
+/// `Api::follow_connectivity`
+pub trait Connectivity: Send + 'static {
+  /// Block until the network changes and return the new one; `None` stops following it
+  fn next_change(&mut self) -> Option<NetworkType>;
+}
+
+/// The traffic of tdlib of `NetworkStatistics`, `Api::network_statistics`
+#[derive(Debug, Clone)]
+pub struct NetworkTraffic {
+  statistics: NetworkStatistics,
+}
+
+impl NetworkTraffic {
+  pub fn new(statistics: NetworkStatistics) -> Self {
+    Self { statistics }
+  }
+
+  pub fn statistics(&self) -> &NetworkStatistics { &self.statistics }
+
+  /// Unix time the statistics are collected since
+  pub fn since_date(&self) -> i64 { self.statistics.since_date() }
+
+  pub fn sent_bytes(&self) -> i64 {
+    self.entries().map(|(_, sent, _)| sent).sum()
+  }
+
+  pub fn received_bytes(&self) -> i64 {
+    self.entries().map(|(_, _, received)| received).sum()
+  }
+
+  /// Sent and received bytes by the td name of the network type, e.g. `networkTypeWiFi`
+  pub fn by_network(&self) -> BTreeMap<String, (i64, i64)> {
+    let mut networks: BTreeMap<String, (i64, i64)> = BTreeMap::new();
+    for (network_type, sent, received) in self.entries() {
+      let bytes = networks.entry(network_type.td_name().to_string()).or_insert((0, 0));
+      bytes.0 += sent;
+      bytes.1 += received;
+    }
+    networks
+  }
+
+  /// the network type, sent and received bytes of every entry, of files and calls
+  fn entries(&self) -> impl Iterator<Item = (&NetworkType, i64, i64)> {
+    self.statistics.entries().iter().filter_map(|entry| match entry {
+      NetworkStatisticsEntry::File(file) => Some((file.network_type(), file.sent_bytes(), file.received_bytes())),
+      NetworkStatisticsEntry::Call(call) => Some((call.network_type(), call.sent_bytes(), call.received_bytes())),
+      _ => None,
+    })
+  }
+}
+
+/// The traffic between two samples of `TrafficSampler`
+#[derive(Debug, Clone)]
+pub struct TrafficSample {
+  elapsed: Duration,
+  sent_bytes: i64,
+  received_bytes: i64,
+  traffic: NetworkTraffic,
+}
+
+impl TrafficSample {
+  /// Time since the previous sample
+  pub fn elapsed(&self) -> Duration { self.elapsed }
+
+  /// Bytes sent since the previous sample
+  pub fn sent_bytes(&self) -> i64 { self.sent_bytes }
+
+  /// Bytes received since the previous sample
+  pub fn received_bytes(&self) -> i64 { self.received_bytes }
+
+  /// Bytes sent and received by second since the previous sample
+  pub fn bytes_per_second(&self) -> f64 {
+    (self.sent_bytes + self.received_bytes) as f64 / self.elapsed.as_secs_f64().max(0.001)
+  }
+
+  /// The whole traffic of the current launch of tdlib
+  pub fn traffic(&self) -> &NetworkTraffic { &self.traffic }
+}
+
+impl Api {
+  /// Tell tdlib the network changed (`setNetworkType`) and wait for it, tdlib reconnects at once and counts the
+  /// traffic of this network type
+  pub fn change_network_type(&self, network_type: NetworkType) -> RTDResult<()> {
+    let _: Ok = self.request(SetNetworkType::builder().type_(network_type).build())?.wait()?;
+    Ok(())
+  }
+
+  /// Send every network change of the connectivity to tdlib in a thread, until `Connectivity::next_change` returns
+  /// `None`; a failed change is logged
+  pub fn follow_connectivity<C: Connectivity>(&self, mut connectivity: C) -> JoinHandle<()> {
+    let api = self.clone();
+    thread::spawn(move || {
+      while let Some(network_type) = connectivity.next_change() {
+        if let Err(e) = api.change_network_type(network_type) {
+          warn!("Can not change the network type: {}", e);
+        }
+      }
+    })
+  }
+
+  /// The traffic of tdlib (`getNetworkStatistics`), of the current launch if `only_current`
+  pub fn network_statistics(&self, only_current: bool) -> RTDResult<NetworkTraffic> {
+    let statistics: NetworkStatistics = self.request(GetNetworkStatistics::builder().only_current(only_current).build())?.wait()?;
+    Ok(NetworkTraffic::new(statistics))
+  }
+
+  /// Sample the traffic of the current launch of tdlib every `interval`
+  pub fn sample_network_traffic(&self, interval: Duration) -> RTDResult<TrafficSampler> {
+    let last = self.network_statistics(true)?;
+    let now = Instant::now();
+    Ok(TrafficSampler { api: self.clone(), interval, last, sampled_at: now, next_poll: now + interval })
+  }
+}
+
+
+/// Iterator of the traffic of tdlib every interval, created by `Api::sample_network_traffic`.
+///
+/// It blocks until the next interval; a failed sample is returned as an error and sampled again at the next
+/// interval.
+#[derive(Debug)]
+pub struct TrafficSampler {
+  api: Api,
+  interval: Duration,
+  last: NetworkTraffic,
+  sampled_at: Instant,
+  next_poll: Instant,
+}
+
+impl Iterator for TrafficSampler {
+  type Item = RTDResult<TrafficSample>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let now = Instant::now();
+    if self.next_poll > now {
+      thread::sleep(self.next_poll - now);
+    }
+    self.next_poll = Instant::now() + self.interval;
+    let traffic = match self.api.network_statistics(true) {
+      Ok(traffic) => traffic,
+      Err(e) => return Some(Err(e)),
+    };
+    let sample = TrafficSample {
+      elapsed: self.sampled_at.elapsed(),
+      // the statistics are reset by `resetNetworkStatistics`, the traffic since is the whole traffic
+      sent_bytes: self::delta(traffic.sent_bytes(), self.last.sent_bytes()),
+      received_bytes: self::delta(traffic.received_bytes(), self.last.received_bytes()),
+      traffic: traffic.clone(),
+    };
+    self.last = traffic;
+    self.sampled_at = Instant::now();
+    Some(Ok(sample))
+  }
+}
+
+fn delta(current: i64, last: i64) -> i64 {
+  if current >= last { current - last } else { current }
+}
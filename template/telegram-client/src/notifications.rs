@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::api::Api;
+use crate::observer::Pending;
+
+/// The active notifications, folded from `updateActiveNotifications`, `updateNotificationGroup`
+/// and `updateNotification` in the receive loop. Enable it by `ApiBuilder::notifications`.
+///
+/// tdlib sends `updateActiveNotifications` once after start, the later changes of the groups are
+/// sent by `updateNotificationGroup`, a group without notifications is removed.
+#[derive(Debug, Default)]
+pub struct Notifications {
+  groups: RwLock<HashMap<i64, ActiveNotificationGroup>>,
+  pending: RwLock<(bool, bool)>,
+}
+
+impl Notifications {
+  pub fn new() -> Self { Notifications::default() }
+
+  /// The active notification group
+  pub fn group(&self, notification_group_id: i64) -> Option<ActiveNotificationGroup> {
+    self.groups.read().unwrap().get(&notification_group_id).cloned()
+  }
+
+  /// All active notification groups, ordered by identifier
+  pub fn groups(&self) -> Vec<ActiveNotificationGroup> {
+    let mut groups: Vec<ActiveNotificationGroup> = self.groups.read().unwrap().values().cloned().collect();
+    groups.sort_by_key(|group| group.id);
+    groups
+  }
+
+  /// Active notification groups of this chat
+  pub fn chat_groups(&self, chat_id: i64) -> Vec<ActiveNotificationGroup> {
+    self.groups().into_iter().filter(|group| group.chat_id == chat_id).collect()
+  }
+
+  /// The active notification
+  pub fn notification(&self, notification_group_id: i64, notification_id: i64) -> Option<Notification> {
+    self.group(notification_group_id)
+      .and_then(|group| group.notifications.into_iter().find(|notification| notification.id() == notification_id))
+  }
+
+  /// Number of all active notifications
+  pub fn count(&self) -> usize {
+    self.groups.read().unwrap().values().map(|group| group.notifications.len()).sum()
+  }
+
+  /// True, if there are some delayed notifications, see `updateHavePendingNotifications`
+  pub fn have_delayed_notifications(&self) -> bool { self.pending.read().unwrap().0 }
+
+  /// True, if there can be some yet unreceived notifications, see `updateHavePendingNotifications`
+  pub fn have_unreceived_notifications(&self) -> bool { self.pending.read().unwrap().1 }
+
+  /// the notifications are changed by the objects of this td type
+  pub(crate) fn keeps(td_type: &str) -> bool {
+    matches!(td_type, "updateActiveNotifications" | "updateNotificationGroup" | "updateNotification" | "updateHavePendingNotifications")
+  }
+
+  /// apply an object returned by tdlib, the receive loop parses it only if `Notifications::keeps` its type
+  pub(crate) fn handle(&self, value: &Value) {
+    let update = match Update::deserialize(value) {
+      Ok(update) => update,
+      Err(e) => {
+        warn!("Can not deserialize a notification update: {:?}", e);
+        return;
+      }
+    };
+    if let Some(update) = update.as_active_notifications() {
+      let mut groups = self.groups.write().unwrap();
+      groups.clear();
+      for group in update.groups() {
+        groups.insert(group.id(), ActiveNotificationGroup::from(group));
+      }
+    }
+    if let Some(update) = update.as_notification_group() {
+      let mut groups = self.groups.write().unwrap();
+      let group = groups.entry(update.notification_group_id())
+        .or_insert_with(|| ActiveNotificationGroup::new(update.notification_group_id()));
+      group.apply(update);
+      if group.notifications.is_empty() {
+        groups.remove(&update.notification_group_id());
+      }
+    }
+    if let Some(update) = update.as_notification() {
+      if let Some(group) = self.groups.write().unwrap().get_mut(&update.notification_group_id()) {
+        let notification = update.notification();
+        if let Some(old) = group.notifications.iter_mut().find(|old| old.id() == notification.id()) {
+          *old = notification.clone();
+        }
+      }
+    }
+    if let Some(update) = update.as_have_pending_notifications() {
+      *self.pending.write().unwrap() = (update.have_delayed_notifications(), update.have_unreceived_notifications());
+    }
+  }
+}
+
+
+/// A group of active notifications
+#[derive(Debug, Clone)]
+pub struct ActiveNotificationGroup {
+  id: i64,
+  type_: NotificationGroupType,
+  chat_id: i64,
+  notification_settings_chat_id: i64,
+  is_silent: bool,
+  total_count: i64,
+  notifications: Vec<Notification>,
+}
+
+impl ActiveNotificationGroup {
+  fn new(id: i64) -> Self {
+    Self {
+      id,
+      type_: NotificationGroupType::messages(NotificationGroupTypeMessages::builder()),
+      chat_id: 0,
+      notification_settings_chat_id: 0,
+      is_silent: false,
+      total_count: 0,
+      notifications: vec![],
+    }
+  }
+
+  fn apply(&mut self, update: &UpdateNotificationGroup) {
+    self.type_ = update.type_().clone();
+    self.chat_id = update.chat_id();
+    self.notification_settings_chat_id = update.notification_settings_chat_id();
+    self.is_silent = update.is_silent();
+    self.total_count = update.total_count();
+    let removed = update.removed_notification_ids();
+    self.notifications.retain(|notification| !removed.contains(&notification.id()));
+    self.notifications.extend(update.added_notifications().iter().cloned());
+    self.notifications.sort_by_key(|notification| notification.id());
+  }
+
+  /// Unique persistent auto-incremented from 1 identifier of the notification group
+  pub fn id(&self) -> i64 { self.id }
+  /// Type of the group
+  pub fn type_(&self) -> &NotificationGroupType { &self.type_ }
+  /// Identifier of a chat to which all notifications in the group belong
+  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// Chat identifier, which notification settings must be applied to the added notifications
+  pub fn notification_settings_chat_id(&self) -> i64 { self.notification_settings_chat_id }
+  /// True, if the last notifications of the group must be shown without sound
+  pub fn is_silent(&self) -> bool { self.is_silent }
+  /// Total number of active notifications in the group
+  pub fn total_count(&self) -> i64 { self.total_count }
+  /// The active notifications of the group, ordered by identifier
+  pub fn notifications(&self) -> &Vec<Notification> { &self.notifications }
+  /// Identifier of the last notification, 0 if no notification
+  pub fn max_notification_id(&self) -> i64 {
+    self.notifications.last().map(|notification| notification.id()).unwrap_or(0)
+  }
+}
+
+impl From<&NotificationGroup> for ActiveNotificationGroup {
+  fn from(group: &NotificationGroup) -> Self {
+    Self {
+      id: group.id(),
+      type_: group.type_().clone(),
+      chat_id: group.chat_id(),
+      notification_settings_chat_id: group.chat_id(),
+      is_silent: false,
+      total_count: group.total_count(),
+      notifications: group.notifications().clone(),
+    }
+  }
+}
+
+
+impl Api {
+  /// Removes an active notification from notification list (`removeNotification`)
+  pub fn dismiss_notification(&self, notification_group_id: i64, notification_id: i64) -> RTDResult<Pending<Ok>> {
+    self.request(RemoveNotification::builder()
+      .notification_group_id(notification_group_id)
+      .notification_id(notification_id)
+      .build())
+  }
+
+  /// Removes all known notifications of the group (`removeNotificationGroup`), the group must be
+  /// known by `Api::notifications`.
+  pub fn dismiss_notification_group(&self, notification_group_id: i64) -> RTDResult<Pending<Ok>> {
+    let max_notification_id = self.notifications()
+      .and_then(|notifications| notifications.group(notification_group_id))
+      .map(|group| group.max_notification_id())
+      .ok_or_else(|| RTDError::custom("Unknown notification group"))?;
+    self.request(RemoveNotificationGroup::builder()
+      .notification_group_id(notification_group_id)
+      .max_notification_id(max_notification_id)
+      .build())
+  }
+}
@@ -0,0 +1,334 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, TryRecvError};
+use std::time::{Duration, Instant};
+
+use rtdlib::errors::*;
+use rtdlib::types as rtd_types;
+use serde::de::DeserializeOwned;
+
+use crate::scan::{decode, scan, Scanned};
+
+/// Route tdlib responses back to the request who send it.
+///
+/// Every request send by `Api::request` carries a unique `@extra`, tdlib returns the same `@extra`
+/// in the response, the observer use it to find the waiting `Pending`.
+/// Updates don't have `@extra`, the observer can forward a copy of them to watchers by td type.
+///
+/// Each `Api` has its own observer, the waiting requests are split into shards by `@extra`, so
+/// threads sending requests and the receive loop don't wait for one lock.
+#[derive(Debug)]
+pub(crate) struct Observer {
+  counter: AtomicUsize,
+  waiters: Vec<Mutex<HashMap<String, Waiter>>>,
+  watchers: Mutex<HashMap<String, Vec<Sender<String>>>>,
+  ttl: Option<Duration>,
+  last_sweep: Mutex<Option<Instant>>,
+  closed: AtomicBool,
+}
+
+#[derive(Debug)]
+struct Waiter {
+  sender: Sender<String>,
+  td_type: String,
+  since: Instant,
+}
+
+/// The watcher key of every update
+pub(crate) const ALL_UPDATES: &str = "*";
+
+/// Number of waiting request shards of the observer by default
+pub const DEFAULT_OBSERVER_SHARDS: usize = 16;
+
+impl Default for Observer {
+  fn default() -> Self { Observer::with_shards(DEFAULT_OBSERVER_SHARDS) }
+}
+
+impl Observer {
+  pub fn new() -> Self { Observer::default() }
+
+  pub fn with_shards(shards: usize) -> Self {
+    Self {
+      counter: AtomicUsize::new(0),
+      waiters: (0..shards.max(1)).map(|_| Mutex::new(HashMap::new())).collect(),
+      watchers: Mutex::new(HashMap::new()),
+      ttl: None,
+      last_sweep: Mutex::new(None),
+      closed: AtomicBool::new(false),
+    }
+  }
+
+  /// expire the requests without response after this time, see `Observer::sweep`
+  pub fn with_ttl(mut self, ttl: Option<Duration>) -> Self {
+    self.ttl = ttl;
+    self
+  }
+
+  pub fn shards(&self) -> usize { self.waiters.len() }
+
+  pub fn ttl(&self) -> Option<Duration> { self.ttl }
+
+  /// generate a new `@extra` value
+  pub fn next_extra(&self) -> String {
+    format!("tgc_{}", self.counter.fetch_add(1, Ordering::SeqCst))
+  }
+
+  fn shard(&self, extra: &str) -> &Mutex<HashMap<String, Waiter>> {
+    let mut hasher = DefaultHasher::new();
+    extra.hash(&mut hasher);
+    &self.waiters[hasher.finish() as usize % self.waiters.len()]
+  }
+
+  /// wait the response of this `@extra`, the request is a `td_type` function
+  pub fn subscribe<S: AsRef<str>, T: AsRef<str>>(&self, extra: S, td_type: T) -> Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+    self.subscribe_with(extra, td_type, sender);
+    receiver
+  }
+
+  /// wait the response of this `@extra` in an existing channel, several requests can share one channel
+  pub fn subscribe_with<S: AsRef<str>, T: AsRef<str>>(&self, extra: S, td_type: T, sender: Sender<String>) {
+    let waiter = Waiter { sender, td_type: td_type.as_ref().to_string(), since: Instant::now() };
+    self.shard(extra.as_ref()).lock().unwrap().insert(extra.as_ref().to_string(), waiter);
+  }
+
+  /// stop waiting the response of this `@extra`
+  pub fn unsubscribe<S: AsRef<str>>(&self, extra: S) {
+    self.shard(extra.as_ref()).lock().unwrap().remove(extra.as_ref());
+  }
+
+  /// all requests waiting for the response, the oldest first
+  pub fn pending_requests(&self) -> Vec<PendingRequest> {
+    let mut requests: Vec<PendingRequest> = self.waiters.iter()
+      .flat_map(|shard| {
+        shard.lock().unwrap().iter()
+          .map(|(extra, waiter)| PendingRequest {
+            extra: extra.clone(),
+            td_type: waiter.td_type.clone(),
+            elapsed: waiter.since.elapsed(),
+          })
+          .collect::<Vec<PendingRequest>>()
+      })
+      .collect();
+    requests.sort_by_key(|request| std::cmp::Reverse(request.elapsed));
+    requests
+  }
+
+  /// the client is logged out, drop all waiting requests, their `Pending` and the later requests fail with
+  /// `RTDError::NotAuthorized`; return the number of dropped requests
+  pub fn close(&self) -> usize {
+    self.closed.store(true, Ordering::SeqCst);
+    self.waiters.iter()
+      .map(|shard| shard.lock().unwrap().drain().count())
+      .sum()
+  }
+
+  /// true after `Observer::close`, no response will be received
+  pub fn is_closed(&self) -> bool { self.closed.load(Ordering::SeqCst) }
+
+  /// drop the requests waiting longer than the ttl, their `Pending` fail with a timeout error.
+  /// The sweep runs at most once a second, return the number of expired requests.
+  pub fn sweep(&self) -> usize {
+    let ttl = match self.ttl {
+      Some(ttl) => ttl,
+      None => return 0
+    };
+    {
+      let mut last_sweep = self.last_sweep.lock().unwrap();
+      if last_sweep.is_some_and(|last| last.elapsed() < Duration::from_secs(1)) {
+        return 0;
+      }
+      *last_sweep = Some(Instant::now());
+    }
+    let mut expired = 0;
+    for shard in &self.waiters {
+      shard.lock().unwrap().retain(|extra, waiter| {
+        if waiter.since.elapsed() < ttl { return true; }
+        warn!("Request {} ({}) is expired, no response received in {:?}", extra, waiter.td_type, ttl);
+        let error = serde_json::json!({"@type": "error", "code": 408, "message": "Request timeout", "@extra": extra});
+        let _ = waiter.sender.send(error.to_string());
+        expired += 1;
+        false
+      });
+    }
+    expired
+  }
+
+  /// current number of waiting requests and watchers
+  pub fn stats(&self) -> ObserverStats {
+    let shard_sizes: Vec<usize> = self.waiters.iter().map(|shard| shard.lock().unwrap().len()).collect();
+    let watchers = self.watchers.lock().unwrap();
+    ObserverStats {
+      pending_requests: shard_sizes.iter().sum(),
+      max_shard_requests: shard_sizes.iter().cloned().max().unwrap_or(0),
+      shards: shard_sizes.len(),
+      watched_types: watchers.len(),
+      watchers: watchers.values().map(|senders| senders.len()).sum(),
+    }
+  }
+
+  /// receive a copy of every `td_type` object returned by tdlib, stop watching by drop the receiver
+  pub fn watch<S: AsRef<str>>(&self, td_type: S) -> Receiver<String> {
+    self.watch_all(&[td_type])
+  }
+
+  /// receive a copy of every object of these td types in one channel, `ALL_UPDATES` watches every update
+  pub fn watch_all<S: AsRef<str>>(&self, td_types: &[S]) -> Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watchers = self.watchers.lock().unwrap();
+    for td_type in td_types {
+      watchers.entry(td_type.as_ref().to_string())
+        .or_default()
+        .push(sender.clone());
+    }
+    receiver
+  }
+
+  /// dispatch json returned by tdlib, return true if this json is a response of a waiting request,
+  /// the json is consumed and should not be handled by listener.
+  pub fn notify(&self, json: &str, scanned: &Scanned) -> bool {
+    if let Some(extra) = scanned.extra() {
+      if let Some(waiter) = self.shard(extra).lock().unwrap().remove(extra) {
+        return waiter.sender.send(json.to_string()).is_ok();
+      }
+    }
+
+    if let Some(td_type) = scanned.td_type() {
+      let mut watchers = self.watchers.lock().unwrap();
+      Self::forward(&mut watchers, td_type, json);
+      if td_type.starts_with("update") {
+        Self::forward(&mut watchers, ALL_UPDATES, json);
+      }
+    }
+    false
+  }
+
+  fn forward(watchers: &mut HashMap<String, Vec<Sender<String>>>, key: &str, json: &str) {
+    if let Some(senders) = watchers.get_mut(key) {
+      senders.retain(|sender| sender.send(json.to_string()).is_ok());
+      if senders.is_empty() {
+        watchers.remove(key);
+      }
+    }
+  }
+}
+
+/// A request waiting for the response, see `Api::pending_requests`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingRequest {
+  extra: String,
+  td_type: String,
+  elapsed: Duration,
+}
+
+impl PendingRequest {
+  /// The `@extra` of the request
+  pub fn extra(&self) -> &String { &self.extra }
+  /// The td type of the request function
+  pub fn td_type(&self) -> &String { &self.td_type }
+  /// Time since the request is send
+  pub fn elapsed(&self) -> Duration { self.elapsed }
+}
+
+
+/// Capacity metrics of the observer of an `Api`, see `Api::observer_stats`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObserverStats {
+  pending_requests: usize,
+  max_shard_requests: usize,
+  shards: usize,
+  watched_types: usize,
+  watchers: usize,
+}
+
+impl ObserverStats {
+  /// Number of requests waiting for the response
+  pub fn pending_requests(&self) -> usize { self.pending_requests }
+  /// Number of waiting requests in the fullest shard
+  pub fn max_shard_requests(&self) -> usize { self.max_shard_requests }
+  /// Number of waiting request shards
+  pub fn shards(&self) -> usize { self.shards }
+  /// Number of td types watched
+  pub fn watched_types(&self) -> usize { self.watched_types }
+  /// Number of watchers of all td types
+  pub fn watchers(&self) -> usize { self.watchers }
+}
+
+
+/// A request already send to tdlib, and the response is not yet received.
+///
+/// The response is delivered by the receiver thread started by `Client::start`, it can be waited in
+/// a listener callback, but the next listener callbacks are delayed until the response is received.
+#[derive(Debug)]
+pub struct Pending<T> {
+  extra: String,
+  receiver: Receiver<String>,
+  observer: Arc<Observer>,
+  _marker: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> Pending<T> {
+  pub(crate) fn new(extra: String, receiver: Receiver<String>, observer: Arc<Observer>) -> Self {
+    Self { extra, receiver, observer, _marker: PhantomData }
+  }
+
+  /// The `@extra` value of this request
+  pub fn extra(&self) -> &String { &self.extra }
+
+  /// Block until the response is received.
+  pub fn wait(self) -> RTDResult<T> {
+    match self.receiver.recv() {
+      Ok(json) => Self::parse(json, &self.extra),
+      Err(_) => Err(self.stopped()),
+    }
+  }
+
+  /// Block until the response is received or timeout.
+  pub fn wait_timeout(self, timeout: Duration) -> RTDResult<T> {
+    match self.receiver.recv_timeout(timeout) {
+      Ok(json) => Self::parse(json, &self.extra),
+      Err(RecvTimeoutError::Timeout) => Err(RTDError::custom("Wait tdlib response timeout")),
+      Err(RecvTimeoutError::Disconnected) => Err(self.stopped()),
+    }
+  }
+
+  /// Get the response if it's already received, or return `None` immediately.
+  pub fn try_wait(&self) -> Option<RTDResult<T>> {
+    match self.receiver.try_recv() {
+      Ok(json) => Some(Self::parse(json, &self.extra)),
+      Err(TryRecvError::Empty) => None,
+      Err(TryRecvError::Disconnected) => Some(Err(self.stopped())),
+    }
+  }
+
+  /// the error of a request whose response will never be received
+  fn stopped(&self) -> RTDError {
+    if self.observer.is_closed() {
+      return RTDError::NotAuthorized;
+    }
+    RTDError::custom("The receive loop is stopped, no response will be received")
+  }
+
+  fn parse(json: String, extra: &str) -> RTDResult<T> {
+    parse_response(&json, extra)
+  }
+}
+
+/// the response of a request, or the error returned by tdlib
+pub(crate) fn parse_response<T: DeserializeOwned>(json: &str, extra: &str) -> RTDResult<T> {
+  if scan(json).td_type() == Some("error") {
+    let error = decode::<rtd_types::Error>(json)?;
+    return Err(RTDError::TdlibError(TdError::from(error).with_extra(extra)));
+  }
+  decode::<T>(json)
+}
+
+impl<T> Drop for Pending<T> {
+  fn drop(&mut self) {
+    self.observer.unsubscribe(&self.extra);
+  }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::api::Api;
+use crate::observer::Pending;
+
+/// Values of tdlib options, kept up to date by `updateOption` in the receive loop.
+///
+/// tdlib sends the values of all options after start, so `my_id` is known when the authorization is ready.
+#[derive(Debug, Default)]
+pub struct Options {
+  values: RwLock<HashMap<String, OptionValue>>,
+}
+
+impl Options {
+  pub fn new() -> Self { Options::default() }
+
+  /// The value of this option; `None` if the option is empty or not received yet
+  pub fn get<S: AsRef<str>>(&self, name: S) -> Option<OptionValue> {
+    self.values.read().unwrap().get(name.as_ref()).cloned()
+  }
+
+  /// The value of this boolean option
+  pub fn boolean<S: AsRef<str>>(&self, name: S) -> Option<bool> {
+    self.get(name).and_then(|value| value.as_boolean().map(|v| v.value()))
+  }
+
+  /// The value of this integer option
+  pub fn integer<S: AsRef<str>>(&self, name: S) -> Option<i64> {
+    self.get(name).and_then(|value| value.as_integer().map(|v| v.value()))
+  }
+
+  /// The value of this string option
+  pub fn string<S: AsRef<str>>(&self, name: S) -> Option<String> {
+    self.get(name).and_then(|value| value.as_string().map(|v| v.value().clone()))
+  }
+
+  /// Names of all received options
+  pub fn names(&self) -> Vec<String> {
+    self.values.read().unwrap().keys().cloned().collect()
+  }
+
+  /// Identifier of the current user
+  pub fn my_id(&self) -> Option<i64> { self.integer("my_id") }
+
+  /// TDLib version
+  pub fn version(&self) -> Option<String> { self.string("version") }
+
+  /// Point in time (Unix timestamp) when the user was authorized
+  pub fn authorization_date(&self) -> Option<i64> { self.integer("authorization_date") }
+
+  /// Current Unix timestamp on the server
+  pub fn unix_time(&self) -> Option<i64> { self.integer("unix_time") }
+
+  /// True, if the user is online
+  pub fn online(&self) -> Option<bool> { self.boolean("online") }
+
+  /// True, if the test environment is used
+  pub fn test_mode(&self) -> Option<bool> { self.boolean("test_mode") }
+
+  /// Maximum length of a message text
+  pub fn message_text_length_max(&self) -> Option<i64> { self.integer("message_text_length_max") }
+
+  /// Maximum length of a message caption
+  pub fn message_caption_length_max(&self) -> Option<i64> { self.integer("message_caption_length_max") }
+
+  /// apply an `updateOption`
+  pub(crate) fn handle(&self, value: &Value) {
+    let update = match UpdateOption::deserialize(value) {
+      Ok(update) => update,
+      Err(e) => {
+        warn!("Can not deserialize updateOption: {:?}", e);
+        return;
+      }
+    };
+    let mut values = self.values.write().unwrap();
+    if update.value().is_empty() {
+      values.remove(update.name());
+    } else {
+      values.insert(update.name().clone(), update.value().clone());
+    }
+  }
+}
+
+/// set the options of `ApiBuilder::option` when the authorization is ready
+pub(crate) fn start(api: &Api) -> Option<JoinHandle<()>> {
+  if api.ready_options().is_empty() { return None; }
+  Some(api.on_ready(set_ready_options))
+}
+
+fn set_ready_options(api: Api) {
+  for (name, value) in api.ready_options() {
+    if let Err(e) = api.set_option_value(name, value.clone()).and_then(|pending| pending.wait()) {
+      warn!("Can not set the option {}: {}", name, e);
+    }
+  }
+}
+
+
+impl Api {
+  /// Sets the value of a boolean option (`setOption`)
+  pub fn set_option_boolean<S: AsRef<str>>(&self, name: S, value: bool) -> RTDResult<Pending<Ok>> {
+    self.set_option_value(name, OptionValue::boolean(OptionValueBoolean::builder().value(value)))
+  }
+
+  /// Sets the value of an integer option (`setOption`)
+  pub fn set_option_integer<S: AsRef<str>>(&self, name: S, value: i64) -> RTDResult<Pending<Ok>> {
+    self.set_option_value(name, OptionValue::integer(OptionValueInteger::builder().value(value)))
+  }
+
+  /// Sets the value of a string option (`setOption`)
+  pub fn set_option_string<S: AsRef<str>, V: AsRef<str>>(&self, name: S, value: V) -> RTDResult<Pending<Ok>> {
+    self.set_option_value(name, OptionValue::string(OptionValueString::builder().value(value)))
+  }
+
+  /// Resets an option to its default value (`setOption` with `optionValueEmpty`)
+  pub fn reset_option<S: AsRef<str>>(&self, name: S) -> RTDResult<Pending<Ok>> {
+    self.set_option_value(name, OptionValue::empty(OptionValueEmpty::builder()))
+  }
+
+  /// Compare the version of the linked tdlib, `getOption("version")` waited at most `timeout`, with the
+  /// `TDLIB_VERSION` of the schema the types are generated from, and warn if they differ; true if they are the
+  /// same, or if the version of the schema is not known
+  pub fn check_tdlib_version(&self, timeout: Duration) -> RTDResult<bool> {
+    if TDLIB_VERSION.is_empty() { return Ok(true); }
+    let value: OptionValue = self.request(GetOption::builder().name("version").build())?.wait_timeout(timeout)?;
+    let version = match value.as_string() {
+      Some(version) => version.value().clone(),
+      None => return Err(RTDError::custom("The version option of tdlib is not a string")),
+    };
+    if version == TDLIB_VERSION { return Ok(true); }
+    warn!("The linked tdlib is {}, but the types are generated from the schema of tdlib {} ({}), some objects may not be deserialized",
+          version, TDLIB_VERSION, TDLIB_SCHEMA_COMMIT);
+    Ok(false)
+  }
+
+  fn set_option_value<S: AsRef<str>>(&self, name: S, value: OptionValue) -> RTDResult<Pending<Ok>> {
+    self.request(SetOption::builder().name(name).value(value).build())
+  }
+}
@@ -0,0 +1,203 @@
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use rtdlib::errors::*;
+use rtdlib::types::RFunction;
+
+use crate::api::Api;
+use crate::observer::parse_response;
+
+const EXTRA_PREFIX: &str = "outbox:";
+/// wait before sending again a request failed by a temporary error
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+/// check the authorization state and the retry time at least this often
+const POLL: Duration = Duration::from_secs(1);
+
+/// Persistent queue of requests, see `ApiBuilder::outbox` and `Api::enqueue`.
+///
+/// A request is kept until tdlib returned its response, the requests enqueued are sent when the authorization
+/// is ready, so the requests not answered when the process stopped are sent again after the client starts.
+/// It's at-least-once: a request sent but not answered before a crash is sent twice, `OutboxEntry::attempts`
+/// tells which ones, remove them before the client starts if they aren't idempotent, like `sendMessage`.
+///
+/// A request failed by a temporary error (flood wait, unauthorized, internal error, timeout) is sent again later,
+/// a request failed by another error is removed.
+#[derive(Debug)]
+pub struct Outbox {
+  entries: sled::Tree,
+  last_id: AtomicU64,
+  sender: Mutex<Sender<String>>,
+  receiver: Mutex<Receiver<String>>,
+}
+
+/// A request of the outbox
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxEntry {
+  id: u64,
+  attempts: u32,
+  td_type: String,
+  json: String,
+}
+
+impl OutboxEntry {
+  /// Id returned by `Api::enqueue`, increasing from 1
+  pub fn id(&self) -> u64 { self.id }
+  /// How many times the request was sent, the last one not answered yet if greater than 0
+  pub fn attempts(&self) -> u32 { self.attempts }
+  pub fn td_type(&self) -> &String { &self.td_type }
+  pub fn json(&self) -> &String { &self.json }
+}
+
+fn sled_error(err: sled::Error) -> RTDError {
+  RTDError::Io(io::Error::from(err))
+}
+
+impl Outbox {
+  /// Open the outbox in this directory, create it if not exists
+  pub fn open<P: AsRef<Path>>(path: P) -> RTDResult<Self> {
+    let db = sled::open(path).map_err(sled_error)?;
+    let entries = db.open_tree("outbox").map_err(sled_error)?;
+    let last_id = entries.last().map_err(sled_error)?
+      .map_or(0, |(key, _)| Self::decode_id(&key));
+    let (sender, receiver) = mpsc::channel();
+    Ok(Self { entries, last_id: AtomicU64::new(last_id), sender: Mutex::new(sender), receiver: Mutex::new(receiver) })
+  }
+
+  /// store a request, return its id
+  pub(crate) fn push<Fnc: RFunction>(&self, fnc: &Fnc) -> RTDResult<u64> {
+    let json = fnc.to_json()?;
+    let id = self.last_id.fetch_add(1, Ordering::SeqCst) + 1;
+    self.write(&OutboxEntry { id, attempts: 0, td_type: fnc.td_name().to_string(), json })?;
+    self.entries.flush().map_err(sled_error)?;
+    // wake the sender, an empty string isn't a response
+    let _ = self.sender.lock().unwrap().send(String::new());
+    Ok(id)
+  }
+
+  /// Number of requests not answered yet
+  pub fn len(&self) -> usize { self.entries.len() }
+
+  pub fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+  /// The requests not answered yet, the oldest first
+  pub fn entries(&self) -> RTDResult<Vec<OutboxEntry>> {
+    self.entries.iter().map(|item| {
+      let (key, value) = item.map_err(sled_error)?;
+      let value = String::from_utf8_lossy(&value);
+      let mut parts = value.splitn(3, '\t');
+      match (parts.next().and_then(|attempts| attempts.parse().ok()), parts.next(), parts.next()) {
+        (Some(attempts), Some(td_type), Some(json)) => Ok(OutboxEntry {
+          id: Self::decode_id(&key),
+          attempts,
+          td_type: td_type.to_string(),
+          json: json.to_string(),
+        }),
+        _ => Err(RTDError::custom("Broken outbox entry")),
+      }
+    }).collect()
+  }
+
+  /// Remove a request, it isn't sent again; return false if it's already answered
+  pub fn remove(&self, id: u64) -> RTDResult<bool> {
+    Ok(self.entries.remove(id.to_be_bytes()).map_err(sled_error)?.is_some())
+  }
+
+  fn write(&self, entry: &OutboxEntry) -> RTDResult<()> {
+    // tdlib json has no line break, `td_type` has no tab
+    let value = format!("{}\t{}\t{}", entry.attempts, entry.td_type, entry.json);
+    self.entries.insert(entry.id.to_be_bytes(), value.as_bytes()).map_err(sled_error)?;
+    Ok(())
+  }
+
+  fn decode_id(bytes: &[u8]) -> u64 {
+    let mut id = [0u8; 8];
+    id.copy_from_slice(&bytes[..8]);
+    u64::from_be_bytes(id)
+  }
+}
+
+
+/// start the thread sending the requests of the outbox of this api, it stops when the client stops
+pub(crate) fn start(api: &Api) -> Option<JoinHandle<()>> {
+  api.outbox()?;
+  Some(api.on_ready(run))
+}
+
+fn run(api: Api) {
+  let outbox = match api.outbox() {
+    Some(outbox) => outbox,
+    None => return,
+  };
+  let receiver = outbox.receiver.lock().unwrap();
+  let mut in_flight: HashSet<u64> = HashSet::new();
+  let mut retry_at: HashMap<u64, Instant> = HashMap::new();
+  while !api.update_queue().is_closed() {
+    let now = Instant::now();
+    match outbox.entries() {
+      Ok(entries) => for entry in entries {
+        if in_flight.contains(&entry.id) || retry_at.get(&entry.id).is_some_and(|at| *at > now) {
+          continue;
+        }
+        retry_at.remove(&entry.id);
+        let id = entry.id;
+        match send(&api, outbox, entry) {
+          Ok(()) => { in_flight.insert(id); }
+          Err(e) => error!("Can not send the request {} of the outbox: {}", id, e),
+        }
+      },
+      Err(e) => error!("Can not read the outbox: {}", e),
+    }
+    let json = match receiver.recv_timeout(POLL) {
+      Ok(json) => json,
+      Err(RecvTimeoutError::Timeout) => continue,
+      Err(RecvTimeoutError::Disconnected) => break,
+    };
+    let extra = match crate::scan::scan(&json).extra() {
+      Some(extra) => extra.to_string(),
+      None => continue,
+    };
+    let id = match extra.strip_prefix(EXTRA_PREFIX).and_then(|id| id.parse::<u64>().ok()) {
+      Some(id) => id,
+      None => continue,
+    };
+    in_flight.remove(&id);
+    match parse_response::<serde_json::Value>(&json, &extra) {
+      Err(RTDError::TdlibError(e)) => match e.kind() {
+        TdErrorKind::FloodWait(seconds) => { retry_at.insert(id, Instant::now() + Duration::from_secs(seconds.max(0) as u64)); }
+        TdErrorKind::Unauthorized | TdErrorKind::Internal | TdErrorKind::Timeout => { retry_at.insert(id, Instant::now() + RETRY_DELAY); }
+        _ => {
+          warn!("The request {} of the outbox failed, it's removed: {}", id, e);
+          let _ = outbox.remove(id);
+        }
+      },
+      _ => if let Err(e) = outbox.remove(id) {
+        error!("Can not remove the request {} of the outbox: {}", id, e);
+      },
+    }
+  }
+}
+
+/// send a request of the outbox, its response is received by the channel of the outbox; a broken request is removed
+fn send(api: &Api, outbox: &Outbox, mut entry: OutboxEntry) -> RTDResult<()> {
+  let extra = format!("{}{}", EXTRA_PREFIX, entry.id);
+  let mut value = serde_json::from_str::<serde_json::Value>(&entry.json).ok();
+  match value.as_mut().and_then(|value| value.as_object_mut()) {
+    Some(map) => map.insert("@extra".to_string(), serde_json::Value::String(extra.clone())),
+    None => {
+      outbox.remove(entry.id)?;
+      return Err(RTDError::custom("The request is not a json object, it's removed"));
+    }
+  };
+  entry.attempts += 1;
+  outbox.write(&entry)?;
+  let sender = outbox.sender.lock().unwrap().clone();
+  api.observer().subscribe_with(&extra, &entry.td_type, sender);
+  api.send_json(&value.unwrap_or_default().to_string());
+  Ok(())
+}
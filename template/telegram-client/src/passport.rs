@@ -0,0 +1,258 @@
+use std::fmt;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+
+/// The kind of a Telegram Passport element, the `PassportElementType` without its td objects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PassportElementKind {
+  PersonalDetails,
+  Passport,
+  DriverLicense,
+  IdentityCard,
+  InternalPassport,
+  Address,
+  UtilityBill,
+  BankStatement,
+  RentalAgreement,
+  PassportRegistration,
+  TemporaryRegistration,
+  PhoneNumber,
+  EmailAddress,
+}
+
+impl PassportElementKind {
+  /// Every kind, in the order of the schema
+  pub const ALL: [PassportElementKind; 13] = [
+    PassportElementKind::PersonalDetails,
+    PassportElementKind::Passport,
+    PassportElementKind::DriverLicense,
+    PassportElementKind::IdentityCard,
+    PassportElementKind::InternalPassport,
+    PassportElementKind::Address,
+    PassportElementKind::UtilityBill,
+    PassportElementKind::BankStatement,
+    PassportElementKind::RentalAgreement,
+    PassportElementKind::PassportRegistration,
+    PassportElementKind::TemporaryRegistration,
+    PassportElementKind::PhoneNumber,
+    PassportElementKind::EmailAddress,
+  ];
+
+  /// The kind of this element type, `None` of an element type unknown to this version
+  pub fn of_type(element_type: &PassportElementType) -> Option<Self> {
+    Some(match element_type {
+      PassportElementType::PersonalDetails(_) => PassportElementKind::PersonalDetails,
+      PassportElementType::Passport(_) => PassportElementKind::Passport,
+      PassportElementType::DriverLicense(_) => PassportElementKind::DriverLicense,
+      PassportElementType::IdentityCard(_) => PassportElementKind::IdentityCard,
+      PassportElementType::InternalPassport(_) => PassportElementKind::InternalPassport,
+      PassportElementType::Address(_) => PassportElementKind::Address,
+      PassportElementType::UtilityBill(_) => PassportElementKind::UtilityBill,
+      PassportElementType::BankStatement(_) => PassportElementKind::BankStatement,
+      PassportElementType::RentalAgreement(_) => PassportElementKind::RentalAgreement,
+      PassportElementType::PassportRegistration(_) => PassportElementKind::PassportRegistration,
+      PassportElementType::TemporaryRegistration(_) => PassportElementKind::TemporaryRegistration,
+      PassportElementType::PhoneNumber(_) => PassportElementKind::PhoneNumber,
+      PassportElementType::EmailAddress(_) => PassportElementKind::EmailAddress,
+      _ => return None,
+    })
+  }
+
+  /// The kind of this element
+  pub fn of_element(element: &PassportElement) -> Option<Self> {
+    Some(match element {
+      PassportElement::PersonalDetails(_) => PassportElementKind::PersonalDetails,
+      PassportElement::Passport(_) => PassportElementKind::Passport,
+      PassportElement::DriverLicense(_) => PassportElementKind::DriverLicense,
+      PassportElement::IdentityCard(_) => PassportElementKind::IdentityCard,
+      PassportElement::InternalPassport(_) => PassportElementKind::InternalPassport,
+      PassportElement::Address(_) => PassportElementKind::Address,
+      PassportElement::UtilityBill(_) => PassportElementKind::UtilityBill,
+      PassportElement::BankStatement(_) => PassportElementKind::BankStatement,
+      PassportElement::RentalAgreement(_) => PassportElementKind::RentalAgreement,
+      PassportElement::PassportRegistration(_) => PassportElementKind::PassportRegistration,
+      PassportElement::TemporaryRegistration(_) => PassportElementKind::TemporaryRegistration,
+      PassportElement::PhoneNumber(_) => PassportElementKind::PhoneNumber,
+      PassportElement::EmailAddress(_) => PassportElementKind::EmailAddress,
+      _ => return None,
+    })
+  }
+
+  /// The kind of this element to be saved
+  pub fn of_input(element: &InputPassportElement) -> Option<Self> {
+    Some(match element {
+      InputPassportElement::PersonalDetails(_) => PassportElementKind::PersonalDetails,
+      InputPassportElement::Passport(_) => PassportElementKind::Passport,
+      InputPassportElement::DriverLicense(_) => PassportElementKind::DriverLicense,
+      InputPassportElement::IdentityCard(_) => PassportElementKind::IdentityCard,
+      InputPassportElement::InternalPassport(_) => PassportElementKind::InternalPassport,
+      InputPassportElement::Address(_) => PassportElementKind::Address,
+      InputPassportElement::UtilityBill(_) => PassportElementKind::UtilityBill,
+      InputPassportElement::BankStatement(_) => PassportElementKind::BankStatement,
+      InputPassportElement::RentalAgreement(_) => PassportElementKind::RentalAgreement,
+      InputPassportElement::PassportRegistration(_) => PassportElementKind::PassportRegistration,
+      InputPassportElement::TemporaryRegistration(_) => PassportElementKind::TemporaryRegistration,
+      InputPassportElement::PhoneNumber(_) => PassportElementKind::PhoneNumber,
+      InputPassportElement::EmailAddress(_) => PassportElementKind::EmailAddress,
+      _ => return None,
+    })
+  }
+
+  /// The element type of tdlib
+  pub fn to_type(&self) -> PassportElementType {
+    match self {
+      PassportElementKind::PersonalDetails => PassportElementType::personal_details(PassportElementTypePersonalDetails::builder()),
+      PassportElementKind::Passport => PassportElementType::passport(PassportElementTypePassport::builder()),
+      PassportElementKind::DriverLicense => PassportElementType::driver_license(PassportElementTypeDriverLicense::builder()),
+      PassportElementKind::IdentityCard => PassportElementType::identity_card(PassportElementTypeIdentityCard::builder()),
+      PassportElementKind::InternalPassport => PassportElementType::internal_passport(PassportElementTypeInternalPassport::builder()),
+      PassportElementKind::Address => PassportElementType::address(PassportElementTypeAddress::builder()),
+      PassportElementKind::UtilityBill => PassportElementType::utility_bill(PassportElementTypeUtilityBill::builder()),
+      PassportElementKind::BankStatement => PassportElementType::bank_statement(PassportElementTypeBankStatement::builder()),
+      PassportElementKind::RentalAgreement => PassportElementType::rental_agreement(PassportElementTypeRentalAgreement::builder()),
+      PassportElementKind::PassportRegistration => PassportElementType::passport_registration(PassportElementTypePassportRegistration::builder()),
+      PassportElementKind::TemporaryRegistration => PassportElementType::temporary_registration(PassportElementTypeTemporaryRegistration::builder()),
+      PassportElementKind::PhoneNumber => PassportElementType::phone_number(PassportElementTypePhoneNumber::builder()),
+      PassportElementKind::EmailAddress => PassportElementType::email_address(PassportElementTypeEmailAddress::builder()),
+    }
+  }
+
+  /// A passport, a driver license, an identity card or an internal passport: an `IdentityDocument` with its sides
+  /// and a selfie
+  pub fn is_identity_document(&self) -> bool {
+    matches!(self, PassportElementKind::Passport | PassportElementKind::DriverLicense | PassportElementKind::IdentityCard
+      | PassportElementKind::InternalPassport)
+  }
+
+  /// A bill, a statement, an agreement or a registration: a `PersonalDocument` of scanned files
+  pub fn is_personal_document(&self) -> bool {
+    matches!(self, PassportElementKind::UtilityBill | PassportElementKind::BankStatement | PassportElementKind::RentalAgreement
+      | PassportElementKind::PassportRegistration | PassportElementKind::TemporaryRegistration)
+  }
+}
+
+/// The Telegram Passport of the user unlocked by the password of the account, created by `Api::passport`.
+///
+/// tdlib encrypts the elements by a secret of the passport, itself encrypted by the password; every request
+/// but the deletion needs the password, kept here and never logged. The elements shared with a bot are encrypted
+/// again by tdlib for the public key of the bot, see `Passport::authorization_form`.
+#[derive(Clone)]
+pub struct Passport {
+  api: Api,
+  password: String,
+}
+
+impl fmt::Debug for Passport {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("Passport").field("password", &"<redacted>").finish()
+  }
+}
+
+impl Api {
+  /// The Telegram Passport of the user, unlocked by the password of the account
+  pub fn passport<S: AsRef<str>>(&self, password: S) -> Passport {
+    Passport { api: self.clone(), password: password.as_ref().to_string() }
+  }
+}
+
+impl Passport {
+  /// The element of this kind (`getPassportElement`), an error if it's not saved
+  pub fn element(&self, kind: PassportElementKind) -> RTDResult<PassportElement> {
+    self.api.request(GetPassportElement::builder().type_(kind.to_type()).password(&self.password).build())?.wait()
+  }
+
+  /// All saved elements (`getAllPassportElements`)
+  pub fn elements(&self) -> RTDResult<Vec<PassportElement>> {
+    let elements: PassportElements = self.api.request(GetAllPassportElements::builder().password(&self.password).build())?.wait()?;
+    Ok(elements.elements().clone())
+  }
+
+  /// The kinds of the saved elements
+  pub fn kinds(&self) -> RTDResult<Vec<PassportElementKind>> {
+    Ok(self.elements()?.iter().filter_map(PassportElementKind::of_element).collect())
+  }
+
+  /// Save an element (`setPassportElement`), it replaces the saved element of its kind; a phone number or an
+  /// email address must be verified before, see `sendPhoneNumberVerificationCode` and `sendEmailAddressVerificationCode`
+  pub fn save<T: AsRef<InputPassportElement>>(&self, element: T) -> RTDResult<PassportElement> {
+    self.api.request(SetPassportElement::builder().element(element).password(&self.password).build())?.wait()
+  }
+
+  /// Delete the element of this kind (`deletePassportElement`)
+  pub fn delete(&self, kind: PassportElementKind) -> RTDResult<()> {
+    let _: Ok = self.api.request(DeletePassportElement::builder().type_(kind.to_type()).build())?.wait()?;
+    Ok(())
+  }
+
+  /// The elements a bot asks for (`getPassportAuthorizationForm`) and the saved elements to choose from
+  /// (`getPassportAuthorizationFormAvailableElements`); the parameters are of the `tg://resolve?domain=telegrampassport`
+  /// link of the bot
+  pub fn authorization_form<S: AsRef<str>>(&self, bot_user_id: i64, scope: S, public_key: S, nonce: S) -> RTDResult<PassportAuthorization> {
+    let form: PassportAuthorizationForm = self.api.request(GetPassportAuthorizationForm::builder()
+      .bot_user_id(bot_user_id)
+      .scope(scope.as_ref())
+      .public_key(public_key.as_ref())
+      .nonce(nonce.as_ref())
+      .build())?.wait()?;
+    let available: PassportElementsWithErrors = self.api.request(GetPassportAuthorizationFormAvailableElements::builder()
+      .autorization_form_id(form.id())
+      .password(&self.password)
+      .build())?.wait()?;
+    Ok(PassportAuthorization { form, available })
+  }
+
+  /// Share the elements of these kinds with the bot of the form (`sendPassportAuthorizationForm`), tdlib encrypts
+  /// them and their credentials for the public key of the bot
+  pub fn authorize(&self, authorization: &PassportAuthorization, kinds: &[PassportElementKind]) -> RTDResult<()> {
+    let types = kinds.iter().map(|kind| kind.to_type()).collect();
+    let _: Ok = self.api.request(SendPassportAuthorizationForm::builder()
+      .autorization_form_id(authorization.form.id())
+      .types(types)
+      .build())?.wait()?;
+    Ok(())
+  }
+}
+
+/// A Telegram Passport authorization form of a bot with the saved elements, see `Passport::authorization_form`
+#[derive(Debug, Clone)]
+pub struct PassportAuthorization {
+  form: PassportAuthorizationForm,
+  available: PassportElementsWithErrors,
+}
+
+impl PassportAuthorization {
+  pub fn form(&self) -> &PassportAuthorizationForm { &self.form }
+
+  /// The saved elements which can be shared
+  pub fn elements(&self) -> &Vec<PassportElement> { self.available.elements() }
+
+  /// The errors of the saved elements, fix them before sharing
+  pub fn errors(&self) -> &Vec<PassportElementError> { self.available.errors() }
+
+  /// The required elements no saved element is suitable for
+  pub fn missing(&self) -> Vec<&PassportRequiredElement> {
+    let saved = self.saved_kinds();
+    self.form.required_elements().iter()
+      .filter(|required| !self::suitable_kinds(required).iter().any(|kind| saved.contains(kind)))
+      .collect()
+  }
+
+  /// The first saved suitable kind of every required element, `None` if one is missing
+  pub fn choose(&self) -> Option<Vec<PassportElementKind>> {
+    let saved = self.saved_kinds();
+    self.form.required_elements().iter()
+      .map(|required| self::suitable_kinds(required).into_iter().find(|kind| saved.contains(kind)))
+      .collect()
+  }
+
+  fn saved_kinds(&self) -> Vec<PassportElementKind> {
+    self.elements().iter().filter_map(PassportElementKind::of_element).collect()
+  }
+}
+
+fn suitable_kinds(required: &PassportRequiredElement) -> Vec<PassportElementKind> {
+  required.suitable_elements().iter().filter_map(|suitable| PassportElementKind::of_type(suitable.type_())).collect()
+}
@@ -0,0 +1,202 @@
+use std::{error, fmt};
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+
+/// An error of a `Checkout`, a step done out of order or a failed request
+#[derive(Debug)]
+pub enum CheckoutError {
+  /// A request of the checkout failed, e.g. the message is not an invoice or the payment was declined by the bot
+  Request(RTDError),
+  /// The invoice needs this order info, one of `name`, `phone_number`, `email_address` and `shipping_address`
+  MissingOrderInfo(&'static str),
+  /// The order info is not validated yet, see `Checkout::submit_order_info`
+  OrderInfoRequired,
+  /// A shipping option is not chosen yet, see `Checkout::choose_shipping_option`
+  ShippingOptionRequired,
+  /// No shipping option has this id
+  UnknownShippingOption(String),
+  /// The user has no saved credentials
+  NoSavedCredentials,
+  /// The payment is not successful and no verification is asked
+  Declined,
+  /// The invoice is paid by this checkout already
+  AlreadyPaid,
+}
+
+impl From<RTDError> for CheckoutError {
+  fn from(err: RTDError) -> Self { CheckoutError::Request(err) }
+}
+
+impl fmt::Display for CheckoutError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      CheckoutError::Request(ref err) => write!(f, "Checkout request failed: {}", err),
+      CheckoutError::MissingOrderInfo(field) => write!(f, "The invoice needs the {} of the order info", field),
+      CheckoutError::OrderInfoRequired => write!(f, "The order info is not validated yet"),
+      CheckoutError::ShippingOptionRequired => write!(f, "A shipping option is not chosen yet"),
+      CheckoutError::UnknownShippingOption(ref id) => write!(f, "No shipping option {}", id),
+      CheckoutError::NoSavedCredentials => write!(f, "No saved credentials"),
+      CheckoutError::Declined => write!(f, "The payment is declined"),
+      CheckoutError::AlreadyPaid => write!(f, "The invoice is paid already"),
+    }
+  }
+}
+
+impl error::Error for CheckoutError {
+  fn cause(&self) -> Option<&dyn error::Error> {
+    match *self {
+      CheckoutError::Request(ref err) => Some(err),
+      _ => None,
+    }
+  }
+}
+
+/// The next step of a `Checkout`
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckoutStep {
+  /// Submit the order info the invoice needs, `Checkout::submit_order_info`
+  OrderInfo,
+  /// Choose one of these shipping options, `Checkout::choose_shipping_option`
+  ShippingOption(Vec<ShippingOption>),
+  /// Pay by the credentials, `Checkout::pay` or `Checkout::pay_with_saved`
+  Credentials,
+  /// Open this url to verify the payment, e.g. 3-D Secure; the bot is paid once it's verified
+  Verification(String),
+  /// The invoice is paid
+  Completed,
+}
+
+/// The payment of an invoice, step by step: the order info, the shipping option, then the credentials.
+///
+/// Created by `Api::checkout`, `Checkout::step` tells what is needed next; a step done out of order is a
+/// `CheckoutError` instead of an error of tdlib.
+#[derive(Debug)]
+pub struct Checkout {
+  api: Api,
+  chat_id: i64,
+  message_id: i64,
+  form: PaymentForm,
+  order_info_id: Option<String>,
+  shipping_options: Vec<ShippingOption>,
+  shipping_option_id: Option<String>,
+  result: Option<PaymentResult>,
+}
+
+impl Api {
+  /// Start paying the invoice of this message (`getPaymentForm`)
+  pub fn checkout(&self, chat_id: i64, message_id: i64) -> Result<Checkout, CheckoutError> {
+    let form: PaymentForm = self.request(GetPaymentForm::builder().chat_id(chat_id).message_id(message_id).build())?.wait()?;
+    Ok(Checkout {
+      api: self.clone(),
+      chat_id,
+      message_id,
+      form,
+      order_info_id: None,
+      shipping_options: vec![],
+      shipping_option_id: None,
+      result: None,
+    })
+  }
+}
+
+impl Checkout {
+  pub fn form(&self) -> &PaymentForm { &self.form }
+
+  pub fn invoice(&self) -> &Invoice { self.form.invoice() }
+
+  /// The order info saved by the user, to submit it again
+  pub fn saved_order_info(&self) -> Option<&OrderInfo> { self.form.saved_order_info().as_ref() }
+
+  /// The shipping options of the validated order info, empty if the invoice has none
+  pub fn shipping_options(&self) -> &Vec<ShippingOption> { &self.shipping_options }
+
+  /// The invoice needs a name, a phone number, an email address or a shipping address
+  pub fn needs_order_info(&self) -> bool {
+    let invoice = self.invoice();
+    invoice.need_name() || invoice.need_phone_number() || invoice.need_email_address() || invoice.need_shipping_address()
+  }
+
+  /// What is needed next
+  pub fn step(&self) -> CheckoutStep {
+    if let Some(result) = &self.result {
+      return if result.success() {
+        CheckoutStep::Completed
+      } else {
+        CheckoutStep::Verification(result.verification_url().clone())
+      };
+    }
+    if self.needs_order_info() && self.order_info_id.is_none() {
+      return CheckoutStep::OrderInfo;
+    }
+    if !self.shipping_options.is_empty() && self.shipping_option_id.is_none() {
+      return CheckoutStep::ShippingOption(self.shipping_options.clone());
+    }
+    CheckoutStep::Credentials
+  }
+
+  /// Check the order info has what the invoice needs, then validate it by the bot (`validateOrderInfo`); return
+  /// the shipping options to choose from, empty if the invoice has none
+  pub fn submit_order_info<T: AsRef<OrderInfo>>(&mut self, order_info: T, allow_save: bool) -> Result<&Vec<ShippingOption>, CheckoutError> {
+    if self.result.is_some() { return Err(CheckoutError::AlreadyPaid); }
+    let order_info = order_info.as_ref();
+    let invoice = self.invoice();
+    if invoice.need_name() && order_info.name().is_empty() { return Err(CheckoutError::MissingOrderInfo("name")); }
+    if invoice.need_phone_number() && order_info.phone_number().is_empty() { return Err(CheckoutError::MissingOrderInfo("phone_number")); }
+    if invoice.need_email_address() && order_info.email_address().is_empty() { return Err(CheckoutError::MissingOrderInfo("email_address")); }
+    if invoice.need_shipping_address() && order_info.shipping_address().is_none() { return Err(CheckoutError::MissingOrderInfo("shipping_address")); }
+    let validated: ValidatedOrderInfo = self.api.request(ValidateOrderInfo::builder()
+      .chat_id(self.chat_id)
+      .message_id(self.message_id)
+      .order_info(order_info)
+      .allow_save(allow_save)
+      .build())?.wait()?;
+    self.order_info_id = Some(validated.order_info_id().clone());
+    self.shipping_options = validated.shipping_options().clone();
+    self.shipping_option_id = None;
+    Ok(&self.shipping_options)
+  }
+
+  /// Choose one of the shipping options of the validated order info
+  pub fn choose_shipping_option<S: AsRef<str>>(&mut self, id: S) -> Result<(), CheckoutError> {
+    if self.result.is_some() { return Err(CheckoutError::AlreadyPaid); }
+    let id = id.as_ref();
+    if !self.shipping_options.iter().any(|option| option.id() == id) {
+      return Err(CheckoutError::UnknownShippingOption(id.to_string()));
+    }
+    self.shipping_option_id = Some(id.to_string());
+    Ok(())
+  }
+
+  /// Pay by these credentials (`sendPaymentForm`), return `CheckoutStep::Completed`, or
+  /// `CheckoutStep::Verification` if the payment needs to be verified at a url
+  pub fn pay<T: AsRef<InputCredentials>>(&mut self, credentials: T) -> Result<CheckoutStep, CheckoutError> {
+    match self.step() {
+      CheckoutStep::OrderInfo => return Err(CheckoutError::OrderInfoRequired),
+      CheckoutStep::ShippingOption(_) => return Err(CheckoutError::ShippingOptionRequired),
+      CheckoutStep::Verification(_) | CheckoutStep::Completed => return Err(CheckoutError::AlreadyPaid),
+      CheckoutStep::Credentials => {}
+    }
+    let result: PaymentResult = self.api.request(SendPaymentForm::builder()
+      .chat_id(self.chat_id)
+      .message_id(self.message_id)
+      .order_info_id(self.order_info_id.clone().unwrap_or_default())
+      .shipping_option_id(self.shipping_option_id.clone().unwrap_or_default())
+      .credentials(credentials)
+      .build())?.wait()?;
+    if !result.success() && result.verification_url().is_empty() {
+      return Err(CheckoutError::Declined);
+    }
+    self.result = Some(result);
+    Ok(self.step())
+  }
+
+  /// Pay by the credentials saved by the user, see `Checkout::pay`
+  pub fn pay_with_saved(&mut self) -> Result<CheckoutStep, CheckoutError> {
+    let saved = self.form.saved_credentials().as_ref().ok_or(CheckoutError::NoSavedCredentials)?;
+    let credentials = InputCredentials::saved(InputCredentialsSaved::builder().saved_credentials_id(saved.id()));
+    self.pay(credentials)
+  }
+}
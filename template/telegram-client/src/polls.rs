@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+use crate::observer::Pending;
+
+/// A poll to be sent by `Api::send_poll`, a regular poll by default
+#[derive(Debug, Clone)]
+pub struct PollDraft {
+  question: String,
+  options: Vec<String>,
+  is_anonymous: bool,
+  type_: PollType,
+  open_period: i64,
+  close_date: i64,
+  is_closed: bool,
+}
+
+impl Default for PollDraft {
+  fn default() -> Self {
+    Self {
+      question: "".to_string(),
+      options: vec![],
+      is_anonymous: true,
+      type_: PollType::regular(PollTypeRegular::builder().allow_multiple_answers(false)),
+      open_period: 0,
+      close_date: 0,
+      is_closed: false,
+    }
+  }
+}
+
+impl PollDraft {
+  pub fn builder() -> PollDraftBuilder {
+    PollDraftBuilder { inner: PollDraft::default() }
+  }
+
+  pub fn question(&self) -> &String { &self.question }
+  pub fn options(&self) -> &Vec<String> { &self.options }
+  pub fn is_anonymous(&self) -> bool { self.is_anonymous }
+  pub fn type_(&self) -> &PollType { &self.type_ }
+  pub fn open_period(&self) -> i64 { self.open_period }
+  pub fn close_date(&self) -> i64 { self.close_date }
+  pub fn is_closed(&self) -> bool { self.is_closed }
+
+  /// The content to be sent by `sendMessage`
+  pub fn to_input_message_content(&self) -> InputMessageContent {
+    InputMessageContent::input_message_poll(InputMessagePoll::builder()
+      .question(&self.question)
+      .options(self.options.clone())
+      .is_anonymous(self.is_anonymous)
+      .type_(&self.type_)
+      .open_period(self.open_period)
+      .close_date(self.close_date)
+      .is_closed(self.is_closed))
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct PollDraftBuilder {
+  inner: PollDraft
+}
+
+impl PollDraftBuilder {
+  pub fn build(&self) -> PollDraft { self.inner.clone() }
+
+  /// Poll question, 1-255 characters (up to 300 characters for bots)
+  pub fn question<S: AsRef<str>>(&mut self, question: S) -> &mut Self {
+    self.inner.question = question.as_ref().to_string();
+    self
+  }
+
+  /// Add an answer option, a poll has 2-10 options of 1-100 characters each
+  pub fn option<S: AsRef<str>>(&mut self, option: S) -> &mut Self {
+    self.inner.options.push(option.as_ref().to_string());
+    self
+  }
+
+  /// True, if the poll voters are anonymous, default is true
+  pub fn is_anonymous(&mut self, is_anonymous: bool) -> &mut Self {
+    self.inner.is_anonymous = is_anonymous;
+    self
+  }
+
+  /// A regular poll, allow to choose more than one option or not
+  pub fn regular(&mut self, allow_multiple_answers: bool) -> &mut Self {
+    self.inner.type_ = PollType::regular(PollTypeRegular::builder().allow_multiple_answers(allow_multiple_answers));
+    self
+  }
+
+  /// A quiz with exactly one correct answer, the index of the option and the text shown to wrong answers
+  pub fn quiz<S: AsRef<str>>(&mut self, correct_option_id: i64, explanation: S) -> &mut Self {
+    let explanation = FormattedText::builder().text(explanation.as_ref()).entities(vec![]).build();
+    self.inner.type_ = PollType::quiz(PollTypeQuiz::builder().correct_option_id(correct_option_id).explanation(explanation));
+    self
+  }
+
+  /// Amount of time the poll will be active after creation, in seconds; for bots only
+  pub fn open_period(&mut self, open_period: i64) -> &mut Self {
+    self.inner.open_period = open_period;
+    self
+  }
+
+  /// Point in time (Unix timestamp) when the poll will be automatically closed; for bots only
+  pub fn close_date(&mut self, close_date: i64) -> &mut Self {
+    self.inner.close_date = close_date;
+    self
+  }
+
+  /// True, if the poll needs to be sent already closed; for bots only
+  pub fn is_closed(&mut self, is_closed: bool) -> &mut Self {
+    self.inner.is_closed = is_closed;
+    self
+  }
+}
+
+impl AsRef<PollDraft> for PollDraft {
+  fn as_ref(&self) -> &PollDraft { self }
+}
+
+impl AsRef<PollDraft> for PollDraftBuilder {
+  fn as_ref(&self) -> &PollDraft { &self.inner }
+}
+
+
+/// The votes of a poll, the counts of `updatePoll` and the answers of `updatePollAnswer`
+#[derive(Debug, Clone)]
+pub struct PollTally {
+  poll: Poll,
+  answers: HashMap<i64, Vec<i64>>,
+}
+
+impl PollTally {
+  /// The latest known poll
+  pub fn poll(&self) -> &Poll { &self.poll }
+
+  /// Total number of voters
+  pub fn total_voter_count(&self) -> i64 { self.poll.total_voter_count() }
+
+  /// Number of voters of each option, in the order of the options
+  pub fn voter_counts(&self) -> Vec<i64> {
+    self.poll.options().iter().map(|option| option.voter_count()).collect()
+  }
+
+  /// The chosen options of each user, only received by bots for non-anonymous polls
+  pub fn answers(&self) -> &HashMap<i64, Vec<i64>> { &self.answers }
+
+  /// True, if the poll is closed
+  pub fn is_closed(&self) -> bool { self.poll.is_closed() }
+}
+
+
+/// Iterator of the tallies of a poll, created by `Api::watch_poll`.
+///
+/// A new tally is returned for every `updatePoll` or `updatePollAnswer` of the poll, the iterator
+/// ends after the poll is closed.
+#[derive(Debug)]
+pub struct PollWatcher {
+  poll_updates: Receiver<String>,
+  answer_updates: Receiver<String>,
+  tally: PollTally,
+  finished: bool,
+}
+
+impl Api {
+  /// Sends a poll to this chat (`sendMessage` with `inputMessagePoll`)
+  pub fn send_poll<T: AsRef<PollDraft>>(&self, chat_id: i64, poll: T) -> RTDResult<Pending<Message>> {
+    self.request(SendMessage::builder()
+      .chat_id(chat_id)
+      .options(MessageSendOptions::builder().build())
+      .input_message_content(poll.as_ref().to_input_message_content())
+      .build())
+  }
+
+  /// Watch the votes of the poll of this message, e.g. the message returned by `Api::send_poll`
+  pub fn watch_poll(&self, message: &Message) -> RTDResult<PollWatcher> {
+    let poll = message.content().as_message_poll()
+      .map(|content| content.poll().clone())
+      .ok_or_else(|| RTDError::custom("The message is not a poll"))?;
+    Ok(PollWatcher {
+      poll_updates: self.watch("updatePoll"),
+      answer_updates: self.watch("updatePollAnswer"),
+      finished: poll.is_closed(),
+      tally: PollTally { poll, answers: HashMap::new() },
+    })
+  }
+}
+
+impl PollWatcher {
+  /// The latest tally
+  pub fn tally(&self) -> &PollTally { &self.tally }
+
+  fn apply_poll(&mut self, json: &str) -> RTDResult<bool> {
+    let update = UpdatePoll::from_json(json)?;
+    if update.poll().id() != self.tally.poll.id() { return Ok(false); }
+    self.tally.poll = update.poll().clone();
+    self.finished = self.tally.poll.is_closed();
+    Ok(true)
+  }
+
+  fn apply_answer(&mut self, json: &str) -> RTDResult<bool> {
+    let update = UpdatePollAnswer::from_json(json)?;
+    if update.poll_id() != self.tally.poll.id() { return Ok(false); }
+    if update.option_ids().is_empty() {
+      // the vote is retracted
+      self.tally.answers.remove(&update.user_id());
+    } else {
+      self.tally.answers.insert(update.user_id(), update.option_ids().clone());
+    }
+    Ok(true)
+  }
+
+  fn next_tally(&mut self) -> RTDResult<Option<PollTally>> {
+    loop {
+      if self.finished { return Ok(None); }
+      // answers are only received by bots, check them first without blocking
+      if let Ok(json) = self.answer_updates.try_recv() {
+        if self.apply_answer(&json)? { return Ok(Some(self.tally.clone())); }
+        continue;
+      }
+      let json = match self.poll_updates.recv_timeout(Duration::from_millis(100)) {
+        Ok(json) => json,
+        Err(RecvTimeoutError::Timeout) => continue,
+        Err(RecvTimeoutError::Disconnected) =>
+          return Err(RTDError::custom("The receive loop is stopped, poll updates will not be received")),
+      };
+      if self.apply_poll(&json)? { return Ok(Some(self.tally.clone())); }
+    }
+  }
+}
+
+impl Iterator for PollWatcher {
+  type Item = RTDResult<PollTally>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    match self.next_tally() {
+      Ok(tally) => tally.map(Ok),
+      Err(e) => {
+        self.finished = true;
+        Some(Err(e))
+      }
+    }
+  }
+}
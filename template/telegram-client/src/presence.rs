@@ -0,0 +1,101 @@
+use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::api::Api;
+use crate::scan::scan;
+
+/// How often the `online` option is set again, also if it's unchanged
+pub const PRESENCE_REFRESH: Duration = Duration::from_secs(60);
+
+/// How often the presence task checks the status to set
+const PRESENCE_TICK: Duration = Duration::from_secs(1);
+
+/// The online status of the account kept by the client, see `ApiBuilder::presence`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presence {
+  /// Online while the client runs
+  AlwaysOnline,
+  /// Never online by this session, the other sessions of the account still can be
+  Offline,
+  /// Online while this `Api` sends requests, offline after this idle time; see `Api::mark_active`
+  Activity(Duration),
+}
+
+/// The presence of an `Api` and its last activity
+#[derive(Debug)]
+pub(crate) struct PresenceKeeper {
+  presence: Presence,
+  last_activity: Mutex<Option<Instant>>,
+}
+
+impl PresenceKeeper {
+  pub fn new(presence: Presence) -> Self {
+    Self { presence, last_activity: Mutex::new(None) }
+  }
+
+  pub fn presence(&self) -> Presence { self.presence }
+
+  /// a request is sent, setting the status itself isn't an activity
+  pub fn touch(&self, json: &str) {
+    if let Presence::Activity(_) = self.presence {
+      if scan(json).td_type() != Some("setOption") {
+        self.mark_active();
+      }
+    }
+  }
+
+  pub fn mark_active(&self) {
+    *self.last_activity.lock().unwrap() = Some(Instant::now());
+  }
+
+  /// the status to set now
+  fn online(&self) -> bool {
+    match self.presence {
+      Presence::AlwaysOnline => true,
+      Presence::Offline => false,
+      Presence::Activity(idle) => self.last_activity.lock().unwrap().is_some_and(|at| at.elapsed() < idle),
+    }
+  }
+}
+
+impl Api {
+  /// The presence kept by the client, `None` if not set by `ApiBuilder::presence`
+  pub fn presence(&self) -> Option<Presence> {
+    self.presence_keeper().map(|keeper| keeper.presence())
+  }
+
+  /// The user is active without a request sent, e.g. reading; online by `Presence::Activity` for its idle time
+  pub fn mark_active(&self) {
+    if let Some(keeper) = self.presence_keeper() {
+      keeper.mark_active();
+    }
+  }
+}
+
+/// keep the presence of `ApiBuilder::presence` after the authorization is ready
+pub(crate) fn start(api: &Api) -> Option<JoinHandle<()>> {
+  api.presence_keeper()?;
+  Some(api.on_ready(run))
+}
+
+fn run(api: Api) {
+  let keeper = match api.presence_keeper() {
+    Some(keeper) => keeper,
+    None => return,
+  };
+  // the status set last and when, it's set again if it changed or after the refresh
+  let mut online: Option<(bool, Instant)> = None;
+  while !api.update_queue().is_closed() {
+    let wanted = keeper.online();
+    let due = online.map_or(true, |(set, at)| set != wanted || at.elapsed() >= PRESENCE_REFRESH);
+    if due {
+      // a failure is logged and tried again at the next refresh
+      if let Err(e) = api.set_option_boolean("online", wanted).and_then(|pending| pending.wait()) {
+        warn!("Can not set the online status: {}", e);
+      }
+      online = Some((wanted, Instant::now()));
+    }
+    thread::sleep(PRESENCE_TICK);
+  }
+}
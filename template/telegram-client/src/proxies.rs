@@ -0,0 +1,132 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+use crate::scan::decode;
+
+/// How often the proxy rotation checks it's stopped
+const ROTATION_TICK: Duration = Duration::from_millis(500);
+
+impl Api {
+  /// The proxies registered in tdlib (`getProxies`)
+  pub fn proxies(&self) -> RTDResult<Vec<Proxy>> {
+    let proxies: Proxies = self.request(GetProxies::builder().build())?.wait()?;
+    Ok(proxies.proxies().clone())
+  }
+
+  /// Register a proxy (`addProxy`) without enabling it, it's used by `Api::fail_over`
+  pub fn register_proxy<S: AsRef<str>>(&self, server: S, port: i64, proxy_type: ProxyType) -> RTDResult<Proxy> {
+    self.request(AddProxy::builder().server(server).port(port).enable(false).type_(proxy_type).build())?.wait()
+  }
+
+  /// Round trip time to Telegram through a proxy (`pingProxy`), an error if it isn't answered in `timeout`
+  pub fn proxy_latency(&self, proxy: &Proxy, timeout: Duration) -> RTDResult<Duration> {
+    let seconds: Seconds = self.request(PingProxy::builder().proxy_id(proxy.id()).build())?.wait_timeout(timeout)?;
+    Ok(Duration::from_secs_f64(seconds.seconds().max(0.0) as f64))
+  }
+
+  /// Health check of every registered proxy, its latency or the error of its ping
+  pub fn check_proxies(&self, timeout: Duration) -> RTDResult<Vec<(Proxy, RTDResult<Duration>)>> {
+    Ok(self.proxies()?.into_iter()
+      .map(|proxy| {
+        let latency = self.proxy_latency(&proxy, timeout);
+        (proxy, latency)
+      })
+      .collect())
+  }
+
+  /// Enable the registered proxy with the lowest latency but the enabled one (`enableProxy`); `None` if no other
+  /// proxy answered the ping in `timeout`, the enabled proxy is kept
+  pub fn fail_over(&self, timeout: Duration) -> RTDResult<Option<Proxy>> {
+    let fastest = self.check_proxies(timeout)?.into_iter()
+      .filter(|(proxy, _)| !proxy.is_enabled())
+      .filter_map(|(proxy, latency)| latency.ok().map(|latency| (proxy, latency)))
+      .min_by_key(|(_, latency)| *latency);
+    let proxy = match fastest {
+      Some((proxy, _)) => proxy,
+      None => return Ok(None),
+    };
+    let _: Ok = self.request(EnableProxy::builder().proxy_id(proxy.id()).build())?.wait()?;
+    Ok(Some(proxy))
+  }
+
+  /// Fail over to another registered proxy when tdlib is connecting longer than `connecting_timeout`, until the
+  /// returned rotation is stopped or dropped; the proxies are pinged with the same timeout.
+  ///
+  /// The connection state is only sent when it changes, start the rotation before the client starts so the
+  /// first state isn't missed.
+  pub fn rotate_proxies(&self, connecting_timeout: Duration) -> ProxyRotation {
+    let states = self.watch("updateConnectionState");
+    let stopped = Arc::new(AtomicBool::new(false));
+    let api = self.clone();
+    let stop = stopped.clone();
+    let handle = thread::spawn(move || self::rotate(api, states, connecting_timeout, stop));
+    ProxyRotation { stopped, handle: Some(handle) }
+  }
+}
+
+/// The proxy rotation started by `Api::rotate_proxies`, stopped when dropped
+#[derive(Debug)]
+pub struct ProxyRotation {
+  stopped: Arc<AtomicBool>,
+  handle: Option<JoinHandle<()>>,
+}
+
+impl ProxyRotation {
+  /// Stop the rotation and wait for it, the enabled proxy is kept
+  pub fn stop(mut self) {
+    self.stop_and_join();
+  }
+
+  fn stop_and_join(&mut self) {
+    self.stopped.store(true, Ordering::SeqCst);
+    if let Some(handle) = self.handle.take() {
+      if handle.join().is_err() {
+        error!("The proxy rotation thread panicked");
+      }
+    }
+  }
+}
+
+impl Drop for ProxyRotation {
+  fn drop(&mut self) {
+    self.stop_and_join();
+  }
+}
+
+fn rotate(api: Api, states: Receiver<String>, timeout: Duration, stopped: Arc<AtomicBool>) {
+  // since when tdlib is connecting, reset by another state and by a fail over
+  let mut connecting_since: Option<Instant> = None;
+  while !stopped.load(Ordering::SeqCst) {
+    match states.recv_timeout(ROTATION_TICK) {
+      Ok(json) => match decode::<Update>(&json) {
+        Ok(Update::ConnectionState(update)) => {
+          let state = update.state();
+          connecting_since = if state.is_connecting() || state.is_connecting_to_proxy() {
+            connecting_since.or_else(|| Some(Instant::now()))
+          } else {
+            None
+          };
+        }
+        Ok(_) => {}
+        Err(e) => warn!("Can not read the connection state: {}", e),
+      },
+      Err(RecvTimeoutError::Timeout) => {}
+      Err(RecvTimeoutError::Disconnected) => break,
+    }
+    if connecting_since.is_some_and(|since| since.elapsed() >= timeout) {
+      match api.fail_over(timeout) {
+        Ok(Some(proxy)) => info!("Connecting for {:?}, fail over to the proxy {}:{}", timeout, proxy.server(), proxy.port()),
+        Ok(None) => warn!("Connecting for {:?}, no other proxy is reachable", timeout),
+        Err(e) => warn!("Can not fail over to another proxy: {}", e),
+      }
+      connecting_since = Some(Instant::now());
+    }
+  }
+}
@@ -0,0 +1,219 @@
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// What the receiver thread does when the update queue is full, see `ApiBuilder::update_queue`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OverflowPolicy {
+  /// Wait at most this time until the listener consumes an update, then drop the received update with a
+  /// warning. The receiver thread also receives the responses of requests, so a listener waiting on a
+  /// `Pending` while the queue is full waits for this timeout, and never gets the response with an
+  /// unbounded wait; keep it short.
+  Block(Duration),
+  /// Drop the oldest queued update
+  DropOldest,
+  /// Drop the received update
+  DropNewest,
+  /// Append the updates to this file until the listener catches up, the order is kept
+  Spill(PathBuf),
+}
+
+/// Metrics of the update queue, see `Api::update_queue_stats`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpdateQueueStats {
+  len: usize,
+  capacity: Option<usize>,
+  dropped: usize,
+  spilled: usize,
+}
+
+impl UpdateQueueStats {
+  /// Number of updates waiting for the listener, in memory and spilled
+  pub fn len(&self) -> usize { self.len }
+  /// True, if no update is waiting
+  pub fn is_empty(&self) -> bool { self.len == 0 }
+  /// Capacity of the queue in memory; `None` if unbounded
+  pub fn capacity(&self) -> Option<usize> { self.capacity }
+  /// Number of updates dropped since start
+  pub fn dropped(&self) -> usize { self.dropped }
+  /// Number of updates written to the spill file since start
+  pub fn spilled(&self) -> usize { self.spilled }
+}
+
+
+/// A queued object: td type, json and sequence number in the journal
+pub(crate) type Queued = (String, String, Option<u64>);
+
+/// The queue between the receiver thread and the dispatcher thread calling the listener.
+#[derive(Debug)]
+pub(crate) struct UpdateQueue {
+  capacity: Option<usize>,
+  policy: OverflowPolicy,
+  state: Mutex<QueueState>,
+  changed: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+  items: VecDeque<Queued>,
+  spill: Option<Spill>,
+  spill_len: usize,
+  dropped: usize,
+  spilled: usize,
+  closed: bool,
+}
+
+#[derive(Debug)]
+struct Spill {
+  writer: File,
+  reader: BufReader<File>,
+}
+
+impl UpdateQueue {
+  pub fn unbounded() -> Self {
+    // never full, the policy is not applied
+    Self::new(None, OverflowPolicy::DropNewest)
+  }
+
+  pub fn new(capacity: Option<usize>, policy: OverflowPolicy) -> Self {
+    Self {
+      capacity,
+      policy,
+      state: Mutex::new(QueueState::default()),
+      changed: Condvar::new(),
+    }
+  }
+
+  /// queue a `td_type` object for the listener, `seq` is its sequence number in the journal
+  pub fn push(&self, td_type: String, json: String, seq: Option<u64>) {
+    let mut state = self.state.lock().unwrap();
+    if state.closed { return; }
+    if !self.is_full(&state) {
+      state.items.push_back((td_type, json, seq));
+      self.changed.notify_all();
+      return;
+    }
+    match &self.policy {
+      OverflowPolicy::Block(timeout) => {
+        let deadline = Instant::now() + *timeout;
+        while self.is_full(&state) && !state.closed {
+          let now = Instant::now();
+          if now >= deadline { break; }
+          state = self.changed.wait_timeout(state, deadline - now).unwrap().0;
+        }
+        if state.closed { return; }
+        if self.is_full(&state) {
+          warn!("The update queue is still full after {:?}, drop update {}", timeout, td_type);
+          state.dropped += 1;
+        } else {
+          state.items.push_back((td_type, json, seq));
+        }
+      }
+      OverflowPolicy::DropOldest => {
+        state.items.pop_front();
+        state.items.push_back((td_type, json, seq));
+        state.dropped += 1;
+      }
+      OverflowPolicy::DropNewest => state.dropped += 1,
+      OverflowPolicy::Spill(path) => {
+        match Self::spill(&mut state, path, &td_type, &json, seq) {
+          Ok(()) => {
+            state.spill_len += 1;
+            state.spilled += 1;
+          }
+          Err(e) => {
+            error!("Can not spill update {} to {:?}: {}", td_type, path, e);
+            state.dropped += 1;
+          }
+        }
+      }
+    }
+    self.changed.notify_all();
+  }
+
+  /// the next object for the listener, block until one is queued; `None` after closed and drained
+  pub fn pop(&self) -> Option<Queued> {
+    let mut state = self.state.lock().unwrap();
+    loop {
+      if let Some(item) = state.items.pop_front() {
+        self.changed.notify_all();
+        return Some(item);
+      }
+      if state.spill_len > 0 {
+        match Self::unspill(&mut state) {
+          Ok(item) => return Some(item),
+          Err(e) => {
+            error!("Can not read spilled updates: {}", e);
+            state.dropped += state.spill_len;
+            state.spill_len = 0;
+            state.spill = None;
+            continue;
+          }
+        }
+      }
+      if state.closed { return None; }
+      state = self.changed.wait(state).unwrap();
+    }
+  }
+
+  /// whether `close` was called, the client stopped
+  pub(crate) fn is_closed(&self) -> bool {
+    self.state.lock().unwrap().closed
+  }
+
+  /// stop accepting objects, `pop` returns the queued objects then `None`
+  pub fn close(&self) {
+    self.state.lock().unwrap().closed = true;
+    self.changed.notify_all();
+  }
+
+  pub fn stats(&self) -> UpdateQueueStats {
+    let state = self.state.lock().unwrap();
+    UpdateQueueStats {
+      len: state.items.len() + state.spill_len,
+      capacity: self.capacity,
+      dropped: state.dropped,
+      spilled: state.spilled,
+    }
+  }
+
+  fn is_full(&self, state: &QueueState) -> bool {
+    // keep spilling until the spilled updates are read, the order is kept
+    state.spill_len > 0 || self.capacity.is_some_and(|capacity| state.items.len() >= capacity)
+  }
+
+  fn spill(state: &mut QueueState, path: &PathBuf, td_type: &str, json: &str, seq: Option<u64>) -> io::Result<()> {
+    if state.spill.is_none() {
+      let writer = OpenOptions::new().create(true).read(true).write(true).truncate(true).open(path)?;
+      let reader = BufReader::new(File::open(path)?);
+      state.spill = Some(Spill { writer, reader });
+    }
+    let spill = state.spill.as_mut().unwrap();
+    // tdlib json has no line break, one object a line
+    writeln!(spill.writer, "{}\t{}\t{}", seq.map_or("".to_string(), |seq| seq.to_string()), td_type, json)
+  }
+
+  fn unspill(state: &mut QueueState) -> io::Result<Queued> {
+    let spill = match state.spill.as_mut() {
+      Some(spill) => spill,
+      None => return Err(io::Error::new(io::ErrorKind::NotFound, "No spill file")),
+    };
+    let mut line = String::new();
+    spill.reader.read_line(&mut line)?;
+    state.spill_len -= 1;
+    if state.spill_len == 0 {
+      // all read, reuse the file from the start
+      spill.writer.set_len(0)?;
+      spill.writer.seek(SeekFrom::Start(0))?;
+      spill.reader.seek(SeekFrom::Start(0))?;
+    }
+    let mut parts = line.trim_end_matches('\n').splitn(3, '\t');
+    match (parts.next(), parts.next(), parts.next()) {
+      (Some(seq), Some(td_type), Some(json)) => Ok((td_type.to_string(), json.to_string(), seq.parse().ok())),
+      _ => Err(io::Error::new(io::ErrorKind::InvalidData, "Broken spilled update")),
+    }
+  }
+}
@@ -0,0 +1,281 @@
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use rtdlib::errors::*;
+use serde_json::Value;
+
+use crate::transport::TdLibClient;
+
+const SEND: &str = "send";
+const RECEIVE: &str = "receive";
+const EXECUTE: &str = "execute";
+const EXECUTED: &str = "executed";
+/// these values are not written to a fixture and don't change the shape of a request
+const REDACTED: &[&str] = &["api_id", "api_hash"];
+
+/// hide the credentials of a json, a fixture may be committed
+fn redact(value: &mut Value) {
+  match value {
+    Value::Object(map) => for (key, value) in map.iter_mut() {
+      if REDACTED.contains(&&key[..]) {
+        *value = Value::String("****".to_string());
+      } else {
+        redact(value);
+      }
+    },
+    Value::Array(values) => values.iter_mut().for_each(redact),
+    _ => {}
+  }
+}
+
+fn extra(value: &Value) -> Option<String> {
+  value.get("@extra").and_then(|extra| extra.as_str()).map(|extra| extra.to_string())
+}
+
+/// the request without `@extra` and credentials, requests of the same shape are the same request
+fn shape(request: &str) -> String {
+  match serde_json::from_str::<Value>(request) {
+    Ok(mut value) => {
+      if let Some(map) = value.as_object_mut() {
+        map.remove("@extra");
+      }
+      redact(&mut value);
+      value.to_string()
+    }
+    Err(_) => request.to_string(),
+  }
+}
+
+
+/// A `TdLibClient` writing the requests sent and the objects received to a fixture, see `ReplayTdLibClient`.
+///
+/// Each line of the fixture is the direction, a tab and the tdlib json, e.g. `send\t{"@type":"getMe"}`.
+/// The `api_id` and `api_hash` are not written.
+#[derive(Debug)]
+pub struct RecordingTdLibClient<C: TdLibClient> {
+  inner: C,
+  fixture: Mutex<File>,
+}
+
+impl<C: TdLibClient> RecordingTdLibClient<C> {
+  /// Record the client to this fixture, an existing fixture is overwritten
+  pub fn create<P: AsRef<Path>>(inner: C, path: P) -> RTDResult<Self> {
+    let fixture = OpenOptions::new().create(true).write(true).truncate(true).open(path)?;
+    Ok(Self { inner, fixture: Mutex::new(fixture) })
+  }
+
+  pub fn inner(&self) -> &C { &self.inner }
+
+  fn record(&self, direction: &str, json: &str) {
+    let json = match serde_json::from_str::<Value>(json) {
+      Ok(mut value) => {
+        redact(&mut value);
+        value.to_string()
+      }
+      // tdlib json has no line break
+      Err(_) => json.replace('\n', " "),
+    };
+    let mut fixture = self.fixture.lock().unwrap();
+    if let Err(e) = fixture.write_all(format!("{}\t{}\n", direction, json).as_bytes()) {
+      error!("Can not write the fixture: {}", e);
+    }
+  }
+}
+
+impl<C: TdLibClient> TdLibClient for RecordingTdLibClient<C> {
+  fn send(&self, request: &str) {
+    self.record(SEND, request);
+    self.inner.send(request);
+  }
+
+  fn receive(&self, timeout: f64) -> Option<String> {
+    let json = self.inner.receive(timeout);
+    if let Some(json) = &json {
+      self.record(RECEIVE, json);
+    }
+    json
+  }
+
+  fn execute(&self, request: &str) -> Option<String> {
+    self.record(EXECUTE, request);
+    let response = self.inner.execute(request);
+    self.record(EXECUTED, response.as_ref().map_or("", |response| &response[..]));
+    response
+  }
+}
+
+
+/// A request of the fixture
+#[derive(Debug)]
+struct RecordedRequest {
+  shape: String,
+  /// the `@extra` of the request sent by the replay, `Some` after it's replayed
+  replayed: Option<Option<String>>,
+}
+
+/// An object of the fixture
+#[derive(Debug)]
+struct RecordedObject {
+  json: String,
+  /// the number of requests sent before it's received
+  epoch: usize,
+  /// the request it responds
+  request: Option<usize>,
+  released: bool,
+}
+
+#[derive(Debug, Default)]
+struct ReplayState {
+  requests: Vec<RecordedRequest>,
+  objects: Vec<RecordedObject>,
+  executes: Vec<(String, Option<String>)>,
+  ready: VecDeque<String>,
+  sent: usize,
+  unmatched: Vec<String>,
+}
+
+/// A `TdLibClient` serving the objects of a fixture written by `RecordingTdLibClient`, for deterministic tests
+/// without a live account.
+///
+/// A request is matched to the first request of the fixture of the same shape not replayed yet, the json
+/// without `@extra` and credentials; its responses are received with the `@extra` of the request.
+/// An update is received after as many requests are sent as were sent before it's recorded, and the objects
+/// are received in the order recorded. A request not in the fixture is never answered, see `unmatched`.
+#[derive(Debug)]
+pub struct ReplayTdLibClient {
+  state: Mutex<ReplayState>,
+  condvar: Condvar,
+}
+
+impl ReplayTdLibClient {
+  /// Replay the fixture of this path
+  pub fn open<P: AsRef<Path>>(path: P) -> RTDResult<Self> {
+    Ok(Self::from_fixture(std::fs::read_to_string(path)?))
+  }
+
+  /// Replay a fixture, e.g. included by `include_str!`
+  pub fn from_fixture<S: AsRef<str>>(fixture: S) -> Self {
+    let mut state = ReplayState::default();
+    let mut extras: Vec<(String, usize)> = vec![];
+    for line in fixture.as_ref().lines() {
+      let mut parts = line.splitn(2, '\t');
+      let (direction, json) = match (parts.next(), parts.next()) {
+        (Some(direction), Some(json)) => (direction, json),
+        _ => continue,
+      };
+      let value = serde_json::from_str::<Value>(json).unwrap_or(Value::Null);
+      match direction {
+        SEND => {
+          if let Some(extra) = extra(&value) {
+            extras.push((extra, state.requests.len()));
+          }
+          state.requests.push(RecordedRequest { shape: shape(json), replayed: None });
+        }
+        RECEIVE => {
+          let request = extra(&value)
+            .and_then(|extra| extras.iter().rev().find(|(recorded, _)| *recorded == extra))
+            .map(|(_, index)| *index);
+          state.objects.push(RecordedObject { json: json.to_string(), epoch: state.requests.len(), request, released: false });
+        }
+        EXECUTE => state.executes.push((shape(json), None)),
+        EXECUTED => if let Some(execute) = state.executes.last_mut() {
+          execute.1 = if json.is_empty() { None } else { Some(json.to_string()) };
+        },
+        _ => warn!("Unknown line of the fixture: {}", line),
+      }
+    }
+    let replay = Self { state: Mutex::new(state), condvar: Condvar::new() };
+    // the updates received before the first request
+    replay.release(&mut replay.state.lock().unwrap());
+    replay
+  }
+
+  /// The requests sent which are not in the fixture
+  pub fn unmatched(&self) -> Vec<String> {
+    self.state.lock().unwrap().unmatched.clone()
+  }
+
+  /// Whether all objects of the fixture are received
+  pub fn is_finished(&self) -> bool {
+    let state = self.state.lock().unwrap();
+    state.ready.is_empty() && state.objects.iter().all(|object| object.released)
+  }
+
+  /// move the objects which can be received to the ready queue, in the order recorded
+  fn release(&self, state: &mut ReplayState) {
+    let ReplayState { requests, objects, ready, sent, .. } = state;
+    for object in objects.iter_mut().filter(|object| !object.released) {
+      let extra = match object.request {
+        Some(index) => match &requests[index].replayed {
+          Some(extra) => Some(extra.clone()),
+          None => continue,
+        },
+        None if object.epoch <= *sent => None,
+        None => continue,
+      };
+      object.released = true;
+      let json = match (extra, serde_json::from_str::<Value>(&object.json)) {
+        (Some(extra), Ok(mut value)) => {
+          if let Some(map) = value.as_object_mut() {
+            match extra {
+              Some(extra) => map.insert("@extra".to_string(), Value::String(extra)),
+              None => map.remove("@extra"),
+            };
+          }
+          value.to_string()
+        }
+        _ => object.json.clone(),
+      };
+      ready.push_back(json);
+    }
+    self.condvar.notify_all();
+  }
+}
+
+impl TdLibClient for ReplayTdLibClient {
+  fn send(&self, request: &str) {
+    let shape = shape(request);
+    let extra = serde_json::from_str::<Value>(request).ok().and_then(|value| extra(&value));
+    let mut state = self.state.lock().unwrap();
+    state.sent += 1;
+    match state.requests.iter_mut().find(|recorded| recorded.replayed.is_none() && recorded.shape == shape) {
+      Some(recorded) => recorded.replayed = Some(extra),
+      None => {
+        warn!("The request is not in the fixture: {}", request);
+        state.unmatched.push(request.to_string());
+      }
+    }
+    self.release(&mut state);
+  }
+
+  fn receive(&self, timeout: f64) -> Option<String> {
+    let deadline = Instant::now() + Duration::from_secs_f64(timeout.max(0.0));
+    let mut state = self.state.lock().unwrap();
+    loop {
+      if let Some(json) = state.ready.pop_front() {
+        return Some(json);
+      }
+      let now = Instant::now();
+      if now >= deadline {
+        return None;
+      }
+      state = self.condvar.wait_timeout(state, deadline - now).unwrap().0;
+    }
+  }
+
+  fn execute(&self, request: &str) -> Option<String> {
+    let shape = shape(request);
+    let state = self.state.lock().unwrap();
+    match state.executes.iter().find(|(recorded, _)| *recorded == shape) {
+      Some((_, response)) => response.clone(),
+      None => {
+        warn!("The request is not in the fixture: {}", request);
+        None
+      }
+    }
+  }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex, Weak};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use crate::scan::scan;
+use crate::transport::TdLibClient;
+
+/// Timeout of each receive of the router thread in seconds, the thread stops after it when no router is left
+const ROUTER_RECEIVE_TIMEOUT: f64 = 1.0;
+
+/// The tdjson interface of tdlib 1.7 and newer, `td_create_client_id`, `td_send`, `td_receive` and `td_execute`:
+/// all clients of the process share one receive, and every object received has the `@client_id` of its client.
+///
+/// rtdlib binds the older `td_json_client` interface, one receive for each client; implement this by the
+/// bindings of the newer one and route the objects by `ClientRouter`.
+pub trait SharedTdLib: Debug + Send + Sync {
+  /// Create a new client (`td_create_client_id`), it's started by its first request
+  fn create_client_id(&self) -> i32;
+
+  /// Send a request of this client (`td_send`)
+  fn send(&self, client_id: i32, request: &str);
+
+  /// Block until the next object of any client or timeout in seconds (`td_receive`), only called by the router thread
+  fn receive(&self, timeout: f64) -> Option<String>;
+
+  /// Execute a synchronous request (`td_execute`)
+  fn execute(&self, request: &str) -> Option<String>;
+}
+
+/// Routes the objects of the shared `td_receive` to the clients by their `@client_id`.
+///
+/// One thread receives for all clients, started by `ClientRouter::new`; every client is a `RoutedClient`, the
+/// transport of its own `Api`, so each client has its own observer and update queue and its receive loop only
+/// gets the objects of its client. The thread stops when the router and all its clients are dropped.
+///
+/// ```ignore
+/// let router = ClientRouter::new(MySharedTdLib::new());
+/// let first = Api::builder().transport(router.client()).build();
+/// let second = Api::builder().transport(router.client()).build();
+/// Client::new(first).start();
+/// Client::new(second).start();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientRouter {
+  inner: Arc<RouterInner>,
+}
+
+#[derive(Debug)]
+struct RouterInner {
+  shared: Arc<dyn SharedTdLib>,
+  /// the queue of each client id
+  clients: Mutex<HashMap<i32, Sender<String>>>,
+}
+
+impl ClientRouter {
+  /// Start the router thread receiving the objects of all clients from this tdlib
+  pub fn new<T: SharedTdLib + 'static>(shared: T) -> Self {
+    let inner = Arc::new(RouterInner { shared: Arc::new(shared), clients: Mutex::new(HashMap::new()) });
+    let shared = inner.shared.clone();
+    let weak = Arc::downgrade(&inner);
+    thread::spawn(move || self::route(shared, weak));
+    Self { inner }
+  }
+
+  /// Create a new client of the shared tdlib, pass it to `ApiBuilder::transport`
+  pub fn client(&self) -> RoutedClient {
+    let client_id = self.inner.shared.create_client_id();
+    self.client_for(client_id).expect("A new client id is routed already")
+  }
+
+  /// Route the objects of a client already created by `td_create_client_id`, e.g. by other bindings; `None` if a
+  /// client of this router has the id. The objects of the client received before are dropped.
+  pub fn client_for(&self, client_id: i32) -> Option<RoutedClient> {
+    let mut clients = self.inner.clients.lock().unwrap();
+    if clients.contains_key(&client_id) { return None; }
+    let (sender, receiver) = mpsc::channel();
+    // registered before the first request of a new client, its objects are not received before
+    clients.insert(client_id, sender);
+    Some(RoutedClient { client_id, router: self.inner.clone(), receiver: Mutex::new(receiver) })
+  }
+
+  /// Ids of the clients receiving their objects
+  pub fn client_ids(&self) -> Vec<i32> {
+    let mut ids: Vec<i32> = self.inner.clients.lock().unwrap().keys().cloned().collect();
+    ids.sort_unstable();
+    ids
+  }
+}
+
+/// the router thread, receive from the shared tdlib and send every object to the queue of its client
+fn route(shared: Arc<dyn SharedTdLib>, router: Weak<RouterInner>) {
+  loop {
+    let json = shared.receive(ROUTER_RECEIVE_TIMEOUT);
+    let router = match router.upgrade() {
+      Some(router) => router,
+      None => return,
+    };
+    let json = match json {
+      Some(json) => json,
+      None => continue,
+    };
+    let client_id = match scan(&json).client_id() {
+      Some(client_id) => client_id,
+      None => {
+        warn!("An object of no client is received, it's dropped: {}", json);
+        continue;
+      }
+    };
+    let mut clients = router.clients.lock().unwrap();
+    let closed = match clients.get(&client_id) {
+      Some(sender) => sender.send(json).is_err(),
+      None => {
+        debug!("An object of the client {} not routed is dropped", client_id);
+        false
+      }
+    };
+    if closed {
+      clients.remove(&client_id);
+    }
+  }
+}
+
+/// A client of the shared tdlib of a `ClientRouter`, the transport of one `Api`: its requests are sent with its
+/// client id, and it receives the objects of its client only.
+#[derive(Debug)]
+pub struct RoutedClient {
+  client_id: i32,
+  router: Arc<RouterInner>,
+  receiver: Mutex<Receiver<String>>,
+}
+
+impl RoutedClient {
+  /// The `@client_id` of the objects of this client
+  pub fn client_id(&self) -> i32 { self.client_id }
+}
+
+impl TdLibClient for RoutedClient {
+  fn send(&self, request: &str) { self.router.shared.send(self.client_id, request) }
+
+  fn receive(&self, timeout: f64) -> Option<String> {
+    self.receiver.lock().unwrap().recv_timeout(Duration::from_secs_f64(timeout.max(0.0))).ok()
+  }
+
+  fn execute(&self, request: &str) -> Option<String> { self.router.shared.execute(request) }
+}
+
+impl Drop for RoutedClient {
+  fn drop(&mut self) {
+    self.router.clients.lock().unwrap().remove(&self.client_id);
+  }
+}
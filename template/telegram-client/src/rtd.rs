@@ -2,10 +2,17 @@ use core::borrow::Borrow;
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::thread::JoinHandle;
+use std::time::Duration;
+
+use serde_json::Value;
 
 use crate::api::Api;
+use crate::cache::Cache;
 use crate::handler::Handler;
 use crate::listener::Lout;
+use crate::notifications::Notifications;
+use crate::scan::{decode, scan};
+use crate::tip;
 
 pub struct TdRecv {}
 
@@ -14,15 +21,135 @@ impl TdRecv {
     Self {}
   }
 
+  /// Start the receive loop, two threads are started:
+  ///
+  /// - the receiver thread owns the receive of the transport, updates the caches and delivers responses to `Pending`
+  /// - the dispatcher thread calls the listener with the other objects from the update queue, in the order received
+  ///
+  /// So a listener callback can wait for a `Pending`, the response is delivered by the receiver thread.
+  /// The returned handle finishes after both threads stop.
+  ///
+  /// The objects received here are of this client only: a `Tdlib` has its own `td_json_client_receive`, and the
+  /// clients sharing `td_receive` are routed by `@client_id` to their own transport, see `router::ClientRouter`.
   pub fn start(&self, api: Arc<Api>, stop_flag: Arc<Mutex<bool>>, lout: Arc<Lout>) -> JoinHandle<()> {
+    #[cfg(feature = "journal")]
+    self.replay_journal(&api);
+    let dispatch_api = api.clone();
+    let dispatcher = thread::spawn(move || {
+      while let Some((td_type, json, seq)) = dispatch_api.update_queue().pop() {
+        Handler::new(dispatch_api.borrow(), lout.borrow()).handle(&td_type, &json);
+        Self::handled(&dispatch_api, seq);
+      }
+    });
     thread::spawn(move || {
-      let is_stop = stop_flag.lock().unwrap();
-      while !*is_stop {
-        if let Some(json) = api.receive(2.0) {
-          Handler::new(api.borrow(), lout.borrow()).handle(&json);
+      {
+        let is_stop = stop_flag.lock().unwrap();
+        let mut idle: Option<Duration> = None;
+        while !*is_stop {
+          api.observer().sweep();
+          let json = match api.receive(api.receive_timeout()) {
+            Some(json) => json,
+            None => {
+              idle = api.idle_backoff().next(idle);
+              if let Some(sleep) = idle {
+                thread::sleep(sleep);
+              }
+              continue;
+            }
+          };
+          idle = None;
+          let scanned = scan(&json);
+          if let Some(td_type) = scanned.td_type() {
+            Self::update_caches(&api, td_type, &json);
+          }
+          if scanned.extra().is_none() && api.filter().is_some_and(|filter| !filter.accept(&scanned)) {
+            continue;
+          }
+          if api.observer().notify(&json, &scanned) {
+            continue;
+          }
+          let td_type = match scanned.td_type() {
+            Some(td_type) => td_type.to_string(),
+            None => {
+              warn!("{}", tip::data_fail_with_json(&json));
+              continue;
+            }
+          };
+          let seq = Self::journal(&api, &td_type, &json);
+          api.update_queue().push(td_type, json, seq);
         }
       }
+      api.update_queue().close();
+      if dispatcher.join().is_err() {
+        error!("The dispatcher thread panicked");
+      }
     })
   }
-}
 
+  /// update the options, the current user, the cache, the notifications and the downloads by the object, the json
+  /// is only parsed if one of them is changed by its td type, and only once
+  fn update_caches(api: &Api, td_type: &str, json: &str) {
+    let options = td_type == "updateOption";
+    let me = td_type == "updateUser";
+    let cache = api.cache().filter(|_| Cache::keeps(td_type));
+    let notifications = api.notifications().filter(|_| Notifications::keeps(td_type));
+    let downloads = api.downloads().filter(|_| td_type == "updateFile");
+    if !options && !me && cache.is_none() && notifications.is_none() && downloads.is_none() { return; }
+    let value: Value = match decode(json) {
+      Ok(value) => value,
+      Err(e) => {
+        warn!("Can not parse {}: {}", td_type, e);
+        return;
+      }
+    };
+    if options { api.options().handle(&value); }
+    if me { api.me_cache().handle(&value); }
+    if let Some(cache) = cache { cache.handle(td_type, &value); }
+    if let Some(notifications) = notifications { notifications.handle(&value); }
+    if let Some(downloads) = downloads { downloads.handle(api, &value); }
+  }
+
+  /// queue the objects of the journal not handled by the listener before
+  #[cfg(feature = "journal")]
+  fn replay_journal(&self, api: &Api) {
+    let journal = match api.journal() {
+      Some(journal) => journal,
+      None => return,
+    };
+    match journal.unhandled() {
+      Ok(entries) => {
+        if !entries.is_empty() {
+          info!("Replay {} objects of the journal not handled", entries.len());
+        }
+        for entry in entries {
+          api.update_queue().push(entry.td_type().clone(), entry.json().clone(), Some(entry.seq()));
+        }
+      }
+      Err(e) => error!("Can not read the journal: {}", e),
+    }
+  }
+
+  #[cfg(feature = "journal")]
+  fn journal(api: &Api, td_type: &str, json: &str) -> Option<u64> {
+    let journal = api.journal()?;
+    journal.append(td_type, json)
+      .map_err(|e| error!("Can not append {} to the journal: {}", td_type, e))
+      .ok()
+  }
+
+  #[cfg(not(feature = "journal"))]
+  fn journal(_api: &Api, _td_type: &str, _json: &str) -> Option<u64> { None }
+
+  /// move the cursor of the journal after the listener handled the object
+  #[cfg(feature = "journal")]
+  fn handled(api: &Api, seq: Option<u64>) {
+    if let (Some(journal), Some(seq)) = (api.journal(), seq) {
+      if let Err(e) = journal.set_cursor(seq) {
+        error!("Can not move the cursor of the journal: {}", e);
+      }
+    }
+  }
+
+  #[cfg(not(feature = "journal"))]
+  fn handled(_api: &Api, _seq: Option<u64>) {}
+}
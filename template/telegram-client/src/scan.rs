@@ -0,0 +1,132 @@
+#[cfg(feature = "simd-json")]
+use std::io;
+
+use rtdlib::errors::*;
+use serde::de::DeserializeOwned;
+
+/// The routing fields of a json object returned by tdlib, read by `scan` without deserialization.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Scanned<'a> {
+  td_type: Option<&'a str>,
+  extra: Option<&'a str>,
+  client_id: Option<i32>,
+  chat_id: Option<i64>,
+  is_outgoing: Option<bool>,
+}
+
+impl<'a> Scanned<'a> {
+  /// the `@type` field
+  pub fn td_type(&self) -> Option<&'a str> { self.td_type }
+
+  /// the `@extra` field, only if it's a string
+  pub fn extra(&self) -> Option<&'a str> { self.extra }
+
+  /// the `@client_id` field of an object of the shared `td_receive`, see `router::ClientRouter`
+  pub fn client_id(&self) -> Option<i32> { self.client_id }
+
+  /// the `chat_id` field, or the `chat_id` of the `message` field
+  pub fn chat_id(&self) -> Option<i64> { self.chat_id }
+
+  /// the `is_outgoing` of the `message` field
+  pub fn is_outgoing(&self) -> Option<bool> { self.is_outgoing }
+
+  fn value(&mut self, depth: usize, keys: &[Option<&'a str>; 3], value: &'a str) {
+    match (depth, keys[1], keys[depth]) {
+      (1, _, Some("@type")) => self.td_type = Some(value),
+      (1, _, Some("@extra")) => self.extra = Some(value),
+      (1, _, Some("@client_id")) => self.client_id = value.parse().ok(),
+      (1, _, Some("chat_id")) => self.chat_id = value.parse().ok(),
+      (2, Some("message"), Some("chat_id")) if self.chat_id.is_none() => self.chat_id = value.parse().ok(),
+      (2, Some("message"), Some("is_outgoing")) => self.is_outgoing = value.parse().ok(),
+      _ => {}
+    }
+  }
+}
+
+/// Read the top level `@type`, `@extra`, `@client_id` and `chat_id` fields of the json object, and the `chat_id`
+/// and `is_outgoing` of the `message` field, skip all other fields.
+///
+/// The receive loop decides where the json goes by them, so objects nobody waits for or listens to
+/// are never deserialized. The string values are returned as they are in the json, not unescaped,
+/// `@type` never contains escaped characters and the `@extra` generated by the observer neither.
+pub(crate) fn scan(json: &str) -> Scanned<'_> {
+  let bytes = json.as_bytes();
+  let mut scanned = Scanned::default();
+  let mut depth = 0;
+  // the top level object and the objects in it: is an object, expect a key next, the last key
+  let mut is_object = [false; 3];
+  let mut expect_key = [false; 3];
+  let mut keys: [Option<&str>; 3] = [None; 3];
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      open @ b'{' | open @ b'[' => {
+        depth += 1;
+        if depth < 3 {
+          is_object[depth] = open == b'{';
+          expect_key[depth] = open == b'{';
+          keys[depth] = None;
+        }
+      }
+      b'}' | b']' => depth = depth.saturating_sub(1),
+      b',' if depth < 3 && is_object[depth] => expect_key[depth] = true,
+      b'"' => {
+        let end = match string_end(bytes, i + 1) {
+          Some(end) => end,
+          None => return Scanned::default(),
+        };
+        let text = &json[i + 1..end];
+        if depth > 0 && depth < 3 && is_object[depth] {
+          if expect_key[depth] {
+            keys[depth] = Some(text);
+            expect_key[depth] = false;
+          } else {
+            scanned.value(depth, &keys, text);
+          }
+        }
+        i = end;
+      }
+      // number, true, false or null
+      b'-' | b'0'..=b'9' | b'a'..=b'z' if depth > 0 && depth < 3 && is_object[depth] && !expect_key[depth] => {
+        let start = i;
+        while i < bytes.len() && !matches!(bytes[i], b',' | b'}' | b']' | b' ' | b'\n' | b'\r' | b'\t') {
+          i += 1;
+        }
+        scanned.value(depth, &keys, &json[start..i]);
+        continue;
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  scanned
+}
+
+/// index of the quote closing the string starts at `start`
+fn string_end(bytes: &[u8], start: usize) -> Option<usize> {
+  let mut i = start;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 2,
+      b'"' => return Some(i),
+      _ => i += 1,
+    }
+  }
+  None
+}
+
+/// Deserialize a json object returned by tdlib.
+///
+/// With the `simd-json` feature the json is parsed by simd-json, the frame is copied into a mutable
+/// buffer because simd-json parses in place.
+#[cfg(not(feature = "simd-json"))]
+pub(crate) fn decode<T: DeserializeOwned>(json: &str) -> RTDResult<T> {
+  Ok(serde_json::from_str(json)?)
+}
+
+#[cfg(feature = "simd-json")]
+pub(crate) fn decode<T: DeserializeOwned>(json: &str) -> RTDResult<T> {
+  let mut buffer = json.as_bytes().to_vec();
+  simd_json::serde::from_slice(&mut buffer)
+    .map_err(|e| RTDError::Io(io::Error::new(io::ErrorKind::InvalidData, e.to_string())))
+}
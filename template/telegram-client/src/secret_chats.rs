@@ -0,0 +1,147 @@
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+use crate::observer::Pending;
+
+/// State of a secret chat, see `SecretChatState`; the state only moves forward, from pending to closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SecretChatStatus {
+  /// Waiting for the other user to come online
+  Pending,
+  /// Ready to be used
+  Ready,
+  /// Closed, it can't be used anymore
+  Closed,
+}
+
+impl SecretChatStatus {
+  pub fn of(state: &SecretChatState) -> Self {
+    if state.is_ready() { return SecretChatStatus::Ready; }
+    if state.is_closed() { return SecretChatStatus::Closed; }
+    SecretChatStatus::Pending
+  }
+}
+
+
+/// A secret chat and the state transitions received by `updateSecretChat`, created by
+/// `Api::start_secret_chat` or `Api::track_secret_chat`.
+#[derive(Debug)]
+pub struct SecretChatTracker {
+  api: Api,
+  chat_id: i64,
+  secret_chat: SecretChat,
+  updates: Receiver<String>,
+}
+
+impl Api {
+  /// Creates a new secret chat with this user (`createNewSecretChat`), it's pending until the
+  /// other user comes online, see `SecretChatTracker::wait_ready`.
+  pub fn start_secret_chat(&self, user_id: i64) -> RTDResult<SecretChatTracker> {
+    // watch before send, otherwise the first updates may be missed
+    let updates = self.watch("updateSecretChat");
+    let chat: Chat = self.request(CreateNewSecretChat::builder().user_id(user_id).build())?.wait()?;
+    let secret_chat_id = chat.type_().as_secret()
+      .map(|secret| secret.secret_chat_id())
+      .ok_or_else(|| RTDError::custom("The created chat is not a secret chat"))?;
+    self.track_with(chat.id(), secret_chat_id, updates)
+  }
+
+  /// Track the state of an existing secret chat (`getSecretChat`)
+  pub fn track_secret_chat(&self, secret_chat_id: i64) -> RTDResult<SecretChatTracker> {
+    let updates = self.watch("updateSecretChat");
+    let chat: Chat = self.request(CreateSecretChat::builder().secret_chat_id(secret_chat_id).build())?.wait()?;
+    self.track_with(chat.id(), secret_chat_id, updates)
+  }
+
+  fn track_with(&self, chat_id: i64, secret_chat_id: i64, updates: Receiver<String>) -> RTDResult<SecretChatTracker> {
+    let secret_chat: SecretChat = self.request(GetSecretChat::builder().secret_chat_id(secret_chat_id).build())?.wait()?;
+    let mut tracker = SecretChatTracker { api: self.clone(), chat_id, secret_chat, updates };
+    // apply the updates received before the secret chat is returned
+    tracker.poll();
+    Ok(tracker)
+  }
+}
+
+impl SecretChatTracker {
+  /// Identifier of the chat of the secret chat
+  pub fn chat_id(&self) -> i64 { self.chat_id }
+
+  /// The latest known secret chat
+  pub fn secret_chat(&self) -> &SecretChat { &self.secret_chat }
+
+  /// The latest known state
+  pub fn status(&self) -> SecretChatStatus { SecretChatStatus::of(self.secret_chat.state()) }
+
+  /// Apply the received updates without blocking, return the latest known state
+  pub fn poll(&mut self) -> SecretChatStatus {
+    while let Ok(json) = self.updates.try_recv() {
+      self.apply(&json);
+    }
+    self.status()
+  }
+
+  /// Block until the next state transition, return the new state
+  pub fn next_status(&mut self) -> RTDResult<SecretChatStatus> {
+    let status = self.status();
+    loop {
+      let json = self.updates.recv()
+        .map_err(|_| RTDError::custom("The receive loop is stopped, secret chat updates will not be received"))?;
+      self.apply(&json);
+      if self.status() != status {
+        return Ok(self.status());
+      }
+    }
+  }
+
+  /// Block until the secret chat is ready, fail if it's closed.
+  pub fn wait_ready(&mut self) -> RTDResult<&SecretChat> {
+    self.wait_ready_until(None)
+  }
+
+  /// Block until the secret chat is ready, fail if it's closed or timeout.
+  pub fn wait_ready_timeout(&mut self, timeout: Duration) -> RTDResult<&SecretChat> {
+    self.wait_ready_until(Some(Instant::now() + timeout))
+  }
+
+  /// Closes the secret chat (`closeSecretChat`)
+  pub fn close(&self) -> RTDResult<Pending<Ok>> {
+    self.api.request(CloseSecretChat::builder().secret_chat_id(self.secret_chat.id()).build())
+  }
+
+  fn wait_ready_until(&mut self, deadline: Option<Instant>) -> RTDResult<&SecretChat> {
+    loop {
+      match self.poll() {
+        SecretChatStatus::Ready => return Ok(&self.secret_chat),
+        SecretChatStatus::Closed => return Err(RTDError::custom("The secret chat is closed")),
+        SecretChatStatus::Pending => {}
+      }
+      let json = match deadline {
+        Some(deadline) => {
+          let timeout = deadline.saturating_duration_since(Instant::now());
+          match self.updates.recv_timeout(timeout) {
+            Ok(json) => json,
+            Err(RecvTimeoutError::Timeout) => return Err(RTDError::custom("Timeout waiting for the secret chat to be ready")),
+            Err(RecvTimeoutError::Disconnected) => return Err(RTDError::custom("The receive loop is stopped, secret chat updates will not be received")),
+          }
+        }
+        None => self.updates.recv()
+          .map_err(|_| RTDError::custom("The receive loop is stopped, secret chat updates will not be received"))?,
+      };
+      self.apply(&json);
+    }
+  }
+
+  fn apply(&mut self, json: &str) {
+    if let Ok(update) = UpdateSecretChat::from_json(json) {
+      // the updates received before `getSecretChat` may be older than the returned state
+      let status = SecretChatStatus::of(update.secret_chat().state());
+      if update.secret_chat().id() == self.secret_chat.id() && status >= self.status() {
+        self.secret_chat = update.secret_chat().clone();
+      }
+    }
+  }
+}
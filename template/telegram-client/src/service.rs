@@ -0,0 +1,103 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+use rtdlib::errors::*;
+use rtdlib::types::RFunction;
+use serde::de::DeserializeOwned;
+use tower_service::Service;
+
+use crate::api::Api;
+
+/// `tower::Service` of tdlib requests, created by `Api::service`, enabled by the `tower` feature.
+///
+/// The request is any function, the response is the return type `T` of the function, so the tower
+/// middlewares (timeout, rate limit, retry...) can be used on top of `Api::request`.
+/// Each call waits the response in its own thread and wakes the future when the response is received.
+#[derive(Debug)]
+pub struct TdService<T> {
+  api: Api,
+  _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for TdService<T> {
+  fn clone(&self) -> Self {
+    Self { api: self.api.clone(), _marker: PhantomData }
+  }
+}
+
+impl Api {
+  /// A `tower::Service` sending requests by this api, `T` is the return type of the functions
+  pub fn service<T>(&self) -> TdService<T> {
+    TdService { api: self.clone(), _marker: PhantomData }
+  }
+}
+
+impl<Fnc, T> Service<Fnc> for TdService<T>
+  where Fnc: RFunction,
+        T: DeserializeOwned + Send + 'static {
+  type Response = T;
+  type Error = RTDError;
+  type Future = ResponseFuture<T>;
+
+  fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+    Poll::Ready(Ok(()))
+  }
+
+  fn call(&mut self, fnc: Fnc) -> Self::Future {
+    let future = ResponseFuture::new();
+    match self.api.request::<Fnc, T>(fnc) {
+      Ok(pending) => {
+        let state = future.state.clone();
+        thread::spawn(move || ResponseFuture::complete(&state, pending.wait()));
+      }
+      Err(e) => ResponseFuture::complete(&future.state, Err(e)),
+    }
+    future
+  }
+}
+
+
+/// The response of a `TdService` call
+#[derive(Debug)]
+pub struct ResponseFuture<T> {
+  state: Arc<Mutex<ResponseState<T>>>,
+}
+
+#[derive(Debug)]
+struct ResponseState<T> {
+  result: Option<RTDResult<T>>,
+  waker: Option<Waker>,
+}
+
+impl<T> ResponseFuture<T> {
+  fn new() -> Self {
+    Self { state: Arc::new(Mutex::new(ResponseState { result: None, waker: None })) }
+  }
+
+  fn complete(state: &Mutex<ResponseState<T>>, result: RTDResult<T>) {
+    let mut state = state.lock().unwrap();
+    state.result = Some(result);
+    if let Some(waker) = state.waker.take() {
+      waker.wake();
+    }
+  }
+}
+
+impl<T> Future for ResponseFuture<T> {
+  type Output = RTDResult<T>;
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let mut state = self.state.lock().unwrap();
+    match state.result.take() {
+      Some(result) => Poll::Ready(result),
+      None => {
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+      }
+    }
+  }
+}
@@ -0,0 +1,112 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+
+/// A change of the active sessions of the current user, returned by `SessionWatcher`
+#[derive(Debug, Clone)]
+pub enum SessionChange {
+  /// A session logged in
+  Added(Session),
+  /// A session is terminated, or expired
+  Removed(Session),
+}
+
+impl Api {
+  /// The active sessions of the current user (`getActiveSessions`), in the order to show them
+  pub fn active_sessions(&self) -> RTDResult<Vec<Session>> {
+    let sessions: Sessions = self.request(GetActiveSessions::builder().build())?.wait()?;
+    Ok(sessions.sessions().clone())
+  }
+
+  /// Terminates a session of the current user (`terminateSession`) and waits for it, the current session is
+  /// logged out by `logOut` instead
+  pub fn terminate_active_session(&self, session: &Session) -> RTDResult<()> {
+    if session.is_current() {
+      return Err(RTDError::custom("The current session can't be terminated, log out instead"));
+    }
+    let _: Ok = self.request(TerminateSession::builder().session_id(session.id()).build())?.wait()?;
+    Ok(())
+  }
+
+  /// Terminates all the sessions of the current user but this one (`terminateAllOtherSessions`) and waits for it
+  pub fn terminate_other_sessions(&self) -> RTDResult<()> {
+    let _: Ok = self.request(TerminateAllOtherSessions::builder().build())?.wait()?;
+    Ok(())
+  }
+
+  /// Watch the sessions of the current user, tdlib sends no update of the sessions, they are polled by
+  /// `getActiveSessions` every `interval`
+  pub fn watch_sessions(&self, interval: Duration) -> RTDResult<SessionWatcher> {
+    let sessions = self.active_sessions()?.into_iter().map(|session| (session.id(), session)).collect();
+    Ok(SessionWatcher {
+      api: self.clone(),
+      interval,
+      sessions,
+      changes: VecDeque::new(),
+      next_poll: Instant::now() + interval,
+    })
+  }
+}
+
+
+/// Iterator of the changes of the sessions of the current user, created by `Api::watch_sessions`.
+///
+/// It blocks until a session is added or removed, the sessions are polled; a failed poll is returned as an
+/// error and polled again at the next interval.
+#[derive(Debug)]
+pub struct SessionWatcher {
+  api: Api,
+  interval: Duration,
+  sessions: BTreeMap<isize, Session>,
+  changes: VecDeque<SessionChange>,
+  next_poll: Instant,
+}
+
+impl SessionWatcher {
+  /// The sessions of the last poll
+  pub fn sessions(&self) -> Vec<&Session> {
+    self.sessions.values().collect()
+  }
+
+  fn poll(&mut self) -> RTDResult<()> {
+    let sessions: BTreeMap<isize, Session> = self.api.active_sessions()?.into_iter()
+      .map(|session| (session.id(), session))
+      .collect();
+    for (id, session) in &self.sessions {
+      if !sessions.contains_key(id) {
+        self.changes.push_back(SessionChange::Removed(session.clone()));
+      }
+    }
+    for (id, session) in &sessions {
+      if !self.sessions.contains_key(id) {
+        self.changes.push_back(SessionChange::Added(session.clone()));
+      }
+    }
+    self.sessions = sessions;
+    Ok(())
+  }
+}
+
+impl Iterator for SessionWatcher {
+  type Item = RTDResult<SessionChange>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      if let Some(change) = self.changes.pop_front() {
+        return Some(Ok(change));
+      }
+      let now = Instant::now();
+      if self.next_poll > now {
+        std::thread::sleep(self.next_poll - now);
+      }
+      self.next_poll = Instant::now() + self.interval;
+      if let Err(e) = self.poll() {
+        return Some(Err(e));
+      }
+    }
+  }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::sync::mpsc::Receiver;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+use crate::files::Progress;
+use crate::observer::Pending;
+
+/// The file format of a sticker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StickerFormat {
+  /// Static WEBP image
+  Webp,
+  /// Animated Lottie sticker, gzipped Lottie JSON
+  Tgs,
+  /// Video sticker, WEBM with VP9
+  Webm,
+}
+
+impl StickerFormat {
+  /// The format of these first bytes of a sticker file, `None` if none is recognized
+  pub fn from_magic(bytes: &[u8]) -> Option<Self> {
+    if bytes.starts_with(&[0x1f, 0x8b]) { return Some(StickerFormat::Tgs); }
+    if bytes.starts_with(&[0x1a, 0x45, 0xdf, 0xa3]) { return Some(StickerFormat::Webm); }
+    if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" { return Some(StickerFormat::Webp); }
+    None
+  }
+
+  /// The format of a downloaded sticker file by its content, by `Sticker::is_animated` if it isn't recognized
+  pub fn detect<P: AsRef<Path>>(sticker: &Sticker, path: P) -> Self {
+    let mut magic = [0u8; 12];
+    let read = fs::File::open(path).and_then(|mut file| file.read(&mut magic)).unwrap_or(0);
+    StickerFormat::from_magic(&magic[..read])
+      .unwrap_or(if sticker.is_animated() { StickerFormat::Tgs } else { StickerFormat::Webp })
+  }
+
+  pub fn extension(&self) -> &'static str {
+    match self {
+      StickerFormat::Webp => "webp",
+      StickerFormat::Tgs => "tgs",
+      StickerFormat::Webm => "webm",
+    }
+  }
+
+  pub fn mime_type(&self) -> &'static str {
+    match self {
+      StickerFormat::Webp => "image/webp",
+      StickerFormat::Tgs => "application/x-tgsticker",
+      StickerFormat::Webm => "video/webm",
+    }
+  }
+
+  /// Animated, TGS or WEBM
+  pub fn is_animated(&self) -> bool { *self != StickerFormat::Webp }
+}
+
+/// A downloaded sticker of `StickerSetDownload::wait`
+#[derive(Debug, Clone)]
+pub struct StickerFile {
+  sticker: Sticker,
+  file: File,
+  format: StickerFormat,
+}
+
+impl StickerFile {
+  pub fn sticker(&self) -> &Sticker { &self.sticker }
+
+  /// The downloaded file of the sticker
+  pub fn file(&self) -> &File { &self.file }
+
+  pub fn format(&self) -> StickerFormat { self.format }
+
+  pub fn emoji(&self) -> &String { self.sticker.emoji() }
+
+  /// Local path of the downloaded file
+  pub fn path(&self) -> &String { self.file.local().path() }
+
+  /// Content of the downloaded file, a TGS is still gzipped
+  pub fn read(&self) -> RTDResult<Vec<u8>> {
+    Ok(fs::read(self.path())?)
+  }
+}
+
+/// The files of a sticker set downloading at once, created by `Api::download_sticker_set`.
+///
+/// Iterate it to get the progress of all files, the iteration finishes when all files are downloaded.
+#[derive(Debug)]
+pub struct StickerSetDownload {
+  set: StickerSet,
+  files: HashMap<i64, File>,
+  updates: Receiver<String>,
+  finished: bool,
+}
+
+impl Api {
+  /// Find a sticker set by its name (`searchStickerSet`) and download all its sticker files at once.
+  ///
+  /// The files are tracked by `ApiBuilder::download_budget` like `Api::download_stream`.
+  pub fn download_sticker_set<S: AsRef<str>>(&self, name: S) -> RTDResult<StickerSetDownload> {
+    let set: StickerSet = self.request(SearchStickerSet::builder().name(name.as_ref()).build())?.wait()?;
+    // watch before the downloads start, otherwise the first updates may be missed
+    let updates = self.watch("updateFile");
+    let pendings = set.stickers().iter()
+      .map(|sticker| self.download_sticker(sticker))
+      .collect::<RTDResult<Vec<Pending<File>>>>()?;
+    let mut files = HashMap::new();
+    for pending in pendings {
+      let file = pending.wait()?;
+      files.insert(file.id(), file);
+    }
+    Ok(StickerSetDownload { set, files, updates, finished: false })
+  }
+
+  fn download_sticker(&self, sticker: &Sticker) -> RTDResult<Pending<File>> {
+    let file_id = sticker.sticker().id();
+    if let Some(downloads) = self.downloads() {
+      downloads.touch(file_id);
+    }
+    self.request(DownloadFile::builder()
+      .file_id(file_id)
+      .priority(1)
+      .offset(0)
+      .limit(0)
+      .synchronous(false)
+      .build())
+  }
+}
+
+impl StickerSetDownload {
+  pub fn set(&self) -> &StickerSet { &self.set }
+
+  /// Progress of all files together
+  pub fn progress(&self) -> Progress {
+    self.files.values().fold(Progress::new(0, 0), |progress, file| {
+      let downloaded = Progress::downloaded(file);
+      Progress::new(progress.transferred() + downloaded.transferred(), progress.total() + downloaded.total())
+    })
+  }
+
+  /// Block until all files are downloaded, return the stickers in the order of the set.
+  pub fn wait(mut self) -> RTDResult<Vec<StickerFile>> {
+    for progress in self.by_ref() {
+      progress?;
+    }
+    let files = self.files;
+    self.set.stickers().iter()
+      .map(|sticker| {
+        let file = files.get(&sticker.sticker().id()).cloned()
+          .ok_or(RTDError::custom("Sticker set download finished without a sticker file"))?;
+        let format = StickerFormat::detect(sticker, file.local().path());
+        Ok(StickerFile { sticker: sticker.clone(), file, format })
+      })
+      .collect()
+  }
+
+  fn completed(&self) -> bool {
+    self.files.values().all(|file| file.local().is_downloading_completed())
+  }
+
+  /// block until the next `updateFile` of a file of the set
+  fn next_file(&mut self) -> RTDResult<()> {
+    loop {
+      let json = match self.updates.recv() {
+        Ok(json) => json,
+        Err(_) => return Err(RTDError::custom("The receive loop is stopped, file updates will not be received")),
+      };
+      let update = UpdateFile::from_json(json)?;
+      let file = update.file();
+      if !self.files.contains_key(&file.id()) { continue; }
+      let local = file.local();
+      if !local.is_downloading_active() && !local.is_downloading_completed() {
+        return Err(RTDError::custom("Download is canceled or failed"));
+      }
+      self.files.insert(file.id(), file.clone());
+      return Ok(());
+    }
+  }
+}
+
+impl Iterator for StickerSetDownload {
+  type Item = RTDResult<Progress>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.finished { return None; }
+    if !self.completed() {
+      if let Err(e) = self.next_file() {
+        self.finished = true;
+        return Some(Err(e));
+      }
+    }
+    self.finished = self.completed();
+    Some(Ok(self.progress()))
+  }
+}
@@ -0,0 +1,163 @@
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+
+/// How often the storage cleanup checks the client is stopped
+const CLEANUP_TICK: Duration = Duration::from_secs(1);
+
+/// Which files `Api::prune_storage` deletes, the limits of `optimizeStorage`; a limit not set is the default of
+/// tdlib, e.g. 100 MB of files
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StoragePolicy {
+  max_size: Option<u64>,
+  ttl: Option<Duration>,
+  max_count: Option<i64>,
+  immunity_delay: Option<Duration>,
+  file_types: Vec<FileType>,
+  chat_ids: Vec<i64>,
+  exclude_chat_ids: Vec<i64>,
+}
+
+impl StoragePolicy {
+  pub fn builder() -> StoragePolicyBuilder {
+    StoragePolicyBuilder { inner: StoragePolicy::default() }
+  }
+
+  pub fn max_size(&self) -> Option<u64> { self.max_size }
+  pub fn ttl(&self) -> Option<Duration> { self.ttl }
+  pub fn max_count(&self) -> Option<i64> { self.max_count }
+  pub fn immunity_delay(&self) -> Option<Duration> { self.immunity_delay }
+
+  /// the request of this policy, the statistics of the deleted files are returned
+  fn optimize_storage(&self) -> OptimizeStorage {
+    let seconds = |duration: Option<Duration>| duration.map_or(-1, |duration| duration.as_secs() as i64);
+    OptimizeStorage::builder()
+      .size(self.max_size.map_or(-1, |size| size as i64))
+      .ttl(seconds(self.ttl))
+      .count(self.max_count.unwrap_or(-1))
+      .immunity_delay(seconds(self.immunity_delay))
+      .file_types(self.file_types.clone())
+      .chat_ids(self.chat_ids.clone())
+      .exclude_chat_ids(self.exclude_chat_ids.clone())
+      .return_deleted_file_statistics(true)
+      .chat_limit(0)
+      .build()
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct StoragePolicyBuilder {
+  inner: StoragePolicy
+}
+
+impl StoragePolicyBuilder {
+  pub fn build(&self) -> StoragePolicy { self.inner.clone() }
+
+  /// Total size of the files kept, in bytes
+  pub fn max_size(&mut self, bytes: u64) -> &mut Self {
+    self.inner.max_size = Some(bytes);
+    self
+  }
+
+  /// Delete the files not accessed for this time
+  pub fn ttl(&mut self, ttl: Duration) -> &mut Self {
+    self.inner.ttl = Some(ttl);
+    self
+  }
+
+  /// Number of files kept
+  pub fn max_count(&mut self, count: i64) -> &mut Self {
+    self.inner.max_count = Some(count);
+    self
+  }
+
+  /// Keep the files accessed in this time, whatever the other limits
+  pub fn immunity_delay(&mut self, delay: Duration) -> &mut Self {
+    self.inner.immunity_delay = Some(delay);
+    self
+  }
+
+  /// Only delete the files of this type, e.g. `FileType::video(FileTypeVideo::builder())`; all types by default
+  pub fn file_type(&mut self, file_type: FileType) -> &mut Self {
+    self.inner.file_types.push(file_type);
+    self
+  }
+
+  /// Only delete the files of this chat; all chats by default
+  pub fn chat(&mut self, chat_id: i64) -> &mut Self {
+    self.inner.chat_ids.push(chat_id);
+    self
+  }
+
+  /// Keep the files of this chat
+  pub fn exclude_chat(&mut self, chat_id: i64) -> &mut Self {
+    self.inner.exclude_chat_ids.push(chat_id);
+    self
+  }
+}
+
+impl AsRef<StoragePolicy> for StoragePolicy {
+  fn as_ref(&self) -> &StoragePolicy { self }
+}
+
+impl AsRef<StoragePolicy> for StoragePolicyBuilder {
+  fn as_ref(&self) -> &StoragePolicy { &self.inner }
+}
+
+/// The scheduled cleanup of `ApiBuilder::storage_cleanup`
+#[derive(Debug)]
+pub(crate) struct StorageCleanup {
+  policy: StoragePolicy,
+  interval: Duration,
+}
+
+impl StorageCleanup {
+  pub fn new(policy: StoragePolicy, interval: Duration) -> Self {
+    Self { policy, interval }
+  }
+}
+
+impl Api {
+  /// The files stored by tdlib by chat and file type (`getStorageStatistics`), the `chat_limit` largest chats
+  pub fn storage_statistics(&self, chat_limit: i64) -> RTDResult<StorageStatistics> {
+    self.request(GetStorageStatistics::builder().chat_limit(chat_limit).build())?.wait()
+  }
+
+  /// Sizes of the files, databases and logs of tdlib (`getStorageStatisticsFast`), quickly
+  pub fn storage_statistics_fast(&self) -> RTDResult<StorageStatisticsFast> {
+    self.request(GetStorageStatisticsFast::builder().build())?.wait()
+  }
+
+  /// Delete the files of the policy (`optimizeStorage`) and wait for it, return the statistics of the deleted files
+  pub fn prune_storage<P: AsRef<StoragePolicy>>(&self, policy: P) -> RTDResult<StorageStatistics> {
+    self.request(policy.as_ref().optimize_storage())?.wait()
+  }
+}
+
+/// prune the storage by `ApiBuilder::storage_cleanup` after the authorization is ready
+pub(crate) fn start(api: &Api) -> Option<JoinHandle<()>> {
+  api.storage_cleanup()?;
+  Some(api.on_ready(run))
+}
+
+fn run(api: Api) {
+  let cleanup = match api.storage_cleanup() {
+    Some(cleanup) => cleanup,
+    None => return,
+  };
+  let mut next_cleanup = Instant::now();
+  while !api.update_queue().is_closed() {
+    if Instant::now() >= next_cleanup {
+      match api.prune_storage(&cleanup.policy) {
+        Ok(deleted) => info!("Storage cleanup deleted {} files, {} bytes", deleted.count(), deleted.size()),
+        Err(e) => warn!("Can not clean up the storage: {}", e),
+      }
+      next_cleanup = Instant::now() + cleanup.interval;
+    }
+    thread::sleep(CLEANUP_TICK);
+  }
+}
@@ -0,0 +1,68 @@
+use std::sync::mpsc::{Receiver, RecvTimeoutError, TryRecvError};
+use std::time::Duration;
+
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+use crate::api::Api;
+use crate::observer::ALL_UPDATES;
+use crate::scan::decode;
+
+/// An independent consumer of updates, created by `Api::subscribe` or `Api::subscribe_all`.
+///
+/// Every subscription receives its own copy of the updates, beside the listener and the other
+/// subscriptions, so several components can consume the same updates. Iterate it to receive the
+/// updates, drop it to unsubscribe.
+/// The updates are buffered until received, so keep consuming a subscription.
+#[derive(Debug)]
+pub struct Subscription {
+  updates: Receiver<String>,
+}
+
+impl Api {
+  /// Subscribe updates of these td types, e.g. `&["updateNewMessage", "updateFile"]`
+  pub fn subscribe<S: AsRef<str>>(&self, td_types: &[S]) -> Subscription {
+    Subscription { updates: self.observer().watch_all(td_types) }
+  }
+
+  /// Subscribe all updates
+  pub fn subscribe_all(&self) -> Subscription {
+    Subscription { updates: self.observer().watch(ALL_UPDATES) }
+  }
+}
+
+impl Subscription {
+  /// Block until the next update; `None` if all `Api` are dropped
+  pub fn recv(&self) -> Option<RTDResult<Update>> {
+    self.updates.recv().ok().map(|json| decode(&json))
+  }
+
+  /// Block until the next update or timeout
+  pub fn recv_timeout(&self, timeout: Duration) -> Option<RTDResult<Update>> {
+    match self.updates.recv_timeout(timeout) {
+      Ok(json) => Some(decode(&json)),
+      Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => None,
+    }
+  }
+
+  /// The next update if it's already received
+  pub fn try_recv(&self) -> Option<RTDResult<Update>> {
+    match self.updates.try_recv() {
+      Ok(json) => Some(decode(&json)),
+      Err(TryRecvError::Empty) | Err(TryRecvError::Disconnected) => None,
+    }
+  }
+
+  /// Block until the next update, return the json as received from tdlib
+  pub fn recv_json(&self) -> Option<String> {
+    self.updates.recv().ok()
+  }
+}
+
+impl Iterator for Subscription {
+  type Item = RTDResult<Update>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.recv()
+  }
+}
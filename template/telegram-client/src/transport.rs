@@ -0,0 +1,44 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use rtdlib::Tdlib;
+
+/// The connection to tdlib used by `Api`, tdlib by default, see `ApiBuilder::transport`.
+///
+/// The requests and the objects are the tdlib json, e.g. to record them, to replay them in tests,
+/// or to reach a tdlib running in another process.
+/// It's object safe, a transport selected at runtime is a `Box<dyn TdLibClient>`, e.g. by a plugin.
+pub trait TdLibClient: Debug + Send + Sync {
+  /// Send a request, the response is received by `receive`
+  fn send(&self, request: &str);
+
+  /// Block until the next object or timeout in seconds
+  fn receive(&self, timeout: f64) -> Option<String>;
+
+  /// Execute a synchronous request, return its response
+  fn execute(&self, request: &str) -> Option<String>;
+}
+
+impl TdLibClient for Tdlib {
+  fn send(&self, request: &str) { Tdlib::send(self, request) }
+
+  fn receive(&self, timeout: f64) -> Option<String> { Tdlib::receive(self, timeout) }
+
+  fn execute(&self, request: &str) -> Option<String> { Tdlib::execute(self, request) }
+}
+
+impl<T: TdLibClient + ?Sized> TdLibClient for Box<T> {
+  fn send(&self, request: &str) { (**self).send(request) }
+
+  fn receive(&self, timeout: f64) -> Option<String> { (**self).receive(timeout) }
+
+  fn execute(&self, request: &str) -> Option<String> { (**self).execute(request) }
+}
+
+impl<T: TdLibClient + ?Sized> TdLibClient for Arc<T> {
+  fn send(&self, request: &str) { (**self).send(request) }
+
+  fn receive(&self, timeout: f64) -> Option<String> { (**self).receive(timeout) }
+
+  fn execute(&self, request: &str) -> Option<String> { (**self).execute(request) }
+}
@@ -0,0 +1,218 @@
+use std::io::{self, Write};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use rtdlib::errors::*;
+
+use crate::api::Api;
+use crate::observer::ALL_UPDATES;
+use crate::scan::scan;
+
+/// check whether the client stopped at least this often
+const POLL: Duration = Duration::from_secs(1);
+/// send a comment to the connection of server-sent events after this time without update
+const KEEPALIVE: Duration = Duration::from_secs(15);
+
+/// Gateway of updates over HTTP for the services not written in rust, see `Webhook::builder`.
+///
+/// The updates are forwarded as the json received from tdlib, by POST to a url, or by an endpoint of
+/// server-sent events, or both. Each event of the endpoint is named by the td type of the update.
+#[derive(Debug, Clone)]
+pub struct Webhook {
+  url: Option<String>,
+  listen: Option<String>,
+  td_types: Vec<String>,
+  headers: Vec<(String, String)>,
+  timeout: Duration,
+  retries: u32,
+}
+
+impl Default for Webhook {
+  fn default() -> Self {
+    Self {
+      url: None,
+      listen: None,
+      td_types: vec![],
+      headers: vec![],
+      timeout: Duration::from_secs(10),
+      retries: 3,
+    }
+  }
+}
+
+impl Webhook {
+  pub fn builder() -> WebhookBuilder {
+    WebhookBuilder { inner: Webhook::default() }
+  }
+
+  pub fn url(&self) -> Option<&String> { self.url.as_ref() }
+  pub fn listen(&self) -> Option<&String> { self.listen.as_ref() }
+  /// The td types forwarded, all updates if empty
+  pub fn td_types(&self) -> &Vec<String> { &self.td_types }
+  pub fn headers(&self) -> &Vec<(String, String)> { &self.headers }
+  pub fn timeout(&self) -> Duration { self.timeout }
+  pub fn retries(&self) -> u32 { self.retries }
+
+  /// Start forwarding the updates of this api in background threads, they stop when the client stops
+  pub fn start(&self, api: &Api) -> RTDResult<()> {
+    if self.url.is_none() && self.listen.is_none() {
+      return Err(RTDError::custom("The webhook has neither a url nor an address to listen"));
+    }
+    if let Some(listen) = &self.listen {
+      let server = tiny_http::Server::http(&listen[..])
+        .map_err(|e| RTDError::Io(io::Error::other(format!("Can not listen {}: {}", listen, e))))?;
+      let (api, webhook) = (api.clone(), self.clone());
+      thread::spawn(move || webhook.serve(api, server));
+    }
+    if self.url.is_some() {
+      let updates = self.watch(api);
+      let (api, webhook) = (api.clone(), self.clone());
+      thread::spawn(move || webhook.push(api, updates));
+    }
+    Ok(())
+  }
+
+  fn watch(&self, api: &Api) -> Receiver<String> {
+    if self.td_types.is_empty() {
+      api.observer().watch(ALL_UPDATES)
+    } else {
+      api.observer().watch_all(&self.td_types)
+    }
+  }
+
+  /// POST every update to the url, retry with backoff if failed
+  fn push(&self, api: Api, updates: Receiver<String>) {
+    let url = match &self.url {
+      Some(url) => url,
+      None => return,
+    };
+    let agent = ureq::AgentBuilder::new().timeout(self.timeout).build();
+    while !api.update_queue().is_closed() {
+      let json = match updates.recv_timeout(POLL) {
+        Ok(json) => json,
+        Err(RecvTimeoutError::Timeout) => continue,
+        Err(RecvTimeoutError::Disconnected) => break,
+      };
+      let mut attempt = 0;
+      loop {
+        let mut request = agent.post(url).set("Content-Type", "application/json");
+        for (name, value) in &self.headers {
+          request = request.set(name, value);
+        }
+        match request.send_string(&json) {
+          Ok(_) => break,
+          Err(e) if attempt < self.retries => {
+            attempt += 1;
+            warn!("Can not push the update to the webhook, retry {}/{}: {}", attempt, self.retries, e);
+            thread::sleep(Duration::from_millis(500 << attempt.min(6)));
+          }
+          Err(e) => {
+            error!("Can not push the update to the webhook, it's dropped: {}", e);
+            break;
+          }
+        }
+      }
+    }
+  }
+
+  /// serve every request as a stream of server-sent events, each connection watches the updates since it connected
+  fn serve(&self, api: Api, server: tiny_http::Server) {
+    while !api.update_queue().is_closed() {
+      let request = match server.recv_timeout(POLL) {
+        Ok(Some(request)) => request,
+        Ok(None) => continue,
+        Err(e) => {
+          error!("The webhook server failed: {}", e);
+          break;
+        }
+      };
+      let (updates, api) = (self.watch(&api), api.clone());
+      thread::spawn(move || {
+        // the connection is closed by the client, or the stream ends when the client stops
+        if let Err(e) = Self::stream(api, updates, request.into_writer()) {
+          debug!("The stream of the webhook is closed: {}", e);
+        }
+      });
+    }
+  }
+
+  fn stream(api: Api, updates: Receiver<String>, mut writer: Box<dyn Write + Send>) -> io::Result<()> {
+    writer.write_all(b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n")?;
+    writer.flush()?;
+    let mut idle = Duration::from_secs(0);
+    while !api.update_queue().is_closed() {
+      match updates.recv_timeout(POLL) {
+        Ok(json) => {
+          idle = Duration::from_secs(0);
+          write!(writer, "event: {}\ndata: {}\n\n", scan(&json).td_type().unwrap_or("update"), json)?;
+        }
+        Err(RecvTimeoutError::Timeout) => {
+          idle += POLL;
+          if idle < KEEPALIVE { continue; }
+          idle = Duration::from_secs(0);
+          // a comment keeps the connection alive, the write fails if the client disconnected
+          writer.write_all(b":\n\n")?;
+        }
+        Err(RecvTimeoutError::Disconnected) => break,
+      }
+      writer.flush()?;
+    }
+    Ok(())
+  }
+}
+
+
+#[derive(Debug, Clone)]
+pub struct WebhookBuilder {
+  inner: Webhook,
+}
+
+impl WebhookBuilder {
+  pub fn build(&self) -> Webhook { self.inner.clone() }
+
+  /// POST every update to this url, the body is the json of the update
+  pub fn url<S: AsRef<str>>(&mut self, url: S) -> &mut Self {
+    self.inner.url = Some(url.as_ref().to_string());
+    self
+  }
+
+  /// Serve the updates as server-sent events on this address, e.g. `127.0.0.1:8080`
+  pub fn listen<S: AsRef<str>>(&mut self, listen: S) -> &mut Self {
+    self.inner.listen = Some(listen.as_ref().to_string());
+    self
+  }
+
+  /// Forward the updates of this td type, e.g. `updateNewMessage`; all updates if none is set
+  pub fn td_type<S: AsRef<str>>(&mut self, td_type: S) -> &mut Self {
+    self.inner.td_types.push(td_type.as_ref().to_string());
+    self
+  }
+
+  /// Add this header to every POST, e.g. a token of the service
+  pub fn header<N: AsRef<str>, V: AsRef<str>>(&mut self, name: N, value: V) -> &mut Self {
+    self.inner.headers.push((name.as_ref().to_string(), value.as_ref().to_string()));
+    self
+  }
+
+  /// Timeout of every POST, 10 seconds by default
+  pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+    self.inner.timeout = timeout;
+    self
+  }
+
+  /// Retry a failed POST this many times before the update is dropped, 3 by default
+  pub fn retries(&mut self, retries: u32) -> &mut Self {
+    self.inner.retries = retries;
+    self
+  }
+}
+
+impl AsRef<Webhook> for Webhook {
+  fn as_ref(&self) -> &Webhook { self }
+}
+
+impl AsRef<Webhook> for WebhookBuilder {
+  fn as_ref(&self) -> &Webhook { &self.inner }
+}
+
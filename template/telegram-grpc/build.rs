@@ -0,0 +1,5 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+  // protoc is required, or set `PROTOC` to its path
+  tonic_build::compile_protos("proto/tdlib.proto")?;
+  Ok(())
+}
@@ -0,0 +1,152 @@
+//! Conversions of the proto messages from and to the tdlib json, the server sends the json by `Api::request_raw`.
+
+use serde_json::{Map, Value};
+
+use crate::pb;
+
+/// The tdlib json of a proto message
+pub trait ToTd {
+  fn to_td(&self) -> Value;
+}
+
+/// A proto message of the tdlib json, a missing field is the default value
+pub trait FromTd: Sized {
+  fn from_td(value: &Value) -> Self;
+}
+
+impl ToTd for i32 {
+  fn to_td(&self) -> Value { Value::from(*self) }
+}
+
+impl FromTd for i32 {
+  fn from_td(value: &Value) -> Self { value.as_i64().unwrap_or_default() as i32 }
+}
+
+/// tdlib accepts an int64 as a number, and returns it as a string
+impl ToTd for i64 {
+  fn to_td(&self) -> Value { Value::from(*self) }
+}
+
+impl FromTd for i64 {
+  fn from_td(value: &Value) -> Self {
+    match value {
+      Value::String(text) => text.parse().unwrap_or_default(),
+      _ => value.as_i64().unwrap_or_default(),
+    }
+  }
+}
+
+impl ToTd for f64 {
+  fn to_td(&self) -> Value { Value::from(*self) }
+}
+
+impl FromTd for f64 {
+  fn from_td(value: &Value) -> Self { value.as_f64().unwrap_or_default() }
+}
+
+impl ToTd for bool {
+  fn to_td(&self) -> Value { Value::from(*self) }
+}
+
+impl FromTd for bool {
+  fn from_td(value: &Value) -> Self { value.as_bool().unwrap_or_default() }
+}
+
+impl ToTd for String {
+  fn to_td(&self) -> Value { Value::from(&self[..]) }
+}
+
+impl FromTd for String {
+  fn from_td(value: &Value) -> Self { value.as_str().unwrap_or_default().to_string() }
+}
+
+/// bytes are base64 in the tdlib json
+impl ToTd for Vec<u8> {
+  fn to_td(&self) -> Value { Value::from(base64::encode(self)) }
+}
+
+impl FromTd for Vec<u8> {
+  fn from_td(value: &Value) -> Self {
+    value.as_str().and_then(|text| base64::decode(text).ok()).unwrap_or_default()
+  }
+}
+
+impl<T: ToTd> ToTd for Vec<T> {
+  fn to_td(&self) -> Value { Value::Array(self.iter().map(ToTd::to_td).collect()) }
+}
+
+impl<T: FromTd> FromTd for Vec<T> {
+  fn from_td(value: &Value) -> Self {
+    value.as_array().map_or_else(Vec::new, |items| items.iter().map(T::from_td).collect())
+  }
+}
+
+impl<T: ToTd> ToTd for Option<T> {
+  fn to_td(&self) -> Value { self.as_ref().map_or(Value::Null, ToTd::to_td) }
+}
+
+impl<T: FromTd> FromTd for Option<T> {
+  fn from_td(value: &Value) -> Self {
+    if value.is_null() { None } else { Some(T::from_td(value)) }
+  }
+}
+
+/// prost boxes the recursive messages
+impl<T: ToTd> ToTd for Box<T> {
+  fn to_td(&self) -> Value { (**self).to_td() }
+}
+
+impl<T: FromTd> FromTd for Box<T> {
+  fn from_td(value: &Value) -> Self { Box::new(T::from_td(value)) }
+}
+{% for vector in vectors %}
+impl ToTd for pb::{{vector.name}} {
+  fn to_td(&self) -> Value { self.items.to_td() }
+}
+
+impl FromTd for pb::{{vector.name}} {
+  fn from_td(value: &Value) -> Self { Self { items: FromTd::from_td(value) } }
+}
+{% endfor %}{% for token in tokens %}{% set name = token.name | to_proto_camel %}{% set subs = sub_tokens(token=token) %}
+{% if token.type_ == 'Trait' and subs %}{% set module = token.name | to_proto_snake %}impl ToTd for pb::{{name}} {
+  fn to_td(&self) -> Value {
+    match &self.kind {
+{% for subt in subs %}      Some(pb::{{module}}::Kind::{{subt.name | to_proto_camel}}(value)) => value.to_td(),
+{% endfor %}      None => Value::Null,
+    }
+  }
+}
+
+impl FromTd for pb::{{name}} {
+  fn from_td(value: &Value) -> Self {
+    let kind = match value["@type"].as_str() {
+{% for subt in subs %}      Some("{{subt.name}}") => Some(pb::{{module}}::Kind::{{subt.name | to_proto_camel}}(FromTd::from_td(value))),
+{% endfor %}      _ => None,
+    };
+    Self { kind }
+  }
+}
+{% elif token.type_ == 'Trait' %}impl ToTd for pb::{{name}} {
+  fn to_td(&self) -> Value { Value::Null }
+}
+
+impl FromTd for pb::{{name}} {
+  fn from_td(_: &Value) -> Self { Self {} }
+}
+{% else %}impl ToTd for pb::{{name}} {
+  fn to_td(&self) -> Value {
+    let mut map = Map::new();
+    map.insert("@type".to_string(), Value::from("{{token.name}}"));
+{% for field in token.arguments %}    map.insert("{{field.sign_name}}".to_string(), self.{{field.sign_name | td_proto_field}}.to_td());
+{% endfor %}    Value::Object(map)
+  }
+}
+
+impl FromTd for pb::{{name}} {
+  fn from_td({% if token.arguments %}value{% else %}_{% endif %}: &Value) -> Self {
+    Self {
+{% for field in token.arguments %}      {{field.sign_name | td_proto_field}}: FromTd::from_td(&value["{{field.sign_name}}"]),
+{% endfor %}    }
+  }
+}
+{% endif %}{% endfor %}
@@ -0,0 +1,31 @@
+//! gRPC gateway of telegram-client, generated by fantasy from the tdlib schema.
+//!
+//! `proto/tdlib.proto` has a message for every tdlib type and function and a rpc for every function,
+//! other languages can drive tdlib by the generated clients of this proto.
+//!
+//! ```no_run
+//! use telegram_client::api::Api;
+//! use telegram_grpc::server::TdlibService;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let api = Api::default();
+//! // start `telegram_client::client::Client` with a clone of the api to receive the responses
+//! tonic::transport::Server::builder()
+//!   .add_service(TdlibService::new(api).into_server())
+//!   .serve("127.0.0.1:50051".parse()?)
+//!   .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#[macro_use]
+extern crate log;
+
+/// The messages and the service generated from `proto/tdlib.proto`
+#[allow(clippy::all)]
+pub mod pb {
+  tonic::include_proto!("tdlib");
+}
+
+pub mod convert;
+pub mod server;
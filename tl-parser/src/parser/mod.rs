@@ -3,3 +3,4 @@ pub mod parser;
 
 mod group_parser;
 mod tl;
+mod unknown;
@@ -6,6 +6,7 @@ use failure::Error;
 use crate::errors;
 use crate::parser::group_parser;
 use crate::parser::tl;
+use crate::parser::unknown;
 use crate::types::*;
 
 pub struct TLParser<P: AsRef<Path>> {
@@ -41,8 +42,11 @@ impl<P: AsRef<Path>> TLParser<P> {
 
     Ok(tokens)
   }
-}
-
-
-
 
+  /// the constructs of the schema the tokens of `parse` drop or misread, e.g. a new scalar type
+  pub fn unknowns(&self) -> Result<Vec<TLUnknown>, Error> {
+    let tlbody = fs::read_to_string(self.path.as_ref())?;
+    let grammars = group_parser::parse(&tlbody)?;
+    Ok(unknown::unknowns(&tlbody, &grammars))
+  }
+}
@@ -88,7 +88,8 @@ fn parse_token_group(group: &TLGroup, token_group_type: TLTokenGroupType) -> Res
     arguments: Default::default(),
     type_: token_group_type,
     blood: None,
-    is_return_type: false
+    is_return_type: false,
+    deprecated: None,
   };
 
   // description builder
@@ -120,8 +121,10 @@ fn parse_token_group(group: &TLGroup, token_group_type: TLTokenGroupType) -> Res
     let args = &mut token_group.arguments;
     for tat in args {
       tat.description = dall.get(&tat.sign_name[..]).map(|v| v.clone());
+      tat.deprecated = tat.description.as_deref().and_then(deprecation);
     }
   }
+  token_group.deprecated = token_group.description.as_deref().and_then(deprecation);
   token_group.description_all = description_all;
   Ok(token_group)
 }
@@ -135,7 +138,8 @@ fn group_trait(gl: &TLGroupLine) -> Result<TLTokenGroup, Error> {
     arguments: Default::default(),
     type_: TLTokenGroupType::Trait,
     blood: None,
-    is_return_type: false
+    is_return_type: false,
+    deprecated: None,
   };
 
   let description_map = tl_description_map(gl.text.clone());
@@ -149,11 +153,17 @@ fn group_trait(gl: &TLGroupLine) -> Result<TLTokenGroup, Error> {
   };
 
   token_group.description_all = Some(description_map);
+  token_group.deprecated = deprecation(&description);
   token_group.description = Some(description);
   token_group.name = name;
   Ok(token_group)
 }
 
+/// the deprecation note of a description documented as deprecated
+fn deprecation(description: &str) -> Option<String> {
+  if description.to_lowercase().contains("deprecated") { Some(description.trim().to_string()) } else { None }
+}
+
 /// parse group arguments
 fn group_args(line: i32, code: &String) -> Result<Vec<TLTokenArgType>, Error> {
   let words: Vec<&str> = code.split(" ").collect();
@@ -226,92 +236,47 @@ fn arg_type<S: AsRef<str>>(line: i32, code: &String, arg_text: S) -> Result<TLTo
     None => return bail!("Syntax fail. line -> {} -> {}", line, code)
   };
 
-  // not have component type
-  if !sign_type.contains("<") {
-    let tat = TLTokenArgType::builder()
-      .sign_name(sign_name)
-      .sign_type(sign_type)
-      .components(vec![])
-      .build();
-//    debug!("{:?}", tat);
-    return Ok(tat);
-  }
-
-  let component_sign_type = component_sign_type(sign_type);
-  let component_sign_components = component_sign_components(sign_type);
+  let (sign_type, components) = generic_type(sign_type);
   let tat = TLTokenArgType::builder()
     .sign_name(sign_name)
-    .sign_type(component_sign_type)
-    .components(arg_component_types(component_sign_components))
+    .sign_type(sign_type)
+    .components(components)
     .build();
-//  debug!("{}:{}  ----> {:#?}", sign_name, sign_type, tat);
-
   Ok(tat)
 }
 
-
-fn arg_component_types<S: AsRef<str>>(sign_type: S) -> Vec<TLTokenComponentType> {
-  let mut rets = vec![];
+/// parse a generic type to its name and its parameters of any depth, `vector<vector<string>>` -> vector with the
+/// component vector<string>; the parameters of a type of many, e.g. `map<string,int32>`, are its components in order
+fn generic_type<S: AsRef<str>>(sign_type: S) -> (String, Vec<TLTokenComponentType>) {
   let sign_type = sign_type.as_ref();
-
-  // not have sub components
-  if !sign_type.contains("<") {
-    let component_sign_type = component_sign_type(sign_type);
-    let tct = TLTokenComponentType::builder()
-      .sign_type(component_sign_type)
-      .components(vec![])
-      .build();
-    rets.push(tct);
-    return rets;
-  }
-
-  // have sub components
-  let mut reader = TextReader::new(sign_type);
-  let mut builder = StringBuilder::new();
-  while reader.has_next() {
-    match reader.next() {
-      Some('<') => {
-        let component_sign_type = component_sign_type(sign_type);
-        let component_sign_components = component_sign_components(sign_type);
-        let tct = TLTokenComponentType::builder()
-          .sign_type(component_sign_type)
-          .components(arg_component_types(component_sign_components))
-          .build();
-        rets.push(tct);
-      }
-      Some(',') => {
-        // tl schema not support `,` , so don't have multi component type (like map<string, string>). nothing to do.
-      }
-      Some(ch) => {
-        builder.append(ch);
-      }
-      None => {}
-    };
+  let start = match sign_type.find('<') {
+    Some(start) => start,
+    None => return (sign_type.to_string(), vec![]),
   };
-
-  rets
-}
-
-/// parse component sign type     vec<vec<string>>    -> vec
-fn component_sign_type<S: AsRef<str>>(sign_type: S) -> String {
-  let sign_type = sign_type.as_ref();
-  let chs = sign_type.chars().collect::<Vec<char>>();
-  let ix = chs.iter().enumerate()
-    .find(|(_, &ch)| ch == '<')
-    .map(|(ix, _)| ix)
-    .map_or(chs.len(), |v| v);
-  sign_type.chars().take(ix).collect()
-}
-
-/// parse component sign type components   vec<vec<string>> -> vec<string>
-fn component_sign_components<S: AsRef<str>>(sign_type: S) -> String {
-  let sign_type = sign_type.as_ref();
-  let chs = sign_type.chars().collect::<Vec<char>>();
-  let ix = chs.iter().enumerate()
-    .find(|(_, &ch)| ch == '<')
-    .map(|(ix, _)| ix)
-    .map_or(chs.len(), |v| v);
-  sign_type.chars().skip(ix + 1).take(chs.len() - (ix + 2)).collect()
+  let end = sign_type.rfind('>').filter(|&end| end > start).unwrap_or(sign_type.len());
+  let parameters = &sign_type[start + 1..end];
+
+  let mut components = vec![];
+  let mut depth = 0;
+  let mut from = 0;
+  for (ix, ch) in parameters.char_indices().chain(std::iter::once((parameters.len(), ','))) {
+    match ch {
+      '<' => depth += 1,
+      '>' => depth -= 1,
+      ',' if depth == 0 => {
+        let parameter = parameters[from..ix].trim();
+        from = ix + 1;
+        if parameter.is_empty() { continue; }
+        let (parameter_type, parameter_components) = generic_type(parameter);
+        components.push(TLTokenComponentType::builder()
+          .sign_type(parameter_type)
+          .components(parameter_components)
+          .build());
+      }
+      _ => {}
+    }
+  }
+  (sign_type[..start].to_string(), components)
 }
 
 
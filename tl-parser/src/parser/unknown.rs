@@ -0,0 +1,136 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::types::*;
+
+/// the type of the type parameters of a builtin, `vector {t:Type}`
+const TYPE_PARAMETER: &str = "Type";
+
+/// The schema constructs the tokens drop or misread: the fields of a type not declared by the schema, e.g. a new
+/// scalar or a tl annotation like `flags.0?string`, the description tags of no field, the descriptions of no
+/// declaration and the names declared twice.
+pub fn unknowns<S: AsRef<str>>(schema: S, grammars: &[Box<dyn TLGrammar>]) -> Vec<TLUnknown> {
+  let groups: Vec<TLGroup> = grammars.iter().filter_map(|grammar| grammar.to_group()).collect();
+  let declarations: Vec<&TLGroupLine> = groups.iter()
+    .flat_map(|group| group.lines.iter())
+    .filter(|line| !matches!(line.token, TLGroupLineToken::Description))
+    .collect();
+
+  // the types the schema declares, the names, the classes and the types of the declarations before the functions
+  let functions = grammars.iter()
+    .filter_map(|grammar| grammar.to_paragraph())
+    .map(|paragraph| match paragraph { TLParagraph::Functions { start, .. } => start })
+    .next()
+    .unwrap_or(i32::MAX);
+  let mut types: HashSet<String> = HashSet::new();
+  types.insert(TYPE_PARAMETER.to_lowercase());
+  for line in &declarations {
+    let words: Vec<&str> = line.text.split(' ').collect();
+    match line.token {
+      TLGroupLineToken::Trait => { types.extend(self::tags(&line.text).remove("class").map(|class| class.to_lowercase())); }
+      _ => {
+        types.extend(words.first().map(|name| name.to_lowercase()));
+        if line.line < functions {
+          if let Some(index) = words.iter().position(|&word| word == "=") {
+            types.extend(words.get(index + 1).map(|blood| blood.to_lowercase()));
+          }
+        }
+      }
+    }
+  }
+
+  let mut unknowns = vec![];
+  let mut declared: HashMap<String, i32> = HashMap::new();
+  for group in &groups {
+    let struct_line = group.lines.iter().find(|line| matches!(line.token, TLGroupLineToken::Struct));
+    let struct_line = match struct_line {
+      Some(line) => line,
+      None => continue,
+    };
+    let unknown = |reason: String| TLUnknown { line: struct_line.line, text: struct_line.text.clone(), reason };
+    let words: Vec<&str> = struct_line.text.split(' ').collect();
+    let name = words.first().cloned().unwrap_or_default();
+    if let Some(line) = declared.insert(name.to_string(), struct_line.line) {
+      unknowns.push(unknown(format!("{} is declared again, the declaration of line {} is dropped", name, line)));
+    }
+
+    let mut fields = vec![];
+    for word in words.iter().skip(1).take_while(|&&word| word != "=" && word != "?") {
+      let (field, type_) = match word.find(':') {
+        Some(index) => (&word[..index], &word[index + 1..]),
+        None => continue,
+      };
+      let field = field.trim_start_matches('{');
+      fields.push(field.to_string());
+      let type_ = type_.trim_end_matches('}');
+      if type_.contains('?') || type_.contains('#') {
+        unknowns.push(unknown(format!("field {} has the tl annotation {}", field, type_)));
+        continue;
+      }
+      for used in type_.split(['<', '>', ',']).filter(|used| !used.is_empty()) {
+        if !types.contains(&used.to_lowercase()) {
+          unknowns.push(unknown(format!("field {} has the type {} not declared by the schema", field, used)));
+        }
+      }
+    }
+
+    let description: Vec<&str> = group.lines.iter()
+      .filter(|line| matches!(line.token, TLGroupLineToken::Description))
+      .map(|line| &line.text[..])
+      .collect();
+    for tag in self::tags(&description.join(" ")).keys() {
+      let field = tag.strip_prefix("param_").unwrap_or(tag);
+      if tag != "description" && !fields.iter().any(|name| name == field) {
+        unknowns.push(unknown(format!("description tag @{} is of no field of {}", tag, name)));
+      }
+    }
+  }
+
+  unknowns.extend(self::dangling(schema.as_ref()));
+  unknowns.sort_by_key(|unknown| unknown.line);
+  unknowns
+}
+
+/// the description lines followed by no declaration, they are dropped or describe the next one
+fn dangling(schema: &str) -> Vec<TLUnknown> {
+  let mut dangling = vec![];
+  let mut description: Option<(i32, &str)> = None;
+  for (ix, line) in schema.split('\n').enumerate() {
+    let line = line.trim_end_matches('\r');
+    if line.is_empty() { continue; }
+    let is_class = line.starts_with("//@class ");
+    if line.starts_with("//") && !is_class {
+      description = description.or(Some((ix as i32 + 1, line)));
+      continue;
+    }
+    if is_class || line.starts_with("---") {
+      if let Some((line, text)) = description.take() {
+        dangling.push(TLUnknown { line, text: text.to_string(), reason: "description of no declaration".to_string() });
+      }
+      continue;
+    }
+    description = None;
+  }
+  if let Some((line, text)) = description {
+    dangling.push(TLUnknown { line, text: text.to_string(), reason: "description of no declaration".to_string() });
+  }
+  dangling
+}
+
+/// the `@tag value` pairs of a description
+fn tags(text: &str) -> BTreeMap<String, String> {
+  let mut tags = BTreeMap::new();
+  let mut tag: Option<&str> = None;
+  let mut words = vec![];
+  for word in text.split(' ').filter(|word| !word.is_empty()) {
+    match word.strip_prefix('@') {
+      Some(next) => {
+        if let Some(tag) = tag { tags.insert(tag.to_string(), words.join(" ")); }
+        tag = Some(next);
+        words.clear();
+      }
+      None => words.push(word),
+    }
+  }
+  if let Some(tag) = tag { tags.insert(tag.to_string(), words.join(" ")); }
+  tags
+}
@@ -10,6 +10,10 @@ pub struct TLTokenArgType {
   #[builder(default)]
   pub(crate) description: Option<String>,
   pub(crate) components: Vec<TLTokenComponentType>,
+  /// deprecation note, the description of an argument documented as deprecated
+  #[builder(default)]
+  #[serde(default)]
+  pub(crate) deprecated: Option<String>,
 }
 
 /// token component type
@@ -45,7 +49,20 @@ pub struct TLTokenGroup {
   pub(crate) blood: Option<String>,
   /// when type is struct, this may be true to
   /// indicate this can be returned be a function
-  pub(crate) is_return_type: bool
+  pub(crate) is_return_type: bool,
+  /// deprecation note, the description of a group documented as deprecated
+  #[serde(default)]
+  pub(crate) deprecated: Option<String>,
+}
+
+/// a schema construct the tokens drop or misread
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TLUnknown {
+  /// line of the schema
+  pub(crate) line: i32,
+  /// text of the line
+  pub(crate) text: String,
+  pub(crate) reason: String,
 }
 
 impl TLTokenGroup {
@@ -56,6 +73,7 @@ impl TLTokenGroup {
   pub fn type_           (&self) -> TLTokenGroupType               { self.type_          .clone() }
   pub fn blood           (&self) -> Option<String>                 { self.blood          .clone() }
   pub fn is_return_type  (&self) -> bool                           { self.is_return_type .clone() }
+  pub fn deprecated      (&self) -> Option<String>                 { self.deprecated     .clone() }
 }
 
 impl TLTokenArgType {
@@ -63,6 +81,13 @@ impl TLTokenArgType {
   pub fn sign_type   (&self) -> String                     { self.sign_type  .clone() }
   pub fn description (&self) -> Option<String>             { self.description.clone() }
   pub fn components  (&self) -> Vec<TLTokenComponentType>  { self.components .clone() }
+  pub fn deprecated  (&self) -> Option<String>             { self.deprecated .clone() }
+}
+
+impl TLUnknown {
+  pub fn line        (&self) -> i32                        { self.line               }
+  pub fn text        (&self) -> &String                    { &self.text              }
+  pub fn reason      (&self) -> &String                    { &self.reason            }
 }
 
 impl TLTokenComponentType {
@@ -0,0 +1,27 @@
+use tl_parser::parser::parser::TLParser;
+use tl_parser::types::TLTokenComponentType;
+
+/// `vector<vector<int64>>` -> vector<int64>
+fn signature(components: Vec<TLTokenComponentType>) -> String {
+  let types: Vec<String> = components.into_iter()
+    .map(|component| match component.components() {
+      sub if sub.is_empty() => component.sign_type(),
+      sub => format!("{}<{}>", component.sign_type(), signature(sub)),
+    })
+    .collect();
+  types.join(",")
+}
+
+/// the generics are read at any depth, with many parameters
+#[test]
+fn test_nested_generic() {
+  let file_tl = std::env::temp_dir().join(format!("tl-generic-{}.tl", std::process::id()));
+  std::fs::write(&file_tl, "//@description A test @cube Cube @names Names\ntestType cube:vector<vector<vector<int64>>> names:map<string,vector<int32>> = TestType;\n").unwrap();
+  let tokens = TLParser::new(&file_tl).parse().unwrap();
+  std::fs::remove_file(&file_tl).unwrap();
+  let args = tokens[0].arguments();
+  assert_eq!(args[0].sign_type(), "vector");
+  assert_eq!(signature(args[0].components()), "vector<vector<int64>>");
+  assert_eq!(args[1].sign_type(), "map");
+  assert_eq!(signature(args[1].components()), "string,vector<int32>");
+}
@@ -0,0 +1,18 @@
+use std::path::Path;
+
+use tl_parser::parser::parser::TLParser;
+
+/// the master schema is understood, a new scalar and a tl annotation aren't
+#[test]
+fn test_unknowns() {
+  let unknowns = TLParser::new(Path::new("../").join("schema/master/td_api.tl")).unknowns().unwrap();
+  assert!(unknowns.is_empty(), "{:?}", unknowns);
+
+  let file_tl = std::env::temp_dir().join(format!("tl-unknown-{}.tl", std::process::id()));
+  std::fs::write(&file_tl, "int32 = Int32;\n\n//@description A test @id Id @flags Flags\ntestType id:int128 flags:# = TestType;\n").unwrap();
+  let unknowns = TLParser::new(&file_tl).unknowns().unwrap();
+  std::fs::remove_file(&file_tl).unwrap();
+  let reasons: Vec<&String> = unknowns.iter().map(|unknown| unknown.reason()).collect();
+  assert_eq!(reasons, vec!["field id has the type int128 not declared by the schema", "field flags has the tl annotation #"]);
+  assert_eq!(unknowns[0].line(), 4);
+}